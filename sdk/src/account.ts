@@ -212,4 +212,20 @@ export class Account {
     return this._address.verify(message, signature);
   }
 
+  /**
+   * Proves ownership of the account's address by signing a challenge issued by a verifier.
+   * Returns a proof string which can be checked with Address.verifyOwnership.
+   *
+   * @param {string} challenge
+   * @returns {string}
+   *
+   * @example
+   * const account = new Account();
+   * const proof = account.proveOwnership("withdrawal-42");
+   * account.address().verifyOwnership(proof, "withdrawal-42");
+   */
+  proveOwnership(challenge: string) {
+    return this._privateKey.proveOwnership(challenge);
+  }
+
 }
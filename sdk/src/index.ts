@@ -130,6 +130,14 @@ import {
     RecordProvider,
     RecordSearchParams,
 } from "./record-provider";
+import {
+    IndexedDBRecordStorage,
+    MemoryRecordStorage,
+    RecordStatus,
+    RecordStorage,
+    RecordStore,
+    StoredRecord,
+} from "./record-store";
 
 // @TODO: This function is no longer needed, remove it.
 async function initializeWasm() {
@@ -172,13 +180,19 @@ export {
     Execution,
     FunctionKeyPair,
     FunctionKeyProvider,
+    IndexedDBRecordStorage,
     Input,
     KeySearchParams,
+    MemoryRecordStorage,
     NetworkRecordProvider,
     ProgramImports,
     Output,
     RecordProvider,
     RecordSearchParams,
+    RecordStatus,
+    RecordStorage,
+    RecordStore,
+    StoredRecord,
     Transaction,
     Transition,
     CREDITS_PROGRAM_KEYS,
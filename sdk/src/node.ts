@@ -1,2 +1,3 @@
 import "./node-polyfill";
 export * from "./index";
+export { FileRecordStorage } from "./record-store-node";
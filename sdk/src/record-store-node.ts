@@ -0,0 +1,36 @@
+import * as $fs from "node:fs";
+import { RecordStorage, StoredRecord } from "./record-store";
+
+/**
+ * Record storage backed by a JSON file for use in node.
+ *
+ * @example
+ * const recordStore = new RecordStore(account, new FileRecordStorage("./records.json"));
+ */
+class FileRecordStorage implements RecordStorage {
+    path: string;
+
+    constructor(path: string) {
+        this.path = path;
+    }
+
+    async load(): Promise<StoredRecord[]> {
+        try {
+            return JSON.parse(await $fs.promises.readFile(this.path, "utf8"));
+        } catch (e: any) {
+            if (e.code === "ENOENT") {
+                return [];
+            }
+            throw e;
+        }
+    }
+
+    async save(records: StoredRecord[]): Promise<void> {
+        // Write to a temporary file first so that a crash cannot leave a partially written store
+        const temporaryPath = `${this.path}.tmp`;
+        await $fs.promises.writeFile(temporaryPath, JSON.stringify(records));
+        await $fs.promises.rename(temporaryPath, this.path);
+    }
+}
+
+export { FileRecordStorage };
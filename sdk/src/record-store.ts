@@ -0,0 +1,339 @@
+import { logAndThrow, RecordPlaintext } from "./index";
+import { Account } from "./account";
+import { RecordProvider, RecordSearchParams } from "./record-provider";
+
+/**
+ * Spend status of a stored record. Records are "pending" once they have been handed out for use in a transaction
+ * which has not yet been confirmed.
+ */
+type RecordStatus = "unspent" | "pending" | "spent";
+
+/**
+ * Record persisted by a RecordStore along with the information needed to query and spend it.
+ */
+interface StoredRecord {
+    record: string;
+    nonce: string;
+    programId: string;
+    recordName: string;
+    // Decimal string so that amounts above Number.MAX_SAFE_INTEGER survive storage and JSON serialization
+    microcredits: string;
+    status: RecordStatus;
+    height?: number;
+}
+
+/**
+ * Interface for the storage backend of a RecordStore. Implementations are provided for IndexedDB in the browser, the
+ * filesystem in node and memory for testing. A custom backend can be provided by implementing this interface.
+ */
+interface RecordStorage {
+    /**
+     * Load all stored records
+     *
+     * @returns {Promise<StoredRecord[]>} The stored records
+     */
+    load(): Promise<StoredRecord[]>;
+
+    /**
+     * Replace the stored records
+     *
+     * @param {StoredRecord[]} records The records to store
+     */
+    save(records: StoredRecord[]): Promise<void>;
+}
+
+/**
+ * Record storage which keeps records in memory. Records are lost when the process exits.
+ */
+class MemoryRecordStorage implements RecordStorage {
+    records: StoredRecord[] = [];
+
+    async load(): Promise<StoredRecord[]> {
+        return this.records.map(record => ({ ...record }));
+    }
+
+    async save(records: StoredRecord[]): Promise<void> {
+        this.records = records.map(record => ({ ...record }));
+    }
+}
+
+/**
+ * Record storage backed by IndexedDB for use in the browser.
+ *
+ * @example
+ * const recordStore = new RecordStore(account, new IndexedDBRecordStorage("aleo-records"));
+ */
+class IndexedDBRecordStorage implements RecordStorage {
+    databaseName: string;
+    storeName = "records";
+
+    constructor(databaseName: string) {
+        this.databaseName = databaseName;
+    }
+
+    async load(): Promise<StoredRecord[]> {
+        const database = await this.open();
+        return new Promise((resolve, reject) => {
+            const request = database.transaction(this.storeName, "readonly").objectStore(this.storeName).getAll();
+            request.onsuccess = () => resolve(<StoredRecord[]>request.result);
+            request.onerror = () => reject(request.error);
+        });
+    }
+
+    async save(records: StoredRecord[]): Promise<void> {
+        const database = await this.open();
+        return new Promise((resolve, reject) => {
+            const transaction = database.transaction(this.storeName, "readwrite");
+            const store = transaction.objectStore(this.storeName);
+            store.clear();
+            records.forEach(record => store.put(record));
+            transaction.oncomplete = () => resolve();
+            transaction.onerror = () => reject(transaction.error);
+        });
+    }
+
+    // Open the database, creating the record object store keyed by nonce if it does not exist
+    private open(): Promise<IDBDatabase> {
+        return new Promise((resolve, reject) => {
+            const request = globalThis.indexedDB.open(this.databaseName, 1);
+            request.onupgradeneeded = () => request.result.createObjectStore(this.storeName, { keyPath: "nonce" });
+            request.onsuccess = () => resolve(request.result);
+            request.onerror = () => reject(request.error);
+        });
+    }
+}
+
+/**
+ * A record provider which persists decrypted records and tracks whether they have been spent. Records handed out by
+ * the find methods are marked as pending so that concurrent transactions do not try to spend the same record. Once
+ * the transaction is confirmed the records should be marked as spent, or as unspent again if it failed.
+ *
+ * Every change loads, modifies and saves the stored records under a single lock, so concurrent calls on the same
+ * record store never overwrite each other's changes or hand out the same record twice. The lock does not extend to
+ * other record stores sharing the same storage.
+ *
+ * @example
+ * const recordStore = new RecordStore(account, new IndexedDBRecordStorage("aleo-records"));
+ * await recordStore.addRecord(record, "credits.aleo", "credits");
+ *
+ * // Query the unspent credits records with at least 5000 microcredits
+ * const records = await recordStore.unspentCreditsRecords(5000);
+ *
+ * // When the program manager is initialized with the record store it will automatically pull fee records and amount
+ * // records for value transfers from the store
+ * const programManager = new ProgramManager("https://vm.aleo.org/api", keyProvider, recordStore);
+ */
+class RecordStore implements RecordProvider {
+    account: Account;
+    storage: RecordStorage;
+    private lock: Promise<unknown> = Promise.resolve();
+
+    constructor(account: Account, storage: RecordStorage = new MemoryRecordStorage()) {
+        this.account = account;
+        this.storage = storage;
+    }
+
+    /**
+     * Add a decrypted record to the store. Records which are already stored are not overwritten.
+     *
+     * @param {RecordPlaintext | string} record The record to store
+     * @param {string} programId The program the record belongs to
+     * @param {string} recordName The name of the record type
+     * @param {number} height (optional) The height of the block containing the record
+     */
+    async addRecord(record: RecordPlaintext | string, programId: string, recordName: string, height?: number) {
+        const plaintext = record instanceof RecordPlaintext ? record : RecordPlaintext.fromString(record);
+        await this.update(records => {
+            if (records.some(stored => stored.nonce === plaintext.nonce())) {
+                return;
+            }
+            records.push({
+                record: plaintext.toString(),
+                nonce: plaintext.nonce(),
+                programId,
+                recordName,
+                microcredits: plaintext.microcredits().toString(),
+                status: "unspent",
+                height,
+            });
+        });
+    }
+
+    /**
+     * Mark records as pending, i.e. used by a transaction which has not been confirmed yet
+     *
+     * @param {string[]} nonces The nonces of the records
+     */
+    async markPending(nonces: string[]) {
+        await this.setStatus(nonces, "pending");
+    }
+
+    /**
+     * Mark records as spent
+     *
+     * @param {string[]} nonces The nonces of the records
+     */
+    async markSpent(nonces: string[]) {
+        await this.setStatus(nonces, "spent");
+    }
+
+    /**
+     * Mark records as unspent, e.g. when the transaction using them failed
+     *
+     * @param {string[]} nonces The nonces of the records
+     */
+    async markUnspent(nonces: string[]) {
+        await this.setStatus(nonces, "unspent");
+    }
+
+    /**
+     * Get all stored records
+     *
+     * @returns {Promise<StoredRecord[]>} The stored records
+     */
+    async records(): Promise<StoredRecord[]> {
+        return this.withLock(() => this.storage.load());
+    }
+
+    /**
+     * Get the unspent credits.aleo records holding at least a given number of microcredits, largest first
+     *
+     * @param {number | bigint} minMicrocredits The minimum number of microcredits a record must hold
+     * @returns {Promise<RecordPlaintext[]>} The matching records
+     */
+    async unspentCreditsRecords(minMicrocredits: number | bigint = 0): Promise<RecordPlaintext[]> {
+        const minimum = BigInt(minMicrocredits);
+        const records = await this.records();
+        return records
+            .filter(record => isCreditsRecord(record) && record.status === "unspent" && microcreditsOf(record) >= minimum)
+            .sort((a, b) => compareMicrocredits(b, a))
+            .map(record => RecordPlaintext.fromString(record.record));
+    }
+
+    /**
+     * Find unspent credits records holding at least each of the given amounts of microcredits and mark them pending
+     *
+     * @param {number[]} microcredits The number of microcredits to search for
+     * @param {boolean} unspent Whether to only search unspent records, pending records are searched as well if false.
+     * Spent records are never found
+     * @param {string[]} nonces Nonces of records already found so that they are not found again
+     * @param {RecordSearchParams} searchParameters Additional parameters to search for
+     * @returns {Promise<RecordPlaintext[] | Error>} The records if found, otherwise an error
+     */
+    async findCreditsRecords(microcredits: number[], unspent: boolean, nonces?: string[], searchParameters?: RecordSearchParams): Promise<RecordPlaintext[] | Error> {
+        const excluded = new Set(nonces || []);
+        // Select and mark the records pending under one lock so concurrent searches never find the same record
+        const found = await this.update(records => {
+            const candidates = records
+                .filter(record => isCreditsRecord(record) && isSelectable(record, unspent) && !excluded.has(record.nonce))
+                .sort(compareMicrocredits);
+
+            // Pick the smallest remaining record which covers each amount
+            const found: StoredRecord[] = [];
+            for (const amount of microcredits) {
+                const index = candidates.findIndex(record => microcreditsOf(record) >= BigInt(amount));
+                if (index < 0) {
+                    throw logAndThrow(`No unspent record with at least ${amount} microcredits found in the record store`);
+                }
+                found.push(...candidates.splice(index, 1));
+            }
+            found.forEach(record => record.status = "pending");
+            return found;
+        });
+        return found.map(record => RecordPlaintext.fromString(record.record));
+    }
+
+    /**
+     * Find an unspent credits record holding at least a given number of microcredits and mark it pending
+     *
+     * @param {number} microcredits The number of microcredits to search for
+     * @param {boolean} unspent Whether or not the record is unspent
+     * @param {string[]} nonces Nonces of records already found so that they are not found again
+     * @param {RecordSearchParams} searchParameters Additional parameters to search for
+     * @returns {Promise<RecordPlaintext | Error>} The record if found, otherwise an error
+     */
+    async findCreditsRecord(microcredits: number, unspent: boolean, nonces?: string[], searchParameters?: RecordSearchParams): Promise<RecordPlaintext | Error> {
+        const records = await this.findCreditsRecords([microcredits], unspent, nonces, searchParameters);
+        return (<RecordPlaintext[]>records)[0];
+    }
+
+    /**
+     * Find a stored record, optionally filtered by the programId and recordName search parameters
+     *
+     * @param {boolean} unspent Whether or not the record is unspent
+     * @param {string[]} nonces Nonces of records already found so that they are not found again
+     * @param {RecordSearchParams} searchParameters Additional parameters to search for
+     * @returns {Promise<RecordPlaintext | Error>} The record if found, otherwise an error
+     */
+    async findRecord(unspent: boolean, nonces?: string[], searchParameters?: RecordSearchParams): Promise<RecordPlaintext | Error> {
+        const records = <RecordPlaintext[]>await this.findRecords(unspent, nonces, searchParameters);
+        if (records.length === 0) {
+            throw logAndThrow("No matching record found in the record store");
+        }
+        return records[0];
+    }
+
+    /**
+     * Find stored records, optionally filtered by the programId and recordName search parameters
+     *
+     * @param {boolean} unspent Whether or not the records are unspent
+     * @param {string[]} nonces Nonces of records already found so that they are not found again
+     * @param {RecordSearchParams} searchParameters Additional parameters to search for
+     * @returns {Promise<RecordPlaintext[] | Error>} The matching records
+     */
+    async findRecords(unspent: boolean, nonces?: string[], searchParameters?: RecordSearchParams): Promise<RecordPlaintext[] | Error> {
+        const excluded = new Set(nonces || []);
+        return (await this.records())
+            .filter(record => (!unspent || record.status === "unspent") && !excluded.has(record.nonce))
+            .filter(record => !searchParameters?.programId || record.programId === searchParameters.programId)
+            .filter(record => !searchParameters?.recordName || record.recordName === searchParameters.recordName)
+            .map(record => RecordPlaintext.fromString(record.record));
+    }
+
+    // Set the status of the records with the given nonces
+    private async setStatus(nonces: string[], status: RecordStatus) {
+        await this.update(records => {
+            records.filter(record => nonces.includes(record.nonce)).forEach(record => record.status = status);
+        });
+    }
+
+    // Load the stored records, apply a change and save them under the lock. Nothing is saved if the change throws
+    private async update<T>(change: (records: StoredRecord[]) => T): Promise<T> {
+        return this.withLock(async () => {
+            const records = await this.storage.load();
+            const result = change(records);
+            await this.storage.save(records);
+            return result;
+        });
+    }
+
+    // Run an operation on the storage once every operation started before it has settled
+    private withLock<T>(operation: () => Promise<T>): Promise<T> {
+        const result = this.lock.then(operation);
+        this.lock = result.catch(() => undefined);
+        return result;
+    }
+}
+
+// Check whether a stored record is a credits.aleo credits record
+function isCreditsRecord(record: StoredRecord): boolean {
+    return record.programId === "credits.aleo" && record.recordName === "credits";
+}
+
+// Check whether a stored record can be selected to fund a transaction, spent records never can
+function isSelectable(record: StoredRecord, unspent: boolean): boolean {
+    return unspent ? record.status === "unspent" : record.status !== "spent";
+}
+
+// Get the number of microcredits held by a stored record
+function microcreditsOf(record: StoredRecord): bigint {
+    return BigInt(record.microcredits);
+}
+
+// Order stored records by the number of microcredits they hold, smallest first
+function compareMicrocredits(a: StoredRecord, b: StoredRecord): number {
+    const difference = microcreditsOf(a) - microcreditsOf(b);
+    return difference < 0n ? -1 : difference > 0n ? 1 : 0;
+}
+
+export { IndexedDBRecordStorage, MemoryRecordStorage, RecordStatus, RecordStorage, RecordStore, StoredRecord };
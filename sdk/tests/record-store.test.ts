@@ -0,0 +1,60 @@
+import {Account, MemoryRecordStorage, RecordStore} from "../src/node";
+import {beaconPrivateKeyString, recordPlaintextString} from "./data/account-data";
+
+describe('RecordStore', () => {
+    let recordStore: RecordStore;
+    beforeEach(() => {
+        recordStore = new RecordStore(new Account({privateKey: beaconPrivateKeyString}), new MemoryRecordStorage());
+    });
+
+    describe('Record store', () => {
+        it('should find unspent credits records and mark them pending', async () => {
+            await recordStore.addRecord(recordPlaintextString, "credits.aleo", "credits", 10);
+            // Adding the same record twice should not duplicate it
+            await recordStore.addRecord(recordPlaintextString, "credits.aleo", "credits", 10);
+            expect((await recordStore.records()).length).toEqual(1);
+            expect((await recordStore.unspentCreditsRecords(5000)).length).toEqual(1);
+            expect((await recordStore.unspentCreditsRecords(2000000000000000)).length).toEqual(0);
+
+            const record = await recordStore.findCreditsRecord(5000, true, []);
+            expect(record.toString()).toEqual(recordPlaintextString);
+            expect((await recordStore.records())[0].status).toEqual("pending");
+            await expect(recordStore.findCreditsRecord(5000, true, [])).rejects.toBeDefined();
+
+            // Pending records can still be found when not restricted to unspent records
+            expect((await recordStore.findCreditsRecord(5000, false, [])).toString()).toEqual(recordPlaintextString);
+
+            await recordStore.markSpent([(await recordStore.records())[0].nonce]);
+            expect((await recordStore.records())[0].status).toEqual("spent");
+            expect((await recordStore.unspentCreditsRecords()).length).toEqual(0);
+
+            // Spent records are never found or marked pending again
+            await expect(recordStore.findCreditsRecord(5000, false, [])).rejects.toBeDefined();
+            expect((await recordStore.records())[0].status).toEqual("spent");
+        });
+
+        it('should store amounts as strings and serialize concurrent updates', async () => {
+            await Promise.all([
+                recordStore.addRecord(recordPlaintextString, "credits.aleo", "credits"),
+                recordStore.addRecord(recordPlaintextString, "credits.aleo", "credits"),
+            ]);
+            const records = await recordStore.records();
+            expect(records.length).toEqual(1);
+            expect(typeof records[0].microcredits).toEqual("string");
+
+            // Only one of two concurrent searches can take the single record
+            const results = await Promise.allSettled([
+                recordStore.findCreditsRecord(5000, true, []),
+                recordStore.findCreditsRecord(5000, true, []),
+            ]);
+            expect(results.filter(result => result.status === "fulfilled").length).toEqual(1);
+            expect((await recordStore.records())[0].status).toEqual("pending");
+        });
+
+        it('should filter records by program and record name', async () => {
+            await recordStore.addRecord(recordPlaintextString, "credits.aleo", "credits");
+            expect((<object[]>await recordStore.findRecords(true, [], {programId: "credits.aleo"})).length).toEqual(1);
+            expect((<object[]>await recordStore.findRecords(true, [], {programId: "token.aleo"})).length).toEqual(0);
+        });
+    });
+});
@@ -15,13 +15,20 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    account::{PrivateKey, Signature, ViewKey},
-    types::AddressNative,
+    account::{Encryptor, PrivateKey, Signature, ViewKey},
+    types::{AddressNative, FromBytes, ToBytes},
 };
 
 use core::{convert::TryFrom, fmt, ops::Deref, str::FromStr};
+use rand::{rngs::StdRng, SeedableRng};
 use wasm_bindgen::prelude::*;
 
+/// Number of bytes in the byte representation of an address
+const ADDRESS_SIZE_IN_BYTES: usize = 32;
+
+/// Prefix of every payload signed by an address ownership proof
+const OWNERSHIP_PROOF_PREFIX: &[u8] = b"Aleo Ownership Proof:\n";
+
 /// Public address of an Aleo account
 #[wasm_bindgen]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -53,6 +60,29 @@ impl Address {
         Self::from_str(address).unwrap()
     }
 
+    /// Get an address from its little-endian byte representation
+    ///
+    /// @param {Uint8Array} bytes 32 byte long Uint8Array representation of an address
+    /// @returns {Address | Error} Address
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Address, String> {
+        if bytes.len() != ADDRESS_SIZE_IN_BYTES {
+            return Err(format!(
+                "Expected {ADDRESS_SIZE_IN_BYTES} bytes for an address, but {} bytes were provided",
+                bytes.len()
+            ));
+        }
+        Ok(Self(AddressNative::from_bytes_le(bytes).map_err(|_| "Invalid address bytes".to_string())?))
+    }
+
+    /// Get the little-endian byte representation of an address
+    ///
+    /// @returns {Uint8Array | Error} 32 byte long Uint8Array representation of an address
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        self.0.to_bytes_le().map_err(|_| "Failed to serialize address".to_string())
+    }
+
     /// Get a string representation of an Aleo address object
     ///
     /// @param {Address} Address
@@ -69,6 +99,55 @@ impl Address {
     pub fn verify(&self, message: &[u8], signature: &Signature) -> bool {
         signature.verify(self, message)
     }
+
+    /// Verify a proof that the owner of the address signed a challenge, as produced by
+    /// `PrivateKey.proveOwnership`. Challenges should be random and only accepted once by the
+    /// verifier so a proof cannot be replayed
+    ///
+    /// @param {string} proof String representation of the ownership proof
+    /// @param {string} challenge Challenge the proof was created for
+    /// @returns {boolean} Boolean representing whether or not the proof is valid
+    #[wasm_bindgen(js_name = verifyOwnership)]
+    pub fn verify_ownership(&self, proof: &str, challenge: &str) -> bool {
+        match Signature::from_str(proof) {
+            Ok(signature) if !challenge.is_empty() => signature.verify(self, &Self::ownership_payload(self, challenge)),
+            _ => false,
+        }
+    }
+}
+
+impl Address {
+    // Encode the payload signed by an ownership proof, binding the challenge to the address
+    pub(crate) fn ownership_payload(address: &Address, challenge: &str) -> Vec<u8> {
+        let address = address.to_string();
+        let mut payload = OWNERSHIP_PROOF_PREFIX.to_vec();
+        for field in [address.as_bytes(), challenge.as_bytes()] {
+            // Length prefix each field so the encoding is unambiguous.
+            payload.extend_from_slice(&(field.len() as u64).to_le_bytes());
+            payload.extend_from_slice(field);
+        }
+        payload
+    }
+}
+
+/// Encrypt arbitrary bytes to the owner of an address, e.g. to send a private payload to the owner
+/// of a record off-chain. Only the view key of the address can decrypt the ciphertext
+///
+/// @param {Address} address Address of the recipient
+/// @param {Uint8Array} bytes The bytes to encrypt
+/// @returns {string | Error} String representation of the ciphertext, in the form
+/// `<ephemeral public key>$<ciphertext>$<ciphertext>...` like `PrivateKey.encryptToAddress`
+#[wasm_bindgen(js_name = encryptForAddress)]
+pub fn encrypt_for_address(address: &Address, bytes: &[u8]) -> Result<String, String> {
+    let (ephemeral, ciphertexts) = Encryptor::encrypt_bytes_to_address(bytes, address, &mut StdRng::from_entropy())
+        .map_err(|_| "Encryption failed".to_string())?;
+    Ok(ciphertexts.iter().fold(ephemeral.to_string(), |encrypted, ciphertext| format!("{encrypted}${ciphertext}")))
+}
+
+impl From<AddressNative> for Address {
+    fn from(address: AddressNative) -> Self {
+        Self(address)
+    }
 }
 
 impl FromStr for Address {
@@ -114,4 +193,40 @@ mod tests {
             assert_eq!(expected, Address::from_view_key(&view_key));
         }
     }
+
+    #[wasm_bindgen_test]
+    pub fn test_to_and_from_bytes() {
+        for _ in 0..ITERATIONS {
+            // Sample a new address.
+            let expected = PrivateKey::new().to_address();
+
+            // Check the byte representation round trips.
+            let bytes = expected.to_bytes().unwrap();
+            assert_eq!(bytes.len(), ADDRESS_SIZE_IN_BYTES);
+            assert_eq!(expected, Address::from_bytes(&bytes).unwrap());
+        }
+
+        // Ensure byte arrays of the wrong length are rejected.
+        let bytes = PrivateKey::new().to_address().to_bytes().unwrap();
+        assert!(Address::from_bytes(&bytes[..31]).is_err());
+        assert!(Address::from_bytes(&[bytes.as_slice(), &[0u8]].concat()).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_verify_ownership() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address();
+        let proof = private_key.prove_ownership("withdrawal-42").unwrap();
+        assert!(address.verify_ownership(&proof, "withdrawal-42"));
+
+        // Ensure proofs for other challenges or addresses are rejected.
+        assert!(!address.verify_ownership(&proof, "withdrawal-43"));
+        assert!(!PrivateKey::new().to_address().verify_ownership(&proof, "withdrawal-42"));
+
+        // Ensure a plain signature of the challenge is not accepted as a proof.
+        let signature = private_key.sign(b"withdrawal-42").to_string();
+        assert!(!address.verify_ownership(&signature, "withdrawal-42"));
+        assert!(!address.verify_ownership("invalid", "withdrawal-42"));
+        assert!(private_key.prove_ownership("").is_err());
+    }
 }
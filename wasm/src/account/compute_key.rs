@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    account::{Address, PrivateKey},
+    types::{ComputeKeyNative, FromBytes, ToBytes},
+};
+
+use core::{convert::TryFrom, ops::Deref};
+use wasm_bindgen::prelude::*;
+
+/// Number of bytes in the byte representation of a compute key
+const COMPUTE_KEY_SIZE_IN_BYTES: usize = 64;
+
+/// Compute key of an Aleo account, which can authorize computations without the signature secret
+#[wasm_bindgen]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct ComputeKey(ComputeKeyNative);
+
+#[wasm_bindgen]
+impl ComputeKey {
+    /// Derive a compute key from a private key
+    ///
+    /// @param {PrivateKey} private_key Private key
+    /// @returns {ComputeKey} Compute key
+    #[wasm_bindgen(js_name = fromPrivateKey)]
+    pub fn from_private_key(private_key: &PrivateKey) -> Self {
+        Self(ComputeKeyNative::try_from(**private_key).unwrap())
+    }
+
+    /// Get the signature public key `pk_sig` of the compute key
+    ///
+    /// @returns {string} String representation of the pk_sig group element
+    #[wasm_bindgen(js_name = pkSig)]
+    pub fn pk_sig(&self) -> String {
+        self.0.pk_sig().to_string()
+    }
+
+    /// Get the signature public randomizer `pr_sig` of the compute key
+    ///
+    /// @returns {string} String representation of the pr_sig group element
+    #[wasm_bindgen(js_name = prSig)]
+    pub fn pr_sig(&self) -> String {
+        self.0.pr_sig().to_string()
+    }
+
+    /// Get the PRF secret key `sk_prf` of the compute key
+    ///
+    /// @returns {string} String representation of the sk_prf scalar
+    #[wasm_bindgen(js_name = skPrf)]
+    pub fn sk_prf(&self) -> String {
+        self.0.sk_prf().to_string()
+    }
+
+    /// Get the address corresponding to the compute key
+    ///
+    /// @returns {Address} Address
+    #[wasm_bindgen(js_name = toAddress)]
+    pub fn to_address(&self) -> Address {
+        Address::from(self.0.to_address())
+    }
+
+    /// Get a compute key from its little-endian byte representation
+    ///
+    /// @param {Uint8Array} bytes 64 byte long Uint8Array representation of a compute key
+    /// @returns {ComputeKey | Error} Compute key
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<ComputeKey, String> {
+        if bytes.len() != COMPUTE_KEY_SIZE_IN_BYTES {
+            return Err(format!(
+                "Expected {COMPUTE_KEY_SIZE_IN_BYTES} bytes for a compute key, but {} bytes were provided",
+                bytes.len()
+            ));
+        }
+        Ok(Self(ComputeKeyNative::from_bytes_le(bytes).map_err(|_| "Invalid compute key bytes".to_string())?))
+    }
+
+    /// Get the little-endian byte representation of a compute key
+    ///
+    /// @returns {Uint8Array | Error} 64 byte long Uint8Array representation of a compute key
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        self.0.to_bytes_le().map_err(|_| "Failed to serialize compute key".to_string())
+    }
+}
+
+impl From<ComputeKeyNative> for ComputeKey {
+    fn from(compute_key: ComputeKeyNative) -> Self {
+        Self(compute_key)
+    }
+}
+
+impl Deref for ComputeKey {
+    type Target = ComputeKeyNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_from_private_key() {
+        let private_key = PrivateKey::new();
+        let compute_key = ComputeKey::from_private_key(&private_key);
+        assert_eq!(compute_key.to_address(), private_key.to_address());
+
+        // Check the byte representation round trips.
+        let bytes = compute_key.to_bytes().unwrap();
+        assert_eq!(bytes.len(), COMPUTE_KEY_SIZE_IN_BYTES);
+        assert!(compute_key == ComputeKey::from_bytes(&bytes).unwrap());
+        assert!(ComputeKey::from_bytes(&bytes[..32]).is_err());
+    }
+}
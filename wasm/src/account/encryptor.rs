@@ -15,19 +15,151 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::types::{
+    AddressNative,
     CiphertextNative,
     CurrentNetwork,
+    Environment,
     FieldNative,
+    GroupNative,
     IdentifierNative,
     LiteralNative,
     Network,
     PlaintextNative,
+    PrimeField,
     PrivateKeyNative,
+    ScalarNative,
+    ToBytes,
     Uniform,
+    ViewKeyNative,
 };
 
+use argon2::{Algorithm, Argon2, Params, Version};
 use once_cell::sync::OnceCell;
-use std::str::FromStr;
+use rand::{rngs::StdRng, CryptoRng, Rng, RngCore, SeedableRng};
+use std::{fmt, str::FromStr};
+
+/// Maximum Argon2id memory cost in KiB, four times the OWASP recommended minimum
+const MAX_ARGON2ID_MEMORY_KIB: u32 = 4 * Params::DEFAULT_M_COST;
+/// Maximum number of Argon2id iterations, four times the OWASP recommended minimum
+const MAX_ARGON2ID_ITERATIONS: u32 = 4 * Params::DEFAULT_T_COST;
+/// Maximum degree of Argon2id parallelism
+const MAX_ARGON2ID_PARALLELISM: u32 = 4;
+
+/// Key derivation function used to derive an encryption key from a secret
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kdf {
+    /// Hash the secret directly into a field element
+    Poseidon,
+    /// Derive the key using the memory-hard Argon2id function
+    Argon2id {
+        /// Memory cost in KiB
+        memory_kib: u32,
+        /// Number of iterations
+        iterations: u32,
+        /// Degree of parallelism
+        parallelism: u32,
+        /// Random salt
+        salt: [u8; 16],
+    },
+}
+
+impl Kdf {
+    /// Create Argon2id parameters with a fresh random salt. Unspecified costs default to the
+    /// OWASP recommended minimums
+    pub fn argon2id(
+        memory_kib: Option<u32>,
+        iterations: Option<u32>,
+        parallelism: Option<u32>,
+    ) -> Result<Self, String> {
+        let memory_kib = memory_kib.unwrap_or(Params::DEFAULT_M_COST);
+        let iterations = iterations.unwrap_or(Params::DEFAULT_T_COST);
+        let parallelism = parallelism.unwrap_or(Params::DEFAULT_P_COST);
+        // Ensure the parameters are valid before any encryption takes place.
+        Self::check_argon2id_costs(memory_kib, iterations, parallelism)?;
+        let mut salt = [0u8; 16];
+        StdRng::from_entropy().fill_bytes(&mut salt);
+        Ok(Self::Argon2id { memory_kib, iterations, parallelism, salt })
+    }
+
+    /// Check Argon2id costs are valid and within the maximums, so that a ciphertext header can't
+    /// make key derivation exhaust memory or time
+    fn check_argon2id_costs(memory_kib: u32, iterations: u32, parallelism: u32) -> Result<(), String> {
+        if memory_kib > MAX_ARGON2ID_MEMORY_KIB
+            || iterations > MAX_ARGON2ID_ITERATIONS
+            || parallelism > MAX_ARGON2ID_PARALLELISM
+        {
+            return Err(format!(
+                "Argon2id parameters exceed the maximums of m={MAX_ARGON2ID_MEMORY_KIB},t={MAX_ARGON2ID_ITERATIONS},p={MAX_ARGON2ID_PARALLELISM}"
+            ));
+        }
+        Params::new(memory_kib, iterations, parallelism, None)
+            .map_err(|e| format!("Invalid Argon2id parameters: {e}"))?;
+        Ok(())
+    }
+
+    /// Derive the field element used as the encryption key from a secret
+    fn derive_key(&self, secret: &str) -> Result<FieldNative, String> {
+        match self {
+            Self::Poseidon => Ok(FieldNative::new_domain_separator(secret)),
+            Self::Argon2id { memory_kib, iterations, parallelism, salt } => {
+                let params = Params::new(*memory_kib, *iterations, *parallelism, Some(32))
+                    .map_err(|e| format!("Invalid Argon2id parameters: {e}"))?;
+                let mut key = [0u8; 32];
+                Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+                    .hash_password_into(secret.as_bytes(), salt, &mut key)
+                    .map_err(|e| format!("Argon2id key derivation failed: {e}"))?;
+                Ok(FieldNative::new(<CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(&key)))
+            }
+        }
+    }
+}
+
+/// The header format is `$argon2id$v=19$m=<memory_kib>,t=<iterations>,p=<parallelism>$<salt hex>`.
+/// Poseidon derived ciphertexts have no header for compatibility with existing ciphertexts.
+impl fmt::Display for Kdf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Poseidon => Ok(()),
+            Self::Argon2id { memory_kib, iterations, parallelism, salt } => {
+                write!(f, "$argon2id$v=19$m={memory_kib},t={iterations},p={parallelism}${}", hex::encode(salt))
+            }
+        }
+    }
+}
+
+impl FromStr for Kdf {
+    type Err = String;
+
+    fn from_str(header: &str) -> Result<Self, Self::Err> {
+        if header.is_empty() {
+            return Ok(Self::Poseidon);
+        }
+        let invalid = || "Invalid key derivation header".to_string();
+        let mut sections = header.strip_prefix('$').ok_or_else(invalid)?.split('$');
+        if sections.next() != Some("argon2id") || sections.next() != Some("v=19") {
+            return Err("Unsupported key derivation function".to_string());
+        }
+        let mut costs = sections.next().ok_or_else(invalid)?.split(',');
+        let mut cost = |prefix: &str| -> Result<u32, String> {
+            costs
+                .next()
+                .and_then(|cost| cost.strip_prefix(prefix))
+                .and_then(|cost| cost.parse().ok())
+                .ok_or_else(invalid)
+        };
+        let (memory_kib, iterations, parallelism) = (cost("m=")?, cost("t=")?, cost("p=")?);
+        Self::check_argon2id_costs(memory_kib, iterations, parallelism)?;
+        let salt = hex::decode(sections.next().ok_or_else(invalid)?).map_err(|_| invalid())?;
+        let salt = salt.try_into().map_err(|_| invalid())?;
+        if sections.next().is_some() {
+            return Err(invalid());
+        }
+        Ok(Self::Argon2id { memory_kib, iterations, parallelism, salt })
+    }
+}
+
+/// Number of bytes packed into each field element of a byte ciphertext
+const BYTES_PER_FIELD: usize = 31;
 
 /// Tool for encrypting and decrypting Aleo key material into ciphertext
 pub struct Encryptor;
@@ -38,7 +170,7 @@ impl Encryptor {
         private_key: &PrivateKeyNative,
         secret: &str,
     ) -> Result<CiphertextNative, String> {
-        Self::encrypt_field(&private_key.seed(), secret, "private_key")
+        Self::encrypt_private_key_with_kdf(private_key, secret, &Kdf::Poseidon)
     }
 
     /// Decrypt a private key from ciphertext using a secret
@@ -46,19 +178,150 @@ impl Encryptor {
         ciphertext: &CiphertextNative,
         secret: &str,
     ) -> Result<PrivateKeyNative, String> {
-        let seed = Self::decrypt_field(ciphertext, secret, "private_key")?;
+        Self::decrypt_private_key_with_kdf(ciphertext, secret, &Kdf::Poseidon)
+    }
+
+    /// Encrypt a private key into ciphertext using a key derived from a secret
+    pub(crate) fn encrypt_private_key_with_kdf(
+        private_key: &PrivateKeyNative,
+        secret: &str,
+        kdf: &Kdf,
+    ) -> Result<CiphertextNative, String> {
+        Self::encrypt_private_key_with_rng(private_key, secret, kdf, &mut rand::thread_rng())
+    }
+
+    /// Encrypt a private key into ciphertext using a key derived from a secret and a nonce sampled
+    /// from the given rng
+    pub(crate) fn encrypt_private_key_with_rng<R: Rng + CryptoRng>(
+        private_key: &PrivateKeyNative,
+        secret: &str,
+        kdf: &Kdf,
+        rng: &mut R,
+    ) -> Result<CiphertextNative, String> {
+        Self::encrypt_field(&private_key.seed(), kdf.derive_key(secret)?, "private_key", rng)
+    }
+
+    /// Decrypt a private key from ciphertext using a key derived from a secret
+    pub(crate) fn decrypt_private_key_with_kdf(
+        ciphertext: &CiphertextNative,
+        secret: &str,
+        kdf: &Kdf,
+    ) -> Result<PrivateKeyNative, String> {
+        let seed = Self::decrypt_field(ciphertext, kdf.derive_key(secret)?, "private_key")?;
+        PrivateKeyNative::try_from(seed).map_err(|e| e.to_string())
+    }
+
+    /// Encrypt a private key to the owner of an address. An ephemeral key pair is sampled and the
+    /// private key is encrypted with its Diffie-Hellman shared secret with the address, so that only
+    /// the holder of the corresponding view key can decrypt it. Returns the ephemeral public key
+    /// alongside the ciphertext
+    pub(crate) fn encrypt_private_key_to_address<R: Rng + CryptoRng>(
+        private_key: &PrivateKeyNative,
+        address: &AddressNative,
+        rng: &mut R,
+    ) -> Result<(GroupNative, CiphertextNative), String> {
+        let (ephemeral, secret) = Self::shared_secret_with_address(address, rng);
+        let ciphertext = Self::encrypt_field(&private_key.seed(), secret, "private_key_to_address", rng)?;
+        Ok((ephemeral, ciphertext))
+    }
+
+    /// Decrypt a private key encrypted to an address using the view key of the address
+    pub(crate) fn decrypt_private_key_with_view_key(
+        ephemeral: &GroupNative,
+        ciphertext: &CiphertextNative,
+        view_key: &ViewKeyNative,
+    ) -> Result<PrivateKeyNative, String> {
+        let secret = Self::shared_secret_with_view_key(ephemeral, view_key);
+        let seed = Self::decrypt_field(ciphertext, secret, "private_key_to_address")?;
         PrivateKeyNative::try_from(seed).map_err(|e| e.to_string())
     }
 
+    /// Encrypt arbitrary bytes to the owner of an address with the same construction as private
+    /// keys. The bytes are packed into field elements behind their length and followed by a
+    /// checksum keyed with the shared secret, and each field is encrypted like a private key seed.
+    /// Returns the ephemeral public key alongside the ciphertexts of the fields
+    pub(crate) fn encrypt_bytes_to_address<R: Rng + CryptoRng>(
+        bytes: &[u8],
+        address: &AddressNative,
+        rng: &mut R,
+    ) -> Result<(GroupNative, Vec<CiphertextNative>), String> {
+        let (ephemeral, secret) = Self::shared_secret_with_address(address, rng);
+        let mut fields = vec![FieldNative::from_u64(bytes.len() as u64)];
+        fields.extend(
+            bytes
+                .chunks(BYTES_PER_FIELD)
+                .map(|chunk| FieldNative::new(<CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(chunk))),
+        );
+        fields.push(Self::checksum(secret, &fields)?);
+        let ciphertexts = fields
+            .iter()
+            .map(|field| Self::encrypt_field(field, secret, "bytes_to_address", rng))
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok((ephemeral, ciphertexts))
+    }
+
+    /// Decrypt bytes encrypted to an address using the view key of the address
+    pub(crate) fn decrypt_bytes_with_view_key(
+        ephemeral: &GroupNative,
+        ciphertexts: &[CiphertextNative],
+        view_key: &ViewKeyNative,
+    ) -> Result<Vec<u8>, String> {
+        let secret = Self::shared_secret_with_view_key(ephemeral, view_key);
+        let mut fields = ciphertexts
+            .iter()
+            .map(|ciphertext| Self::decrypt_field(ciphertext, secret, "bytes_to_address"))
+            .collect::<Result<Vec<_>, String>>()?;
+        let checksum = fields.pop();
+        if fields.is_empty() || checksum != Some(Self::checksum(secret, &fields)?) {
+            return Err("The ciphertext was not encrypted to this view key or was modified".to_string());
+        }
+
+        let length = u64::from_le_bytes(fields[0].to_bytes_le().map_err(|e| e.to_string())?[..8].try_into().unwrap());
+        let mut bytes = Vec::with_capacity(fields.len() * BYTES_PER_FIELD);
+        for field in &fields[1..] {
+            bytes.extend_from_slice(&field.to_bytes_le().map_err(|e| e.to_string())?[..BYTES_PER_FIELD]);
+        }
+        if length > bytes.len() as u64 || bytes.len() as u64 - length >= BYTES_PER_FIELD as u64 {
+            return Err("Invalid plaintext length".to_string());
+        }
+        bytes.truncate(length as usize);
+        Ok(bytes)
+    }
+
+    // Sample an ephemeral key pair and derive its Diffie-Hellman shared secret with an address
+    fn shared_secret_with_address<R: Rng + CryptoRng>(
+        address: &AddressNative,
+        rng: &mut R,
+    ) -> (GroupNative, FieldNative) {
+        let randomizer = ScalarNative::rand(rng);
+        let ephemeral = GroupNative::generator() * randomizer;
+        (ephemeral, (**address * randomizer).to_x_coordinate())
+    }
+
+    // Derive the Diffie-Hellman shared secret of an ephemeral public key and a view key
+    fn shared_secret_with_view_key(ephemeral: &GroupNative, view_key: &ViewKeyNative) -> FieldNative {
+        (*ephemeral * **view_key).to_x_coordinate()
+    }
+
+    // Checksum of the fields of a byte plaintext, which detects reordered, truncated or modified ciphertexts
+    fn checksum(secret: FieldNative, fields: &[FieldNative]) -> Result<FieldNative, String> {
+        let domain = FieldNative::new_domain_separator("bytes_to_address_checksum");
+        let input = [domain, secret].into_iter().chain(fields.iter().copied()).collect::<Vec<_>>();
+        CurrentNetwork::hash_psd4(&input).map_err(|e| e.to_string())
+    }
+
     // Encrypted a field element into a ciphertext representation
-    fn encrypt_field(field: &FieldNative, secret: &str, domain: &str) -> Result<CiphertextNative, String> {
-        // Derive the domain separators and the secret.
+    fn encrypt_field<R: Rng + CryptoRng>(
+        field: &FieldNative,
+        secret: FieldNative,
+        domain: &str,
+        rng: &mut R,
+    ) -> Result<CiphertextNative, String> {
+        // Derive the domain separators.
         let domain = FieldNative::new_domain_separator(domain);
-        let secret = FieldNative::new_domain_separator(secret);
 
         // Generate a nonce
-        let mut rng = rand::thread_rng();
-        let nonce = Uniform::rand(&mut rng);
+        let nonce = Uniform::rand(rng);
 
         // Derive a blinding factor and create an encryption target
         let blinding = CurrentNetwork::hash_psd2(&[domain, nonce, secret]).map_err(|e| e.to_string())?;
@@ -80,9 +343,8 @@ impl Encryptor {
     }
 
     // Recover a field element encrypted within ciphertext
-    fn decrypt_field(ciphertext: &CiphertextNative, secret: &str, domain: &str) -> Result<FieldNative, String> {
+    fn decrypt_field(ciphertext: &CiphertextNative, secret: FieldNative, domain: &str) -> Result<FieldNative, String> {
         let domain = FieldNative::new_domain_separator(domain);
-        let secret = FieldNative::new_domain_separator(secret);
         let decrypted = ciphertext.decrypt_symmetric(secret).map_err(|e| e.to_string())?;
         let recovered_key = Self::extract_value(&decrypted, "key")?;
         let recovered_nonce = Self::extract_value(&decrypted, "nonce")?;
@@ -172,4 +434,44 @@ mod tests {
         let recovered_key_2 = Encryptor::decrypt_private_key_with_secret(&enc2, "mypassword").unwrap();
         assert_ne!(recovered_key_1, recovered_key_2);
     }
+
+    #[wasm_bindgen_test]
+    fn test_encryptor_encrypt_to_address() {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKeyNative::new(&mut rng).unwrap();
+        let recipient = PrivateKeyNative::new(&mut rng).unwrap();
+        let view_key = ViewKeyNative::try_from(recipient).unwrap();
+        let address = AddressNative::try_from(recipient).unwrap();
+        let (ephemeral, enc) = Encryptor::encrypt_private_key_to_address(&private_key, &address, &mut rng).unwrap();
+
+        // Assert that only the view key of the recipient can decrypt the ciphertext
+        let recovered_key = Encryptor::decrypt_private_key_with_view_key(&ephemeral, &enc, &view_key).unwrap();
+        assert_eq!(private_key, recovered_key);
+        let other_view_key = ViewKeyNative::try_from(PrivateKeyNative::new(&mut rng).unwrap()).unwrap();
+        assert!(Encryptor::decrypt_private_key_with_view_key(&ephemeral, &enc, &other_view_key).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encryptor_encrypt_bytes_to_address() {
+        let mut rng = TestRng::default();
+        let recipient = PrivateKeyNative::new(&mut rng).unwrap();
+        let view_key = ViewKeyNative::try_from(recipient).unwrap();
+        let address = AddressNative::try_from(recipient).unwrap();
+
+        for length in [0, 1, 30, 31, 32, 100] {
+            let bytes = (0..length).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let (ephemeral, enc) = Encryptor::encrypt_bytes_to_address(&bytes, &address, &mut rng).unwrap();
+            assert_eq!(Encryptor::decrypt_bytes_with_view_key(&ephemeral, &enc, &view_key).unwrap(), bytes);
+        }
+
+        // Assert that only the view key of the recipient can decrypt the ciphertext and that reordered
+        // or truncated ciphertexts are rejected
+        let (ephemeral, enc) = Encryptor::encrypt_bytes_to_address(&[7u8; 40], &address, &mut rng).unwrap();
+        let other_view_key = ViewKeyNative::try_from(PrivateKeyNative::new(&mut rng).unwrap()).unwrap();
+        assert!(Encryptor::decrypt_bytes_with_view_key(&ephemeral, &enc, &other_view_key).is_err());
+        let reordered = [enc[0].clone(), enc[2].clone(), enc[1].clone(), enc[3].clone()];
+        assert!(Encryptor::decrypt_bytes_with_view_key(&ephemeral, &reordered, &view_key).is_err());
+        assert!(Encryptor::decrypt_bytes_with_view_key(&ephemeral, &enc[..3], &view_key).is_err());
+        assert!(Encryptor::decrypt_bytes_with_view_key(&ephemeral, &[], &view_key).is_err());
+    }
 }
@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    account::{PrivateKey, ViewKey},
+    types::{FromBytes, GraphKeyNative, ToBytes},
+};
+
+use core::{convert::TryFrom, fmt, ops::Deref, str::FromStr};
+use wasm_bindgen::prelude::*;
+
+/// Number of bytes in the byte representation of a graph key
+const GRAPH_KEY_SIZE_IN_BYTES: usize = 32;
+
+/// Graph key of an Aleo account, which is used to compute the tags of the records of the account
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GraphKey(GraphKeyNative);
+
+#[wasm_bindgen]
+impl GraphKey {
+    /// Derive a graph key from a view key
+    ///
+    /// @param {ViewKey} view_key View key
+    /// @returns {GraphKey} Graph key
+    #[wasm_bindgen(js_name = fromViewKey)]
+    pub fn from_view_key(view_key: &ViewKey) -> Self {
+        Self(GraphKeyNative::try_from(**view_key).unwrap())
+    }
+
+    /// Derive a graph key from a private key
+    ///
+    /// @param {PrivateKey} private_key Private key
+    /// @returns {GraphKey} Graph key
+    #[wasm_bindgen(js_name = fromPrivateKey)]
+    pub fn from_private_key(private_key: &PrivateKey) -> Self {
+        Self::from_view_key(&ViewKey::from_private_key(private_key))
+    }
+
+    /// Create a graph key from a string representation of a graph key
+    ///
+    /// @param {string} graph_key String representation of a graph key
+    /// @returns {GraphKey | Error} Graph key
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(graph_key: &str) -> Result<GraphKey, String> {
+        Self::from_str(graph_key).map_err(|_| "Invalid graph key".to_string())
+    }
+
+    /// Get a string representation of a graph key
+    ///
+    /// @returns {string} String representation of a graph key
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Get the tag secret key `sk_tag` of the graph key
+    ///
+    /// @returns {string} String representation of the sk_tag field element
+    #[wasm_bindgen(js_name = skTag)]
+    pub fn sk_tag(&self) -> String {
+        self.0.sk_tag().to_string()
+    }
+
+    /// Get a graph key from its little-endian byte representation
+    ///
+    /// @param {Uint8Array} bytes 32 byte long Uint8Array representation of a graph key
+    /// @returns {GraphKey | Error} Graph key
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<GraphKey, String> {
+        if bytes.len() != GRAPH_KEY_SIZE_IN_BYTES {
+            return Err(format!(
+                "Expected {GRAPH_KEY_SIZE_IN_BYTES} bytes for a graph key, but {} bytes were provided",
+                bytes.len()
+            ));
+        }
+        Ok(Self(GraphKeyNative::from_bytes_le(bytes).map_err(|_| "Invalid graph key bytes".to_string())?))
+    }
+
+    /// Get the little-endian byte representation of a graph key
+    ///
+    /// @returns {Uint8Array | Error} 32 byte long Uint8Array representation of a graph key
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        self.0.to_bytes_le().map_err(|_| "Failed to serialize graph key".to_string())
+    }
+}
+
+impl FromStr for GraphKey {
+    type Err = anyhow::Error;
+
+    fn from_str(graph_key: &str) -> Result<Self, Self::Err> {
+        Ok(Self(GraphKeyNative::from_str(graph_key)?))
+    }
+}
+
+impl fmt::Display for GraphKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for GraphKey {
+    type Target = GraphKeyNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_from_private_key() {
+        let private_key = PrivateKey::new();
+        let graph_key = GraphKey::from_private_key(&private_key);
+        assert_eq!(graph_key, GraphKey::from_view_key(&private_key.to_view_key()));
+        assert_ne!(graph_key, GraphKey::from_private_key(&PrivateKey::new()));
+
+        // Check the string and byte representations round trip.
+        assert_eq!(graph_key, GraphKey::from_string(&graph_key.to_string()).unwrap());
+        let bytes = graph_key.to_bytes().unwrap();
+        assert_eq!(bytes.len(), GRAPH_KEY_SIZE_IN_BYTES);
+        assert_eq!(graph_key, GraphKey::from_bytes(&bytes).unwrap());
+        assert!(GraphKey::from_bytes(&bytes[..31]).is_err());
+        assert!(GraphKey::from_string("GraphKey1invalid").is_err());
+    }
+}
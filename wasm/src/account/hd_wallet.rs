@@ -0,0 +1,196 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    account::PrivateKey,
+    types::{CurrentNetwork, Environment, FieldNative, Network, PrimeField, PrivateKeyNative},
+};
+
+use bip39::Mnemonic;
+use wasm_bindgen::prelude::*;
+
+/// SLIP-44 coin type registered for Aleo
+const ALEO_COIN_TYPE: u32 = 683;
+
+/// Offset added to the index of hardened path components
+const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+/// Hierarchical deterministic wallet deriving Aleo private keys from a single master seed along
+/// BIP44 style paths such as `m/44'/683'/0'/0/0`.
+///
+/// Aleo keys do not support public child key derivation, so every path component is derived from
+/// the parent private material. Hardened (`'`) and non-hardened components yield different keys.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HDWallet {
+    key: FieldNative,
+    chain_code: FieldNative,
+}
+
+#[wasm_bindgen]
+impl HDWallet {
+    /// Create a master wallet from a seed
+    ///
+    /// @param {Uint8Array} seed Seed between 16 and 64 bytes long
+    /// @returns {HDWallet | Error} Master wallet
+    #[wasm_bindgen(js_name = fromSeed)]
+    pub fn from_seed(seed: &[u8]) -> Result<HDWallet, String> {
+        if !(16..=64).contains(&seed.len()) {
+            return Err("HD wallet seeds must be between 16 and 64 bytes long".to_string());
+        }
+        // Absorb the seed 32 bytes at a time to avoid discarding entropy.
+        let mut input = vec![FieldNative::new_domain_separator("AleoHDMasterKey")];
+        input.extend(
+            seed.chunks(32)
+                .map(|chunk| FieldNative::new(<CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(chunk))),
+        );
+        let key = CurrentNetwork::hash_psd4(&input).map_err(|e| e.to_string())?;
+        input[0] = FieldNative::new_domain_separator("AleoHDChainCode");
+        let chain_code = CurrentNetwork::hash_psd4(&input).map_err(|e| e.to_string())?;
+        Ok(Self { key, chain_code })
+    }
+
+    /// Create a master wallet from a BIP39 mnemonic phrase using the standard BIP39 seed
+    /// derivation
+    ///
+    /// @param {string} phrase 12 to 24 word mnemonic phrase
+    /// @param {string | undefined} passphrase (optional) BIP39 passphrase
+    /// @returns {HDWallet | Error} Master wallet
+    #[wasm_bindgen(js_name = fromMnemonic)]
+    pub fn from_mnemonic(phrase: &str, passphrase: Option<String>) -> Result<HDWallet, String> {
+        let mnemonic = Mnemonic::parse(phrase).map_err(|e| format!("Invalid mnemonic phrase: {e}"))?;
+        Self::from_seed(&mnemonic.to_seed(passphrase.unwrap_or_default()))
+    }
+
+    /// Derive a child wallet from the master wallet along a path
+    ///
+    /// @param {string} path Derivation path, e.g. "m/44'/683'/0'/0/0"
+    /// @returns {HDWallet | Error} Child wallet
+    pub fn derive(&self, path: &str) -> Result<HDWallet, String> {
+        Self::parse_path(path)?.into_iter().try_fold(self.clone(), |wallet, index| wallet.derive_child(index))
+    }
+
+    /// Derive the private key at a path
+    ///
+    /// @param {string} path Derivation path, e.g. "m/44'/683'/0'/0/0"
+    /// @returns {PrivateKey | Error} Private key at the path
+    #[wasm_bindgen(js_name = derivePrivateKey)]
+    pub fn derive_private_key(&self, path: &str) -> Result<PrivateKey, String> {
+        self.derive(path)?.private_key()
+    }
+
+    /// Derive the private key of an account using the standard Aleo path `m/44'/683'/0'/0/{index}`
+    ///
+    /// @param {number} index Index of the account
+    /// @returns {PrivateKey | Error} Private key of the account
+    #[wasm_bindgen(js_name = deriveAccount)]
+    pub fn derive_account(&self, index: u32) -> Result<PrivateKey, String> {
+        self.derive_private_key(&format!("m/44'/{ALEO_COIN_TYPE}'/0'/0/{index}"))
+    }
+
+    /// Get the private key of this node of the wallet
+    ///
+    /// @returns {PrivateKey | Error} Private key
+    #[wasm_bindgen(js_name = privateKey)]
+    pub fn private_key(&self) -> Result<PrivateKey, String> {
+        Ok(PrivateKey::from(PrivateKeyNative::try_from(self.key).map_err(|e| e.to_string())?))
+    }
+}
+
+impl HDWallet {
+    // Derive the child wallet at the given index
+    fn derive_child(self, index: u32) -> Result<HDWallet, String> {
+        let index = FieldNative::from_u64(index as u64);
+        let key_domain = FieldNative::new_domain_separator("AleoHDChildKey");
+        let chain_domain = FieldNative::new_domain_separator("AleoHDChildChainCode");
+        let key =
+            CurrentNetwork::hash_psd4(&[key_domain, self.chain_code, self.key, index]).map_err(|e| e.to_string())?;
+        let chain_code =
+            CurrentNetwork::hash_psd4(&[chain_domain, self.chain_code, self.key, index]).map_err(|e| e.to_string())?;
+        Ok(Self { key, chain_code })
+    }
+
+    // Parse a derivation path into its child indices
+    fn parse_path(path: &str) -> Result<Vec<u32>, String> {
+        let mut components = path.trim().split('/');
+        if components.next() != Some("m") {
+            return Err(format!("Invalid derivation path '{path}' - paths must start with 'm'"));
+        }
+        components
+            .map(|component| {
+                let (index, hardened) = match component.strip_suffix('\'').or_else(|| component.strip_suffix('h')) {
+                    Some(index) => (index, true),
+                    None => (component, false),
+                };
+                let index = index
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|index| *index < HARDENED_OFFSET)
+                    .ok_or_else(|| format!("Invalid derivation path component '{component}' in '{path}'"))?;
+                Ok(if hardened { index + HARDENED_OFFSET } else { index })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const SEED: [u8; 32] = [7u8; 32];
+    const PHRASE: &str =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[wasm_bindgen_test]
+    fn test_derivation_is_deterministic() {
+        let wallet = HDWallet::from_seed(&SEED).unwrap();
+        let expected = wallet.derive_account(0).unwrap();
+        assert_eq!(expected, HDWallet::from_seed(&SEED).unwrap().derive_account(0).unwrap());
+        assert_eq!(expected, wallet.derive_private_key("m/44'/683'/0'/0/0").unwrap());
+        assert_eq!(expected, wallet.derive_private_key("m/44h/683h/0h/0/0").unwrap());
+
+        // Ensure the mnemonic wallet matches across constructions.
+        let wallet = HDWallet::from_mnemonic(PHRASE, None).unwrap();
+        assert_eq!(wallet, HDWallet::from_mnemonic(PHRASE, Some("".to_string())).unwrap());
+        assert_ne!(wallet, HDWallet::from_mnemonic(PHRASE, Some("passphrase".to_string())).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_derived_keys_differ() {
+        let wallet = HDWallet::from_seed(&SEED).unwrap();
+        let first = wallet.derive_account(0).unwrap();
+        let second = wallet.derive_account(1).unwrap();
+        assert_ne!(first, second);
+        assert_ne!(first, wallet.private_key().unwrap());
+
+        // Hardened and non-hardened components derive different keys.
+        let hardened = wallet.derive_private_key("m/44'/683'/0'/0/0'").unwrap();
+        assert_ne!(first, hardened);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_invalid_paths() {
+        let wallet = HDWallet::from_seed(&SEED).unwrap();
+        assert!(wallet.derive("44'/683'").is_err());
+        assert!(wallet.derive("m/44'/abc").is_err());
+        assert!(wallet.derive("m/44'//0").is_err());
+        assert!(wallet.derive("m/2147483648").is_err());
+        assert_eq!(wallet.derive("m").unwrap(), wallet);
+        assert!(HDWallet::from_seed(&[0u8; 8]).is_err());
+    }
+}
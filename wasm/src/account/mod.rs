@@ -17,9 +17,21 @@
 pub mod address;
 pub use address::*;
 
+pub mod compute_key;
+pub use compute_key::*;
+
 pub mod encryptor;
 pub use encryptor::*;
 
+pub mod graph_key;
+pub use graph_key::*;
+
+pub mod hd_wallet;
+pub use hd_wallet::*;
+
+pub mod multisig;
+pub use multisig::*;
+
 pub mod private_key;
 pub use private_key::*;
 
@@ -32,5 +44,23 @@ pub use private_key_ciphertext::*;
 pub mod signature;
 pub use signature::*;
 
+pub mod signed_message;
+pub use signed_message::*;
+
 pub mod view_key;
 pub use view_key::*;
+
+pub mod view_only_account;
+pub use view_only_account::*;
+
+#[cfg(feature = "testing")]
+use rand::{rngs::StdRng, SeedableRng};
+
+/// Construct a deterministic rng from a caller supplied 32 byte seed
+#[cfg(feature = "testing")]
+pub(crate) fn rng_from_seed(seed: &[u8]) -> Result<StdRng, String> {
+    let seed: [u8; 32] = seed
+        .try_into()
+        .map_err(|_| format!("RNG seeds must be 32 bytes long, but {} bytes were provided", seed.len()))?;
+    Ok(StdRng::from_seed(seed))
+}
@@ -0,0 +1,898 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Threshold Schnorr signing of Aleo messages in the style of FROST.
+//!
+//! A dealer splits the signature secret of an existing private key into `participants` Shamir
+//! shares, any `threshold` of which can jointly produce a signature which verifies against the
+//! original address. Signing takes two rounds: each signer publishes a commitment to a pair of
+//! single use nonces, then each signer produces a partial signature over the message and the full
+//! set of commitments. Any party can aggregate the partial signatures into an Aleo `Signature`,
+//! or into an `Authorization` when the group signs a request to call a program function.
+
+use crate::{
+    account::{Address, PrivateKey, Signature, ViewKey},
+    types::{
+        AuthorizationNative,
+        ComputeKeyNative,
+        CurrentNetwork,
+        Environment,
+        FieldNative,
+        FromBits,
+        GraphKeyNative,
+        GroupNative,
+        IdentifierNative,
+        InputIDNative,
+        Network,
+        ProgramIDNative,
+        ProgramNative,
+        RequestNative,
+        ScalarNative,
+        SignatureNative,
+        SizeInDataBits,
+        ToBits,
+        ToFields,
+        Uniform,
+        ValueNative,
+        ValueTypeNative,
+        ViewKeyNative,
+        U16,
+    },
+    Authorization,
+};
+
+use core::{fmt, str::FromStr};
+use js_sys::Array;
+use rand::{rngs::StdRng, SeedableRng};
+use serde_json::{json, Value};
+use wasm_bindgen::prelude::*;
+
+/// Public key of a threshold signing group
+#[wasm_bindgen]
+#[derive(Clone, PartialEq, Eq)]
+pub struct MultisigPublicKey {
+    threshold: u16,
+    compute_key: ComputeKeyNative,
+    verification_shares: Vec<GroupNative>,
+}
+
+#[wasm_bindgen]
+impl MultisigPublicKey {
+    /// Get the number of participants required to produce a signature
+    ///
+    /// @returns {number} Signing threshold
+    pub fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Get the number of signers holding a key share
+    ///
+    /// @returns {number} Number of signers
+    pub fn participants(&self) -> u16 {
+        self.verification_shares.len() as u16
+    }
+
+    /// Get the address signatures produced by the group verify against
+    ///
+    /// @returns {Address} Address of the group
+    pub fn address(&self) -> Address {
+        Address::from(self.compute_key.to_address())
+    }
+
+    /// Get a string representation of the group public key
+    ///
+    /// @returns {string} String representation of the group public key
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        json!({
+            "threshold": self.threshold,
+            "pk_sig": self.compute_key.pk_sig().to_string(),
+            "pr_sig": self.compute_key.pr_sig().to_string(),
+            "verification_shares": self.verification_shares.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        })
+        .to_string()
+    }
+
+    /// Get a group public key from its string representation
+    ///
+    /// @param {string} public_key String representation of a group public key
+    /// @returns {MultisigPublicKey | Error} Group public key
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(public_key: &str) -> Result<MultisigPublicKey, String> {
+        Self::from_str(public_key)
+    }
+
+    /// Aggregate the partial signatures of at least `threshold` signers into a signature which
+    /// verifies against the address of the group. Each partial signature is checked against the
+    /// verification share of its signer, so an invalid partial signature identifies its signer
+    ///
+    /// @param {Uint8Array} message Byte representation of the message which was signed
+    /// @param {Array} commitments Array of the nonce commitment strings of the signers
+    /// @param {Array} partial_signatures Array of the partial signature strings of the signers
+    /// @returns {Signature | Error} Signature of the message
+    pub fn aggregate(
+        &self,
+        message: &[u8],
+        commitments: Array,
+        partial_signatures: Array,
+    ) -> Result<Signature, String> {
+        let message_fields = bytes_to_fields(message)?;
+        let commitments = self.parse_commitments(commitments)?;
+        let (g_r, binding_factors) = group_commitment(&commitments, &message_fields)?;
+        let challenge = self.challenge(g_r, &message_fields)?;
+        let response = self.combine(&commitments, &binding_factors, challenge, partial_signatures)?;
+
+        let signature = SignatureNative::from((challenge, response, self.compute_key));
+        if !signature.verify_bytes(&self.compute_key.to_address(), message) {
+            return Err("The aggregated signature is invalid".to_string());
+        }
+        Ok(Signature::from(signature))
+    }
+
+    /// Aggregate the partial signatures of at least `threshold` signers over a request to call a
+    /// program function into an authorization of the call by the address of the group. The
+    /// function must not call other programs or take records as inputs
+    ///
+    /// @param {ViewKey} view_key View key of the address of the group
+    /// @param {string} program Source code of the program containing the function
+    /// @param {string} function_name Name of the function to call
+    /// @param {Array} inputs Array of the input strings of the call
+    /// @param {Array} commitments Array of the nonce commitment strings of the signers
+    /// @param {Array} partial_signatures Array of the partial signature strings of the signers
+    /// @returns {Authorization | Error} Authorization of the call
+    #[wasm_bindgen(js_name = aggregateRequest)]
+    pub fn aggregate_request(
+        &self,
+        view_key: &ViewKey,
+        program: &str,
+        function_name: &str,
+        inputs: Array,
+        commitments: Array,
+        partial_signatures: Array,
+    ) -> Result<Authorization, String> {
+        let request = MultisigRequest::new(self, view_key, program, function_name, inputs)?;
+        let commitments = self.parse_commitments(commitments)?;
+        let (g_r, binding_factors) = group_commitment(&commitments, &request.binding_message()?)?;
+        let (tvk, tcm, input_ids, message) = request.message(g_r)?;
+        let challenge = self.challenge(g_r, &message)?;
+        let response = self.combine(&commitments, &binding_factors, challenge, partial_signatures)?;
+
+        let input_types = request.input_types.clone();
+        let request = RequestNative::from((
+            self.compute_key.to_address(),
+            U16::new(CurrentNetwork::ID),
+            request.program_id,
+            request.function_name,
+            input_ids,
+            request.inputs,
+            SignatureNative::from((challenge, response, self.compute_key)),
+            GraphKeyNative::try_from(request.view_key).map_err(|e| e.to_string())?.sk_tag(),
+            tvk,
+            tcm,
+        ));
+        if !request.verify(&input_types) {
+            return Err("The aggregated request signature is invalid".to_string());
+        }
+        Ok(Authorization::from(AuthorizationNative::new(&[request])))
+    }
+}
+
+impl MultisigPublicKey {
+    // Compute the Schnorr challenge exactly as `Signature::sign` does
+    fn challenge(&self, g_r: GroupNative, message: &[FieldNative]) -> Result<ScalarNative, String> {
+        let address = self.compute_key.to_address();
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend(
+            [g_r, self.compute_key.pk_sig(), self.compute_key.pr_sig(), *address].map(|point| point.to_x_coordinate()),
+        );
+        preimage.extend_from_slice(message);
+        CurrentNetwork::hash_to_scalar_psd8(&preimage).map_err(|e| e.to_string())
+    }
+
+    // Check each partial signature against the verification share of its signer and sum them into
+    // the response of the group
+    fn combine(
+        &self,
+        commitments: &[NonceCommitment],
+        binding_factors: &[ScalarNative],
+        challenge: ScalarNative,
+        partial_signatures: Array,
+    ) -> Result<ScalarNative, String> {
+        let partial_signatures = partial_signatures
+            .iter()
+            .map(|partial| partial.as_string().ok_or_else(|| "Partial signatures must be strings".to_string()))
+            .map(|partial| PartialSignature::from_str(&partial?))
+            .collect::<Result<Vec<_>, String>>()?;
+        if partial_signatures.len() != commitments.len() {
+            return Err(format!(
+                "Received {} partial signatures for {} commitments",
+                partial_signatures.len(),
+                commitments.len()
+            ));
+        }
+
+        let mut response = scalar_from_u16(0);
+        for (commitment, binding_factor) in commitments.iter().zip(binding_factors) {
+            let partial = partial_signatures
+                .iter()
+                .find(|partial| partial.index == commitment.index)
+                .ok_or_else(|| format!("Missing the partial signature of signer {}", commitment.index))?;
+            // A valid partial response satisfies `z_i * G + (lambda_i * c) * Y_i = D_i + rho_i * E_i`.
+            let verification_share = self.verification_shares[commitment.index as usize - 1];
+            let lagrange = lagrange_coefficient(commitment.index, commitments);
+            let expected = commitment.hiding + commitment.binding * *binding_factor;
+            if CurrentNetwork::g_scalar_multiply(&partial.response) + verification_share * (lagrange * challenge)
+                != expected
+            {
+                return Err(format!("The partial signature of signer {} is invalid", commitment.index));
+            }
+            response += partial.response;
+        }
+        Ok(response)
+    }
+
+    // Parse and validate the commitments of the signers, sorted by signer index
+    fn parse_commitments(&self, commitments: Array) -> Result<Vec<NonceCommitment>, String> {
+        let mut commitments = commitments
+            .iter()
+            .map(|commitment| commitment.as_string().ok_or_else(|| "Commitments must be strings".to_string()))
+            .map(|commitment| NonceCommitment::from_str(&commitment?))
+            .collect::<Result<Vec<_>, String>>()?;
+        if let Some(commitment) =
+            commitments.iter().find(|commitment| commitment.index == 0 || commitment.index > self.participants())
+        {
+            return Err(format!(
+                "Signer {} is not one of the {} signers of the group",
+                commitment.index,
+                self.participants()
+            ));
+        }
+        commitments.sort_by_key(|commitment| commitment.index);
+        if commitments.windows(2).any(|pair| pair[0].index == pair[1].index) {
+            return Err("Each signer may only provide a single commitment".to_string());
+        }
+        if commitments.len() < self.threshold as usize {
+            return Err(format!(
+                "At least {} signers are required, but only {} commitments were provided",
+                self.threshold,
+                commitments.len()
+            ));
+        }
+        Ok(commitments)
+    }
+}
+
+impl FromStr for MultisigPublicKey {
+    type Err = String;
+
+    fn from_str(public_key: &str) -> Result<Self, Self::Err> {
+        let value: Value = serde_json::from_str(public_key).map_err(|_| "Invalid multisig public key".to_string())?;
+        let threshold = parse_u16(&value, "threshold")?;
+        let compute_key = ComputeKeyNative::try_from((parse(&value, "pk_sig")?, parse(&value, "pr_sig")?))
+            .map_err(|e| e.to_string())?;
+        let verification_shares = value["verification_shares"]
+            .as_array()
+            .ok_or_else(|| "Invalid 'verification_shares'".to_string())?
+            .iter()
+            .map(|share| share.as_str().and_then(|share| GroupNative::from_str(share).ok()))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| "Invalid 'verification_shares'".to_string())?;
+        if threshold == 0
+            || threshold as usize > verification_shares.len()
+            || verification_shares.len() > u16::MAX as usize
+        {
+            return Err(format!("Invalid {threshold}-of-{} threshold", verification_shares.len()));
+        }
+        Ok(Self { threshold, compute_key, verification_shares })
+    }
+}
+
+impl fmt::Display for MultisigPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_string())
+    }
+}
+
+/// Share of the signature secret of a threshold signing group held by a single signer
+#[wasm_bindgen]
+#[derive(Clone, PartialEq, Eq)]
+pub struct MultisigKeyShare {
+    index: u16,
+    secret_share: ScalarNative,
+    public_key: MultisigPublicKey,
+}
+
+#[wasm_bindgen]
+impl MultisigKeyShare {
+    /// Split the signature secret of a private key into shares, any `threshold` of which can
+    /// produce signatures verifying against the address of the private key. The private key
+    /// should be discarded once the shares have been distributed to the signers
+    ///
+    /// @param {PrivateKey} private_key Private key to split
+    /// @param {number} threshold Number of signers required to produce a signature
+    /// @param {number} participants Total number of shares to create
+    /// @returns {Array | Error} Array of key share strings
+    #[wasm_bindgen(js_name = splitPrivateKey)]
+    pub fn split_private_key(private_key: &PrivateKey, threshold: u16, participants: u16) -> Result<Array, String> {
+        if threshold == 0 || threshold > participants {
+            return Err(format!("Invalid {threshold}-of-{participants} threshold"));
+        }
+        let compute_key = ComputeKeyNative::try_from(**private_key).map_err(|e| e.to_string())?;
+
+        // Sample a random polynomial of degree `threshold - 1` whose constant term is the secret.
+        let rng = &mut StdRng::from_entropy();
+        let mut coefficients = vec![private_key.sk_sig()];
+        coefficients.extend((1..threshold).map(|_| ScalarNative::rand(rng)));
+        let secret_shares = (1..=participants)
+            .map(|index| {
+                let x = scalar_from_u16(index);
+                coefficients.iter().rev().skip(1).fold(*coefficients.last().unwrap(), |sum, c| sum * x + *c)
+            })
+            .collect::<Vec<_>>();
+
+        // Publish the verification share of each signer so partial signatures can be checked.
+        let verification_shares = secret_shares.iter().map(CurrentNetwork::g_scalar_multiply).collect();
+        let public_key = MultisigPublicKey { threshold, compute_key, verification_shares };
+        let shares = Array::new();
+        for (index, secret_share) in (1..=participants).zip(secret_shares) {
+            let share = Self { index, secret_share, public_key: public_key.clone() };
+            shares.push(&JsValue::from_str(&share.to_string()));
+        }
+        Ok(shares)
+    }
+
+    /// Get the index of the signer holding the share
+    ///
+    /// @returns {number} Index of the signer
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Get the public key of the signing group
+    ///
+    /// @returns {MultisigPublicKey} Public key of the signing group
+    #[wasm_bindgen(js_name = publicKey)]
+    pub fn public_key(&self) -> MultisigPublicKey {
+        self.public_key.clone()
+    }
+
+    /// Produce a partial signature of a message. Every signer must use the same message and the
+    /// same set of commitments, which must include the commitment of the nonces provided. The
+    /// nonces are consumed and cannot be used again
+    ///
+    /// @param {MultisigNonces} nonces Nonces committed to in the first signing round
+    /// @param {Uint8Array} message Byte representation of the message to sign
+    /// @param {Array} commitments Array of the nonce commitment strings of the signers
+    /// @returns {string | Error} Partial signature string
+    pub fn sign(&self, nonces: MultisigNonces, message: &[u8], commitments: Array) -> Result<String, String> {
+        let message_fields = bytes_to_fields(message)?;
+        let commitments = self.parse_commitments(&nonces, commitments)?;
+        let (g_r, binding_factors) = group_commitment(&commitments, &message_fields)?;
+        let challenge = self.public_key.challenge(g_r, &message_fields)?;
+        Ok(self.respond(nonces, &commitments, &binding_factors, challenge).to_string())
+    }
+
+    /// Produce a partial signature of a request to call a program function on behalf of the
+    /// group. Every signer must use the same call and the same set of commitments, which must
+    /// include the commitment of the nonces provided. The nonces are consumed and cannot be used
+    /// again
+    ///
+    /// @param {MultisigNonces} nonces Nonces committed to in the first signing round
+    /// @param {ViewKey} view_key View key of the address of the group
+    /// @param {string} program Source code of the program containing the function
+    /// @param {string} function_name Name of the function to call
+    /// @param {Array} inputs Array of the input strings of the call
+    /// @param {Array} commitments Array of the nonce commitment strings of the signers
+    /// @returns {string | Error} Partial signature string
+    #[wasm_bindgen(js_name = signRequest)]
+    pub fn sign_request(
+        &self,
+        nonces: MultisigNonces,
+        view_key: &ViewKey,
+        program: &str,
+        function_name: &str,
+        inputs: Array,
+        commitments: Array,
+    ) -> Result<String, String> {
+        let request = MultisigRequest::new(&self.public_key, view_key, program, function_name, inputs)?;
+        let commitments = self.parse_commitments(&nonces, commitments)?;
+        let (g_r, binding_factors) = group_commitment(&commitments, &request.binding_message()?)?;
+        let (_, _, _, message) = request.message(g_r)?;
+        let challenge = self.public_key.challenge(g_r, &message)?;
+        Ok(self.respond(nonces, &commitments, &binding_factors, challenge).to_string())
+    }
+
+    /// Get a string representation of the key share. This function should be used very carefully
+    /// as it exposes the secret share
+    ///
+    /// @returns {string} String representation of the key share
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        json!({
+            "index": self.index,
+            "secret_share": self.secret_share.to_string(),
+            "public_key": self.public_key.to_string(),
+        })
+        .to_string()
+    }
+
+    /// Get a key share from its string representation
+    ///
+    /// @param {string} share String representation of a key share
+    /// @returns {MultisigKeyShare | Error} Key share
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(share: &str) -> Result<MultisigKeyShare, String> {
+        Self::from_str(share)
+    }
+}
+
+impl MultisigKeyShare {
+    // Parse the commitments of the signers, checking they include the commitment of the nonces
+    fn parse_commitments(&self, nonces: &MultisigNonces, commitments: Array) -> Result<Vec<NonceCommitment>, String> {
+        if nonces.index != self.index {
+            return Err(format!("The nonces belong to signer {}, not signer {}", nonces.index, self.index));
+        }
+        let commitments = self.public_key.parse_commitments(commitments)?;
+        if !commitments.contains(&nonces.commitment()) {
+            return Err("The commitments do not include the commitment of the nonces provided".to_string());
+        }
+        Ok(commitments)
+    }
+
+    // Compute the partial response of the signer to the challenge
+    fn respond(
+        &self,
+        nonces: MultisigNonces,
+        commitments: &[NonceCommitment],
+        binding_factors: &[ScalarNative],
+        challenge: ScalarNative,
+    ) -> PartialSignature {
+        let position = commitments.iter().position(|commitment| commitment.index == self.index).unwrap();
+        let lagrange = lagrange_coefficient(self.index, commitments);
+        let response =
+            nonces.hiding + nonces.binding * binding_factors[position] - lagrange * challenge * self.secret_share;
+        PartialSignature { index: self.index, response }
+    }
+}
+
+impl FromStr for MultisigKeyShare {
+    type Err = String;
+
+    fn from_str(share: &str) -> Result<Self, Self::Err> {
+        let value: Value = serde_json::from_str(share).map_err(|_| "Invalid multisig key share".to_string())?;
+        let index = parse_u16(&value, "index")?;
+        let secret_share = parse(&value, "secret_share")?;
+        let public_key = MultisigPublicKey::from_str(
+            value["public_key"].as_str().ok_or_else(|| "Invalid multisig key share".to_string())?,
+        )?;
+        Ok(Self { index, secret_share, public_key })
+    }
+}
+
+impl fmt::Display for MultisigKeyShare {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_string())
+    }
+}
+
+/// Single use nonces generated by a signer in the first signing round
+#[wasm_bindgen]
+pub struct MultisigNonces {
+    index: u16,
+    hiding: ScalarNative,
+    binding: ScalarNative,
+}
+
+#[wasm_bindgen]
+impl MultisigNonces {
+    /// Sample fresh nonces for a signer
+    ///
+    /// @param {MultisigKeyShare} share Key share of the signer
+    /// @returns {MultisigNonces} Nonces of the signer
+    #[wasm_bindgen(constructor)]
+    pub fn new(share: &MultisigKeyShare) -> Self {
+        let rng = &mut StdRng::from_entropy();
+        Self { index: share.index, hiding: ScalarNative::rand(rng), binding: ScalarNative::rand(rng) }
+    }
+
+    /// Get the commitment to the nonces which must be shared with the other signers
+    ///
+    /// @returns {string} Nonce commitment string
+    #[wasm_bindgen(js_name = commitment)]
+    pub fn commitment_string(&self) -> String {
+        self.commitment().to_string()
+    }
+}
+
+impl MultisigNonces {
+    // Commit to the nonces
+    fn commitment(&self) -> NonceCommitment {
+        NonceCommitment {
+            index: self.index,
+            hiding: CurrentNetwork::g_scalar_multiply(&self.hiding),
+            binding: CurrentNetwork::g_scalar_multiply(&self.binding),
+        }
+    }
+}
+
+// Commitment to the nonces of a signer
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct NonceCommitment {
+    index: u16,
+    hiding: GroupNative,
+    binding: GroupNative,
+}
+
+impl FromStr for NonceCommitment {
+    type Err = String;
+
+    fn from_str(commitment: &str) -> Result<Self, Self::Err> {
+        let value: Value = serde_json::from_str(commitment).map_err(|_| "Invalid nonce commitment".to_string())?;
+        Ok(Self {
+            index: parse_u16(&value, "index")?,
+            hiding: parse(&value, "hiding")?,
+            binding: parse(&value, "binding")?,
+        })
+    }
+}
+
+impl fmt::Display for NonceCommitment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let commitment =
+            json!({ "index": self.index, "hiding": self.hiding.to_string(), "binding": self.binding.to_string() });
+        write!(f, "{commitment}")
+    }
+}
+
+// Partial signature produced by a signer in the second signing round
+struct PartialSignature {
+    index: u16,
+    response: ScalarNative,
+}
+
+impl FromStr for PartialSignature {
+    type Err = String;
+
+    fn from_str(partial_signature: &str) -> Result<Self, Self::Err> {
+        let value: Value =
+            serde_json::from_str(partial_signature).map_err(|_| "Invalid partial signature".to_string())?;
+        Ok(Self { index: parse_u16(&value, "index")?, response: parse(&value, "response")? })
+    }
+}
+
+impl fmt::Display for PartialSignature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", json!({ "index": self.index, "response": self.response.to_string() }))
+    }
+}
+
+// Request to call a program function on behalf of a threshold signing group
+struct MultisigRequest {
+    view_key: ViewKeyNative,
+    program_id: ProgramIDNative,
+    function_name: IdentifierNative,
+    function_id: FieldNative,
+    inputs: Vec<ValueNative>,
+    input_types: Vec<ValueTypeNative>,
+}
+
+impl MultisigRequest {
+    // Parse a call to a function, which may only take constant, public and private inputs
+    fn new(
+        public_key: &MultisigPublicKey,
+        view_key: &ViewKey,
+        program: &str,
+        function_name: &str,
+        inputs: Array,
+    ) -> Result<Self, String> {
+        if view_key.to_address() != public_key.address() {
+            return Err("The view key does not belong to the address of the group".to_string());
+        }
+        let program = ProgramNative::from_str(program).map_err(|e| e.to_string())?;
+        let function_name = IdentifierNative::from_str(function_name).map_err(|e| e.to_string())?;
+        let input_types = program.get_function(&function_name).map_err(|e| e.to_string())?.input_types();
+        if input_types
+            .iter()
+            .any(|input_type| matches!(input_type, ValueTypeNative::Record(..) | ValueTypeNative::ExternalRecord(..)))
+        {
+            return Err("Threshold signing does not support functions with record inputs".to_string());
+        }
+        let inputs = inputs
+            .iter()
+            .map(|input| input.as_string().ok_or_else(|| "Inputs must be strings".to_string()))
+            .map(|input| ValueNative::from_str(&input?).map_err(|e| e.to_string()))
+            .collect::<Result<Vec<_>, String>>()?;
+        if inputs.len() != input_types.len() {
+            return Err(format!("Expected {} inputs, but {} were provided", input_types.len(), inputs.len()));
+        }
+        let program_id = *program.id();
+        // Compute the function id as `Hash(network_id, program_id, function_name)`, as requests do.
+        let function_id = CurrentNetwork::hash_bhp1024(
+            &(U16::<CurrentNetwork>::new(CurrentNetwork::ID), program_id.name(), program_id.network(), function_name)
+                .to_bits_le(),
+        )
+        .map_err(|e| e.to_string())?;
+        Ok(Self { view_key: **view_key, program_id, function_name, function_id, inputs, input_types })
+    }
+
+    // Get the message the nonces of the signers are bound to. The signed message depends on the
+    // group commitment, so the nonces are bound to the call itself instead
+    fn binding_message(&self) -> Result<Vec<FieldNative>, String> {
+        let mut message = vec![self.function_id];
+        for input in &self.inputs {
+            message.extend(input.to_fields().map_err(|e| e.to_string())?);
+        }
+        Ok(message)
+    }
+
+    // Compute the transition view key, the transition commitment, the input ids and the message
+    // signed by the request `[tvk, tcm, function_id, input ids]` exactly as `Request::sign` does
+    fn message(
+        &self,
+        g_r: GroupNative,
+    ) -> Result<(FieldNative, FieldNative, Vec<InputIDNative>, Vec<FieldNative>), String> {
+        // The address of the group is `view_key * G`, so `tvk = r * address = view_key * g_r`.
+        let tvk = (g_r * *self.view_key).to_x_coordinate();
+        let tcm = CurrentNetwork::hash_psd2(&[tvk]).map_err(|e| e.to_string())?;
+        let mut input_ids = Vec::with_capacity(self.inputs.len());
+        for (index, (input, input_type)) in self.inputs.iter().zip(&self.input_types).enumerate() {
+            let ValueNative::Plaintext(plaintext) = input else {
+                return Err(format!("Input {index} must be a plaintext"));
+            };
+            let index = FieldNative::from_u64(index as u64);
+            let hash_input = || {
+                let mut preimage = vec![self.function_id];
+                preimage.extend(input.to_fields().map_err(|e| e.to_string())?);
+                preimage.extend([tcm, index]);
+                CurrentNetwork::hash_psd8(&preimage).map_err(|e| e.to_string())
+            };
+            input_ids.push(match input_type {
+                ValueTypeNative::Constant(..) => InputIDNative::Constant(hash_input()?),
+                ValueTypeNative::Public(..) => InputIDNative::Public(hash_input()?),
+                ValueTypeNative::Private(..) => {
+                    let input_view_key =
+                        CurrentNetwork::hash_psd4(&[self.function_id, tvk, index]).map_err(|e| e.to_string())?;
+                    let ciphertext = plaintext.encrypt_symmetric(input_view_key).map_err(|e| e.to_string())?;
+                    let fields = ciphertext.to_fields().map_err(|e| e.to_string())?;
+                    InputIDNative::Private(CurrentNetwork::hash_psd8(&fields).map_err(|e| e.to_string())?)
+                }
+                _ => return Err("Threshold signing does not support functions with record inputs".to_string()),
+            });
+        }
+        let mut message = vec![tvk, tcm, self.function_id];
+        message.extend(input_ids.iter().map(|input_id| match input_id {
+            InputIDNative::Constant(hash) | InputIDNative::Public(hash) | InputIDNative::Private(hash) => *hash,
+            _ => unreachable!("record inputs are rejected"),
+        }));
+        Ok((tvk, tcm, input_ids, message))
+    }
+}
+
+// Compute the group commitment `g_r` and the binding factor of each signer
+fn group_commitment(
+    commitments: &[NonceCommitment],
+    message: &[FieldNative],
+) -> Result<(GroupNative, Vec<ScalarNative>), String> {
+    // Bind every signer to the message and the full set of commitments.
+    let mut preimage = vec![FieldNative::new_domain_separator("AleoMultisigBinding"), FieldNative::from_u64(0)];
+    preimage.extend_from_slice(message);
+    for commitment in commitments {
+        preimage.extend([
+            FieldNative::from_u64(commitment.index as u64),
+            commitment.hiding.to_x_coordinate(),
+            commitment.binding.to_x_coordinate(),
+        ]);
+    }
+
+    let mut g_r = Vec::with_capacity(commitments.len());
+    let mut binding_factors = Vec::with_capacity(commitments.len());
+    for commitment in commitments {
+        preimage[1] = FieldNative::from_u64(commitment.index as u64);
+        let binding_factor = CurrentNetwork::hash_to_scalar_psd8(&preimage).map_err(|e| e.to_string())?;
+        g_r.push(commitment.hiding + commitment.binding * binding_factor);
+        binding_factors.push(binding_factor);
+    }
+    let g_r =
+        g_r.into_iter().reduce(|sum, point| sum + point).ok_or_else(|| "No commitments were provided".to_string())?;
+    Ok((g_r, binding_factors))
+}
+
+// Compute the Lagrange coefficient at zero of a signer within the set of signers
+fn lagrange_coefficient(index: u16, commitments: &[NonceCommitment]) -> ScalarNative {
+    let x_i = scalar_from_u16(index);
+    let (numerator, denominator) = commitments.iter().filter(|commitment| commitment.index != index).fold(
+        (scalar_from_u16(1), scalar_from_u16(1)),
+        |(numerator, denominator), commitment| {
+            let x_j = scalar_from_u16(commitment.index);
+            (numerator * x_j, denominator * (x_j - x_i))
+        },
+    );
+    numerator / denominator
+}
+
+// Encode a message into field elements exactly as `Signature::sign_bytes` does
+fn bytes_to_fields(message: &[u8]) -> Result<Vec<FieldNative>, String> {
+    message
+        .to_bits_le()
+        .chunks(FieldNative::size_in_data_bits())
+        .map(FieldNative::from_bits_le)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())
+}
+
+// Get a scalar from a small integer
+fn scalar_from_u16(value: u16) -> ScalarNative {
+    ScalarNative::new(<CurrentNetwork as Environment>::Scalar::from(value as u64))
+}
+
+// Parse a u16 member of a JSON object
+fn parse_u16(value: &Value, key: &str) -> Result<u16, String> {
+    value[key].as_u64().and_then(|index| u16::try_from(index).ok()).ok_or_else(|| format!("Invalid '{key}'"))
+}
+
+// Parse a string member of a JSON object
+fn parse<T: FromStr>(value: &Value, key: &str) -> Result<T, String> {
+    value[key].as_str().and_then(|member| T::from_str(member).ok()).ok_or_else(|| format!("Invalid '{key}'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const MESSAGE: &[u8] = b"transfer 100 credits to the treasury";
+
+    // Run both signing rounds for the given signers and aggregate the result
+    fn threshold_sign(shares: &[MultisigKeyShare], message: &[u8]) -> Result<Signature, String> {
+        let nonces = shares.iter().map(MultisigNonces::new).collect::<Vec<_>>();
+        let commitments = Array::new();
+        nonces.iter().for_each(|nonces| {
+            commitments.push(&JsValue::from_str(&nonces.commitment_string()));
+        });
+        let partial_signatures = Array::new();
+        for (share, nonces) in shares.iter().zip(nonces) {
+            partial_signatures.push(&JsValue::from_str(&share.sign(nonces, message, commitments.clone())?));
+        }
+        shares[0].public_key().aggregate(message, commitments, partial_signatures)
+    }
+
+    fn split(private_key: &PrivateKey, threshold: u16, participants: u16) -> Vec<MultisigKeyShare> {
+        MultisigKeyShare::split_private_key(private_key, threshold, participants)
+            .unwrap()
+            .iter()
+            .map(|share| MultisigKeyShare::from_string(&share.as_string().unwrap()).unwrap())
+            .collect()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_threshold_signature_verifies() {
+        let private_key = PrivateKey::new();
+        let shares = split(&private_key, 2, 3);
+        assert_eq!(shares[0].public_key().address(), private_key.to_address());
+
+        // Every pair of signers can produce a valid signature.
+        for signers in [[0, 1], [0, 2], [1, 2]] {
+            let signers = signers.map(|index| shares[index].clone());
+            let signature = threshold_sign(&signers, MESSAGE).unwrap();
+            assert!(signature.verify(&private_key.to_address(), MESSAGE));
+            assert!(!signature.verify(&private_key.to_address(), b"a different message"));
+        }
+
+        // All signers together can also produce a valid signature.
+        let signature = threshold_sign(&shares, MESSAGE).unwrap();
+        assert!(signature.verify(&private_key.to_address(), MESSAGE));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_threshold_not_met() {
+        let private_key = PrivateKey::new();
+        let shares = split(&private_key, 2, 3);
+        assert!(threshold_sign(&shares[..1], MESSAGE).is_err());
+        assert!(MultisigKeyShare::split_private_key(&private_key, 0, 3).is_err());
+        assert!(MultisigKeyShare::split_private_key(&private_key, 4, 3).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_string_roundtrips() {
+        let private_key = PrivateKey::new();
+        let share = split(&private_key, 2, 3).remove(0);
+        assert!(share == MultisigKeyShare::from_string(&share.to_string()).unwrap());
+        let public_key = share.public_key();
+        assert!(public_key == MultisigPublicKey::from_string(&public_key.to_string()).unwrap());
+        assert!(MultisigKeyShare::from_string("{}").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_invalid_partial_signature_rejected() {
+        let private_key = PrivateKey::new();
+        let shares = split(&private_key, 2, 3);
+        let nonces = shares[..2].iter().map(MultisigNonces::new).collect::<Vec<_>>();
+        let commitments = Array::new();
+        nonces.iter().for_each(|nonces| {
+            commitments.push(&JsValue::from_str(&nonces.commitment_string()));
+        });
+        let partial_signatures = Array::new();
+        for (share, nonces) in shares[..2].iter().zip(nonces) {
+            partial_signatures.push(&JsValue::from_str(&share.sign(nonces, MESSAGE, commitments.clone()).unwrap()));
+        }
+
+        // Replace the response of the second signer with a random scalar.
+        let tampered = PartialSignature { index: 2, response: ScalarNative::rand(&mut StdRng::from_entropy()) };
+        partial_signatures.set(1, JsValue::from_str(&tampered.to_string()));
+        let error = shares[0].public_key().aggregate(MESSAGE, commitments, partial_signatures).err().unwrap();
+        assert_eq!(error, "The partial signature of signer 2 is invalid");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_signer_index_out_of_range() {
+        let private_key = PrivateKey::new();
+        let shares = split(&private_key, 2, 3);
+        let nonces = MultisigNonces::new(&shares[0]);
+        for index in [0, 4] {
+            let commitments = Array::new();
+            commitments.push(&JsValue::from_str(&nonces.commitment_string()));
+            let foreign = NonceCommitment { index, ..NonceCommitment::from_str(&nonces.commitment_string()).unwrap() };
+            commitments.push(&JsValue::from_str(&foreign.to_string()));
+            assert!(shares[0].public_key().parse_commitments(commitments).is_err());
+        }
+    }
+
+    const PROGRAM: &str = r"program multisig_test.aleo;
+
+function vote:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+";
+
+    // Run both signing rounds of a request to call `vote` for the given signers
+    fn threshold_sign_request(shares: &[MultisigKeyShare], view_key: &ViewKey) -> Result<Authorization, String> {
+        let inputs = Array::new();
+        inputs.push(&JsValue::from_str("5u32"));
+        inputs.push(&JsValue::from_str("10u32"));
+        let nonces = shares.iter().map(MultisigNonces::new).collect::<Vec<_>>();
+        let commitments = Array::new();
+        nonces.iter().for_each(|nonces| {
+            commitments.push(&JsValue::from_str(&nonces.commitment_string()));
+        });
+        let partial_signatures = Array::new();
+        for (share, nonces) in shares.iter().zip(nonces) {
+            let partial = share.sign_request(nonces, view_key, PROGRAM, "vote", inputs.clone(), commitments.clone())?;
+            partial_signatures.push(&JsValue::from_str(&partial));
+        }
+        shares[0].public_key().aggregate_request(view_key, PROGRAM, "vote", inputs, commitments, partial_signatures)
+    }
+
+    #[wasm_bindgen_test]
+    fn test_threshold_request_signature() {
+        let private_key = PrivateKey::new();
+        let view_key = ViewKey::from_private_key(&private_key);
+        let shares = split(&private_key, 2, 3);
+
+        let authorization = threshold_sign_request(&shares[1..], &view_key).unwrap();
+        assert_eq!(authorization.len(), 1);
+        assert_eq!(authorization.program_id().unwrap(), "multisig_test.aleo");
+        assert_eq!(authorization.function_name().unwrap(), "vote");
+
+        // The view key must belong to the address of the group.
+        let other_view_key = ViewKey::from_private_key(&PrivateKey::new());
+        assert!(threshold_sign_request(&shares[1..], &other_view_key).is_err());
+    }
+}
@@ -15,14 +15,34 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    account::{Address, Encryptor, PrivateKeyCiphertext, Signature, ViewKey},
-    types::{CurrentNetwork, Environment, FromBytes, PrimeField, PrivateKeyNative, ToBytes},
+    account::{Address, Encryptor, HDWallet, PrivateKeyCiphertext, Signature, ViewKey},
+    types::{
+        CiphertextNative,
+        CurrentNetwork,
+        Environment,
+        FieldNative,
+        FromBytes,
+        GroupNative,
+        PrimeField,
+        PrivateKeyNative,
+        ToBytes,
+    },
 };
 
+use bip39::Mnemonic;
 use core::{convert::TryInto, fmt, ops::Deref, str::FromStr};
-use rand::{rngs::StdRng, SeedableRng};
+use hkdf::Hkdf;
+use js_sys::{Object, Reflect};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use sha2::Sha256;
 use wasm_bindgen::prelude::*;
 
+/// Number of bytes in the byte representation of a private key
+const PRIVATE_KEY_SIZE_IN_BYTES: usize = 32;
+
+/// Minimum number of bytes accepted as a seed by `PrivateKey::from_seed`
+const MIN_SEED_LENGTH: usize = 16;
+
 /// Private key of an Aleo account
 #[wasm_bindgen]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -39,6 +59,18 @@ impl PrivateKey {
         Self(PrivateKeyNative::new(&mut StdRng::from_entropy()).unwrap())
     }
 
+    /// Generate a private key deterministically from a 32 byte rng seed. This is intended for
+    /// reproducible test vectors only and must never be used to create real accounts
+    ///
+    /// @param {Uint8Array} rng_seed 32 byte long Uint8Array used to seed the random number generator
+    /// @returns {PrivateKey | Error}
+    #[cfg(feature = "testing")]
+    #[wasm_bindgen(js_name = newWithRngSeed)]
+    pub fn new_with_rng_seed(rng_seed: &[u8]) -> Result<PrivateKey, String> {
+        let rng = &mut super::rng_from_seed(rng_seed)?;
+        Ok(Self(PrivateKeyNative::new(rng).map_err(|e| e.to_string())?))
+    }
+
     /// Get a private key from a series of unchecked bytes
     ///
     /// @param {Uint8Array} seed Unchecked 32 byte long Uint8Array acting as the seed for the private key
@@ -52,6 +84,29 @@ impl PrivateKey {
         Self(PrivateKeyNative::try_from(FromBytes::read_le(&*field.to_bytes_le().unwrap()).unwrap()).unwrap())
     }
 
+    /// Get a private key from a seed of arbitrary length. The seed is expanded with HKDF-SHA256
+    /// into a uniformly distributed field element, so seeds of any length at least 16 bytes long
+    /// can be used safely
+    ///
+    /// @param {Uint8Array} seed Uint8Array of at least 16 bytes acting as the seed for the private key
+    /// @returns {PrivateKey | Error}
+    #[wasm_bindgen(js_name = fromSeed)]
+    pub fn from_seed(seed: &[u8]) -> Result<PrivateKey, String> {
+        if seed.len() < MIN_SEED_LENGTH {
+            return Err(format!(
+                "Seed must be at least {MIN_SEED_LENGTH} bytes long, but only {} bytes were provided",
+                seed.len()
+            ));
+        }
+        // Expand the seed to 64 bytes so the reduction into the field is statistically uniform.
+        let mut okm = [0u8; 64];
+        Hkdf::<Sha256>::new(Some(b"AleoPrivateKeySeed"), seed)
+            .expand(b"private_key", &mut okm)
+            .map_err(|e| format!("Failed to expand the seed: {e}"))?;
+        let seed = FieldNative::new(<CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(&okm));
+        Ok(Self(PrivateKeyNative::try_from(seed).map_err(|e| format!("Failed to derive a private key: {e}"))?))
+    }
+
     /// Get a private key from a string representation of a private key
     ///
     /// @param {string} seed String representation of a private key
@@ -60,6 +115,77 @@ impl PrivateKey {
         Self::from_str(private_key).map_err(|_| "Invalid private key".to_string())
     }
 
+    /// Generate a new random BIP39 mnemonic phrase which can be used to derive a private key via
+    /// `fromMnemonic`
+    ///
+    /// @param {number} word_count Number of words in the phrase (12, 15, 18, 21 or 24)
+    /// @returns {string | Error} Space separated mnemonic phrase
+    #[wasm_bindgen(js_name = generateMnemonic)]
+    pub fn generate_mnemonic(word_count: usize) -> Result<String, String> {
+        if !(12..=24).contains(&word_count) || word_count % 3 != 0 {
+            return Err("Mnemonic phrases must contain 12, 15, 18, 21 or 24 words".to_string());
+        }
+        // Each group of 3 words encodes 32 bits of entropy.
+        let mut entropy = vec![0u8; word_count / 3 * 4];
+        StdRng::from_entropy().fill_bytes(&mut entropy);
+        let mnemonic = Mnemonic::from_entropy(&entropy).map_err(|e| e.to_string())?;
+        Ok(mnemonic.to_string())
+    }
+
+    /// Recover a private key from a BIP39 mnemonic phrase. The phrase is checked against the
+    /// english wordlist and its checksum is verified, then the standard BIP39 seed is derived from
+    /// the phrase and the passphrase. The private key is the first account of the HD wallet of
+    /// that seed, at the path `m/44'/683'/0'/0/0`, so `HDWallet.fromMnemonic` recovers the same
+    /// key and the other accounts of the phrase
+    ///
+    /// @param {string} phrase 12 to 24 word mnemonic phrase
+    /// @param {string | undefined} passphrase (optional) BIP39 passphrase
+    /// @returns {PrivateKey | Error}
+    #[wasm_bindgen(js_name = fromMnemonic)]
+    pub fn from_mnemonic(phrase: &str, passphrase: Option<String>) -> Result<PrivateKey, String> {
+        HDWallet::from_mnemonic(phrase, passphrase)?.derive_account(0)
+    }
+
+    /// Generate a new random BIP39 mnemonic phrase along with the private key derived from it via
+    /// `fromMnemonic`. A phrase cannot be recovered from an existing private key, so accounts
+    /// which should be backed up with a phrase must be created from one
+    ///
+    /// @param {number} word_count Number of words in the phrase (12, 15, 18, 21 or 24)
+    /// @param {string | undefined} passphrase (optional) BIP39 passphrase
+    /// @returns {Object | Error} The phrase and private key in the form
+    /// \{ mnemonic: string, privateKey: PrivateKey \}
+    #[wasm_bindgen(js_name = newWithMnemonic)]
+    pub fn new_with_mnemonic(word_count: usize, passphrase: Option<String>) -> Result<Object, String> {
+        let (mnemonic, private_key) = Self::generate_with_mnemonic(word_count, passphrase)?;
+        let account = Object::new();
+        Reflect::set(&account, &"mnemonic".into(), &mnemonic.into()).map_err(|_| "Failed to set property")?;
+        Reflect::set(&account, &"privateKey".into(), &private_key.into()).map_err(|_| "Failed to set property")?;
+        Ok(account)
+    }
+
+    /// Get a private key from its little-endian byte representation
+    ///
+    /// @param {Uint8Array} bytes 32 byte long Uint8Array representation of a private key
+    /// @returns {PrivateKey | Error} PrivateKey
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<PrivateKey, String> {
+        if bytes.len() != PRIVATE_KEY_SIZE_IN_BYTES {
+            return Err(format!(
+                "Expected {PRIVATE_KEY_SIZE_IN_BYTES} bytes for a private key, but {} bytes were provided",
+                bytes.len()
+            ));
+        }
+        Ok(Self(PrivateKeyNative::from_bytes_le(bytes).map_err(|_| "Invalid private key bytes".to_string())?))
+    }
+
+    /// Get the little-endian byte representation of a private key
+    ///
+    /// @returns {Uint8Array | Error} 32 byte long Uint8Array representation of a private key
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        self.0.to_bytes_le().map_err(|_| "Failed to serialize private key".to_string())
+    }
+
     /// Get a string representation of the private key. This function should be used very carefully
     /// as it exposes the private key plaintext
     ///
@@ -91,6 +217,30 @@ impl PrivateKey {
         Signature::sign(self, message)
     }
 
+    /// Prove ownership of the address of the private key by signing a challenge issued by a
+    /// verifier. The proof is bound to both the challenge and the address and is checked with
+    /// `Address.verifyOwnership`
+    ///
+    /// @param {string} challenge Challenge issued by the verifier, e.g. a random nonce
+    /// @returns {string | Error} String representation of the ownership proof
+    #[wasm_bindgen(js_name = proveOwnership)]
+    pub fn prove_ownership(&self, challenge: &str) -> Result<String, String> {
+        if challenge.is_empty() {
+            return Err("The challenge of an ownership proof cannot be empty".to_string());
+        }
+        let payload = Address::ownership_payload(&self.to_address(), challenge);
+        Ok(Signature::sign(self, &payload).to_string())
+    }
+
+    /// Sign a structured Aleo plaintext value with the private key
+    ///
+    /// @param {string} value String representation of an Aleo plaintext value, e.g. "{ amount: 5u64 }"
+    /// @returns {Signature | Error} Signature generated by signing the value with the private key
+    #[wasm_bindgen(js_name = signPlaintext)]
+    pub fn sign_plaintext(&self, value: &str) -> Result<Signature, String> {
+        Signature::sign_plaintext(self, value)
+    }
+
     /// Get a new randomly generated private key ciphertext using a secret. The secret is sensitive
     /// and will be needed to decrypt the private key later, so it should be stored securely
     ///
@@ -116,6 +266,26 @@ impl PrivateKey {
         Ok(PrivateKeyCiphertext::from(ciphertext))
     }
 
+    /// Encrypt an existing private key with a key derived from a secret using the memory-hard
+    /// Argon2id function. The secret is sensitive and will be needed to decrypt the private key
+    /// later, so it should be stored securely
+    ///
+    /// @param {string} secret Secret used to encrypt the private key
+    /// @param {number | undefined} memory_kib (optional) Argon2id memory cost in KiB
+    /// @param {number | undefined} iterations (optional) Argon2id number of iterations
+    /// @param {number | undefined} parallelism (optional) Argon2id degree of parallelism
+    /// @returns {PrivateKeyCiphertext | Error} Ciphertext representation of the private key
+    #[wasm_bindgen(js_name = toCiphertextArgon2id)]
+    pub fn to_ciphertext_argon2id(
+        &self,
+        secret: &str,
+        memory_kib: Option<u32>,
+        iterations: Option<u32>,
+        parallelism: Option<u32>,
+    ) -> Result<PrivateKeyCiphertext, String> {
+        PrivateKeyCiphertext::encrypt_private_key_argon2id(self, secret, memory_kib, iterations, parallelism)
+    }
+
     /// Get private key from a private key ciphertext and secret originally used to encrypt it
     ///
     /// @param {PrivateKeyCiphertext} ciphertext Ciphertext representation of the private key
@@ -123,9 +293,39 @@ impl PrivateKey {
     /// @returns {PrivateKey | Error} Private key
     #[wasm_bindgen(js_name = fromPrivateKeyCiphertext)]
     pub fn from_private_key_ciphertext(ciphertext: &PrivateKeyCiphertext, secret: &str) -> Result<PrivateKey, String> {
-        let private_key = Encryptor::decrypt_private_key_with_secret(ciphertext, secret)
-            .map_err(|_| "Decryption failed".to_string())?;
-        Ok(Self::from(private_key))
+        ciphertext.decrypt_to_private_key(secret).map_err(|_| "Decryption failed".to_string())
+    }
+
+    /// Encrypt the private key to another Aleo address so that only the holder of the view key of
+    /// that address can decrypt it, e.g. to hand a key off to another device or custodian
+    ///
+    /// @param {Address} recipient Address of the recipient of the private key
+    /// @returns {string | Error} String representation of the ciphertext, in the form
+    /// `<ephemeral public key>$<ciphertext>`
+    #[wasm_bindgen(js_name = encryptToAddress)]
+    pub fn encrypt_to_address(&self, recipient: &Address) -> Result<String, String> {
+        let (ephemeral, ciphertext) =
+            Encryptor::encrypt_private_key_to_address(self, recipient, &mut StdRng::from_entropy())
+                .map_err(|_| "Encryption failed".to_string())?;
+        Ok(format!("{ephemeral}${ciphertext}"))
+    }
+
+    /// Get a private key from a ciphertext created with `encryptToAddress` using the view key of
+    /// the address it was encrypted to
+    ///
+    /// @param {string} ciphertext String representation of the ciphertext
+    /// @param {ViewKey} view_key View key of the recipient address
+    /// @returns {PrivateKey | Error} Private key
+    #[wasm_bindgen(js_name = fromAddressCiphertext)]
+    pub fn from_address_ciphertext(ciphertext: &str, view_key: &ViewKey) -> Result<PrivateKey, String> {
+        let (ephemeral, ciphertext) =
+            ciphertext.split_once('$').ok_or_else(|| "Invalid address ciphertext".to_string())?;
+        let ephemeral = GroupNative::from_str(ephemeral).map_err(|_| "Invalid address ciphertext".to_string())?;
+        let ciphertext =
+            CiphertextNative::from_str(ciphertext).map_err(|_| "Invalid address ciphertext".to_string())?;
+        Encryptor::decrypt_private_key_with_view_key(&ephemeral, &ciphertext, view_key)
+            .map(Self)
+            .map_err(|_| "Decryption failed".to_string())
     }
 }
 
@@ -168,6 +368,15 @@ impl Deref for PrivateKey {
     }
 }
 
+impl PrivateKey {
+    // Generate a random mnemonic phrase and derive its private key
+    fn generate_with_mnemonic(word_count: usize, passphrase: Option<String>) -> Result<(String, PrivateKey), String> {
+        let mnemonic = Self::generate_mnemonic(word_count)?;
+        let private_key = Self::from_mnemonic(&mnemonic, passphrase)?;
+        Ok((mnemonic, private_key))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,6 +427,125 @@ mod tests {
         }
     }
 
+    #[wasm_bindgen_test]
+    pub fn test_to_and_from_bytes() {
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let expected = PrivateKey::new();
+
+            // Check the byte representation round trips.
+            let bytes = expected.to_bytes().unwrap();
+            assert_eq!(bytes.len(), PRIVATE_KEY_SIZE_IN_BYTES);
+            assert_eq!(expected, PrivateKey::from_bytes(&bytes).unwrap());
+        }
+
+        // Ensure byte arrays of the wrong length are rejected.
+        let bytes = PrivateKey::new().to_bytes().unwrap();
+        assert!(PrivateKey::from_bytes(&bytes[..31]).is_err());
+        assert!(PrivateKey::from_bytes(&[bytes.as_slice(), &[0u8]].concat()).is_err());
+    }
+
+    #[cfg(feature = "testing")]
+    #[wasm_bindgen_test]
+    pub fn test_new_with_rng_seed() {
+        // Ensure the same rng seed produces the same private key and ciphertext.
+        let private_key = PrivateKey::new_with_rng_seed(&[1u8; 32]).unwrap();
+        assert_eq!(private_key, PrivateKey::new_with_rng_seed(&[1u8; 32]).unwrap());
+        assert_ne!(private_key, PrivateKey::new_with_rng_seed(&[2u8; 32]).unwrap());
+
+        let ciphertext =
+            PrivateKeyCiphertext::encrypt_private_key_with_rng_seed(&private_key, "mypassword", &[3u8; 32]).unwrap();
+        let ciphertext_2 =
+            PrivateKeyCiphertext::encrypt_private_key_with_rng_seed(&private_key, "mypassword", &[3u8; 32]).unwrap();
+        assert_eq!(ciphertext, ciphertext_2);
+        assert_eq!(private_key, ciphertext.decrypt_to_private_key("mypassword").unwrap());
+
+        // Ensure rng seeds of the wrong length are rejected.
+        assert!(PrivateKey::new_with_rng_seed(&[1u8; 31]).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_from_seed() {
+        for _ in 0..ITERATIONS {
+            // Sample a random seed.
+            let seed: [u8; 32] = StdRng::from_entropy().gen();
+
+            // Ensure the private key is deterministically recoverable.
+            let expected = PrivateKey::from_seed(&seed).unwrap();
+            assert_eq!(expected, PrivateKey::from_seed(&seed).unwrap());
+        }
+
+        // Ensure seeds of arbitrary length are accepted and produce distinct keys.
+        let short = PrivateKey::from_seed(&[1u8; 16]).unwrap();
+        let long = PrivateKey::from_seed(&[1u8; 100]).unwrap();
+        assert_ne!(short, long);
+
+        // Ensure seeds that are too short are rejected instead of panicking.
+        assert!(PrivateKey::from_seed(&[]).is_err());
+        assert!(PrivateKey::from_seed(&[1u8; 15]).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_mnemonic_matches_hd_wallet() {
+        for word_count in [12, 24] {
+            let phrase = PrivateKey::generate_mnemonic(word_count).unwrap();
+
+            // Ensure the private key is the first account of the HD wallet of the BIP39 seed.
+            let expected = HDWallet::from_mnemonic(&phrase, None).unwrap().derive_account(0).unwrap();
+            assert_eq!(expected, PrivateKey::from_mnemonic(&phrase, None).unwrap());
+            let mnemonic = Mnemonic::parse(&phrase).unwrap();
+            let expected = HDWallet::from_seed(&mnemonic.to_seed("passphrase")).unwrap().derive_account(0).unwrap();
+            assert_eq!(expected, PrivateKey::from_mnemonic(&phrase, Some("passphrase".to_string())).unwrap());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_new_with_mnemonic() {
+        let (phrase, private_key) = PrivateKey::generate_with_mnemonic(24, Some("passphrase".to_string())).unwrap();
+        assert_eq!(phrase.split_whitespace().count(), 24);
+
+        // Ensure the private key is recovered from the phrase and the passphrase.
+        assert_eq!(private_key, PrivateKey::from_mnemonic(&phrase, Some("passphrase".to_string())).unwrap());
+        assert_ne!(private_key, PrivateKey::from_mnemonic(&phrase, None).unwrap());
+
+        let account = PrivateKey::new_with_mnemonic(12, None).unwrap();
+        let phrase = Reflect::get(&account, &"mnemonic".into()).unwrap().as_string().unwrap();
+        assert_eq!(phrase.split_whitespace().count(), 12);
+        assert!(Reflect::get(&account, &"privateKey".into()).unwrap().is_object());
+        assert!(PrivateKey::new_with_mnemonic(13, None).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_mnemonic_passphrase() {
+        let phrase = PrivateKey::generate_mnemonic(12).unwrap();
+        assert_eq!(phrase.split_whitespace().count(), 12);
+
+        // Ensure the passphrase deterministically derives a different private key.
+        let private_key = PrivateKey::from_mnemonic(&phrase, None).unwrap();
+        let with_passphrase = PrivateKey::from_mnemonic(&phrase, Some("passphrase".to_string())).unwrap();
+        assert_ne!(private_key, with_passphrase);
+        assert_eq!(with_passphrase, PrivateKey::from_mnemonic(&phrase, Some("passphrase".to_string())).unwrap());
+        // An empty passphrase is equivalent to no passphrase.
+        assert_eq!(private_key, PrivateKey::from_mnemonic(&phrase, Some("".to_string())).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_invalid_mnemonic() {
+        // Check invalid word counts are rejected.
+        assert!(PrivateKey::generate_mnemonic(11).is_err());
+        assert!(PrivateKey::generate_mnemonic(27).is_err());
+
+        // Check words outside of the wordlist are rejected.
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon aleo";
+        assert!(PrivateKey::from_mnemonic(phrase, None).is_err());
+
+        // Check phrases with an invalid checksum are rejected.
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        assert!(PrivateKey::from_mnemonic(phrase, None).is_err());
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        assert!(PrivateKey::from_mnemonic(phrase, None).is_ok());
+    }
+
     #[wasm_bindgen_test]
     pub fn test_to_address() {
         for _ in 0..ITERATIONS {
@@ -246,4 +574,19 @@ mod tests {
             assert!(signature.verify_bytes(&private_key.to_address(), &message));
         }
     }
+
+    #[wasm_bindgen_test]
+    pub fn test_encrypt_to_address() {
+        let private_key = PrivateKey::new();
+        let recipient = PrivateKey::new();
+        let ciphertext = private_key.encrypt_to_address(&recipient.to_address()).unwrap();
+
+        // Check the recipient can recover the private key.
+        let recovered = PrivateKey::from_address_ciphertext(&ciphertext, &recipient.to_view_key()).unwrap();
+        assert_eq!(private_key, recovered);
+
+        // Ensure other view keys and malformed ciphertexts are rejected.
+        assert!(PrivateKey::from_address_ciphertext(&ciphertext, &PrivateKey::new().to_view_key()).is_err());
+        assert!(PrivateKey::from_address_ciphertext("invalid", &recipient.to_view_key()).is_err());
+    }
 }
@@ -15,7 +15,7 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    account::{Encryptor, PrivateKey},
+    account::{Encryptor, Kdf, PrivateKey},
     types::CiphertextNative,
 };
 
@@ -25,7 +25,10 @@ use wasm_bindgen::prelude::*;
 /// Private Key in ciphertext form
 #[wasm_bindgen]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct PrivateKeyCiphertext(CiphertextNative);
+pub struct PrivateKeyCiphertext {
+    ciphertext: CiphertextNative,
+    kdf: Kdf,
+}
 
 #[wasm_bindgen]
 impl PrivateKeyCiphertext {
@@ -42,6 +45,49 @@ impl PrivateKeyCiphertext {
         Ok(Self::from(ciphertext))
     }
 
+    /// Encrypt a private key using a secret string and a deterministic 32 byte rng seed. This is
+    /// intended for reproducible test vectors only and must never be used to encrypt real keys
+    ///
+    /// @param {PrivateKey} private_key Private key to encrypt
+    /// @param {string} secret Secret to encrypt the private key with
+    /// @param {Uint8Array} rng_seed 32 byte long Uint8Array used to seed the random number generator
+    /// @returns {PrivateKeyCiphertext | Error} Private key ciphertext
+    #[cfg(feature = "testing")]
+    #[wasm_bindgen(js_name = encryptPrivateKeyWithRngSeed)]
+    pub fn encrypt_private_key_with_rng_seed(
+        private_key: &PrivateKey,
+        secret: &str,
+        rng_seed: &[u8],
+    ) -> Result<PrivateKeyCiphertext, String> {
+        let rng = &mut super::rng_from_seed(rng_seed)?;
+        let ciphertext = Encryptor::encrypt_private_key_with_rng(private_key, secret, &Kdf::Poseidon, rng)
+            .map_err(|_| "Encryption failed".to_string())?;
+        Ok(Self::from(ciphertext))
+    }
+
+    /// Encrypt a private key using a key derived from a secret string with the memory-hard Argon2id
+    /// function. The Argon2id parameters are recorded in the ciphertext header so they are not
+    /// needed for decryption. Unspecified parameters default to 19 MiB of memory, 2 iterations
+    /// and a parallelism of 1, and may be at most 76 MiB of memory, 8 iterations and a parallelism
+    /// of 4
+    ///
+    /// @param {PrivateKey} private_key Private key to encrypt
+    /// @param {string} secret Secret to encrypt the private key with
+    /// @param {number | undefined} memory_kib (optional) Memory cost in KiB
+    /// @param {number | undefined} iterations (optional) Number of iterations
+    /// @param {number | undefined} parallelism (optional) Degree of parallelism
+    /// @returns {PrivateKeyCiphertext | Error} Private key ciphertext
+    #[wasm_bindgen(js_name = encryptPrivateKeyArgon2id)]
+    pub fn encrypt_private_key_argon2id(
+        private_key: &PrivateKey,
+        secret: &str,
+        memory_kib: Option<u32>,
+        iterations: Option<u32>,
+        parallelism: Option<u32>,
+    ) -> Result<PrivateKeyCiphertext, String> {
+        Self::encrypt_with_kdf(private_key, secret, Kdf::argon2id(memory_kib, iterations, parallelism)?)
+    }
+
     /// Decrypts a private ciphertext using a secret string. This must be the same secret used to
     /// encrypt the private key
     ///
@@ -49,18 +95,32 @@ impl PrivateKeyCiphertext {
     /// @returns {PrivateKey | Error} Private key
     #[wasm_bindgen(js_name = decryptToPrivateKey)]
     pub fn decrypt_to_private_key(&self, secret: &str) -> Result<PrivateKey, String> {
-        let private_key = Encryptor::decrypt_private_key_with_secret(&self.0, secret)
+        let private_key = Encryptor::decrypt_private_key_with_kdf(&self.ciphertext, secret, &self.kdf)
             .map_err(|_| "Decryption failed - ciphertext was not a private key")?;
         Ok(PrivateKey::from(private_key))
     }
 
+    /// Returns the name of the key derivation function used to encrypt the private key
+    ///
+    /// @returns {string} "poseidon" or "argon2id"
+    #[wasm_bindgen(js_name = kdf)]
+    pub fn kdf(&self) -> String {
+        match self.kdf {
+            Kdf::Poseidon => "poseidon".to_string(),
+            Kdf::Argon2id { .. } => "argon2id".to_string(),
+        }
+    }
+
     /// Returns the ciphertext string
     ///
     /// @returns {string} Ciphertext string
     #[allow(clippy::inherent_to_string)]
     #[wasm_bindgen(js_name = toString)]
     pub fn to_string(&self) -> String {
-        self.0.to_string()
+        match self.kdf {
+            Kdf::Poseidon => self.ciphertext.to_string(),
+            Kdf::Argon2id { .. } => format!("{}${}", self.kdf, self.ciphertext),
+        }
     }
 
     /// Creates a PrivateKeyCiphertext from a string
@@ -73,9 +133,18 @@ impl PrivateKeyCiphertext {
     }
 }
 
+impl PrivateKeyCiphertext {
+    /// Encrypt a private key with a secret using the given key derivation function
+    pub(crate) fn encrypt_with_kdf(private_key: &PrivateKey, secret: &str, kdf: Kdf) -> Result<Self, String> {
+        let ciphertext = Encryptor::encrypt_private_key_with_kdf(private_key, secret, &kdf)
+            .map_err(|_| "Encryption failed".to_string())?;
+        Ok(Self { ciphertext, kdf })
+    }
+}
+
 impl From<CiphertextNative> for PrivateKeyCiphertext {
     fn from(ciphertext: CiphertextNative) -> Self {
-        Self(ciphertext)
+        Self { ciphertext, kdf: Kdf::Poseidon }
     }
 }
 
@@ -83,7 +152,14 @@ impl TryFrom<String> for PrivateKeyCiphertext {
     type Error = String;
 
     fn try_from(ciphertext: String) -> Result<Self, Self::Error> {
-        Ok(Self(CiphertextNative::from_str(&ciphertext).map_err(|_| "Invalid ciphertext".to_string())?))
+        // The ciphertext is prefixed by the key derivation header, if any.
+        let (header, ciphertext) = match ciphertext.rfind('$') {
+            Some(index) => (&ciphertext[..index], &ciphertext[index + 1..]),
+            None => ("", ciphertext.as_str()),
+        };
+        let kdf = Kdf::from_str(header)?;
+        let ciphertext = CiphertextNative::from_str(ciphertext).map_err(|_| "Invalid ciphertext".to_string())?;
+        Ok(Self { ciphertext, kdf })
     }
 }
 
@@ -91,7 +167,7 @@ impl Deref for PrivateKeyCiphertext {
     type Target = CiphertextNative;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.ciphertext
     }
 }
 
@@ -229,4 +305,78 @@ mod tests {
         let bad_secret_attempt = PrivateKey::from_private_key_ciphertext(&private_key_ciphertext, "badpassword");
         assert!(bad_secret_attempt.is_err());
     }
+
+    #[wasm_bindgen_test]
+    fn test_private_key_ciphertext_argon2id_encrypt_and_decrypt() {
+        let private_key = PrivateKey::new();
+        let private_key_ciphertext =
+            PrivateKeyCiphertext::encrypt_private_key_argon2id(&private_key, "mypassword", Some(64), Some(1), Some(1))
+                .unwrap();
+        assert_eq!(private_key_ciphertext.kdf(), "argon2id");
+
+        // Assert the parameters survive the round trip to and from string
+        let ciphertext_string = private_key_ciphertext.to_string();
+        assert!(ciphertext_string.starts_with("$argon2id$v=19$m=64,t=1,p=1$"));
+        let private_key_ciphertext_2 = PrivateKeyCiphertext::from_string(ciphertext_string).unwrap();
+        assert_eq!(private_key_ciphertext, private_key_ciphertext_2);
+
+        // Assert the private key is only recovered with the correct secret
+        let recovered_private_key = private_key_ciphertext_2.decrypt_to_private_key("mypassword").unwrap();
+        assert_eq!(private_key, recovered_private_key);
+        assert!(private_key_ciphertext_2.decrypt_to_private_key("wrong_password").is_err());
+        assert_eq!(
+            private_key,
+            PrivateKey::from_private_key_ciphertext(&private_key_ciphertext_2, "mypassword").unwrap()
+        );
+
+        // Assert the private key can also be encrypted directly
+        let private_key_ciphertext_3 =
+            private_key.to_ciphertext_argon2id("mypassword", Some(64), Some(1), Some(1)).unwrap();
+        assert_eq!(private_key, private_key_ciphertext_3.decrypt_to_private_key("mypassword").unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_private_key_ciphertext_invalid_argon2id_parameters() {
+        let private_key = PrivateKey::new();
+        assert!(
+            PrivateKeyCiphertext::encrypt_private_key_argon2id(&private_key, "mypassword", Some(1), None, None)
+                .is_err()
+        );
+        assert!(
+            PrivateKeyCiphertext::encrypt_private_key_argon2id(&private_key, "mypassword", None, Some(0), None)
+                .is_err()
+        );
+
+        // Legacy ciphertexts have no header and use the poseidon key derivation
+        let private_key_ciphertext = PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypassword").unwrap();
+        assert_eq!(private_key_ciphertext.kdf(), "poseidon");
+        assert!(private_key_ciphertext.to_string().starts_with("ciphertext1"));
+
+        // Malformed headers are rejected
+        let ciphertext =
+            format!("$argon2id$v=19$m=64,t=1${}${}", hex::encode([0u8; 16]), private_key_ciphertext.ciphertext);
+        assert!(PrivateKeyCiphertext::from_string(ciphertext).is_err());
+
+        // Parameters above the maximums are rejected when encrypting
+        assert!(
+            PrivateKeyCiphertext::encrypt_private_key_argon2id(&private_key, "mypassword", Some(77825), None, None)
+                .is_err()
+        );
+        assert!(
+            PrivateKeyCiphertext::encrypt_private_key_argon2id(&private_key, "mypassword", None, Some(9), None)
+                .is_err()
+        );
+        assert!(
+            PrivateKeyCiphertext::encrypt_private_key_argon2id(&private_key, "mypassword", None, None, Some(5))
+                .is_err()
+        );
+
+        // Oversized headers are rejected when parsing, before any key derivation takes place
+        for costs in ["m=4294967295,t=1,p=1", "m=64,t=4294967295,p=1", "m=64,t=1,p=16777215"] {
+            let ciphertext =
+                format!("$argon2id$v=19${costs}${}${}", hex::encode([0u8; 16]), private_key_ciphertext.ciphertext);
+            let error = PrivateKeyCiphertext::from_string(ciphertext).unwrap_err();
+            assert!(error.contains("exceed the maximums"));
+        }
+    }
 }
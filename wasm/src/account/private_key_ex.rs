@@ -16,11 +16,12 @@
 
 use crate::{
     account::ViewKey,
-    record::RecordCiphertext,
+    record::{RecordCiphertext, RecordPlaintext},
     types::{CurrentNetwork, Network, Field, RecordPlaintextNative as Record}, PrivateKey,
 };
 
 use core::ops::Deref;
+use js_sys::Array;
 use wasm_bindgen::prelude::*;
 
 use serde::{Deserialize, Serialize};
@@ -33,7 +34,9 @@ pub struct OldRecordData<N: Network> {
     serial_number: Field<CurrentNetwork>,
 }
 
-#[derive(Serialize)]
+/// Record owned by a private key along with the metadata of the transition which created it
+#[wasm_bindgen]
+#[derive(Clone, Serialize)]
 pub struct RecordData {
     record: Record,
     identifier: String,
@@ -49,6 +52,100 @@ pub struct RecordData {
     input: Option<Vec<String>>,
 }
 
+#[wasm_bindgen]
+impl RecordData {
+    /// Get the decrypted record
+    ///
+    /// @returns {RecordPlaintext} Record plaintext
+    pub fn record(&self) -> RecordPlaintext {
+        RecordPlaintext::from(self.record.clone())
+    }
+
+    /// Get the name of the record type
+    ///
+    /// @returns {string} Record identifier
+    pub fn identifier(&self) -> String {
+        self.identifier.clone()
+    }
+
+    /// Get the serial number of the record
+    ///
+    /// @returns {string} Serial number of the record
+    #[wasm_bindgen(js_name = serialNumber)]
+    pub fn serial_number(&self) -> String {
+        self.serial_number.clone()
+    }
+
+    /// Get the id of the program which created the record
+    ///
+    /// @returns {string} Program id
+    #[wasm_bindgen(js_name = programId)]
+    pub fn program_id(&self) -> String {
+        self.program_id.clone()
+    }
+
+    /// Get the height of the block containing the record
+    ///
+    /// @returns {number} Block height
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Get the timestamp of the block containing the record
+    ///
+    /// @returns {number} Block timestamp
+    pub fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    /// Get the hash of the block containing the record
+    ///
+    /// @returns {string} Block hash
+    #[wasm_bindgen(js_name = blockHash)]
+    pub fn block_hash(&self) -> String {
+        self.block_hash.clone()
+    }
+
+    /// Get the id of the transaction which created the record
+    ///
+    /// @returns {string} Transaction id
+    #[wasm_bindgen(js_name = transactionId)]
+    pub fn transaction_id(&self) -> String {
+        self.transaction_id.clone()
+    }
+
+    /// Get the id of the transition which created the record
+    ///
+    /// @returns {string} Transition id
+    #[wasm_bindgen(js_name = transitionId)]
+    pub fn transition_id(&self) -> String {
+        self.transition_id.clone()
+    }
+
+    /// Get the name of the function which created the record
+    ///
+    /// @returns {string} Function name
+    #[wasm_bindgen(js_name = functionName)]
+    pub fn function_name(&self) -> String {
+        self.function_name.clone()
+    }
+
+    /// Get the index of the record within the outputs of the transition
+    ///
+    /// @returns {number} Output index
+    #[wasm_bindgen(js_name = outputIndex)]
+    pub fn output_index(&self) -> u8 {
+        self.output_index
+    }
+
+    /// Get the inputs of the transition which created the record, if known
+    ///
+    /// @returns {Array<string> | undefined} Transition inputs
+    pub fn input(&self) -> Option<Array> {
+        self.input.as_ref().map(|input| input.iter().map(|input| JsValue::from_str(input)).collect())
+    }
+}
+
 #[derive(Deserialize)]
 pub struct RecordOrgData {
     record_ciphertext: String,
@@ -69,6 +166,24 @@ impl PrivateKey {
 
     #[wasm_bindgen(js_name = "decryptrecords")]
     pub fn decrypt_records(&self, recordstext: &str) -> Result<String, String> {
+        let records = self.decrypt_record_data(recordstext);
+        Ok(serde_json::to_string_pretty(&records).unwrap_or_default().replace("\\n", ""))
+    }
+
+    /// Decrypt the records owned by the private key from a JSON array of record ciphertexts and
+    /// their transition metadata
+    ///
+    /// @param {string} recordstext JSON array of record ciphertexts and their metadata
+    /// @returns {Array<RecordData>} Array of the decrypted records owned by the private key
+    #[wasm_bindgen(js_name = decryptRecordsJs)]
+    pub fn decrypt_records_js(&self, recordstext: &str) -> Array {
+        self.decrypt_record_data(recordstext).into_iter().map(JsValue::from).collect()
+    }
+}
+
+impl PrivateKey {
+    // Decrypt the records owned by the private key, skipping records which cannot be decrypted
+    fn decrypt_record_data(&self, recordstext: &str) -> Vec<RecordData> {
         let record_org_datas: Vec<RecordOrgData> = serde_json::from_str(recordstext).unwrap_or_default();
         let mut records = Vec::new();
         for record_org in record_org_datas {
@@ -97,6 +212,80 @@ impl PrivateKey {
                 };
             };
         }
-        Ok(serde_json::to_string_pretty(&records).unwrap_or_default().replace("\\n", ""))
+        records
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const OWNER_PRIVATE_KEY: &str = "APrivateKey1zkpJkyYRGYtkeHDaFfwsKtUJzia7csiWhfBWPXWhXJzy9Ls";
+    const OWNER_CIPHERTEXT: &str = "record1qyqsqpe2szk2wwwq56akkwx586hkndl3r8vzdwve32lm7elvphh37rsyqyxx66trwfhkxun9v35hguerqqpqzqrtjzeu6vah9x2me2exkgege824sd8x2379scspmrmtvczs0d93qttl7y92ga0k0rsexu409hu3vlehe3yxjhmey3frh2z5pxm5cmxsv4un97q";
+    const FOREIGN_CIPHERTEXT: &str = "record1qyqsq553yxz8ylwqyqfmcfmwz03x6xsxf2h2kypcwhykzgm50ut4susyqyxx66trwfhkxun9v35hguerqqpqzqyjt8kxnp28v83t460knvp0dq86a3r3dyve945u0xqeksq323paqtegslprdc5zypksrja7rmctx90jnpeq5sqkwlfct7ygy990a5pqs7y5pt0";
+
+    // Create the JSON metadata of a credits record created by a transfer
+    fn record_org_data(record_ciphertext: &str, input: Option<&[&str]>) -> serde_json::Value {
+        serde_json::json!({
+            "record_ciphertext": record_ciphertext,
+            "identifier": "credits",
+            "program_id": "credits.aleo",
+            "height": 12,
+            "timestamp": 1700000000,
+            "block_hash": "ab1block",
+            "transaction_id": "at1transaction",
+            "transition_id": "au1transition",
+            "function_name": "transfer_private",
+            "output_index": 1,
+            "input": input,
+        })
+    }
+
+    #[wasm_bindgen_test]
+    fn test_decrypt_records() {
+        let private_key = PrivateKey::from_string(OWNER_PRIVATE_KEY).unwrap();
+        let recordstext = serde_json::json!([
+            record_org_data(OWNER_CIPHERTEXT, Some(&["1u64", "aleo1recipient"])),
+            record_org_data(FOREIGN_CIPHERTEXT, None),
+            record_org_data("invalid", None),
+            record_org_data(OWNER_CIPHERTEXT, None),
+        ])
+        .to_string();
+
+        // Ensure only the records owned by the private key are returned.
+        let records = private_key.decrypt_record_data(&recordstext);
+        assert_eq!(records.len(), 2);
+        assert_eq!(private_key.decrypt_records_js(&recordstext).length(), 2);
+
+        let expected = RecordCiphertext::from_string(OWNER_CIPHERTEXT)
+            .unwrap()
+            .decrypt(&ViewKey::from_private_key(&private_key))
+            .unwrap();
+        let record = &records[0];
+        assert_eq!(record.record().to_string(), expected.to_string());
+        assert_eq!(record.record().microcredits(), 1500000000000000);
+        assert_eq!(record.identifier(), "credits");
+        assert_eq!(
+            record.serial_number(),
+            expected.serial_number_string(&private_key, "credits.aleo", "credits").unwrap()
+        );
+        assert_eq!(record.program_id(), "credits.aleo");
+        assert_eq!(record.height(), 12);
+        assert_eq!(record.timestamp(), 1700000000);
+        assert_eq!(record.block_hash(), "ab1block");
+        assert_eq!(record.transaction_id(), "at1transaction");
+        assert_eq!(record.transition_id(), "au1transition");
+        assert_eq!(record.function_name(), "transfer_private");
+        assert_eq!(record.output_index(), 1);
+        let input = record.input().unwrap();
+        assert_eq!(input.length(), 2);
+        assert_eq!(input.get(0).as_string().unwrap(), "1u64");
+        assert_eq!(input.get(1).as_string().unwrap(), "aleo1recipient");
+        assert!(records[1].input().is_none());
+
+        // Ensure a private key which owns none of the records decrypts nothing.
+        assert!(PrivateKey::new().decrypt_record_data(&recordstext).is_empty());
+        assert!(private_key.decrypt_record_data("not json").is_empty());
+    }
+}
@@ -16,12 +16,30 @@
 
 use crate::{
     account::{Address, PrivateKey},
-    types::SignatureNative,
+    types::{
+        AddressNative,
+        ComputeKeyNative,
+        FieldNative,
+        FromBytes,
+        GroupNative,
+        PlaintextNative,
+        ScalarNative,
+        SignatureNative,
+        ToBytes,
+        ToFields,
+    },
 };
 
 use core::{fmt, ops::Deref, str::FromStr};
+use js_sys::{Array, Uint8Array};
 use rand::{rngs::StdRng, SeedableRng};
-use wasm_bindgen::prelude::*;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use serde_json::{json, Value};
+use wasm_bindgen::{prelude::*, JsCast};
+
+/// Number of bytes in the byte representation of a signature
+const SIGNATURE_SIZE_IN_BYTES: usize = 128;
 
 /// Cryptographic signature of a message signed by an Aleo account
 #[wasm_bindgen]
@@ -38,6 +56,92 @@ impl Signature {
         Self(SignatureNative::sign_bytes(private_key, message, &mut StdRng::from_entropy()).unwrap())
     }
 
+    /// Sign a message with a private key using a deterministic 32 byte rng seed. This is intended
+    /// for reproducible test vectors only and must never be used to sign real messages
+    ///
+    /// @param {PrivateKey} private_key The private key to sign the message with
+    /// @param {Uint8Array} message Byte representation of the message to sign
+    /// @param {Uint8Array} rng_seed 32 byte long Uint8Array used to seed the random number generator
+    /// @returns {Signature | Error} Signature of the message
+    #[cfg(feature = "testing")]
+    #[wasm_bindgen(js_name = signWithRngSeed)]
+    pub fn sign_with_rng_seed(private_key: &PrivateKey, message: &[u8], rng_seed: &[u8]) -> Result<Signature, String> {
+        let rng = &mut super::rng_from_seed(rng_seed)?;
+        Ok(Self(SignatureNative::sign_bytes(private_key, message, rng).map_err(|e| e.to_string())?))
+    }
+
+    /// Verify a batch of signatures in a single call. The arrays must be the same length, and the
+    /// signature at each index is verified against the address and message at the same index.
+    /// Verification is parallelized when the `parallel` feature is enabled
+    ///
+    /// @param {Array} addresses Array of address strings
+    /// @param {Array} messages Array of Uint8Array messages
+    /// @param {Array} signatures Array of signature strings
+    /// @returns {boolean | Error} True if every signature is valid, false otherwise
+    #[wasm_bindgen(js_name = verifyBatch)]
+    pub fn verify_batch(addresses: Array, messages: Array, signatures: Array) -> Result<bool, String> {
+        if addresses.length() != messages.length() || addresses.length() != signatures.length() {
+            return Err(format!(
+                "Batch length mismatch - received {} addresses, {} messages and {} signatures",
+                addresses.length(),
+                messages.length(),
+                signatures.length()
+            ));
+        }
+        let batch = (0..addresses.length())
+            .map(|index| {
+                let address = addresses
+                    .get(index)
+                    .as_string()
+                    .and_then(|address| AddressNative::from_str(&address).ok())
+                    .ok_or_else(|| format!("Invalid address at index {index}"))?;
+                let message = messages
+                    .get(index)
+                    .dyn_into::<Uint8Array>()
+                    .map_err(|_| format!("Invalid message at index {index} - messages must be Uint8Arrays"))?
+                    .to_vec();
+                let signature = signatures
+                    .get(index)
+                    .as_string()
+                    .and_then(|signature| SignatureNative::from_str(&signature).ok())
+                    .ok_or_else(|| format!("Invalid signature at index {index}"))?;
+                Ok((address, message, signature))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        #[cfg(feature = "parallel")]
+        let batch = batch.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let mut batch = batch.iter();
+        Ok(batch.all(|(address, message, signature)| signature.verify_bytes(address, message)))
+    }
+
+    /// Sign a structured Aleo plaintext value with a private key. The value is parsed and encoded
+    /// into field elements before signing, so the signature does not depend on the formatting of
+    /// the value string
+    ///
+    /// @param {PrivateKey} private_key The private key to sign the value with
+    /// @param {string} value String representation of an Aleo plaintext value, e.g. "{ amount: 5u64 }"
+    /// @returns {Signature | Error} Signature of the value
+    #[wasm_bindgen(js_name = signPlaintext)]
+    pub fn sign_plaintext(private_key: &PrivateKey, value: &str) -> Result<Signature, String> {
+        let message = Self::plaintext_to_message(value)?;
+        Ok(Self(SignatureNative::sign(private_key, &message, &mut StdRng::from_entropy()).map_err(|e| e.to_string())?))
+    }
+
+    /// Verify a signature of a structured Aleo plaintext value with an address
+    ///
+    /// @param {Address} address The address to verify the signature with
+    /// @param {string} value String representation of the Aleo plaintext value to verify
+    /// @returns {boolean} True if the signature is valid, false otherwise
+    #[wasm_bindgen(js_name = verifyPlaintext)]
+    pub fn verify_plaintext(&self, address: &Address, value: &str) -> bool {
+        match Self::plaintext_to_message(value) {
+            Ok(message) => self.0.verify(address, &message),
+            Err(_) => false,
+        }
+    }
+
     /// Verify a signature of a message with an address
     ///
     /// @param {Address} address The address to verify the signature with
@@ -62,6 +166,119 @@ impl Signature {
     pub fn to_string(&self) -> String {
         self.0.to_string()
     }
+
+    /// Get a signature from its little-endian byte representation
+    ///
+    /// @param {Uint8Array} bytes 128 byte long Uint8Array representation of a signature
+    /// @returns {Signature | Error} Signature
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Signature, String> {
+        if bytes.len() != SIGNATURE_SIZE_IN_BYTES {
+            return Err(format!(
+                "Expected {SIGNATURE_SIZE_IN_BYTES} bytes for a signature, but {} bytes were provided",
+                bytes.len()
+            ));
+        }
+        Ok(Self(SignatureNative::from_bytes_le(bytes).map_err(|_| "Invalid signature bytes".to_string())?))
+    }
+
+    /// Get the little-endian byte representation of a signature
+    ///
+    /// @returns {Uint8Array | Error} 128 byte long Uint8Array representation of a signature
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        self.0.to_bytes_le().map_err(|_| "Failed to serialize signature".to_string())
+    }
+
+    /// Get the challenge of the signature
+    ///
+    /// @returns {string} String representation of the challenge scalar
+    pub fn challenge(&self) -> String {
+        self.0.challenge().to_string()
+    }
+
+    /// Get the response of the signature
+    ///
+    /// @returns {string} String representation of the response scalar
+    pub fn response(&self) -> String {
+        self.0.response().to_string()
+    }
+
+    /// Get the compute key of the signer, as a JSON string with the members `pk_sig` and `pr_sig`
+    ///
+    /// @returns {string} JSON string representation of the compute key
+    #[wasm_bindgen(js_name = computeKey)]
+    pub fn compute_key(&self) -> String {
+        self.compute_key_json().to_string()
+    }
+
+    /// Get a JSON string representation of the components of the signature, with the members
+    /// `challenge`, `response` and `compute_key`
+    ///
+    /// @returns {string} JSON string representation of the signature
+    #[wasm_bindgen(js_name = toJson)]
+    pub fn to_json(&self) -> String {
+        json!({
+            "challenge": self.challenge(),
+            "response": self.response(),
+            "compute_key": self.compute_key_json(),
+        })
+        .to_string()
+    }
+
+    /// Get a signature from the JSON string representation of its components
+    ///
+    /// @param {string} json JSON string representation of a signature
+    /// @returns {Signature | Error} Signature
+    #[wasm_bindgen(js_name = fromJson)]
+    pub fn from_json(json: &str) -> Result<Signature, String> {
+        let value: Value = serde_json::from_str(json).map_err(|_| "Invalid signature JSON".to_string())?;
+        let member = |value: &Value, key: &str| {
+            value[key].as_str().map(str::to_string).ok_or_else(|| format!("Missing signature member '{key}'"))
+        };
+        let challenge = ScalarNative::from_str(&member(&value, "challenge")?).map_err(|e| e.to_string())?;
+        let response = ScalarNative::from_str(&member(&value, "response")?).map_err(|e| e.to_string())?;
+        let pk_sig = GroupNative::from_str(&member(&value["compute_key"], "pk_sig")?).map_err(|e| e.to_string())?;
+        let pr_sig = GroupNative::from_str(&member(&value["compute_key"], "pr_sig")?).map_err(|e| e.to_string())?;
+        let compute_key = ComputeKeyNative::try_from((pk_sig, pr_sig)).map_err(|e| e.to_string())?;
+        Ok(Self(SignatureNative::from((challenge, response, compute_key))))
+    }
+}
+
+impl Signature {
+    // Get the JSON representation of the compute key
+    fn compute_key_json(&self) -> Value {
+        let compute_key = self.0.compute_key();
+        json!({ "pk_sig": compute_key.pk_sig().to_string(), "pr_sig": compute_key.pr_sig().to_string() })
+    }
+
+    // Encode a plaintext value into a domain separated message of field elements
+    fn plaintext_to_message(value: &str) -> Result<Vec<FieldNative>, String> {
+        let plaintext = PlaintextNative::from_str(value).map_err(|_| format!("Invalid plaintext value '{value}'"))?;
+        let mut message = vec![FieldNative::new_domain_separator("AleoSignedPlaintext")];
+        message.extend(plaintext.to_fields().map_err(|e| e.to_string())?);
+        Ok(message)
+    }
+}
+
+/// Verify a signature of a message with an address without constructing `Address` and
+/// `Signature` objects
+///
+/// @param {string} address String representation of the address to verify the signature with
+/// @param {Uint8Array} message Byte representation of the message to verify
+/// @param {string} signature String representation of the signature
+/// @returns {boolean | Error} True if the signature is valid, false otherwise
+#[wasm_bindgen(js_name = verifyMessage)]
+pub fn verify_message(address: &str, message: &[u8], signature: &str) -> Result<bool, String> {
+    let address = AddressNative::from_str(address).map_err(|_| format!("Invalid address '{address}'"))?;
+    let signature = SignatureNative::from_str(signature).map_err(|_| format!("Invalid signature '{signature}'"))?;
+    Ok(signature.verify_bytes(&address, message))
+}
+
+impl From<SignatureNative> for Signature {
+    fn from(signature: SignatureNative) -> Self {
+        Self(signature)
+    }
 }
 
 impl FromStr for Signature {
@@ -113,4 +330,115 @@ mod tests {
             assert!(!signature.verify(&private_key.to_address(), &bad_message));
         }
     }
+
+    #[wasm_bindgen_test]
+    pub fn test_verify_message() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address().to_string();
+        let signature = Signature::sign(&private_key, b"login").to_string();
+
+        assert!(verify_message(&address, b"login", &signature).unwrap());
+        assert!(!verify_message(&address, b"logout", &signature).unwrap());
+        assert!(!verify_message(&PrivateKey::new().to_address().to_string(), b"login", &signature).unwrap());
+        assert!(verify_message("aleo1invalid", b"login", &signature).is_err());
+        assert!(verify_message(&address, b"login", "sign1invalid").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_serialization() {
+        let private_key = PrivateKey::new();
+        let message = b"serialization";
+        let signature = Signature::sign(&private_key, message);
+
+        // Check the byte representation round trips.
+        let bytes = signature.to_bytes().unwrap();
+        assert_eq!(bytes.len(), SIGNATURE_SIZE_IN_BYTES);
+        let recovered = Signature::from_bytes(&bytes).unwrap();
+        assert_eq!(signature.to_string(), recovered.to_string());
+        assert!(recovered.verify(&private_key.to_address(), message));
+        assert!(Signature::from_bytes(&bytes[..64]).is_err());
+
+        // Check the JSON representation round trips and exposes the components.
+        let json: Value = serde_json::from_str(&signature.to_json()).unwrap();
+        assert_eq!(json["challenge"], signature.challenge());
+        assert_eq!(json["response"], signature.response());
+        let compute_key: Value = serde_json::from_str(&signature.compute_key()).unwrap();
+        assert_eq!(json["compute_key"], compute_key);
+        let recovered = Signature::from_json(&signature.to_json()).unwrap();
+        assert_eq!(signature.to_string(), recovered.to_string());
+        assert!(Signature::from_json("{}").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_verify_batch() {
+        let (addresses, messages, signatures) = (Array::new(), Array::new(), Array::new());
+        for _ in 0..10 {
+            // Sample a new private key and message and sign the message.
+            let private_key = PrivateKey::new();
+            let message: [u8; 32] = StdRng::from_entropy().gen();
+            let signature = Signature::sign(&private_key, &message);
+
+            addresses.push(&JsValue::from_str(&private_key.to_address().to_string()));
+            messages.push(&Uint8Array::from(message.as_slice()));
+            signatures.push(&JsValue::from_str(&signature.to_string()));
+        }
+        // Check the batch is valid.
+        assert!(Signature::verify_batch(addresses.clone(), messages.clone(), signatures.clone()).unwrap());
+
+        // Check the batch is invalid if a single message is altered.
+        let bad_messages = messages.slice(0, messages.length());
+        bad_messages.set(3, Uint8Array::from([0u8; 32].as_slice()).into());
+        assert!(!Signature::verify_batch(addresses.clone(), bad_messages, signatures.clone()).unwrap());
+
+        // Check malformed batches are rejected.
+        assert!(Signature::verify_batch(addresses.slice(0, 9), messages.clone(), signatures.clone()).is_err());
+        let bad_addresses = addresses.slice(0, addresses.length());
+        bad_addresses.set(0, JsValue::from_str("aleo1invalid"));
+        assert!(Signature::verify_batch(bad_addresses, messages, signatures).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_and_verify_plaintext() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address();
+        let offer =
+            "{ price: 100u64, item: 5field, buyer: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3 }";
+
+        // Sign the value and check the signature is valid regardless of formatting.
+        let signature = private_key.sign_plaintext(offer).unwrap();
+        assert!(signature.verify_plaintext(&address, offer));
+        let reformatted = "{\n  price: 100u64,\n  item: 5field,\n  buyer: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3\n}";
+        assert!(signature.verify_plaintext(&address, reformatted));
+
+        // Check the signature is invalid for a different value or address.
+        let bad_offer =
+            "{ price: 1u64, item: 5field, buyer: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3 }";
+        assert!(!signature.verify_plaintext(&address, bad_offer));
+        assert!(!signature.verify_plaintext(&PrivateKey::new().to_address(), offer));
+        assert!(!signature.verify_plaintext(&address, "not a plaintext"));
+
+        // Check signing invalid values fails.
+        assert!(private_key.sign_plaintext("{ price: 100 }").is_err());
+    }
+
+    #[cfg(feature = "testing")]
+    #[wasm_bindgen_test]
+    pub fn test_sign_with_rng_seed() {
+        let private_key = PrivateKey::new_with_rng_seed(&[1u8; 32]).unwrap();
+        let message = b"golden vector";
+
+        // Ensure the same rng seed produces the same signature.
+        let signature = Signature::sign_with_rng_seed(&private_key, message, &[2u8; 32]).unwrap();
+        let signature_2 = Signature::sign_with_rng_seed(&private_key, message, &[2u8; 32]).unwrap();
+        assert_eq!(signature.to_string(), signature_2.to_string());
+        assert!(signature.verify(&private_key.to_address(), message));
+
+        // Ensure a different rng seed produces a different but valid signature.
+        let signature_3 = Signature::sign_with_rng_seed(&private_key, message, &[3u8; 32]).unwrap();
+        assert_ne!(signature.to_string(), signature_3.to_string());
+        assert!(signature_3.verify(&private_key.to_address(), message));
+
+        // Ensure rng seeds of the wrong length are rejected.
+        assert!(Signature::sign_with_rng_seed(&private_key, message, &[2u8; 16]).is_err());
+    }
 }
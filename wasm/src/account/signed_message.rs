@@ -0,0 +1,275 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    account::{Address, PrivateKey, Signature},
+    types::{AddressNative, SignatureNative},
+};
+
+use core::{fmt, str::FromStr};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+/// Prefix of every payload signed by a signed message envelope
+const SIGNED_MESSAGE_PREFIX: &[u8] = b"Aleo Signed Message:\n";
+
+/// Message wrapped in an envelope binding it to a domain, a timestamp and a random nonce before it
+/// is signed. Envelopes are verified with a `SignedMessageVerifier` which rejects envelopes for
+/// other domains, stale envelopes and envelopes which have already been seen.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedMessage {
+    domain: String,
+    timestamp: u64,
+    nonce: String,
+    message: String,
+    signer: String,
+    signature: String,
+}
+
+#[wasm_bindgen]
+impl SignedMessage {
+    /// Wrap a message in an envelope and sign it with a private key
+    ///
+    /// @param {PrivateKey} private_key The private key to sign the envelope with
+    /// @param {string} domain Domain the message is intended for, e.g. "app.example.com"
+    /// @param {Uint8Array} message Byte representation of the message to sign
+    /// @param {number | undefined} timestamp (optional) Unix timestamp in seconds, defaults to the current time
+    /// @returns {SignedMessage | Error} Signed message envelope
+    pub fn sign(
+        private_key: &PrivateKey,
+        domain: &str,
+        message: &[u8],
+        timestamp: Option<u64>,
+    ) -> Result<SignedMessage, String> {
+        if domain.is_empty() {
+            return Err("The domain of a signed message cannot be empty".to_string());
+        }
+        let timestamp = timestamp.unwrap_or_else(current_timestamp);
+        let nonce: [u8; 16] = StdRng::from_entropy().gen();
+        let nonce = hex::encode(nonce);
+        let payload = Self::payload(domain, timestamp, &nonce, message);
+        let signature = Signature::sign(private_key, &payload);
+        Ok(Self {
+            domain: domain.to_string(),
+            timestamp,
+            nonce,
+            message: hex::encode(message),
+            signer: private_key.to_address().to_string(),
+            signature: signature.to_string(),
+        })
+    }
+
+    /// Get the domain the message is intended for
+    ///
+    /// @returns {string} Domain of the message
+    pub fn domain(&self) -> String {
+        self.domain.clone()
+    }
+
+    /// Get the unix timestamp in seconds at which the message was signed
+    ///
+    /// @returns {number} Timestamp of the message
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Get the hex encoded random nonce of the envelope
+    ///
+    /// @returns {string} Nonce of the message
+    pub fn nonce(&self) -> String {
+        self.nonce.clone()
+    }
+
+    /// Get the message wrapped in the envelope
+    ///
+    /// @returns {Uint8Array | Error} Byte representation of the message
+    pub fn message(&self) -> Result<Vec<u8>, String> {
+        hex::decode(&self.message).map_err(|_| "Invalid message encoding".to_string())
+    }
+
+    /// Get the address which signed the envelope
+    ///
+    /// @returns {Address | Error} Address of the signer
+    pub fn signer(&self) -> Result<Address, String> {
+        Address::from_str(&self.signer).map_err(|_| "Invalid signer address".to_string())
+    }
+
+    /// Get a JSON string representation of the signed message
+    ///
+    /// @returns {string} JSON string representation of the signed message
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// Get a signed message from its JSON string representation
+    ///
+    /// @param {string} signed_message JSON string representation of a signed message
+    /// @returns {SignedMessage | Error} Signed message
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(signed_message: &str) -> Result<SignedMessage, String> {
+        Self::from_str(signed_message)
+    }
+}
+
+impl SignedMessage {
+    // Encode the envelope into the payload which is signed
+    fn payload(domain: &str, timestamp: u64, nonce: &str, message: &[u8]) -> Vec<u8> {
+        let mut payload = SIGNED_MESSAGE_PREFIX.to_vec();
+        for field in [domain.as_bytes(), &timestamp.to_le_bytes(), nonce.as_bytes(), message] {
+            // Length prefix each field so the encoding is unambiguous.
+            payload.extend_from_slice(&(field.len() as u64).to_le_bytes());
+            payload.extend_from_slice(field);
+        }
+        payload
+    }
+
+    // Check the signature of the envelope is valid for the signer
+    fn is_signature_valid(&self) -> bool {
+        let (Ok(signer), Ok(signature), Ok(message)) =
+            (AddressNative::from_str(&self.signer), SignatureNative::from_str(&self.signature), self.message())
+        else {
+            return false;
+        };
+        signature.verify_bytes(&signer, &Self::payload(&self.domain, self.timestamp, &self.nonce, &message))
+    }
+}
+
+impl FromStr for SignedMessage {
+    type Err = String;
+
+    fn from_str(signed_message: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(signed_message).map_err(|e| format!("Invalid signed message: {e}"))
+    }
+}
+
+impl fmt::Display for SignedMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_string())
+    }
+}
+
+/// Verifier of signed message envelopes for a single domain. The verifier remembers the nonces of
+/// the envelopes it has accepted within the maximum age, so each envelope is only accepted once.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct SignedMessageVerifier {
+    domain: String,
+    max_age_secs: u64,
+    seen: HashMap<String, u64>,
+}
+
+#[wasm_bindgen]
+impl SignedMessageVerifier {
+    /// Create a verifier for a domain
+    ///
+    /// @param {string} domain Domain envelopes must be signed for
+    /// @param {number} max_age_secs Maximum age in seconds of an accepted envelope
+    /// @returns {SignedMessageVerifier} Verifier
+    #[wasm_bindgen(constructor)]
+    pub fn new(domain: &str, max_age_secs: u64) -> Self {
+        Self { domain: domain.to_string(), max_age_secs, seen: HashMap::new() }
+    }
+
+    /// Verify a signed message envelope. Envelopes signed for another domain, signed more than
+    /// `max_age_secs` away from the current time, or which have already been accepted are rejected
+    ///
+    /// @param {SignedMessage} signed_message Signed message envelope to verify
+    /// @param {number | undefined} now (optional) Current unix timestamp in seconds, defaults to the current time
+    /// @returns {Address | Error} Address of the signer if the envelope is valid
+    pub fn verify(&mut self, signed_message: &SignedMessage, now: Option<u64>) -> Result<Address, String> {
+        let now = now.unwrap_or_else(current_timestamp);
+        if signed_message.domain != self.domain {
+            return Err(format!(
+                "Signed message is for domain '{}', expected '{}'",
+                signed_message.domain, self.domain
+            ));
+        }
+        if now.abs_diff(signed_message.timestamp) > self.max_age_secs {
+            return Err("Signed message has expired".to_string());
+        }
+        if !signed_message.is_signature_valid() {
+            return Err("Invalid signature".to_string());
+        }
+        // Forget nonces which are too old to be accepted again.
+        let max_age_secs = self.max_age_secs;
+        self.seen.retain(|_, timestamp| now.abs_diff(*timestamp) <= max_age_secs);
+        if self.seen.insert(signed_message.nonce.clone(), signed_message.timestamp).is_some() {
+            return Err("Signed message has already been used".to_string());
+        }
+        signed_message.signer()
+    }
+}
+
+// Get the current unix timestamp in seconds
+fn current_timestamp() -> u64 {
+    (js_sys::Date::now() / 1000.0) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const DOMAIN: &str = "app.example.com";
+    const NOW: u64 = 1_700_000_000;
+
+    #[wasm_bindgen_test]
+    fn test_sign_and_verify() {
+        let private_key = PrivateKey::new();
+        let signed_message = SignedMessage::sign(&private_key, DOMAIN, b"Sign in", Some(NOW)).unwrap();
+        assert_eq!(signed_message.message().unwrap(), b"Sign in");
+
+        // Check the envelope survives the round trip to and from string.
+        let signed_message = SignedMessage::from_string(&signed_message.to_string()).unwrap();
+        let mut verifier = SignedMessageVerifier::new(DOMAIN, 300);
+        assert_eq!(verifier.verify(&signed_message, Some(NOW + 10)).unwrap(), private_key.to_address());
+
+        // Check replayed envelopes are rejected.
+        assert!(verifier.verify(&signed_message, Some(NOW + 20)).is_err());
+
+        // Check a new envelope for the same message is accepted.
+        let signed_message = SignedMessage::sign(&private_key, DOMAIN, b"Sign in", Some(NOW)).unwrap();
+        assert!(verifier.verify(&signed_message, Some(NOW + 20)).is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_invalid_envelopes() {
+        let private_key = PrivateKey::new();
+        let signed_message = SignedMessage::sign(&private_key, DOMAIN, b"Sign in", Some(NOW)).unwrap();
+
+        // Check envelopes for other domains or outside the maximum age are rejected.
+        assert!(SignedMessageVerifier::new("evil.example.com", 300).verify(&signed_message, Some(NOW)).is_err());
+        assert!(SignedMessageVerifier::new(DOMAIN, 300).verify(&signed_message, Some(NOW + 301)).is_err());
+        assert!(SignedMessageVerifier::new(DOMAIN, 300).verify(&signed_message, Some(NOW - 301)).is_err());
+
+        // Check tampered envelopes are rejected.
+        let mut tampered = signed_message.clone();
+        tampered.message = hex::encode(b"Transfer everything");
+        assert!(SignedMessageVerifier::new(DOMAIN, 300).verify(&tampered, Some(NOW)).is_err());
+        let mut tampered = signed_message;
+        tampered.timestamp = NOW + 100;
+        assert!(SignedMessageVerifier::new(DOMAIN, 300).verify(&tampered, Some(NOW + 100)).is_err());
+
+        // Check envelopes cannot be signed without a domain.
+        assert!(SignedMessage::sign(&private_key, "", b"Sign in", Some(NOW)).is_err());
+    }
+}
@@ -14,12 +14,21 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use super::{Address, PrivateKey};
-use crate::{record::RecordCiphertext, types::ViewKeyNative};
+use super::{Address, Encryptor, PrivateKey};
+use crate::{
+    record::{RecordCiphertext, RecordPlaintext},
+    types::{CiphertextNative, FromBytes, GroupNative, RecordCiphertextNative, ToBytes, ViewKeyNative},
+};
 
 use core::{convert::TryFrom, fmt, ops::Deref, str::FromStr};
+use js_sys::{Array, Object, Reflect};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use wasm_bindgen::prelude::*;
 
+/// Number of bytes in the byte representation of a view key
+const VIEW_KEY_SIZE_IN_BYTES: usize = 32;
+
 #[wasm_bindgen]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ViewKey(ViewKeyNative);
@@ -42,6 +51,29 @@ impl ViewKey {
         Self::from_str(view_key).unwrap()
     }
 
+    /// Get a view key from its little-endian byte representation
+    ///
+    /// @param {Uint8Array} bytes 32 byte long Uint8Array representation of a view key
+    /// @returns {ViewKey | Error} ViewKey
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<ViewKey, String> {
+        if bytes.len() != VIEW_KEY_SIZE_IN_BYTES {
+            return Err(format!(
+                "Expected {VIEW_KEY_SIZE_IN_BYTES} bytes for a view key, but {} bytes were provided",
+                bytes.len()
+            ));
+        }
+        Ok(Self(ViewKeyNative::from_bytes_le(bytes).map_err(|_| "Invalid view key bytes".to_string())?))
+    }
+
+    /// Get the little-endian byte representation of a view key
+    ///
+    /// @returns {Uint8Array | Error} 32 byte long Uint8Array representation of a view key
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        self.0.to_bytes_le().map_err(|_| "Failed to serialize view key".to_string())
+    }
+
     /// Get a string representation of a view key
     ///
     /// @returns {string} String representation of a view key
@@ -68,6 +100,99 @@ impl ViewKey {
             Err(error) => Err(error),
         }
     }
+
+    /// Check which of many record ciphertexts are owned by the view key in a single call
+    ///
+    /// @param {Array<string>} ciphertexts Array of string representations of record ciphertexts
+    /// @returns {Array<boolean> | Error} Array of booleans representing whether or not each record is owned by the view key
+    #[wasm_bindgen(js_name = isOwnerBatch)]
+    pub fn is_owner_batch(&self, ciphertexts: Array) -> Result<Array, String> {
+        let ciphertexts = (0..ciphertexts.length())
+            .map(|index| {
+                ciphertexts
+                    .get(index)
+                    .as_string()
+                    .and_then(|ciphertext| RecordCiphertextNative::from_str(&ciphertext).ok())
+                    .ok_or_else(|| format!("Invalid record ciphertext at index {index}"))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        #[cfg(feature = "parallel")]
+        let ciphertexts = ciphertexts.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let ciphertexts = ciphertexts.iter();
+        let owned = crate::with_thread_budget("scanning", || {
+            ciphertexts.map(|ciphertext| ciphertext.is_owner(&self.0)).collect::<Vec<bool>>()
+        });
+        Ok(owned.into_iter().map(JsValue::from_bool).collect())
+    }
+
+    /// Decrypt many record ciphertexts in a single call. The result has one object per ciphertext
+    /// of the form `{ record }` if it was decrypted or `{ error }` if it was invalid or not owned by
+    /// the view key
+    ///
+    /// @param {Array<string>} ciphertexts Array of string representations of record ciphertexts
+    /// @returns {Array<Object>} Array of `{ record: RecordPlaintext }` or `{ error: string }` objects
+    #[wasm_bindgen(js_name = decryptMany)]
+    pub fn decrypt_many(&self, ciphertexts: Array) -> Array {
+        let ciphertexts = ciphertexts
+            .iter()
+            .map(|ciphertext| {
+                ciphertext
+                    .as_string()
+                    .and_then(|ciphertext| RecordCiphertextNative::from_str(&ciphertext).ok())
+                    .ok_or_else(|| "Invalid record ciphertext".to_string())
+            })
+            .collect::<Vec<_>>();
+
+        #[cfg(feature = "parallel")]
+        let ciphertexts = ciphertexts.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let ciphertexts = ciphertexts.iter();
+        let records = crate::with_thread_budget("scanning", || {
+            ciphertexts
+                .map(|ciphertext| {
+                    let ciphertext = ciphertext.as_ref().map_err(Clone::clone)?;
+                    ciphertext
+                        .decrypt(&self.0)
+                        .map_err(|_| "Decryption failed - view key did not match record".to_string())
+                })
+                .collect::<Vec<_>>()
+        });
+
+        records
+            .into_iter()
+            .map(|record| {
+                let object = Object::new();
+                let (key, value) = match record {
+                    Ok(record) => ("record", JsValue::from(RecordPlaintext::from(record))),
+                    Err(error) => ("error", JsValue::from_str(&error)),
+                };
+                // Setting a property on a fresh object cannot fail
+                let _ = Reflect::set(&object, &JsValue::from_str(key), &value);
+                JsValue::from(object)
+            })
+            .collect()
+    }
+}
+
+/// Decrypt bytes encrypted to the address of a view key with `encryptForAddress`
+///
+/// @param {ViewKey} view_key View key of the recipient address
+/// @param {string} ciphertext String representation of the ciphertext returned by `encryptForAddress`
+/// @returns {Uint8Array | Error} The decrypted bytes, or an error if the ciphertext was not
+/// encrypted to the address of the view key or was modified
+#[wasm_bindgen(js_name = decryptWithViewKey)]
+pub fn decrypt_with_view_key(view_key: &ViewKey, ciphertext: &str) -> Result<Vec<u8>, String> {
+    let mut sections = ciphertext.split('$');
+    let ephemeral = sections
+        .next()
+        .and_then(|ephemeral| GroupNative::from_str(ephemeral).ok())
+        .ok_or_else(|| "Invalid address ciphertext".to_string())?;
+    let ciphertexts = sections
+        .map(|ciphertext| CiphertextNative::from_str(ciphertext).map_err(|_| "Invalid address ciphertext".to_string()))
+        .collect::<Result<Vec<_>, String>>()?;
+    Encryptor::decrypt_bytes_with_view_key(&ephemeral, &ciphertexts, view_key)
 }
 
 impl FromStr for ViewKey {
@@ -95,6 +220,7 @@ impl Deref for ViewKey {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::account::encrypt_for_address;
 
     use wasm_bindgen_test::*;
 
@@ -116,6 +242,18 @@ mod tests {
         assert_eq!(given_view_key, view_key.to_string());
     }
 
+    #[wasm_bindgen_test]
+    pub fn test_to_and_from_bytes() {
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY);
+        let bytes = view_key.to_bytes().unwrap();
+        assert_eq!(bytes.len(), VIEW_KEY_SIZE_IN_BYTES);
+        assert_eq!(view_key, ViewKey::from_bytes(&bytes).unwrap());
+
+        // Ensure byte arrays of the wrong length are rejected.
+        assert!(ViewKey::from_bytes(&bytes[..31]).is_err());
+        assert!(ViewKey::from_bytes(&[bytes.as_slice(), &[0u8]].concat()).is_err());
+    }
+
     #[wasm_bindgen_test]
     pub fn test_decrypt_success() {
         let view_key = ViewKey::from_string(OWNER_VIEW_KEY);
@@ -132,4 +270,53 @@ mod tests {
         let plaintext = ciphertext.decrypt(&incorrect_view_key);
         assert!(plaintext.is_err());
     }
+
+    #[wasm_bindgen_test]
+    pub fn test_is_owner_batch() {
+        let ciphertexts = Array::new();
+        ciphertexts.push(&JsValue::from_str(OWNER_CIPHERTEXT));
+        ciphertexts.push(&JsValue::from_str(OWNER_CIPHERTEXT));
+
+        let owned = ViewKey::from_string(OWNER_VIEW_KEY).is_owner_batch(ciphertexts.clone()).unwrap();
+        assert_eq!(owned.length(), 2);
+        assert!(owned.every(&mut |owned, _, _| owned.as_bool() == Some(true)));
+        let owned = ViewKey::from_string(NON_OWNER_VIEW_KEY).is_owner_batch(ciphertexts.clone()).unwrap();
+        assert!(owned.every(&mut |owned, _, _| owned.as_bool() == Some(false)));
+
+        // Ensure invalid ciphertexts are rejected.
+        ciphertexts.push(&JsValue::from_str("invalid"));
+        assert!(ViewKey::from_string(OWNER_VIEW_KEY).is_owner_batch(ciphertexts).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_decrypt_many() {
+        let ciphertexts = Array::new();
+        ciphertexts.push(&JsValue::from_str(OWNER_CIPHERTEXT));
+        ciphertexts.push(&JsValue::from_str("invalid"));
+        ciphertexts.push(&JsValue::from_str(OWNER_CIPHERTEXT));
+
+        let results = ViewKey::from_string(OWNER_VIEW_KEY).decrypt_many(ciphertexts.clone());
+        assert_eq!(results.length(), 3);
+        let error = |index| Reflect::get(&results.get(index), &JsValue::from_str("error")).unwrap();
+        let record = |index| Reflect::get(&results.get(index), &JsValue::from_str("record")).unwrap();
+        assert!(error(0).is_undefined() && !record(0).is_undefined());
+        assert_eq!(error(1).as_string().unwrap(), "Invalid record ciphertext");
+        assert!(record(1).is_undefined());
+        assert!(error(2).is_undefined() && !record(2).is_undefined());
+
+        let results = ViewKey::from_string(NON_OWNER_VIEW_KEY).decrypt_many(ciphertexts);
+        assert!(results.every(&mut |result, _, _| Reflect::has(&result, &JsValue::from_str("error")).unwrap()));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_encrypt_for_address_and_decrypt_with_view_key() {
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY);
+        let ciphertext = encrypt_for_address(&view_key.to_address(), b"order details").unwrap();
+        assert_ne!(encrypt_for_address(&view_key.to_address(), b"order details").unwrap(), ciphertext);
+        assert_eq!(decrypt_with_view_key(&view_key, &ciphertext).unwrap(), b"order details");
+        assert!(decrypt_with_view_key(&ViewKey::from_string(NON_OWNER_VIEW_KEY), &ciphertext).is_err());
+        assert!(decrypt_with_view_key(&view_key, "").is_err());
+        let (ephemeral, _) = ciphertext.split_once('$').unwrap();
+        assert!(decrypt_with_view_key(&view_key, ephemeral).is_err());
+    }
 }
@@ -0,0 +1,333 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{Address, GraphKey, ViewKey};
+use crate::{
+    log,
+    network::MAX_BLOCKS_PER_REQUEST,
+    record::{RecordScanner, ScannedRecord},
+    types::{BlockNative, FieldNative, Input, RecordPlaintextNative},
+    AleoNetworkClient,
+    IndexerClient,
+};
+
+use js_sys::Array;
+use std::{cell::RefCell, str::FromStr};
+use wasm_bindgen::prelude::*;
+
+/// Watch-only account which tracks the records, balance and history of an account using only its
+/// view key. Records are found by scanning blocks and are marked as spent when a transition
+/// publishes their tag, so no private key is ever needed.
+#[wasm_bindgen]
+pub struct ViewOnlyAccount {
+    view_key: ViewKey,
+    graph_key: GraphKey,
+    url: String,
+    state: RefCell<AccountState>,
+}
+
+// Records and history found by the blocks scanned so far
+struct AccountState {
+    cursor: u32,
+    records: Vec<TrackedRecord>,
+    history: Vec<HistoryEntry>,
+}
+
+// Record owned by the account along with its tag and spent status
+struct TrackedRecord {
+    scanned: ScannedRecord,
+    tag: FieldNative,
+    spent: bool,
+}
+
+#[wasm_bindgen]
+impl ViewOnlyAccount {
+    /// Create a watch-only account for a view key
+    ///
+    /// @param {ViewKey} view_key View key of the account
+    /// @param {string} url The url of the Aleo network node to fetch blocks from
+    /// @param {number | undefined} start_height (optional) Height of the first block to scan, defaults to 0
+    /// @returns {ViewOnlyAccount} Watch-only account
+    #[wasm_bindgen(constructor)]
+    pub fn new(view_key: &ViewKey, url: &str, start_height: Option<u32>) -> Self {
+        Self {
+            view_key: view_key.clone(),
+            graph_key: GraphKey::from_view_key(view_key),
+            url: url.trim_end_matches('/').to_string(),
+            state: RefCell::new(AccountState {
+                cursor: start_height.unwrap_or(0),
+                records: Vec::new(),
+                history: Vec::new(),
+            }),
+        }
+    }
+
+    /// Get the address of the account
+    ///
+    /// @returns {Address} Address of the account
+    pub fn address(&self) -> Address {
+        self.view_key.to_address()
+    }
+
+    /// Get the view key of the account
+    ///
+    /// @returns {ViewKey} View key of the account
+    #[wasm_bindgen(js_name = viewKey)]
+    pub fn view_key(&self) -> ViewKey {
+        self.view_key.clone()
+    }
+
+    /// Decrypt a record ciphertext owned by the account
+    ///
+    /// @param {string} ciphertext String representation of a record ciphertext
+    /// @returns {string | Error} String representation of the record plaintext
+    pub fn decrypt(&self, ciphertext: &str) -> Result<String, String> {
+        self.view_key.decrypt(ciphertext)
+    }
+
+    /// Scan the blocks from the cursor up to `end_height` (exclusive), or up to the latest block if
+    /// it is omitted, for records received and spent by the account
+    ///
+    /// @param {number | undefined} end_height (optional) Height after the last block to scan
+    /// @returns {number | Error} The new cursor, i.e. the height of the next block to scan
+    pub async fn sync(&self, end_height: Option<u32>) -> Result<u32, String> {
+        let client = AleoNetworkClient::new(&self.url);
+        let end_height = match end_height {
+            Some(end_height) => end_height,
+            None => client.get_latest_height().await? + 1,
+        };
+        let scanner = RecordScanner::new(&self.view_key, &self.url, None);
+        let mut cursor = self.cursor();
+        while cursor < end_height {
+            let chunk_end = end_height.min(cursor.saturating_add(MAX_BLOCKS_PER_REQUEST));
+            log(&format!("Syncing blocks {cursor} to {chunk_end} for a view only account"));
+            let blocks = client.blocks(cursor, chunk_end).await?;
+            // The state is only borrowed after the blocks are fetched so that no borrow is held across an await
+            let mut state = self.state.borrow_mut();
+            for block in &blocks {
+                self.process_block(&mut state, &scanner, block)?;
+            }
+            state.cursor = chunk_end;
+            cursor = chunk_end;
+        }
+        Ok(cursor)
+    }
+
+    /// Process only the blocks an indexer lists as holding transitions which create or spend records
+    /// of the account from the cursor onwards, instead of scanning every block. The records and
+    /// history are only complete if the indexer lists every such transition
+    ///
+    /// @param {IndexerClient} indexer The indexer listing the transitions of the account
+    /// @returns {number | Error} The new cursor, i.e. the height after the last processed block
+    #[wasm_bindgen(js_name = syncWithIndexer)]
+    pub async fn sync_with_indexer(&self, indexer: &IndexerClient) -> Result<u32, String> {
+        let address = self.address().to_string();
+        let transitions = indexer.indexed_transitions(&address, Some(self.cursor()), None).await?;
+        let mut heights = transitions.iter().map(|transition| transition.height()).collect::<Vec<_>>();
+        heights.sort_unstable();
+        heights.dedup();
+
+        let client = AleoNetworkClient::new(&self.url);
+        let scanner = RecordScanner::new(&self.view_key, &self.url, None);
+        for height in heights.into_iter().filter(|height| *height >= self.cursor()) {
+            log(&format!("Syncing block {height} listed by the indexer for a view only account"));
+            let block = client.block(height).await?;
+            // The state is only borrowed after the block is fetched so that no borrow is held across an await
+            let mut state = self.state.borrow_mut();
+            self.process_block(&mut state, &scanner, &block)?;
+            state.cursor = height.saturating_add(1);
+        }
+        Ok(self.cursor())
+    }
+
+    /// Get the height of the next block to scan
+    ///
+    /// @returns {number} Height of the next block to scan
+    pub fn cursor(&self) -> u32 {
+        self.state.borrow().cursor
+    }
+
+    /// Get the total number of microcredits in the unspent credits.aleo records of the account
+    ///
+    /// @returns {bigint} Private balance of the account in microcredits
+    pub fn balance(&self) -> u64 {
+        self.state
+            .borrow()
+            .records
+            .iter()
+            .filter(|record| !record.spent && record.scanned.program_id() == "credits.aleo")
+            .map(|record| record.scanned.record().microcredits())
+            .sum()
+    }
+
+    /// Get the unspent records of the account
+    ///
+    /// @returns {Array<ScannedRecord>} Unspent records found so far
+    #[wasm_bindgen(js_name = unspentRecords)]
+    pub fn unspent_records(&self) -> Array {
+        self.state
+            .borrow()
+            .records
+            .iter()
+            .filter(|record| !record.spent)
+            .map(|record| JsValue::from(record.scanned.clone()))
+            .collect()
+    }
+
+    /// Get the history of records received and spent by the account in the order they occurred
+    ///
+    /// @returns {Array<HistoryEntry>} History entries found so far
+    pub fn history(&self) -> Array {
+        self.state.borrow().history.iter().cloned().map(JsValue::from).collect()
+    }
+}
+
+impl ViewOnlyAccount {
+    // Track the records received in a block, then mark the records whose tags were published as spent
+    fn process_block(
+        &self,
+        state: &mut AccountState,
+        scanner: &RecordScanner,
+        block: &BlockNative,
+    ) -> Result<(), String> {
+        for scanned in scanner.find_records(block) {
+            let commitment = FieldNative::from_str(&scanned.commitment()).map_err(|e| e.to_string())?;
+            let tag = RecordPlaintextNative::tag((*self.graph_key).sk_tag(), commitment).map_err(|e| e.to_string())?;
+            state.history.push(HistoryEntry::new("received", &scanned, scanned.height(), None));
+            state.records.push(TrackedRecord { scanned, tag, spent: false });
+        }
+
+        for confirmed in block.transactions().iter() {
+            let transaction = confirmed.transaction();
+            for transition in transaction.transitions() {
+                for input in transition.inputs() {
+                    let Input::Record(_, tag) = input else {
+                        continue;
+                    };
+                    if let Some(record) = state.records.iter_mut().find(|record| !record.spent && &record.tag == tag) {
+                        record.spent = true;
+                        let spent_in = (transaction.id().to_string(), transition.id().to_string());
+                        let entry = HistoryEntry::new("spent", &record.scanned, block.height(), Some(spent_in));
+                        state.history.push(entry);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Record received or spent by a watch-only account
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct HistoryEntry {
+    kind: &'static str,
+    record: ScannedRecord,
+    height: u32,
+    transaction_id: String,
+    transition_id: String,
+}
+
+impl HistoryEntry {
+    // Create a history entry for a record, using the transaction which created it unless it was spent
+    fn new(kind: &'static str, record: &ScannedRecord, height: u32, spent_in: Option<(String, String)>) -> Self {
+        let (transaction_id, transition_id) =
+            spent_in.unwrap_or_else(|| (record.transaction_id(), record.transition_id()));
+        Self { kind, record: record.clone(), height, transaction_id, transition_id }
+    }
+}
+
+#[wasm_bindgen]
+impl HistoryEntry {
+    /// Get the kind of the entry, either "received" or "spent"
+    ///
+    /// @returns {string} Kind of the entry
+    pub fn kind(&self) -> String {
+        self.kind.to_string()
+    }
+
+    /// Get the record which was received or spent
+    ///
+    /// @returns {ScannedRecord} Record of the entry
+    pub fn record(&self) -> ScannedRecord {
+        self.record.clone()
+    }
+
+    /// Get the number of microcredits in the record, which is 0 for records which are not credits
+    ///
+    /// @returns {bigint} Microcredits of the record
+    pub fn microcredits(&self) -> u64 {
+        match self.record.program_id() == "credits.aleo" {
+            true => self.record.record().microcredits(),
+            false => 0,
+        }
+    }
+
+    /// Get the height of the block in which the record was received or spent
+    ///
+    /// @returns {number} Block height
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Get the id of the transaction in which the record was received or spent
+    ///
+    /// @returns {string} Transaction id
+    #[wasm_bindgen(js_name = transactionId)]
+    pub fn transaction_id(&self) -> String {
+        self.transaction_id.clone()
+    }
+
+    /// Get the id of the transition in which the record was received or spent
+    ///
+    /// @returns {string} Transition id
+    #[wasm_bindgen(js_name = transitionId)]
+    pub fn transition_id(&self) -> String {
+        self.transition_id.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::account::PrivateKey;
+
+    use wasm_bindgen_test::*;
+
+    const OWNER_CIPHERTEXT: &str = "record1qyqsqpe2szk2wwwq56akkwx586hkndl3r8vzdwve32lm7elvphh37rsyqyxx66trwfhkxun9v35hguerqqpqzqrtjzeu6vah9x2me2exkgege824sd8x2379scspmrmtvczs0d93qttl7y92ga0k0rsexu409hu3vlehe3yxjhmey3frh2z5pxm5cmxsv4un97q";
+    const OWNER_VIEW_KEY: &str = "AViewKey1ccEt8A2Ryva5rxnKcAbn7wgTaTsb79tzkKHFpeKsm9NX";
+    const OWNER_ADDRESS: &str = "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3";
+
+    #[wasm_bindgen_test]
+    async fn test_new_account_is_empty() {
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY);
+        let account = ViewOnlyAccount::new(&view_key, "https://api.explorer.aleo.org/v1/", Some(100));
+        assert_eq!(account.address().to_string(), OWNER_ADDRESS);
+        assert_eq!(account.view_key(), view_key);
+        assert!(account.decrypt(OWNER_CIPHERTEXT).is_ok());
+
+        // Syncing up to the cursor does not contact the node.
+        assert_eq!(account.sync(Some(100)).await.unwrap(), 100);
+        assert_eq!(account.cursor(), 100);
+        assert_eq!(account.balance(), 0);
+        assert_eq!(account.unspent_records().length(), 0);
+        assert_eq!(account.history().length(), 0);
+
+        let other = ViewOnlyAccount::new(&PrivateKey::new().to_view_key(), "https://api.explorer.aleo.org/v1", None);
+        assert!(other.decrypt(OWNER_CIPHERTEXT).is_err());
+        assert_eq!(other.cursor(), 0);
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Scalar;
+use crate::types::{FieldNative, GroupNative};
+
+use core::{fmt, ops::Deref, str::FromStr};
+use wasm_bindgen::prelude::*;
+
+/// An element of the prime order subgroup of Aleo's twisted Edwards curve
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Group(GroupNative);
+
+#[wasm_bindgen]
+impl Group {
+    /// Get the generator of the group
+    ///
+    /// @returns {Group} The generator of the group
+    pub fn generator() -> Group {
+        Self(GroupNative::generator())
+    }
+
+    /// Create a group element from its string representation
+    ///
+    /// @param {string} group String representation of the group element, e.g. "2group"
+    /// @returns {Group | Error}
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(group: &str) -> Result<Group, String> {
+        Self::from_str(group).map_err(|_| format!("Invalid group element '{group}'"))
+    }
+
+    /// Get the string representation of the group element
+    ///
+    /// @returns {string} String representation of the group element
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Recover the group element with the given x-coordinate
+    ///
+    /// @param {string} x_coordinate String representation of the x-coordinate field
+    /// @returns {Group | Error}
+    #[wasm_bindgen(js_name = fromXCoordinate)]
+    pub fn from_x_coordinate(x_coordinate: &str) -> Result<Group, String> {
+        let x_coordinate =
+            FieldNative::from_str(x_coordinate).map_err(|_| format!("Invalid field element '{x_coordinate}'"))?;
+        GroupNative::from_x_coordinate(x_coordinate)
+            .map(Self)
+            .map_err(|_| format!("No group element has the x-coordinate {x_coordinate}"))
+    }
+
+    /// Get the x-coordinate of the group element
+    ///
+    /// @returns {string} String representation of the x-coordinate field
+    #[wasm_bindgen(js_name = toXCoordinate)]
+    pub fn to_x_coordinate(&self) -> String {
+        self.0.to_x_coordinate().to_string()
+    }
+
+    /// Add another group element to this one
+    ///
+    /// @param {Group} other The group element to add
+    /// @returns {Group} The sum of the group elements
+    pub fn add(&self, other: &Group) -> Group {
+        Self(self.0 + other.0)
+    }
+
+    /// Subtract another group element from this one
+    ///
+    /// @param {Group} other The group element to subtract
+    /// @returns {Group} The difference of the group elements
+    pub fn subtract(&self, other: &Group) -> Group {
+        Self(self.0 - other.0)
+    }
+
+    /// Negate the group element
+    ///
+    /// @returns {Group} The inverse of the group element
+    pub fn negate(&self) -> Group {
+        Self(-self.0)
+    }
+
+    /// Multiply the group element by a scalar
+    ///
+    /// @param {Scalar} scalar The scalar to multiply by
+    /// @returns {Group} The product of the group element and the scalar
+    #[wasm_bindgen(js_name = scalarMultiply)]
+    pub fn scalar_multiply(&self, scalar: &Scalar) -> Group {
+        Self(self.0 * **scalar)
+    }
+
+    /// Check if two group elements are equal
+    ///
+    /// @param {Group} other The group element to compare with
+    /// @returns {boolean} True if the group elements are equal
+    pub fn equals(&self, other: &Group) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl From<GroupNative> for Group {
+    fn from(group: GroupNative) -> Self {
+        Self(group)
+    }
+}
+
+impl FromStr for Group {
+    type Err = anyhow::Error;
+
+    fn from_str(group: &str) -> Result<Self, Self::Err> {
+        Ok(Self(GroupNative::from_str(group)?))
+    }
+}
+
+impl fmt::Display for Group {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for Group {
+    type Target = GroupNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_group_arithmetic() {
+        let generator = Group::generator();
+        let double = generator.add(&generator);
+        let scalar = |scalar: &str| Scalar::from_string(scalar).unwrap();
+        assert_eq!(generator.scalar_multiply(&scalar("2scalar")), double);
+        assert_eq!(double.subtract(&generator), generator);
+        assert!(generator.add(&generator.negate()).equals(&generator.scalar_multiply(&scalar("0scalar"))));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_group_conversions() {
+        let group = Group::generator().scalar_multiply(&Scalar::from_string("12345scalar").unwrap());
+        assert_eq!(Group::from_string(&group.to_string()).unwrap(), group);
+        let x_coordinate = group.to_x_coordinate();
+        assert_eq!(Group::from_x_coordinate(&x_coordinate).unwrap().to_x_coordinate(), x_coordinate);
+        assert_eq!(group.to_string(), x_coordinate.replace("field", "group"));
+        assert!(Group::from_string("1field").is_err());
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{Group, Scalar};
+use crate::types::{FieldNative, GroupNative, ScalarNative, Uniform};
+
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+#[cfg(feature = "testing")]
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "testing")]
+thread_local! {
+    /// Deterministic RNG which replaces the platform CSPRNG while set, for reproducible tests
+    static TEST_RNG: RefCell<Option<StdRng>> = RefCell::new(None);
+}
+
+/// Run a closure with the test RNG if one is set, or with an RNG seeded by the platform CSPRNG.
+/// Only builds with the `testing` feature can set a test RNG
+pub(crate) fn with_rng<T>(f: impl FnOnce(&mut StdRng) -> T) -> T {
+    #[cfg(feature = "testing")]
+    if let Some(mut rng) = TEST_RNG.with(|test_rng| test_rng.borrow_mut().take()) {
+        let output = f(&mut rng);
+        TEST_RNG.with(|test_rng| *test_rng.borrow_mut() = Some(rng));
+        return output;
+    }
+    f(&mut StdRng::from_entropy())
+}
+
+/// Replace the platform CSPRNG with a deterministic RNG so that the random elements are
+/// reproducible. This is meant for tests only, the elements are predictable from the seed
+///
+/// @param {bigint | undefined} seed Seed of the deterministic RNG, or undefined to use the platform
+/// CSPRNG again
+#[cfg(feature = "testing")]
+#[wasm_bindgen(js_name = setTestRng)]
+pub fn set_test_rng(seed: Option<u64>) {
+    TEST_RNG.with(|test_rng| *test_rng.borrow_mut() = seed.map(StdRng::seed_from_u64));
+}
+
+/// Sample a uniformly random field element
+///
+/// @returns {string} String representation of the field element
+#[wasm_bindgen(js_name = randomField)]
+pub fn random_field() -> String {
+    with_rng(FieldNative::rand).to_string()
+}
+
+/// Sample a uniformly random scalar
+///
+/// @returns {Scalar} The random scalar
+#[wasm_bindgen(js_name = randomScalar)]
+pub fn random_scalar() -> Scalar {
+    Scalar::random()
+}
+
+/// Sample a uniformly random element of the prime order subgroup
+///
+/// @returns {Group} The random group element
+#[wasm_bindgen(js_name = randomGroup)]
+pub fn random_group() -> Group {
+    Group::from(GroupNative::generator() * with_rng(ScalarNative::rand))
+}
+
+/// Sample random bytes to seed keys or other randomness from
+///
+/// @param {number} length The number of bytes to sample
+/// @returns {Uint8Array} The random bytes
+#[wasm_bindgen(js_name = randomSeed)]
+pub fn random_seed(length: usize) -> Vec<u8> {
+    let mut seed = vec![0u8; length];
+    with_rng(|rng| rng.fill_bytes(&mut seed));
+    seed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_random_elements() {
+        assert_ne!(random_field(), random_field());
+        assert_ne!(random_scalar(), random_scalar());
+        assert_ne!(random_group(), random_group());
+        assert_eq!(random_seed(32).len(), 32);
+        assert_ne!(random_seed(32), random_seed(32));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_test_rng_is_only_exported_by_testing_builds() {
+        let exported =
+            |name: &str| !js_sys::Reflect::get(&wasm_bindgen::exports(), &name.into()).unwrap().is_undefined();
+        assert!(exported("randomField"));
+        assert_eq!(exported("setTestRng"), cfg!(feature = "testing"));
+    }
+
+    #[cfg(feature = "testing")]
+    #[wasm_bindgen_test]
+    fn test_random_elements_with_test_rng() {
+        let sample = || (random_field(), random_scalar(), random_group(), random_seed(16));
+
+        set_test_rng(Some(42));
+        let first = sample();
+        set_test_rng(Some(42));
+        assert_eq!(sample(), first);
+        set_test_rng(None);
+        assert_ne!(sample(), first);
+    }
+}
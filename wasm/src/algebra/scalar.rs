@@ -0,0 +1,206 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::with_rng;
+use crate::types::{FieldNative, FromBits, Inverse, LiteralNative, LiteralType, ScalarNative, ToBits, Uniform};
+
+use core::{fmt, ops::Deref, str::FromStr};
+use js_sys::Array;
+use wasm_bindgen::prelude::*;
+
+/// An element of the scalar field of Aleo's curve, used as randomness for commitments, nonces and
+/// ciphertexts
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Scalar(ScalarNative);
+
+#[wasm_bindgen]
+impl Scalar {
+    /// Sample a uniformly random scalar with the platform CSPRNG, or the test RNG if one is set
+    ///
+    /// @returns {Scalar} A random scalar
+    pub fn random() -> Scalar {
+        Self(with_rng(ScalarNative::rand))
+    }
+
+    /// Create a scalar from its string representation
+    ///
+    /// @param {string} scalar String representation of the scalar, e.g. "2scalar"
+    /// @returns {Scalar | Error}
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(scalar: &str) -> Result<Scalar, String> {
+        Self::from_str(scalar).map_err(|_| format!("Invalid scalar '{scalar}'"))
+    }
+
+    /// Get the string representation of the scalar
+    ///
+    /// @returns {string} String representation of the scalar
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Convert a field to a scalar, keeping the low bits of the field which fit in a scalar as
+    /// the `cast.lossy` instruction does
+    ///
+    /// @param {string} field String representation of the field, e.g. "2field"
+    /// @returns {Scalar | Error}
+    #[wasm_bindgen(js_name = fromField)]
+    pub fn from_field(field: &str) -> Result<Scalar, String> {
+        let field = FieldNative::from_str(field).map_err(|_| format!("Invalid field element '{field}'"))?;
+        match LiteralNative::Field(field).cast_lossy(LiteralType::Scalar).map_err(|e| e.to_string())? {
+            LiteralNative::Scalar(scalar) => Ok(Self(scalar)),
+            _ => Err("Failed to cast the field to a scalar".to_string()),
+        }
+    }
+
+    /// Create a scalar from its little endian bits
+    ///
+    /// @param {Array<boolean>} bits The little endian bits of the scalar
+    /// @returns {Scalar | Error} The scalar, or an error if the bits exceed the scalar modulus
+    #[wasm_bindgen(js_name = fromBitsLe)]
+    pub fn from_bits_le(bits: Array) -> Result<Scalar, String> {
+        let bits = (0..bits.length())
+            .map(|index| bits.get(index).as_bool().ok_or_else(|| format!("Invalid bit at index {index}")))
+            .collect::<Result<Vec<_>, String>>()?;
+        ScalarNative::from_bits_le(&bits).map(Self).map_err(|e| format!("Invalid scalar bits: {e}"))
+    }
+
+    /// Get the little endian bits of the scalar
+    ///
+    /// @returns {Array<boolean>} The little endian bits of the scalar
+    #[wasm_bindgen(js_name = toBitsLe)]
+    pub fn to_bits_le(&self) -> Array {
+        self.0.to_bits_le().into_iter().map(JsValue::from_bool).collect()
+    }
+
+    /// Add another scalar to this one
+    ///
+    /// @param {Scalar} other The scalar to add
+    /// @returns {Scalar} The sum of the scalars
+    pub fn add(&self, other: &Scalar) -> Scalar {
+        Self(self.0 + other.0)
+    }
+
+    /// Subtract another scalar from this one
+    ///
+    /// @param {Scalar} other The scalar to subtract
+    /// @returns {Scalar} The difference of the scalars
+    pub fn subtract(&self, other: &Scalar) -> Scalar {
+        Self(self.0 - other.0)
+    }
+
+    /// Multiply this scalar by another one
+    ///
+    /// @param {Scalar} other The scalar to multiply by
+    /// @returns {Scalar} The product of the scalars
+    pub fn multiply(&self, other: &Scalar) -> Scalar {
+        Self(self.0 * other.0)
+    }
+
+    /// Divide this scalar by another one
+    ///
+    /// @param {Scalar} other The scalar to divide by
+    /// @returns {Scalar | Error} The quotient of the scalars, or an error if the divisor is zero
+    pub fn divide(&self, other: &Scalar) -> Result<Scalar, String> {
+        Ok(Self(self.0 * other.inverse()?.0))
+    }
+
+    /// Get the multiplicative inverse of the scalar
+    ///
+    /// @returns {Scalar | Error} The inverse of the scalar, or an error if the scalar is zero
+    pub fn inverse(&self) -> Result<Scalar, String> {
+        self.0.inverse().map(Self).map_err(|_| "Zero has no inverse".to_string())
+    }
+
+    /// Negate the scalar
+    ///
+    /// @returns {Scalar} The additive inverse of the scalar
+    pub fn negate(&self) -> Scalar {
+        Self(-self.0)
+    }
+
+    /// Check if two scalars are equal
+    ///
+    /// @param {Scalar} other The scalar to compare with
+    /// @returns {boolean} True if the scalars are equal
+    pub fn equals(&self, other: &Scalar) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl From<ScalarNative> for Scalar {
+    fn from(scalar: ScalarNative) -> Self {
+        Self(scalar)
+    }
+}
+
+impl FromStr for Scalar {
+    type Err = anyhow::Error;
+
+    fn from_str(scalar: &str) -> Result<Self, Self::Err> {
+        Ok(Self(ScalarNative::from_str(scalar)?))
+    }
+}
+
+impl fmt::Display for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for Scalar {
+    type Target = ScalarNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_scalar_arithmetic() {
+        let two = Scalar::from_string("2scalar").unwrap();
+        let three = Scalar::from_string("3scalar").unwrap();
+        assert_eq!(two.add(&three).to_string(), "5scalar");
+        assert_eq!(two.multiply(&three).to_string(), "6scalar");
+        assert_eq!(three.subtract(&two).to_string(), "1scalar");
+        assert_eq!(two.multiply(&three).divide(&three).unwrap(), two);
+        assert_eq!(two.add(&two.negate()).to_string(), "0scalar");
+        assert!(two.divide(&Scalar::from_string("0scalar").unwrap()).is_err());
+
+        let random = Scalar::random();
+        assert_ne!(random, Scalar::random());
+        assert_eq!(random.multiply(&random.inverse().unwrap()).to_string(), "1scalar");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_scalar_conversions() {
+        let scalar = Scalar::random();
+        assert_eq!(Scalar::from_string(&scalar.to_string()).unwrap(), scalar);
+        assert_eq!(Scalar::from_bits_le(scalar.to_bits_le()).unwrap(), scalar);
+        assert!(Scalar::from_bits_le(Array::of1(&JsValue::from(1))).is_err());
+
+        assert_eq!(Scalar::from_field("12345field").unwrap().to_string(), "12345scalar");
+        assert!(Scalar::from_field("12345scalar").is_err());
+    }
+}
@@ -155,6 +155,12 @@
 pub mod account;
 pub use account::*;
 
+pub mod algebra;
+pub use algebra::*;
+
+pub mod network;
+pub use network::*;
+
 pub mod programs;
 pub use programs::*;
 
@@ -219,3 +225,52 @@ pub async fn init_thread_pool(url: web_sys::Url, num_threads: usize) -> Result<(
 
     Ok(())
 }
+
+/// Get the number of threads the device can run concurrently
+///
+/// @returns {number} The reported hardware concurrency, or 1 when the environment does not report it
+#[cfg(not(test))]
+#[wasm_bindgen(js_name = "availableConcurrency")]
+pub fn available_concurrency() -> usize {
+    thread_pool::available_concurrency()
+}
+
+/// Get the number of threads used by parallel sections without a thread budget
+///
+/// @returns {number} The size of the thread pool
+#[cfg(not(test))]
+#[wasm_bindgen(js_name = "threadPoolSize")]
+pub fn thread_pool_size() -> usize {
+    thread_pool::thread_pool_size()
+}
+
+/// Resize the thread pool used by parallel sections without a thread budget
+///
+/// @param num_threads {number} The number of threads to use, or 0 to go back to the pool created by initThreadPool
+#[cfg(not(test))]
+#[wasm_bindgen(js_name = "setThreadPoolSize")]
+pub async fn set_thread_pool_size(num_threads: usize) -> Result<(), JsValue> {
+    thread_pool::set_thread_pool_size(num_threads).await
+}
+
+/// Limit the number of threads a parallel section of the module may use
+///
+/// Sections are "proving" for proof generation and "scanning" for record ownership checks and
+/// decryption. Limiting proving on devices with few cores keeps threads free for other work.
+///
+/// @param section {string} The name of the section
+/// @param num_threads {number | undefined} The number of threads the section may use, or undefined to remove its budget
+#[cfg(not(test))]
+#[wasm_bindgen(js_name = "setThreadBudget")]
+pub async fn set_thread_budget(section: String, num_threads: Option<usize>) -> Result<(), JsValue> {
+    thread_pool::set_thread_budget(&section, num_threads).await
+}
+
+#[cfg(not(test))]
+pub(crate) use thread_pool::with_thread_budget;
+
+// Parallel sections run on the current thread pool when the thread pool module is not built
+#[cfg(test)]
+pub(crate) fn with_thread_budget<R: Send>(_section: &str, op: impl FnOnce() -> R + Send) -> R {
+    op()
+}
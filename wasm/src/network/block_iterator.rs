@@ -0,0 +1,182 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{block_to_js, AleoNetworkClient, MAX_BLOCKS_PER_REQUEST};
+use crate::types::BlockNative;
+
+use futures::future::join;
+use js_sys::{Array, Function, Object, Promise, Reflect};
+use std::cell::Cell;
+use wasm_bindgen::{prelude::*, JsCast};
+use wasm_bindgen_futures::JsFuture;
+
+/// Iterator over the blocks in a height range which fetches the blocks from the node in chunks
+///
+/// The iterator follows the javascript async iterator protocol, so chunks can be consumed by
+/// calling `next` until it reports it is done, or by passing a callback to `forEach`. The cursor
+/// is the height of the first block which has not been consumed yet, and a new iterator starting
+/// at the cursor resumes where an interrupted iteration stopped.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct BlockRangeIterator {
+    client: AleoNetworkClient,
+    cursor: Cell<u32>,
+    end_height: u32,
+    chunk_size: u32,
+}
+
+#[wasm_bindgen]
+impl AleoNetworkClient {
+    /// Iterate over the blocks in the height range `[start_height, end_height)` in chunks
+    ///
+    /// @param {number} start_height Height of the first block
+    /// @param {number} end_height Height after the last block
+    /// @param {number | undefined} chunk_size (optional) Number of blocks fetched per request, at
+    /// most 50 which is the default
+    /// @returns {BlockRangeIterator | Error} Iterator over chunks of blocks
+    #[wasm_bindgen(js_name = blockRangeIterator)]
+    pub fn block_range_iterator(
+        &self,
+        start_height: u32,
+        end_height: u32,
+        chunk_size: Option<u32>,
+    ) -> Result<BlockRangeIterator, String> {
+        if start_height > end_height {
+            return Err(format!("Invalid block range - start height {start_height} is after end height {end_height}"));
+        }
+        let chunk_size = chunk_size.unwrap_or(MAX_BLOCKS_PER_REQUEST);
+        if chunk_size == 0 || chunk_size > MAX_BLOCKS_PER_REQUEST {
+            return Err(format!("The chunk size must be between 1 and {MAX_BLOCKS_PER_REQUEST}"));
+        }
+        Ok(BlockRangeIterator { client: self.clone(), cursor: Cell::new(start_height), end_height, chunk_size })
+    }
+}
+
+#[wasm_bindgen]
+impl BlockRangeIterator {
+    /// Get the height of the first block which has not been consumed yet
+    ///
+    /// @returns {number} Height to resume the iteration from
+    pub fn cursor(&self) -> u32 {
+        self.cursor.get()
+    }
+
+    /// Get the height after the last block of the range
+    ///
+    /// @returns {number} End height of the range
+    #[wasm_bindgen(js_name = endHeight)]
+    pub fn end_height(&self) -> u32 {
+        self.end_height
+    }
+
+    /// Fetch the next chunk of blocks and advance the cursor past it
+    ///
+    /// @returns {Object | Error} The next chunk in the form \{ value: Array<Object> | undefined,
+    /// done: boolean \}
+    pub async fn next(&self) -> Result<Object, String> {
+        let blocks = match self.next_blocks().await? {
+            Some(blocks) => blocks.iter().map(block_to_js).collect::<Result<Array, String>>()?.into(),
+            None => JsValue::UNDEFINED,
+        };
+        let result = Object::new();
+        Reflect::set(&result, &"done".into(), &blocks.is_undefined().into()).map_err(|_| "Failed to set property")?;
+        Reflect::set(&result, &"value".into(), &blocks).map_err(|_| "Failed to set property")?;
+        Ok(result)
+    }
+
+    /// Pass every remaining chunk of blocks to a callback. The next chunk is fetched while the
+    /// callback processes the current one, and the cursor only advances past a chunk once the
+    /// callback has processed it, so the iteration can be resumed from the cursor after a failure
+    ///
+    /// @param {function} callback Called with an array of blocks and the height after the last
+    /// block of the chunk, may return a promise which is awaited before the next call
+    /// @returns {number | Error} The cursor after the last chunk
+    #[wasm_bindgen(js_name = forEach)]
+    pub async fn for_each(&self, callback: Function) -> Result<u32, String> {
+        let mut start_height = self.cursor.get();
+        let mut blocks = self.fetch_chunk(start_height).await?;
+        while let Some(chunk) = blocks.take() {
+            let chunk_end = self.chunk_end(start_height);
+            let chunk = chunk.iter().map(block_to_js).collect::<Result<Array, String>>()?;
+            let (processed, next) = join(call_callback(&callback, chunk, chunk_end), self.fetch_chunk(chunk_end)).await;
+            processed?;
+            self.cursor.set(chunk_end);
+            blocks = next?;
+            start_height = chunk_end;
+        }
+        Ok(self.cursor.get())
+    }
+}
+
+impl BlockRangeIterator {
+    /// Fetch the next chunk of blocks and advance the cursor past it, or return None once every
+    /// block of the range has been fetched
+    pub(crate) async fn next_blocks(&self) -> Result<Option<Vec<BlockNative>>, String> {
+        let start_height = self.cursor.get();
+        let blocks = self.fetch_chunk(start_height).await?;
+        if blocks.is_some() {
+            self.cursor.set(self.chunk_end(start_height));
+        }
+        Ok(blocks)
+    }
+
+    // Fetch the chunk of blocks starting at a height, if the height is in the range
+    async fn fetch_chunk(&self, start_height: u32) -> Result<Option<Vec<BlockNative>>, String> {
+        if start_height >= self.end_height {
+            return Ok(None);
+        }
+        Ok(Some(self.client.blocks(start_height, self.chunk_end(start_height)).await?))
+    }
+
+    // Get the height after the last block of the chunk starting at a height
+    fn chunk_end(&self, start_height: u32) -> u32 {
+        self.end_height.min(start_height.saturating_add(self.chunk_size))
+    }
+}
+
+// Call the callback of a block iteration with a chunk and wait for it if it returns a promise
+async fn call_callback(callback: &Function, chunk: Array, chunk_end: u32) -> Result<(), String> {
+    let result = callback
+        .call2(&JsValue::NULL, &chunk, &JsValue::from(chunk_end))
+        .map_err(|e| format!("The block callback failed: {e:?}"))?;
+    if let Ok(promise) = result.dyn_into::<Promise>() {
+        JsFuture::from(promise).await.map_err(|e| format!("The block callback failed: {e:?}"))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    async fn test_block_range_iterator() {
+        let client = AleoNetworkClient::new("https://api.explorer.aleo.org/v1");
+        assert!(client.block_range_iterator(10, 5, None).is_err());
+        assert!(client.block_range_iterator(0, 10, Some(0)).is_err());
+        assert!(client.block_range_iterator(0, 10, Some(MAX_BLOCKS_PER_REQUEST + 1)).is_err());
+
+        // Check the chunks are bounded by the end of the range and empty ranges are done
+        // without contacting the node
+        let iterator = client.block_range_iterator(10, 25, Some(10)).unwrap();
+        assert_eq!(iterator.chunk_end(10), 20);
+        assert_eq!(iterator.chunk_end(20), 25);
+        let iterator = client.block_range_iterator(10, 10, None).unwrap();
+        assert!(iterator.next_blocks().await.unwrap().is_none());
+        assert_eq!(iterator.for_each(Function::new_no_args("")).await.unwrap(), 10);
+    }
+}
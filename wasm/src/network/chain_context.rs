@@ -0,0 +1,201 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::AleoNetworkClient;
+use crate::{
+    types::{BlockNative, StateRootNative},
+    OfflineQuery,
+};
+
+use futures::future::try_join;
+use serde_json::{json, Value};
+use std::{fmt, str::FromStr};
+use wasm_bindgen::prelude::*;
+
+/// Default age after which a cached chain context is fetched again
+const DEFAULT_MAX_AGE_MS: u32 = 15_000;
+
+/// Context of the chain at the latest block, fetched once and shared with the signers which build
+/// transactions offline
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainContext {
+    state_root: StateRootNative,
+    height: u32,
+    coinbase_target: u64,
+    fetched_at: u64,
+}
+
+#[wasm_bindgen]
+impl ChainContext {
+    /// Get the latest global state root
+    ///
+    /// @returns {string} The global state root
+    #[wasm_bindgen(js_name = stateRoot)]
+    pub fn state_root(&self) -> String {
+        self.state_root.to_string()
+    }
+
+    /// Get the height of the latest block
+    ///
+    /// @returns {number} Height of the latest block
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Get the coinbase target of the latest block
+    ///
+    /// @returns {bigint} The coinbase target
+    #[wasm_bindgen(js_name = coinbaseTarget)]
+    pub fn coinbase_target(&self) -> u64 {
+        self.coinbase_target
+    }
+
+    /// Get the time the context was fetched at
+    ///
+    /// @returns {number} Milliseconds since the unix epoch
+    #[wasm_bindgen(js_name = fetchedAt)]
+    pub fn fetched_at(&self) -> f64 {
+        self.fetched_at as f64
+    }
+
+    /// Create an offline query for the state root of the context. The state paths of the records
+    /// being spent still have to be added to the query
+    ///
+    /// @returns {OfflineQuery | Error}
+    #[wasm_bindgen(js_name = toOfflineQuery)]
+    pub fn to_offline_query(&self) -> Result<OfflineQuery, String> {
+        OfflineQuery::new(&self.state_root.to_string())
+    }
+
+    /// Get the chain context as a JSON string
+    ///
+    /// @returns {string} JSON string of the form \{ "state_root": string, "height": number,
+    /// "coinbase_target": number, "fetched_at": number \}
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        json!({
+            "state_root": self.state_root.to_string(),
+            "height": self.height,
+            "coinbase_target": self.coinbase_target,
+            "fetched_at": self.fetched_at,
+        })
+        .to_string()
+    }
+
+    /// Create a chain context from the JSON string returned by toString
+    ///
+    /// @param {string} chain_context JSON string of the chain context
+    /// @returns {ChainContext | Error}
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(chain_context: &str) -> Result<ChainContext, String> {
+        ChainContext::from_str(chain_context)
+    }
+}
+
+impl fmt::Display for ChainContext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_string())
+    }
+}
+
+impl FromStr for ChainContext {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = serde_json::from_str::<Value>(s).map_err(|e| format!("Invalid chain context: {e}"))?;
+        let field = |name: &str| json.get(name).ok_or_else(|| format!("The chain context is missing the {name}"));
+        let state_root = field("state_root")?.as_str().ok_or_else(|| "Invalid state root".to_string())?;
+        let state_root = StateRootNative::from_str(state_root).map_err(|e| format!("Invalid state root: {e}"))?;
+        let height = field("height")?.as_u64().and_then(|height| u32::try_from(height).ok());
+        let coinbase_target = field("coinbase_target")?.as_u64();
+        let fetched_at = field("fetched_at")?.as_u64();
+        Ok(Self {
+            state_root,
+            height: height.ok_or_else(|| "Invalid height".to_string())?,
+            coinbase_target: coinbase_target.ok_or_else(|| "Invalid coinbase target".to_string())?,
+            fetched_at: fetched_at.ok_or_else(|| "Invalid fetch time".to_string())?,
+        })
+    }
+}
+
+#[wasm_bindgen]
+impl AleoNetworkClient {
+    /// Get the latest state root, block height and coinbase target of the chain. The context is
+    /// cached and shared by the clones of the client, and only fetched again once it is older than
+    /// the maximum age
+    ///
+    /// @param {number | undefined} max_age_ms (optional) Maximum age of a cached context in
+    /// milliseconds, 15000 by default. Pass 0 to always fetch the latest context
+    /// @returns {ChainContext | Error} The context of the chain
+    #[wasm_bindgen(js_name = getChainContext)]
+    pub async fn get_chain_context(&self, max_age_ms: Option<u32>) -> Result<ChainContext, String> {
+        let now = js_sys::Date::now();
+        let max_age = f64::from(max_age_ms.unwrap_or(DEFAULT_MAX_AGE_MS));
+        if let Some(context) = self.chain_context.borrow().as_ref() {
+            if now - context.fetched_at() < max_age {
+                return Ok(context.clone());
+            }
+        }
+
+        let (state_root, block) = try_join(self.get_state_root(), self.get("latest/block")).await?;
+        let state_root = StateRootNative::from_str(&state_root)
+            .map_err(|e| format!("The node returned an invalid state root: {e}"))?;
+        let block: BlockNative =
+            serde_json::from_str(&block).map_err(|e| format!("The node returned an invalid block: {e}"))?;
+        let context = ChainContext {
+            state_root,
+            height: block.height(),
+            coinbase_target: block.coinbase_target(),
+            fetched_at: js_sys::Date::now() as u64,
+        };
+        *self.chain_context.borrow_mut() = Some(context.clone());
+        Ok(context)
+    }
+
+    /// Remove the cached chain context so that the next request fetches the latest context
+    #[wasm_bindgen(js_name = clearChainContext)]
+    pub fn clear_chain_context(&self) {
+        self.chain_context.borrow_mut().take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::QueryTrait;
+
+    use wasm_bindgen_test::*;
+
+    const STATE_ROOT: &str = "ar1ekees06ce437zyrpy3xryal7wpfsw2zlsvwrr0rrfv3ywc8ehcrsg0tlrf";
+
+    #[wasm_bindgen_test]
+    fn test_chain_context_to_and_from_string() {
+        let chain_context = format!(
+            r#"{{"state_root":"{STATE_ROOT}","height":12,"coinbase_target":1099511627775,"fetched_at":1700000000000}}"#
+        );
+        let chain_context = ChainContext::from_string(&chain_context).unwrap();
+        assert_eq!(chain_context.state_root(), STATE_ROOT);
+        assert_eq!(chain_context.height(), 12);
+        assert_eq!(chain_context.coinbase_target(), 1099511627775);
+        assert_eq!(ChainContext::from_string(&chain_context.to_string()).unwrap(), chain_context);
+        assert!(ChainContext::from_string(r#"{"state_root":"ar1abc","height":12}"#).is_err());
+
+        let offline_query = chain_context.to_offline_query().unwrap();
+        assert_eq!(offline_query.current_state_root().unwrap().to_string(), STATE_ROOT);
+    }
+}
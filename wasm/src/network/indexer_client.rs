@@ -0,0 +1,295 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use js_sys::Array;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+/// Default maximum number of items fetched from the indexer per query
+const DEFAULT_LIMIT: u32 = 1_000;
+
+/// Client for an indexer API which answers questions about the history of an address that the
+/// REST API of a node cannot answer without scanning the whole chain
+///
+/// The indexer is expected to serve paginated lists at
+/// `GET {url}/address/{address}/transfers?start={height}&cursor={cursor}` and
+/// `GET {url}/address/{address}/transitions?start={height}&cursor={cursor}` in the form
+/// `{ "items": [...], "cursor": string | null }`, where the cursor of a response is passed to
+/// fetch the next page and is null on the last page. Transfers are of the form
+/// `{ "transaction_id", "transition_id", "function_name", "height", "sender", "recipient",
+/// "amount" }` and transitions of the form `{ "transaction_id", "transition_id", "program_id",
+/// "function_name", "height" }`, where the transitions are the ones creating or spending records
+/// of the address.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct IndexerClient {
+    url: String,
+    api_key: Option<String>,
+}
+
+/// Public transfer of credits to or from an address
+#[wasm_bindgen]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct PublicTransfer {
+    transaction_id: String,
+    transition_id: String,
+    function_name: String,
+    height: u32,
+    sender: String,
+    recipient: String,
+    amount: u64,
+}
+
+/// Transition which creates or spends a record of an address
+#[wasm_bindgen]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct IndexedTransition {
+    transaction_id: String,
+    transition_id: String,
+    program_id: String,
+    function_name: String,
+    height: u32,
+}
+
+#[wasm_bindgen]
+impl IndexerClient {
+    /// Create a client for an indexer API
+    ///
+    /// @param {string} url The base url of the indexer
+    /// @param {string | undefined} api_key (optional) Key sent as a bearer token with every request
+    /// @returns {IndexerClient}
+    #[wasm_bindgen(constructor)]
+    pub fn new(url: &str, api_key: Option<String>) -> IndexerClient {
+        Self { url: url.trim_end_matches('/').to_string(), api_key }
+    }
+
+    /// Get the public transfers of credits to or from an address
+    ///
+    /// @param {string} address The address
+    /// @param {number | undefined} start_height (optional) Height of the first block to include
+    /// @param {number | undefined} limit (optional) Maximum number of transfers, 1000 by default
+    /// @returns {Array<PublicTransfer> | Error} Transfers in order of height
+    #[wasm_bindgen(js_name = publicTransfers)]
+    pub async fn public_transfers(
+        &self,
+        address: &str,
+        start_height: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<Array, String> {
+        let transfers: Vec<PublicTransfer> = self.list(address, "transfers", start_height, limit).await?;
+        Ok(transfers.into_iter().map(JsValue::from).collect())
+    }
+
+    /// Get the transitions creating or spending records of an address
+    ///
+    /// @param {string} address The address
+    /// @param {number | undefined} start_height (optional) Height of the first block to include
+    /// @param {number | undefined} limit (optional) Maximum number of transitions, 1000 by default
+    /// @returns {Array<IndexedTransition> | Error} Transitions in order of height
+    #[wasm_bindgen(js_name = recordTransitions)]
+    pub async fn record_transitions(
+        &self,
+        address: &str,
+        start_height: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<Array, String> {
+        let transitions = self.indexed_transitions(address, start_height, limit).await?;
+        Ok(transitions.into_iter().map(JsValue::from).collect())
+    }
+}
+
+impl IndexerClient {
+    /// Get the transitions creating or spending records of an address
+    pub(crate) async fn indexed_transitions(
+        &self,
+        address: &str,
+        start_height: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<Vec<IndexedTransition>, String> {
+        self.list(address, "transitions", start_height, limit).await
+    }
+
+    // Fetch the pages of a list of the indexer until the last page or the limit is reached
+    async fn list<T: DeserializeOwned>(
+        &self,
+        address: &str,
+        list: &str,
+        start_height: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<Vec<T>, String> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT) as usize;
+        let mut items = Vec::new();
+        let mut cursor: Option<String> = None;
+        while items.len() < limit {
+            let mut url = format!("{}/address/{address}/{list}?start={}", self.url, start_height.unwrap_or(0));
+            if let Some(cursor) = &cursor {
+                url.push_str(&format!("&cursor={cursor}"));
+            }
+            let page = self.get(&url).await?;
+            let page_items = page.get("items").cloned().unwrap_or(Value::Array(Vec::new()));
+            let page_items: Vec<T> = serde_json::from_value(page_items)
+                .map_err(|e| format!("The indexer returned invalid {list} for {address}: {e}"))?;
+            items.extend(page_items);
+            cursor = match page.get("cursor") {
+                Some(Value::String(cursor)) => Some(cursor.clone()),
+                Some(Value::Number(cursor)) => Some(cursor.to_string()),
+                _ => break,
+            };
+        }
+        items.truncate(limit);
+        Ok(items)
+    }
+
+    // Send a request to the indexer and parse the JSON response
+    async fn get(&self, url: &str) -> Result<Value, String> {
+        let request = reqwest::Client::new().get(url);
+        let request = match &self.api_key {
+            Some(api_key) => request.bearer_auth(api_key),
+            None => request,
+        };
+        let response = request.send().await.map_err(|e| format!("Failed to reach the indexer: {e}"))?;
+        if !response.status().is_success() {
+            return Err(format!("The indexer responded with {}", response.status()));
+        }
+        let response = response.text().await.map_err(|e| format!("Failed to read the indexer response: {e}"))?;
+        serde_json::from_str(&response).map_err(|e| format!("The indexer returned invalid JSON: {e}"))
+    }
+}
+
+#[wasm_bindgen]
+impl PublicTransfer {
+    /// Get the id of the transaction of the transfer
+    ///
+    /// @returns {string} Transaction id
+    #[wasm_bindgen(js_name = transactionId)]
+    pub fn transaction_id(&self) -> String {
+        self.transaction_id.clone()
+    }
+
+    /// Get the id of the transition of the transfer
+    ///
+    /// @returns {string} Transition id
+    #[wasm_bindgen(js_name = transitionId)]
+    pub fn transition_id(&self) -> String {
+        self.transition_id.clone()
+    }
+
+    /// Get the name of the credits.aleo function of the transfer, e.g. transfer_public
+    ///
+    /// @returns {string} Function name
+    #[wasm_bindgen(js_name = functionName)]
+    pub fn function_name(&self) -> String {
+        self.function_name.clone()
+    }
+
+    /// Get the height of the block of the transfer
+    ///
+    /// @returns {number} Block height
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Get the address the credits were sent from
+    ///
+    /// @returns {string} Address of the sender
+    pub fn sender(&self) -> String {
+        self.sender.clone()
+    }
+
+    /// Get the address the credits were sent to
+    ///
+    /// @returns {string} Address of the recipient
+    pub fn recipient(&self) -> String {
+        self.recipient.clone()
+    }
+
+    /// Get the amount of the transfer in microcredits
+    ///
+    /// @returns {bigint} Amount in microcredits
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+}
+
+#[wasm_bindgen]
+impl IndexedTransition {
+    /// Get the id of the transaction of the transition
+    ///
+    /// @returns {string} Transaction id
+    #[wasm_bindgen(js_name = transactionId)]
+    pub fn transaction_id(&self) -> String {
+        self.transaction_id.clone()
+    }
+
+    /// Get the id of the transition
+    ///
+    /// @returns {string} Transition id
+    #[wasm_bindgen(js_name = transitionId)]
+    pub fn transition_id(&self) -> String {
+        self.transition_id.clone()
+    }
+
+    /// Get the id of the program of the transition
+    ///
+    /// @returns {string} Program id
+    #[wasm_bindgen(js_name = programId)]
+    pub fn program_id(&self) -> String {
+        self.program_id.clone()
+    }
+
+    /// Get the name of the function of the transition
+    ///
+    /// @returns {string} Function name
+    #[wasm_bindgen(js_name = functionName)]
+    pub fn function_name(&self) -> String {
+        self.function_name.clone()
+    }
+
+    /// Get the height of the block of the transition
+    ///
+    /// @returns {number} Block height
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_indexer_items() {
+        let transfer = serde_json::json!({
+            "transaction_id": "at1nxeg7yttrgn2usx9kvprf4m6l90hf46xeaydtzcd9g75nus89spshz3nq3",
+            "transition_id": "as1elwd3rrs6usm29au4m7930dw34qc9fgpcft2cttpr22v0wqtcugq3w9ju2",
+            "function_name": "transfer_public",
+            "height": 12,
+            "sender": "aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8",
+            "recipient": "aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8",
+            "amount": 1
+        });
+        let transfer: PublicTransfer = serde_json::from_value(transfer).unwrap();
+        assert_eq!(transfer.function_name(), "transfer_public");
+        assert_eq!(transfer.height(), 12);
+        assert_eq!(transfer.amount(), 1);
+
+        let transition = serde_json::json!({ "transaction_id": "at1", "transition_id": "as1", "height": 12 });
+        assert!(serde_json::from_value::<IndexedTransition>(transition).is_err());
+        assert_eq!(IndexerClient::new("https://indexer.example.com/", None).url, "https://indexer.example.com");
+    }
+}
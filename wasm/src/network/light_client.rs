@@ -0,0 +1,232 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::AleoNetworkClient;
+use crate::{
+    log,
+    types::{BlockHashNative, BlockNative, FieldNative, StatePathNative, StateRootNative},
+};
+
+use std::{cell::RefCell, collections::HashMap, rc::Rc, str::FromStr};
+use wasm_bindgen::prelude::*;
+
+/// Light client which follows the chain from a trusted checkpoint block and verifies the state
+/// the program manager builds transactions against
+///
+/// Every block downloaded while syncing must extend the verified chain through its previous
+/// hash, and the consistency of the block with its hash is checked when it is parsed. The header
+/// of a block commits to the global state root after its parent, so the state roots of the
+/// verified blocks are anchored to the checkpoint. State paths fetched from the node are only
+/// accepted if their inclusion proofs verify against such a root and name a verified block.
+///
+/// The light client can be passed to ProgramManager.setQueryProvider so that executions and fees
+/// only spend records whose inclusion is anchored to the checkpoint.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct LightClient {
+    client: AleoNetworkClient,
+    state: Rc<RefCell<VerifiedChain>>,
+}
+
+// Block hashes and state roots which were verified to descend from the checkpoint
+#[derive(Debug)]
+struct VerifiedChain {
+    height: u32,
+    hash: BlockHashNative,
+    hashes: HashMap<BlockHashNative, u32>,
+    state_roots: HashMap<StateRootNative, u32>,
+    latest_state_root: Option<StateRootNative>,
+}
+
+impl VerifiedChain {
+    // Extend the verified chain with the next block
+    fn extend(&mut self, block: &BlockNative) -> Result<(), String> {
+        let height = block.height();
+        if height != self.height.saturating_add(1) || block.previous_hash() != self.hash {
+            return Err(format!("Block {height} does not extend the verified chain at height {}", self.height));
+        }
+        // The header of a block commits to the global state root after the previous block
+        let state_root = block.header().previous_state_root();
+        self.state_roots.insert(state_root, height - 1);
+        self.latest_state_root = Some(state_root);
+        self.hashes.insert(block.hash(), height);
+        self.height = height;
+        self.hash = block.hash();
+        Ok(())
+    }
+
+    // Ensure a state path proves inclusion in a verified state root and a verified block
+    fn verify_state_path(&self, state_path: &StatePathNative) -> Result<(), String> {
+        state_path.verify(true, FieldNative::from_u16(0)).map_err(|e| format!("Invalid state path: {e}"))?;
+        let state_root = state_path.global_state_root();
+        let Some(root_height) = self.state_roots.get(&state_root) else {
+            return Err(format!("The state root {state_root} is not anchored to the verified chain"));
+        };
+        match self.hashes.get(&state_path.block_hash()) {
+            Some(block_height) if block_height <= root_height => Ok(()),
+            _ => Err(format!("The block {} of the state path is not in the verified chain", state_path.block_hash())),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl LightClient {
+    /// Create a light client which trusts a checkpoint block
+    ///
+    /// @param {AleoNetworkClient} client The client of the node to download blocks from
+    /// @param {number} checkpoint_height Height of the trusted block
+    /// @param {string} checkpoint_hash Hash of the trusted block, obtained from a trusted source
+    /// @returns {LightClient | Error} Light client
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        client: &AleoNetworkClient,
+        checkpoint_height: u32,
+        checkpoint_hash: &str,
+    ) -> Result<LightClient, String> {
+        let hash = BlockHashNative::from_str(checkpoint_hash).map_err(|e| format!("Invalid block hash: {e}"))?;
+        let chain = VerifiedChain {
+            height: checkpoint_height,
+            hash,
+            hashes: HashMap::from([(hash, checkpoint_height)]),
+            state_roots: HashMap::new(),
+            latest_state_root: None,
+        };
+        Ok(Self { client: client.clone(), state: Rc::new(RefCell::new(chain)) })
+    }
+
+    /// Get the height of the latest verified block
+    ///
+    /// @returns {number} Height of the latest verified block
+    #[wasm_bindgen(js_name = verifiedHeight)]
+    pub fn verified_height(&self) -> u32 {
+        self.state.borrow().height
+    }
+
+    /// Get the hash of the latest verified block
+    ///
+    /// @returns {string} Hash of the latest verified block
+    #[wasm_bindgen(js_name = verifiedHash)]
+    pub fn verified_hash(&self) -> String {
+        self.state.borrow().hash.to_string()
+    }
+
+    /// Download and verify the blocks after the latest verified block
+    ///
+    /// @param {number | undefined} end_height (optional) Height of the last block to verify, the
+    /// latest block of the node by default
+    /// @returns {number | Error} Height of the latest verified block
+    pub async fn sync(&self, end_height: Option<u32>) -> Result<u32, String> {
+        let end_height = match end_height {
+            Some(end_height) => end_height,
+            None => self.client.get_latest_height().await?,
+        };
+        let start_height = self.verified_height().saturating_add(1);
+        if start_height > end_height {
+            return Ok(self.verified_height());
+        }
+        log(&format!("Verifying blocks {start_height} to {end_height}"));
+        let blocks = self.client.block_range_iterator(start_height, end_height.saturating_add(1), None)?;
+        while let Some(chunk) = blocks.next_blocks().await? {
+            let mut state = self.state.borrow_mut();
+            for block in &chunk {
+                state.extend(block)?;
+            }
+        }
+        Ok(self.verified_height())
+    }
+
+    /// Verify that a state path proves inclusion in a state root and block of the verified chain
+    ///
+    /// @param {string} state_path The state path of a record commitment
+    #[wasm_bindgen(js_name = verifyStatePath)]
+    pub fn verify_state_path(&self, state_path: &str) -> Result<(), String> {
+        let state_path = StatePathNative::from_str(state_path).map_err(|e| format!("Invalid state path: {e}"))?;
+        self.state.borrow().verify_state_path(&state_path)
+    }
+
+    /// Get the latest verified global state root after syncing with the node
+    ///
+    /// @returns {string | Error} The latest verified global state root
+    #[wasm_bindgen(js_name = stateRoot)]
+    pub async fn state_root(&self) -> Result<String, String> {
+        Ok(self.verified_state_root().await?.to_string())
+    }
+
+    /// Fetch the state path of a record commitment from the node and verify it is anchored to the
+    /// verified chain. The light client syncs once if the state root of the path is newer than
+    /// the verified chain, as the root after a block is only committed to by the next block
+    ///
+    /// @param {string} commitment The commitment of the record
+    /// @returns {string | Error} The verified state path
+    #[wasm_bindgen(js_name = statePath)]
+    pub async fn state_path(&self, commitment: &str) -> Result<String, String> {
+        let commitment = FieldNative::from_str(commitment).map_err(|e| format!("Invalid commitment: {e}"))?;
+        Ok(self.verified_state_path(&commitment).await?.to_string())
+    }
+}
+
+impl LightClient {
+    /// Get the latest verified global state root after syncing with the node
+    pub(crate) async fn verified_state_root(&self) -> Result<StateRootNative, String> {
+        self.sync(None).await?;
+        self.state.borrow().latest_state_root.ok_or_else(|| "No state root has been verified yet".to_string())
+    }
+
+    /// Fetch the state path of a record commitment and verify it is anchored to the verified chain
+    pub(crate) async fn verified_state_path(&self, commitment: &FieldNative) -> Result<StatePathNative, String> {
+        let state_path = self.client.get(&format!("statePath/{commitment}")).await?;
+        let state_path = serde_json::from_str::<String>(&state_path).unwrap_or(state_path);
+        let state_path = StatePathNative::from_str(&state_path).map_err(|e| format!("Invalid state path: {e}"))?;
+        if self.state.borrow().verify_state_path(&state_path).is_err() {
+            self.sync(None).await?;
+        }
+        self.state.borrow().verify_state_path(&state_path)?;
+        Ok(state_path)
+    }
+}
+
+#[cfg(feature = "browser")]
+impl crate::QueryProvider for LightClient {
+    fn state_root(&self) -> futures::future::LocalBoxFuture<'_, Result<StateRootNative, String>> {
+        Box::pin(self.verified_state_root())
+    }
+
+    fn state_path<'a>(
+        &'a self,
+        commitment: &'a FieldNative,
+    ) -> futures::future::LocalBoxFuture<'a, Result<StatePathNative, String>> {
+        Box::pin(self.verified_state_path(commitment))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    const BLOCK_HASH: &str = "ab1sm6kyqle2ftg4z8gegafqrjy0jwjhzu6fmy73726dgszrtxhxvfqha0eee";
+
+    #[wasm_bindgen_test]
+    fn test_light_client_checkpoint() {
+        let client = AleoNetworkClient::new("http://localhost:3030");
+        assert!(LightClient::new(&client, 10, "ab1invalid").is_err());
+
+        let light_client = LightClient::new(&client, 10, BLOCK_HASH).unwrap();
+        assert_eq!(light_client.verified_height(), 10);
+        assert_eq!(light_client.verified_hash(), BLOCK_HASH);
+        assert!(light_client.state.borrow().latest_state_root.is_none());
+    }
+}
@@ -0,0 +1,532 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::ChainContext;
+use crate::{
+    log,
+    types::{BlockNative, TransactionNative},
+    Transaction,
+};
+
+use futures::future::{select, Either};
+use js_sys::{Array, Function, Promise, Reflect, JSON};
+use reqwest::{
+    header::{HeaderName, HeaderValue},
+    StatusCode,
+};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    str::FromStr,
+};
+use wasm_bindgen::{prelude::*, JsCast};
+use wasm_bindgen_futures::JsFuture;
+
+/// Maximum number of blocks a node returns for a single block range request
+pub(crate) const MAX_BLOCKS_PER_REQUEST: u32 = 50;
+/// Default number of retries of a request after a transient failure
+const DEFAULT_MAX_RETRIES: u32 = 3;
+/// Delay before the first retry of a request when no node is healthy, doubled for every later retry
+const RETRY_DELAY_MS: u32 = 1_000;
+/// Upper bound of the delay between retries of a request
+const MAX_RETRY_DELAY_MS: u32 = 30_000;
+/// Time a node is skipped after its first consecutive failure, doubled for every later failure
+const HEALTH_COOLDOWN_MS: u32 = 5_000;
+/// Upper bound of the time a failing node is skipped
+const MAX_HEALTH_COOLDOWN_MS: u32 = 300_000;
+/// Reasons for rejecting a transaction identified by a phrase in the response of the node
+const REJECTION_REASONS: [(&str, &str); 8] = [
+    ("already exists", "duplicate"),
+    ("incorrect transaction id", "invalid_id"),
+    ("proof", "invalid_proof"),
+    ("insufficient", "insufficient_balance"),
+    ("fee", "invalid_fee"),
+    ("global state root", "unknown_state_root"),
+    ("exceeds", "too_large"),
+    ("too large", "too_large"),
+];
+
+/// Client for the REST API of Aleo network nodes
+///
+/// Requests are sent to the first healthy node in the order the nodes were given. A node which
+/// cannot be reached, is overloaded or fails with a server error is skipped for a cooldown which
+/// doubles with every consecutive failure, and the request fails over to the next healthy node.
+/// When no node is healthy the client backs off exponentially before retrying. Every request may
+/// be retried up to the retry budget of the client. Clones of a client share the health of the
+/// nodes and the cached chain context.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct AleoNetworkClient {
+    hosts: Rc<[NodeHost]>,
+    max_retries: u32,
+    timeout_ms: Option<u32>,
+    headers: Vec<(HeaderName, HeaderValue)>,
+    pub(crate) chain_context: Rc<RefCell<Option<ChainContext>>>,
+}
+
+// Node along with its health
+#[derive(Debug)]
+struct NodeHost {
+    url: String,
+    failures: Cell<u32>,
+    unhealthy_until: Cell<f64>,
+}
+
+impl NodeHost {
+    fn new(url: &str) -> Self {
+        Self { url: url.trim_end_matches('/').to_string(), failures: Cell::new(0), unhealthy_until: Cell::new(0.0) }
+    }
+
+    fn is_healthy(&self, now: f64) -> bool {
+        self.unhealthy_until.get() <= now
+    }
+
+    fn record_success(&self) {
+        self.failures.set(0);
+        self.unhealthy_until.set(0.0);
+    }
+
+    fn record_failure(&self) {
+        let failures = self.failures.get().saturating_add(1);
+        let cooldown = HEALTH_COOLDOWN_MS.saturating_mul(1 << (failures - 1).min(16)).min(MAX_HEALTH_COOLDOWN_MS);
+        self.failures.set(failures);
+        self.unhealthy_until.set(js_sys::Date::now() + f64::from(cooldown));
+    }
+}
+
+#[wasm_bindgen]
+impl AleoNetworkClient {
+    /// Create a client for an Aleo network node
+    ///
+    /// @param {string} host The url of the node, e.g. https://api.explorer.aleo.org/v1
+    /// @returns {AleoNetworkClient} Network client
+    #[wasm_bindgen(constructor)]
+    pub fn new(host: &str) -> Self {
+        Self::from_hosts(Rc::new([NodeHost::new(host)]))
+    }
+
+    /// Create a client which fails over between several Aleo network nodes
+    ///
+    /// @param {Array<string>} hosts The urls of the nodes in order of preference
+    /// @returns {AleoNetworkClient | Error} Network client
+    #[wasm_bindgen(js_name = withHosts)]
+    pub fn with_hosts(hosts: Array) -> Result<AleoNetworkClient, String> {
+        let hosts = hosts
+            .iter()
+            .map(|host| host.as_string().map(|host| NodeHost::new(&host)))
+            .collect::<Option<Rc<[NodeHost]>>>()
+            .ok_or_else(|| "The hosts must be strings".to_string())?;
+        if hosts.is_empty() {
+            return Err("At least one host is required".to_string());
+        }
+        Ok(Self::from_hosts(hosts))
+    }
+
+    /// Set the number of times a request is retried after a transient failure
+    ///
+    /// @param {number} max_retries Number of retries of every request, 3 by default
+    #[wasm_bindgen(js_name = setRetryBudget)]
+    pub fn set_retry_budget(&mut self, max_retries: u32) {
+        self.max_retries = max_retries;
+    }
+
+    /// Set the time after which a request to a node is abandoned. A request which times out counts
+    /// as a transient failure and is retried on the next healthy node
+    ///
+    /// @param {number | undefined} timeout_ms Milliseconds to wait for a response including its
+    /// body, or undefined to wait indefinitely which is the default
+    #[wasm_bindgen(js_name = setTimeout)]
+    pub fn set_timeout(&mut self, timeout_ms: Option<u32>) {
+        self.timeout_ms = timeout_ms;
+    }
+
+    /// Set a header sent with every request, e.g. the API key of an authenticated gateway. Setting
+    /// a header again replaces its value
+    ///
+    /// @param {string} name The name of the header
+    /// @param {string} value The value of the header
+    #[wasm_bindgen(js_name = setHeader)]
+    pub fn set_header(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let name = HeaderName::from_str(name).map_err(|e| format!("Invalid header name {name}: {e}"))?;
+        let mut value = HeaderValue::from_str(value).map_err(|e| format!("Invalid value for header {name}: {e}"))?;
+        value.set_sensitive(true);
+        self.headers.retain(|(header, _)| *header != name);
+        self.headers.push((name, value));
+        Ok(())
+    }
+
+    /// Remove a header set with setHeader
+    ///
+    /// @param {string} name The name of the header
+    #[wasm_bindgen(js_name = removeHeader)]
+    pub fn remove_header(&mut self, name: &str) {
+        self.headers.retain(|(header, _)| !header.as_str().eq_ignore_ascii_case(name));
+    }
+
+    /// Set the user agent sent with every request. Browsers may ignore it as it is controlled by
+    /// the browser
+    ///
+    /// @param {string} user_agent The user agent
+    #[wasm_bindgen(js_name = setUserAgent)]
+    pub fn set_user_agent(&mut self, user_agent: &str) -> Result<(), String> {
+        self.set_header(reqwest::header::USER_AGENT.as_str(), user_agent)
+    }
+
+    /// Get the url of the node requests are currently sent to
+    ///
+    /// @returns {string} The url of the healthiest node
+    pub fn host(&self) -> String {
+        self.select_host().url.clone()
+    }
+
+    /// Get the urls of all nodes of the client
+    ///
+    /// @returns {Array<string>} The urls of the nodes in order of preference
+    pub fn hosts(&self) -> Array {
+        self.hosts.iter().map(|host| JsValue::from_str(&host.url)).collect()
+    }
+
+    /// Get the block at a height
+    ///
+    /// @param {number} height Height of the block
+    /// @returns {Object | Error} JSON representation of the block
+    #[wasm_bindgen(js_name = getBlock)]
+    pub async fn get_block(&self, height: u32) -> Result<JsValue, String> {
+        block_to_js(&self.block(height).await?)
+    }
+
+    /// Get the blocks in the height range `[start_height, end_height)`, which may hold at most 50
+    /// blocks
+    ///
+    /// @param {number} start_height Height of the first block
+    /// @param {number} end_height Height after the last block
+    /// @returns {Array | Error} JSON representations of the blocks
+    #[wasm_bindgen(js_name = getBlockRange)]
+    pub async fn get_block_range(&self, start_height: u32, end_height: u32) -> Result<Array, String> {
+        if start_height > end_height || end_height - start_height > MAX_BLOCKS_PER_REQUEST {
+            return Err(format!(
+                "Invalid block range - {start_height} to {end_height} must hold at most {MAX_BLOCKS_PER_REQUEST} blocks"
+            ));
+        }
+        self.blocks(start_height, end_height).await?.iter().map(block_to_js).collect()
+    }
+
+    /// Get a transaction by its id
+    ///
+    /// @param {string} transaction_id The id of the transaction
+    /// @returns {Transaction | Error} The transaction
+    #[wasm_bindgen(js_name = getTransaction)]
+    pub async fn get_transaction(&self, transaction_id: &str) -> Result<Transaction, String> {
+        let transaction = self.get(&format!("transaction/{transaction_id}")).await?;
+        let transaction = TransactionNative::from_str(&transaction)
+            .map_err(|e| format!("The node returned an invalid transaction: {e}"))?;
+        Ok(Transaction::from(transaction))
+    }
+
+    /// Get the source code of a deployed program
+    ///
+    /// @param {string} program_id The id of the program
+    /// @returns {string | Error} The source code of the program
+    #[wasm_bindgen(js_name = getProgram)]
+    pub async fn get_program(&self, program_id: &str) -> Result<String, String> {
+        let program = self.get(&format!("program/{program_id}")).await?;
+        // The node responds with the program source code as a JSON string
+        Ok(serde_json::from_str::<String>(&program).unwrap_or(program))
+    }
+
+    /// Get the height of the latest block
+    ///
+    /// @returns {number | Error} Height of the latest block
+    #[wasm_bindgen(js_name = getLatestHeight)]
+    pub async fn get_latest_height(&self) -> Result<u32, String> {
+        let height = self.get("latest/height").await?;
+        height.trim().parse().map_err(|_| format!("The node returned an invalid height: {height}"))
+    }
+
+    /// Get the latest state root of the ledger
+    ///
+    /// @returns {string | Error} The latest state root
+    #[wasm_bindgen(js_name = getStateRoot)]
+    pub async fn get_state_root(&self) -> Result<String, String> {
+        let state_root = self.get("latest/stateRoot").await?;
+        Ok(serde_json::from_str::<String>(&state_root).unwrap_or(state_root))
+    }
+
+    /// Broadcast a transaction, failing over to the other nodes and retrying with exponential
+    /// backoff when the node cannot be reached, is overloaded or fails with a server error
+    ///
+    /// When the node rejects the transaction the error names the reason in parentheses, one of
+    /// "duplicate", "invalid_id", "invalid_proof", "invalid_fee", "insufficient_balance",
+    /// "unknown_state_root", "too_large" or "rejected" when the reason is not recognized, followed
+    /// by the response of the node. A retry rejected as a duplicate means an earlier attempt reached
+    /// the node, so the broadcast succeeds.
+    ///
+    /// @param {Transaction} transaction The transaction to broadcast
+    /// @param {number | undefined} max_retries (optional) Number of retries after a transient
+    /// failure, the retry budget of the client by default
+    /// @returns {string | Error} The id of the transaction accepted by the node
+    pub async fn broadcast(&self, transaction: &Transaction, max_retries: Option<u32>) -> Result<String, String> {
+        let max_retries = max_retries.unwrap_or(self.max_retries);
+        let (status, body, attempts) = self
+            .send("transaction/broadcast", Some(&transaction.to_string()), max_retries)
+            .await
+            .map_err(|e| format!("Failed to broadcast the transaction after {e}"))?;
+        broadcast_result(&transaction.id(), status, body, attempts)
+    }
+}
+
+impl AleoNetworkClient {
+    /// Get the block at a height
+    pub(crate) async fn block(&self, height: u32) -> Result<BlockNative, String> {
+        let block = self.get(&format!("block/{height}")).await?;
+        serde_json::from_str(&block).map_err(|e| format!("The node returned an invalid block: {e}"))
+    }
+
+    // Create a client for nodes with the default retry budget and no cached chain context
+    fn from_hosts(hosts: Rc<[NodeHost]>) -> Self {
+        Self {
+            hosts,
+            max_retries: DEFAULT_MAX_RETRIES,
+            timeout_ms: None,
+            headers: Vec::new(),
+            chain_context: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// Get the blocks in the height range [start_height, end_height), ensuring the node returned
+    /// exactly the requested blocks so that no block is skipped
+    pub(crate) async fn blocks(&self, start_height: u32, end_height: u32) -> Result<Vec<BlockNative>, String> {
+        let blocks = self.get(&format!("blocks?start={start_height}&end={end_height}")).await?;
+        let blocks: Vec<BlockNative> =
+            serde_json::from_str(&blocks).map_err(|e| format!("The node returned invalid blocks: {e}"))?;
+        let heights_match = blocks.len() == (end_height - start_height) as usize
+            && blocks.iter().zip(start_height..end_height).all(|(block, height)| block.height() == height);
+        if !heights_match {
+            return Err(format!("The node did not return blocks {start_height} to {end_height}"));
+        }
+        Ok(blocks)
+    }
+
+    /// Send a GET request for a path of the API and return the body of a successful response
+    pub(crate) async fn get(&self, path: &str) -> Result<String, String> {
+        let (status, body) = self.get_response(path).await?;
+        if !status.is_success() {
+            return Err(format!("Failed to fetch {path} - the node responded with {status}: {body}"));
+        }
+        Ok(body)
+    }
+
+    /// Send a GET request for a path of the API and return the status and body of the response
+    pub(crate) async fn get_response(&self, path: &str) -> Result<(StatusCode, String), String> {
+        let (status, body, _) =
+            self.send(path, None, self.max_retries).await.map_err(|e| format!("Failed to fetch {path} after {e}"))?;
+        Ok((status, body))
+    }
+
+    // Send a GET request, or a POST request with a JSON body, to the healthiest node. After a
+    // transient failure the request fails over to the next healthy node, or backs off if there is
+    // none, until the retries are spent. Other responses, including rejections of a POST request
+    // which nodes report as server errors, are returned whatever their status along with the
+    // number of attempts it took
+    async fn send(
+        &self,
+        path: &str,
+        request_body: Option<&str>,
+        max_retries: u32,
+    ) -> Result<(StatusCode, String, u32), String> {
+        let client = reqwest::Client::new();
+        let mut attempt = 0;
+        loop {
+            let host = self.select_host();
+            let url = format!("{}/testnet3/{path}", host.url);
+            let request = match request_body {
+                Some(body) => client.post(&url).header("Content-Type", "application/json").body(body.to_string()),
+                None => client.get(&url),
+            };
+            let request =
+                self.headers.iter().fold(request, |request, (name, value)| request.header(name.clone(), value.clone()));
+            let exchange = async {
+                let response = request.send().await.map_err(|e| e.to_string())?;
+                let status = response.status();
+                Ok::<_, String>((status, response.text().await.unwrap_or_default()))
+            };
+            let response = match self.timeout_ms {
+                Some(timeout_ms) => match select(Box::pin(exchange), Box::pin(sleep(timeout_ms))).await {
+                    Either::Left((response, _)) => response,
+                    Either::Right(_) => Err(format!("{} did not respond within {timeout_ms} ms", host.url)),
+                },
+                None => exchange.await,
+            };
+            let error = match response {
+                Ok((status, body)) if !is_transient_failure(status, &body, request_body.is_some()) => {
+                    host.record_success();
+                    return Ok((status, body, attempt + 1));
+                }
+                Ok((status, body)) => format!("{} responded with {status}: {body}", host.url),
+                Err(error) => error,
+            };
+            host.record_failure();
+            if attempt >= max_retries {
+                return Err(format!("{} attempts, {error}", attempt + 1));
+            }
+            // Fail over to another node right away if one is healthy
+            if !self.hosts.iter().any(|host| host.is_healthy(js_sys::Date::now())) {
+                let delay = RETRY_DELAY_MS.saturating_mul(1 << attempt.min(16)).min(MAX_RETRY_DELAY_MS);
+                log(&format!("Request to {path} failed, {error}. Retrying in {delay} ms"));
+                sleep(delay).await?;
+            }
+            attempt += 1;
+        }
+    }
+
+    // Select the first healthy node, or the node which recovers first if none is healthy
+    fn select_host(&self) -> &NodeHost {
+        let now = js_sys::Date::now();
+        self.hosts.iter().find(|host| host.is_healthy(now)).unwrap_or_else(|| {
+            self.hosts
+                .iter()
+                .min_by(|a, b| a.unhealthy_until.get().total_cmp(&b.unhealthy_until.get()))
+                .expect("A network client has at least one host")
+        })
+    }
+}
+
+// Convert a block into its JSON representation as a javascript object
+pub(crate) fn block_to_js(block: &BlockNative) -> Result<JsValue, String> {
+    let block = serde_json::to_string(block).map_err(|e| e.to_string())?;
+    JSON::parse(&block).map_err(|_| "Failed to convert the block to a javascript object".to_string())
+}
+
+// Get the id of a broadcast transaction from the response of the node, or the reason it was rejected
+fn broadcast_result(transaction_id: &str, status: StatusCode, body: String, attempts: u32) -> Result<String, String> {
+    if !status.is_success() {
+        let reason = rejection_reason(&body);
+        // An earlier attempt which timed out or failed may still have reached a node, in which case
+        // the retry is rejected because the transaction was already accepted
+        if attempts > 1 && reason == Some("duplicate") {
+            return Ok(transaction_id.to_string());
+        }
+        return Err(format!("The node rejected the transaction ({}): {body}", reason.unwrap_or("rejected")));
+    }
+    // The node responds with the transaction id as a JSON string
+    Ok(serde_json::from_str::<String>(&body).unwrap_or(body))
+}
+
+// Identify the reason the node gave for rejecting a transaction, if it is a known rejection
+fn rejection_reason(response: &str) -> Option<&'static str> {
+    let response = response.to_lowercase();
+    REJECTION_REASONS.iter().find(|(phrase, _)| response.contains(phrase)).map(|(_, reason)| *reason)
+}
+
+// Check whether a response is a transient failure to retry on another node. Nodes report every
+// rejected transaction as a server error, so a POST request rejected for a known reason is final
+// and does not count against the health of the node
+fn is_transient_failure(status: StatusCode, response: &str, is_post: bool) -> bool {
+    let failed = status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS;
+    failed && !(is_post && rejection_reason(response).is_some())
+}
+
+// Wait for a number of milliseconds using the setTimeout function of the global scope, which is
+// available in both windows and web workers
+pub(crate) async fn sleep(milliseconds: u32) -> Result<(), String> {
+    let set_timeout = Reflect::get(&js_sys::global(), &JsValue::from_str("setTimeout"))
+        .ok()
+        .and_then(|set_timeout| set_timeout.dyn_into::<Function>().ok())
+        .ok_or_else(|| "setTimeout is not available in this environment".to_string())?;
+    let promise = Promise::new(&mut |resolve, _| {
+        let _ = set_timeout.call2(&JsValue::NULL, &resolve, &JsValue::from(milliseconds));
+    });
+    JsFuture::from(promise).await.map(|_| ()).map_err(|e| format!("{e:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_network_client_failover() {
+        assert_eq!(
+            AleoNetworkClient::new("https://api.explorer.aleo.org/v1/").host(),
+            "https://api.explorer.aleo.org/v1"
+        );
+        assert!(AleoNetworkClient::with_hosts(Array::new()).is_err());
+
+        let hosts = Array::of2(&"http://localhost:3030".into(), &"http://localhost:3031/".into());
+        let client = AleoNetworkClient::with_hosts(hosts).unwrap();
+        assert_eq!(client.hosts().length(), 2);
+        assert_eq!(client.host(), "http://localhost:3030");
+
+        // A failing node is skipped until it recovers, and clones share the health of the nodes
+        client.select_host().record_failure();
+        assert_eq!(client.clone().host(), "http://localhost:3031");
+        client.hosts[1].record_failure();
+        assert_eq!(client.host(), "http://localhost:3030");
+        client.hosts[0].record_success();
+        assert_eq!(client.host(), "http://localhost:3030");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_network_client_headers() {
+        let mut client = AleoNetworkClient::new("http://localhost:3030");
+        client.set_header("X-API-Key", "key").unwrap();
+        client.set_header("x-api-key", "other key").unwrap();
+        client.set_user_agent("wallet/1.0").unwrap();
+        assert_eq!(client.headers.len(), 2);
+        assert_eq!(client.headers[0].1, "other key");
+        assert!(client.set_header("Invalid Header", "key").is_err());
+        assert!(client.set_header("X-API-Key", "invalid\nkey").is_err());
+
+        client.remove_header("X-Api-Key");
+        assert_eq!(client.headers.len(), 1);
+        assert_eq!(client.headers[0].0, reqwest::header::USER_AGENT);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_rejection_reason() {
+        assert_eq!(rejection_reason("Transaction 'at1abc' already exists in the ledger"), Some("duplicate"));
+        assert_eq!(rejection_reason("Invalid proof for the execution"), Some("invalid_proof"));
+        assert_eq!(rejection_reason("Global state root 'ar1abc' does not exist"), Some("unknown_state_root"));
+        assert_eq!(rejection_reason("Something went wrong"), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_broadcast_result() {
+        let id = "at1nxeg7yttrgn2usx9kvprf4m6l90hf46xeaydtzcd9g75nus89spshz3nq3";
+        let duplicate = || format!("Something went wrong: Transaction '{id}' already exists in the ledger");
+        let error = StatusCode::INTERNAL_SERVER_ERROR;
+        assert_eq!(broadcast_result(id, StatusCode::OK, format!("\"{id}\""), 1).unwrap(), id);
+
+        // A duplicate is only a success when an earlier attempt may have reached the node
+        assert_eq!(broadcast_result(id, error, duplicate(), 2).unwrap(), id);
+        assert!(broadcast_result(id, error, duplicate(), 1).unwrap_err().contains("(duplicate)"));
+        assert!(broadcast_result(id, error, "Invalid proof".to_string(), 2).unwrap_err().contains("(invalid_proof)"));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_transient_failures() {
+        let rejection = "Something went wrong: Transaction 'at1abc' already exists in the ledger";
+        let error = StatusCode::INTERNAL_SERVER_ERROR;
+
+        // Rejected broadcasts are final even though the node responds with a server error
+        assert!(!is_transient_failure(error, rejection, true));
+        assert!(is_transient_failure(error, "Something went wrong", true));
+        assert!(is_transient_failure(error, rejection, false));
+        assert!(is_transient_failure(StatusCode::TOO_MANY_REQUESTS, "", false));
+        assert!(!is_transient_failure(StatusCode::NOT_FOUND, "", false));
+        assert!(!is_transient_failure(StatusCode::OK, "", true));
+    }
+}
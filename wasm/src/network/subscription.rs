@@ -0,0 +1,208 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{block_to_js, sleep, AleoNetworkClient};
+use crate::{
+    account::ViewKey,
+    log,
+    types::{BlockNative, Input, TransactionNative},
+    Transaction,
+};
+
+use js_sys::Function;
+use std::{
+    cell::Cell,
+    collections::{HashSet, VecDeque},
+    rc::Rc,
+};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+/// Default interval between requests for the latest height of a subscription
+const DEFAULT_POLL_INTERVAL_MS: u32 = 10_000;
+/// Number of delivered transaction ids remembered to avoid delivering a transaction twice
+const DELIVERED_TRANSACTIONS: usize = 1_024;
+
+/// Subscription to new blocks which are delivered to a callback until it is cancelled
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct Subscription {
+    active: Rc<Cell<bool>>,
+}
+
+#[wasm_bindgen]
+impl Subscription {
+    /// Stop delivering blocks to the callback of the subscription
+    pub fn unsubscribe(&self) {
+        self.active.set(false);
+    }
+
+    /// Determine whether the subscription still delivers blocks
+    ///
+    /// @returns {boolean} True until the subscription is cancelled
+    #[wasm_bindgen(js_name = isActive)]
+    pub fn is_active(&self) -> bool {
+        self.active.get()
+    }
+}
+
+#[wasm_bindgen]
+impl AleoNetworkClient {
+    /// Call a callback with every block added to the chain after subscribing. The client polls
+    /// the latest height and only fetches blocks when it changes, and every block is delivered
+    /// exactly once in order of height
+    ///
+    /// @param {function} callback Called with the JSON representation of every new block
+    /// @param {number | undefined} poll_interval_ms (optional) Milliseconds between requests for
+    /// the latest height, 10000 by default
+    /// @returns {Subscription} Subscription which can be cancelled with unsubscribe
+    #[wasm_bindgen(js_name = onNewBlock)]
+    pub fn on_new_block(&self, callback: Function, poll_interval_ms: Option<u32>) -> Subscription {
+        self.subscribe(poll_interval_ms, move |block| {
+            let block = block_to_js(block)?;
+            callback.call1(&JsValue::NULL, &block).map(|_| ()).map_err(|e| format!("{e:?}"))
+        })
+    }
+
+    /// Call a callback with every transaction added to the chain after subscribing which creates a
+    /// record owned by a view key or has the address of the view key as a public input
+    ///
+    /// @param {ViewKey} view_key View key of the account to find transactions for
+    /// @param {function} callback Called with the transaction and the height of its block
+    /// @param {number | undefined} poll_interval_ms (optional) Milliseconds between requests for
+    /// the latest height, 10000 by default
+    /// @returns {Subscription} Subscription which can be cancelled with unsubscribe
+    #[wasm_bindgen(js_name = onTransactionForAddress)]
+    pub fn on_transaction_for_address(
+        &self,
+        view_key: &ViewKey,
+        callback: Function,
+        poll_interval_ms: Option<u32>,
+    ) -> Subscription {
+        let view_key = view_key.clone();
+        let address = view_key.to_address().to_string();
+        let mut delivered = VecDeque::new();
+        let mut delivered_ids = HashSet::new();
+        self.subscribe(poll_interval_ms, move |block| {
+            for confirmed in block.transactions().iter() {
+                let transaction = confirmed.transaction();
+                if !concerns_account(transaction, &view_key, &address) || !delivered_ids.insert(transaction.id()) {
+                    continue;
+                }
+                delivered.push_back(transaction.id());
+                if delivered.len() > DELIVERED_TRANSACTIONS {
+                    if let Some(id) = delivered.pop_front() {
+                        delivered_ids.remove(&id);
+                    }
+                }
+                let transaction = Transaction::from(transaction.clone());
+                callback
+                    .call2(&JsValue::NULL, &transaction.into(), &JsValue::from(block.height()))
+                    .map_err(|e| format!("{e:?}"))?;
+            }
+            Ok(())
+        })
+    }
+}
+
+impl AleoNetworkClient {
+    // Poll the node for new blocks and pass them to a handler until the subscription is cancelled
+    fn subscribe(
+        &self,
+        poll_interval_ms: Option<u32>,
+        mut on_block: impl FnMut(&BlockNative) -> Result<(), String> + 'static,
+    ) -> Subscription {
+        let client = self.clone();
+        let active = Rc::new(Cell::new(true));
+        let subscription = Subscription { active: active.clone() };
+        let poll_interval_ms = poll_interval_ms.unwrap_or(DEFAULT_POLL_INTERVAL_MS);
+        spawn_local(async move {
+            let mut cursor = None;
+            while active.get() {
+                if let Err(error) = client.deliver_new_blocks(&mut cursor, &active, &mut on_block).await {
+                    log(&format!("A subscription failed to fetch new blocks, {error}"));
+                }
+                if sleep(poll_interval_ms).await.is_err() {
+                    break;
+                }
+            }
+        });
+        subscription
+    }
+
+    // Pass the blocks from the cursor up to the latest block to a handler. The first poll only
+    // sets the cursor after the latest block, and the cursor advances past every delivered block
+    // so that no block is delivered twice
+    async fn deliver_new_blocks(
+        &self,
+        cursor: &mut Option<u32>,
+        active: &Cell<bool>,
+        on_block: &mut impl FnMut(&BlockNative) -> Result<(), String>,
+    ) -> Result<(), String> {
+        let latest_height = self.get_latest_height().await?;
+        let start_height = match *cursor {
+            Some(start_height) => start_height,
+            None => {
+                *cursor = Some(latest_height.saturating_add(1));
+                return Ok(());
+            }
+        };
+        // A node lagging behind the one which was polled before has no new blocks
+        if start_height > latest_height {
+            return Ok(());
+        }
+        let blocks = self.block_range_iterator(start_height, latest_height.saturating_add(1), None)?;
+        while let Some(chunk) = blocks.next_blocks().await? {
+            for block in &chunk {
+                if !active.get() {
+                    return Ok(());
+                }
+                if let Err(error) = on_block(block) {
+                    log(&format!("A subscription callback failed for block {}: {error}", block.height()));
+                }
+                *cursor = Some(block.height().saturating_add(1));
+            }
+        }
+        Ok(())
+    }
+}
+
+// Determine whether a transaction creates a record owned by a view key or has its address as a
+// public input
+fn concerns_account(transaction: &TransactionNative, view_key: &ViewKey, address: &str) -> bool {
+    transaction.transitions().any(|transition| {
+        transition.records().any(|(_, record)| record.is_owner(view_key))
+            || transition.inputs().iter().any(|input| match input {
+                Input::Public(_, Some(value)) => value.to_string() == address,
+                _ => false,
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_subscription_unsubscribe() {
+        let subscription = Subscription { active: Rc::new(Cell::new(true)) };
+        let clone = subscription.clone();
+        assert!(clone.is_active());
+        subscription.unsubscribe();
+        assert!(!clone.is_active());
+    }
+}
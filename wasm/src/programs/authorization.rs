@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::types::AuthorizationNative;
+
+use std::{fmt, ops::Deref, str::FromStr};
+use wasm_bindgen::prelude::*;
+
+/// Webassembly Representation of an Aleo authorization
+///
+/// An authorization contains the signed requests for an execution or fee without the proof, so
+/// it can be created by a thin client and sent elsewhere to be proven.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct Authorization(AuthorizationNative);
+
+#[wasm_bindgen]
+impl Authorization {
+    /// Create an authorization from a string
+    ///
+    /// @param {string} authorization String representation of an authorization
+    /// @returns {Authorization | Error}
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(authorization: &str) -> Result<Authorization, String> {
+        Authorization::from_str(authorization)
+    }
+
+    /// Get the authorization as a string
+    ///
+    /// @returns {string} String representation of the authorization
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Get the id of the program of the first request in the authorization
+    ///
+    /// @returns {string | Error} Program id
+    #[wasm_bindgen(js_name = programId)]
+    pub fn program_id(&self) -> Result<String, String> {
+        Ok(self.0.peek_next().map_err(|e| e.to_string())?.program_id().to_string())
+    }
+
+    /// Get the name of the function of the first request in the authorization
+    ///
+    /// @returns {string | Error} Function name
+    #[wasm_bindgen(js_name = functionName)]
+    pub fn function_name(&self) -> Result<String, String> {
+        Ok(self.0.peek_next().map_err(|e| e.to_string())?.function_name().to_string())
+    }
+
+    /// Get the number of requests in the authorization
+    ///
+    /// @returns {number} Number of requests
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check if the authorization contains no requests
+    ///
+    /// @returns {boolean}
+    #[wasm_bindgen(js_name = isEmpty)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Deref for Authorization {
+    type Target = AuthorizationNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for Authorization {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<Authorization> for AuthorizationNative {
+    fn from(authorization: Authorization) -> Self {
+        authorization.0
+    }
+}
+
+impl From<AuthorizationNative> for Authorization {
+    fn from(authorization: AuthorizationNative) -> Self {
+        Self(authorization)
+    }
+}
+
+impl FromStr for Authorization {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(AuthorizationNative::from_str(s).map_err(|e| e.to_string())?))
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use js_sys::Uint8Array;
+use std::ops::{Deref, DerefMut};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Bytes held in wasm memory which javascript can read and fill through a Uint8Array view, so
+/// large keys and transactions can cross the JS boundary without being copied
+///
+/// A view is only valid until the buffer is freed, resized by `toBytesInto`, or the wasm memory
+/// grows. Take a new view after any call into the module instead of keeping one around.
+///
+/// @example
+/// const buffer = new ByteBuffer(response.headers.get("Content-Length"));
+/// let offset = 0;
+/// for await (const chunk of response.body) {
+///     buffer.view().set(chunk, offset);
+///     offset += chunk.length;
+/// }
+/// const provingKey = ProvingKey.fromBytesView(buffer);
+/// buffer.free();
+#[wasm_bindgen]
+#[derive(Clone, Debug, Default)]
+pub struct ByteBuffer(Vec<u8>);
+
+#[wasm_bindgen]
+impl ByteBuffer {
+    /// Allocate a zeroed buffer in wasm memory
+    ///
+    /// @param {number} len The number of bytes in the buffer
+    /// @returns {ByteBuffer}
+    #[wasm_bindgen(constructor)]
+    pub fn new(len: usize) -> ByteBuffer {
+        Self(vec![0; len])
+    }
+
+    /// Get a view of the bytes of the buffer without copying them out of wasm memory
+    ///
+    /// @returns {Uint8Array} A view into wasm memory which is invalidated when the buffer is freed or
+    /// resized, or when wasm memory grows
+    #[wasm_bindgen]
+    pub fn view(&self) -> Uint8Array {
+        // This is safe as long as the view is not used after the buffer is freed or resized, or after
+        // wasm memory grows, which the documentation of the method requires of the caller
+        unsafe { Uint8Array::view(&self.0) }
+    }
+
+    /// Get the number of bytes in the buffer
+    ///
+    /// @returns {number} The number of bytes in the buffer
+    #[wasm_bindgen]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Determine whether the buffer holds no bytes
+    ///
+    /// @returns {boolean} True if the buffer is empty
+    #[wasm_bindgen(js_name = "isEmpty")]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Remove all bytes from the buffer and release its memory
+    #[wasm_bindgen]
+    pub fn clear(&mut self) {
+        self.0 = Vec::new();
+    }
+}
+
+impl Deref for ByteBuffer {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ByteBuffer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Vec<u8>> for ByteBuffer {
+    fn from(bytes: Vec<u8>) -> ByteBuffer {
+        ByteBuffer(bytes)
+    }
+}
+
+impl From<ByteBuffer> for Vec<u8> {
+    fn from(buffer: ByteBuffer) -> Vec<u8> {
+        buffer.0
+    }
+}
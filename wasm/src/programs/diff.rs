@@ -0,0 +1,203 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use indexmap::IndexMap;
+use js_sys::{Array, Object, Reflect};
+use std::{fmt::Display, hash::Hash};
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+/// Names of the items added, removed and changed between two versions of a program
+struct Changes {
+    added: Vec<String>,
+    removed: Vec<String>,
+    changed: Vec<String>,
+}
+
+impl Changes {
+    // Compare the items of two versions of a program by their rendered source
+    fn between<K: Display + Eq + Hash, V>(
+        old: &IndexMap<K, V>,
+        new: &IndexMap<K, V>,
+        render: impl Fn(&V) -> String,
+    ) -> Self {
+        let added = new.keys().filter(|name| !old.contains_key(*name)).map(ToString::to_string).collect();
+        let removed = old.keys().filter(|name| !new.contains_key(*name)).map(ToString::to_string).collect();
+        let changed = old
+            .iter()
+            .filter_map(|(name, item)| new.get(name).filter(|upgrade| render(item) != render(*upgrade)).map(|_| name))
+            .map(ToString::to_string)
+            .collect();
+        Self { added, removed, changed }
+    }
+
+    fn to_object(&self) -> Result<Object, String> {
+        let changes = Object::new();
+        for (key, names) in [("added", &self.added), ("removed", &self.removed), ("changed", &self.changed)] {
+            let names = names.iter().map(JsValue::from).collect::<Array>();
+            Reflect::set(&changes, &key.into(), &names.into()).map_err(|_| "Failed to set property")?;
+        }
+        Ok(changes)
+    }
+}
+
+#[wasm_bindgen]
+impl Program {
+    /// Compare the program with an upgraded version of it and report the differences
+    ///
+    /// Functions are reported as changed when their inputs or outputs change, and their body and
+    /// finalize logic are compared separately. Removing a function, struct, record or mapping,
+    /// changing the inputs or outputs of a function, or changing the layout of a struct, record or
+    /// mapping breaks existing callers and is listed in `breaking`.
+    ///
+    /// @param {Program} upgrade The upgraded version of the program
+    /// @returns {Object | Error} The differences in the form \{ functions, logic, finalize,
+    /// structs, records, mappings, breaking \} where each of the first six is an object of the
+    /// names \{ added: string[], removed: string[], changed: string[] \} and breaking is an array
+    /// of descriptions of the breaking differences, empty if the upgrade is compatible
+    ///
+    /// @example
+    /// const deployed = aleo_wasm.Program.fromString(DEPLOYED_SOURCE);
+    /// const upgrade = aleo_wasm.Program.fromString(UPGRADE_SOURCE);
+    /// const diff = deployed.diff(upgrade);
+    /// if (diff.breaking.length > 0) console.warn(diff.breaking.join("\n"));
+    #[wasm_bindgen]
+    pub fn diff(&self, upgrade: &Program) -> Result<Object, String> {
+        let (old, new) = (&self.0, &upgrade.0);
+        if old.id() != new.id() {
+            return Err(format!("Cannot compare {} with a different program {}", old.id(), new.id()));
+        }
+
+        let functions = Changes::between(old.functions(), new.functions(), |function| {
+            let inputs = function.inputs().iter().map(ToString::to_string);
+            inputs.chain(function.outputs().iter().map(ToString::to_string)).collect::<Vec<_>>().join("\n")
+        });
+        let logic = Changes::between(old.functions(), new.functions(), |function| {
+            function.instructions().iter().map(ToString::to_string).collect::<Vec<_>>().join("\n")
+        });
+        let finalize = Changes::between(old.functions(), new.functions(), |function| {
+            function.finalize_logic().map(ToString::to_string).unwrap_or_default()
+        });
+        let structs = Changes::between(old.structs(), new.structs(), ToString::to_string);
+        let records = Changes::between(old.records(), new.records(), ToString::to_string);
+        let mappings = Changes::between(old.mappings(), new.mappings(), ToString::to_string);
+
+        let mut breaking = Vec::new();
+        for (kind, changes) in [("function", &functions), ("struct", &structs), ("record", &records)] {
+            breaking.extend(changes.removed.iter().map(|name| format!("{kind} '{name}' was removed")));
+        }
+        breaking.extend(functions.changed.iter().map(|name| format!("the inputs or outputs of '{name}' changed")));
+        breaking.extend(structs.changed.iter().map(|name| format!("the layout of struct '{name}' changed")));
+        breaking.extend(records.changed.iter().map(|name| format!("the layout of record '{name}' changed")));
+        breaking.extend(mappings.removed.iter().map(|name| format!("mapping '{name}' was removed")));
+        breaking.extend(mappings.changed.iter().map(|name| format!("the key or value of mapping '{name}' changed")));
+
+        let diff = Object::new();
+        for (key, changes) in [
+            ("functions", &functions),
+            ("logic", &logic),
+            ("finalize", &finalize),
+            ("structs", &structs),
+            ("records", &records),
+            ("mappings", &mappings),
+        ] {
+            Reflect::set(&diff, &key.into(), &changes.to_object()?.into()).map_err(|_| "Failed to set property")?;
+        }
+        let breaking = breaking.iter().map(JsValue::from).collect::<Array>();
+        Reflect::set(&diff, &"breaking".into(), &breaking.into()).map_err(|_| "Failed to set property")?;
+        Ok(diff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    const PROGRAM: &str = r#"program counter.aleo;
+
+struct settings:
+    step as u32;
+
+mapping counts:
+    key as address.public;
+    value as u32.public;
+
+function increment:
+    input r0 as u32.public;
+    add r0 1u32 into r1;
+    finalize self.caller r1;
+
+finalize increment:
+    input r0 as address.public;
+    input r1 as u32.public;
+    get.or_use counts[r0] 0u32 into r2;
+    add r2 r1 into r3;
+    set r3 into counts[r0];
+
+function double:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;
+"#;
+
+    fn names(diff: &Object, key: &str, field: &str) -> String {
+        let changes = Reflect::get(diff, &key.into()).unwrap();
+        format!("{:?}", Array::from(&Reflect::get(&changes, &field.into()).unwrap()).to_vec())
+    }
+
+    fn breaking(diff: &Object) -> Array {
+        Array::from(&Reflect::get(diff, &"breaking".into()).unwrap())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_diff_identical_programs() {
+        let program = Program::from_string(PROGRAM).unwrap();
+        let diff = program.diff(&program).unwrap();
+        assert_eq!(breaking(&diff).length(), 0);
+        assert_eq!(names(&diff, "functions", "changed"), "[]");
+        assert_eq!(names(&diff, "logic", "changed"), "[]");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_diff_compatible_upgrade() {
+        let program = Program::from_string(PROGRAM).unwrap();
+        let upgrade = format!(
+            "{}\nfunction triple:\n    input r0 as u32.private;\n    mul r0 3u32 into r1;\n    output r1 as u32.private;\n",
+            PROGRAM
+                .replace("add r2 r1 into r3", "add.w r2 r1 into r3")
+                .replace("add r0 r0 into r1", "mul r0 2u32 into r1")
+        );
+        let diff = program.diff(&Program::from_string(&upgrade).unwrap()).unwrap();
+        assert_eq!(breaking(&diff).length(), 0);
+        assert_eq!(names(&diff, "functions", "added"), r#"[JsValue("triple")]"#);
+        assert_eq!(names(&diff, "logic", "changed"), r#"[JsValue("double")]"#);
+        assert_eq!(names(&diff, "finalize", "changed"), r#"[JsValue("increment")]"#);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_diff_breaking_upgrade() {
+        let program = Program::from_string(PROGRAM).unwrap();
+        let upgrade = PROGRAM
+            .replace("step as u32;", "step as u64;")
+            .replace("output r1 as u32.private;", "output r1 as u32.public;");
+        let diff = program.diff(&Program::from_string(&upgrade).unwrap()).unwrap();
+        assert_eq!(names(&diff, "structs", "changed"), r#"[JsValue("settings")]"#);
+        assert_eq!(names(&diff, "functions", "changed"), r#"[JsValue("double")]"#);
+        assert_eq!(breaking(&diff).length(), 2);
+    }
+}
@@ -0,0 +1,442 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::types::{
+    CurrentNetwork,
+    FieldNative,
+    GroupNative,
+    LiteralNative,
+    LiteralType,
+    Network,
+    PlaintextNative,
+    ToBits,
+    ToFields,
+};
+
+use js_sys::Array;
+use std::str::FromStr;
+use wasm_bindgen::{prelude::*, JsCast};
+
+/// Hash an Aleo value with BHP256, matching the `hash.bhp256` instruction
+///
+/// @param {string | Array<string>} input String representation of the Aleo value to hash, or an array of values
+/// which are concatenated bit-wise before hashing
+/// @param {string} destination_type The type of the resulting hash: "field", "scalar" or "group"
+/// @returns {string | Error} String representation of the hash
+#[wasm_bindgen(js_name = hashBHP256)]
+pub fn hash_bhp256(input: JsValue, destination_type: &str) -> Result<String, String> {
+    hash_bits(input, destination_type, CurrentNetwork::hash_to_group_bhp256)
+}
+
+/// Hash the little endian bits of a byte array with BHP256
+///
+/// @param {Uint8Array} bytes The bytes to hash
+/// @param {string} destination_type The type of the resulting hash: "field", "scalar" or "group"
+/// @returns {string | Error} String representation of the hash
+#[wasm_bindgen(js_name = hashBHP256Bytes)]
+pub fn hash_bhp256_bytes(bytes: &[u8], destination_type: &str) -> Result<String, String> {
+    hash_bytes(bytes, destination_type, CurrentNetwork::hash_to_group_bhp256)
+}
+
+/// Hash an Aleo value with BHP512, matching the `hash.bhp512` instruction
+///
+/// @param {string | Array<string>} input String representation of the Aleo value to hash, or an array of values
+/// which are concatenated bit-wise before hashing
+/// @param {string} destination_type The type of the resulting hash: "field", "scalar" or "group"
+/// @returns {string | Error} String representation of the hash
+#[wasm_bindgen(js_name = hashBHP512)]
+pub fn hash_bhp512(input: JsValue, destination_type: &str) -> Result<String, String> {
+    hash_bits(input, destination_type, CurrentNetwork::hash_to_group_bhp512)
+}
+
+/// Hash the little endian bits of a byte array with BHP512
+///
+/// @param {Uint8Array} bytes The bytes to hash
+/// @param {string} destination_type The type of the resulting hash: "field", "scalar" or "group"
+/// @returns {string | Error} String representation of the hash
+#[wasm_bindgen(js_name = hashBHP512Bytes)]
+pub fn hash_bhp512_bytes(bytes: &[u8], destination_type: &str) -> Result<String, String> {
+    hash_bytes(bytes, destination_type, CurrentNetwork::hash_to_group_bhp512)
+}
+
+/// Hash an Aleo value with BHP768, matching the `hash.bhp768` instruction
+///
+/// @param {string | Array<string>} input String representation of the Aleo value to hash, or an array of values
+/// which are concatenated bit-wise before hashing
+/// @param {string} destination_type The type of the resulting hash: "field", "scalar" or "group"
+/// @returns {string | Error} String representation of the hash
+#[wasm_bindgen(js_name = hashBHP768)]
+pub fn hash_bhp768(input: JsValue, destination_type: &str) -> Result<String, String> {
+    hash_bits(input, destination_type, CurrentNetwork::hash_to_group_bhp768)
+}
+
+/// Hash the little endian bits of a byte array with BHP768
+///
+/// @param {Uint8Array} bytes The bytes to hash
+/// @param {string} destination_type The type of the resulting hash: "field", "scalar" or "group"
+/// @returns {string | Error} String representation of the hash
+#[wasm_bindgen(js_name = hashBHP768Bytes)]
+pub fn hash_bhp768_bytes(bytes: &[u8], destination_type: &str) -> Result<String, String> {
+    hash_bytes(bytes, destination_type, CurrentNetwork::hash_to_group_bhp768)
+}
+
+/// Hash an Aleo value with BHP1024, matching the `hash.bhp1024` instruction
+///
+/// @param {string | Array<string>} input String representation of the Aleo value to hash, or an array of values
+/// which are concatenated bit-wise before hashing
+/// @param {string} destination_type The type of the resulting hash: "field", "scalar" or "group"
+/// @returns {string | Error} String representation of the hash
+#[wasm_bindgen(js_name = hashBHP1024)]
+pub fn hash_bhp1024(input: JsValue, destination_type: &str) -> Result<String, String> {
+    hash_bits(input, destination_type, CurrentNetwork::hash_to_group_bhp1024)
+}
+
+/// Hash the little endian bits of a byte array with BHP1024
+///
+/// @param {Uint8Array} bytes The bytes to hash
+/// @param {string} destination_type The type of the resulting hash: "field", "scalar" or "group"
+/// @returns {string | Error} String representation of the hash
+#[wasm_bindgen(js_name = hashBHP1024Bytes)]
+pub fn hash_bhp1024_bytes(bytes: &[u8], destination_type: &str) -> Result<String, String> {
+    hash_bytes(bytes, destination_type, CurrentNetwork::hash_to_group_bhp1024)
+}
+
+/// Hash an Aleo value with Poseidon using a rate of 2, matching the `hash.psd2` instruction
+///
+/// @param {string | Array<string>} input String representation of the Aleo value to hash, or an array of values
+/// which are concatenated bit-wise before hashing
+/// @param {string} destination_type The type of the resulting hash: "field", "scalar" or "group"
+/// @returns {string | Error} String representation of the hash
+#[wasm_bindgen(js_name = hashPoseidon2)]
+pub fn hash_poseidon2(input: JsValue, destination_type: &str) -> Result<String, String> {
+    hash_fields(input, destination_type, CurrentNetwork::hash_to_group_psd2)
+}
+
+/// Hash an Aleo value with Poseidon using a rate of 4, matching the `hash.psd4` instruction
+///
+/// @param {string | Array<string>} input String representation of the Aleo value to hash, or an array of values
+/// which are concatenated bit-wise before hashing
+/// @param {string} destination_type The type of the resulting hash: "field", "scalar" or "group"
+/// @returns {string | Error} String representation of the hash
+#[wasm_bindgen(js_name = hashPoseidon4)]
+pub fn hash_poseidon4(input: JsValue, destination_type: &str) -> Result<String, String> {
+    hash_fields(input, destination_type, CurrentNetwork::hash_to_group_psd4)
+}
+
+/// Hash an Aleo value with Poseidon using a rate of 8, matching the `hash.psd8` instruction
+///
+/// @param {string | Array<string>} input String representation of the Aleo value to hash, or an array of values
+/// which are concatenated bit-wise before hashing
+/// @param {string} destination_type The type of the resulting hash: "field", "scalar" or "group"
+/// @returns {string | Error} String representation of the hash
+#[wasm_bindgen(js_name = hashPoseidon8)]
+pub fn hash_poseidon8(input: JsValue, destination_type: &str) -> Result<String, String> {
+    hash_fields(input, destination_type, CurrentNetwork::hash_to_group_psd8)
+}
+
+/// Hash an Aleo value with Keccak256, matching the `hash.keccak256` instruction
+///
+/// @param {string | Array<string>} input String representation of the Aleo value to hash, or an array of values
+/// which are concatenated bit-wise before hashing
+/// @param {string} destination_type The type of the resulting hash: "field", "scalar" or "group"
+/// @returns {string | Error} String representation of the hash
+#[wasm_bindgen(js_name = hashKeccak256)]
+pub fn hash_keccak256(input: JsValue, destination_type: &str) -> Result<String, String> {
+    hash_digest(input, destination_type, CurrentNetwork::hash_keccak256, CurrentNetwork::hash_to_group_bhp256)
+}
+
+/// Compute the raw Keccak256 digest of a byte array
+///
+/// @param {Uint8Array} bytes The bytes to hash
+/// @returns {Uint8Array | Error} The digest bytes
+#[wasm_bindgen(js_name = hashKeccak256Bytes)]
+pub fn hash_keccak256_bytes(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    digest_bytes(bytes, CurrentNetwork::hash_keccak256)
+}
+
+/// Hash an Aleo value with Keccak384, matching the `hash.keccak384` instruction
+///
+/// @param {string | Array<string>} input String representation of the Aleo value to hash, or an array of values
+/// which are concatenated bit-wise before hashing
+/// @param {string} destination_type The type of the resulting hash: "field", "scalar" or "group"
+/// @returns {string | Error} String representation of the hash
+#[wasm_bindgen(js_name = hashKeccak384)]
+pub fn hash_keccak384(input: JsValue, destination_type: &str) -> Result<String, String> {
+    hash_digest(input, destination_type, CurrentNetwork::hash_keccak384, CurrentNetwork::hash_to_group_bhp512)
+}
+
+/// Compute the raw Keccak384 digest of a byte array
+///
+/// @param {Uint8Array} bytes The bytes to hash
+/// @returns {Uint8Array | Error} The digest bytes
+#[wasm_bindgen(js_name = hashKeccak384Bytes)]
+pub fn hash_keccak384_bytes(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    digest_bytes(bytes, CurrentNetwork::hash_keccak384)
+}
+
+/// Hash an Aleo value with Keccak512, matching the `hash.keccak512` instruction
+///
+/// @param {string | Array<string>} input String representation of the Aleo value to hash, or an array of values
+/// which are concatenated bit-wise before hashing
+/// @param {string} destination_type The type of the resulting hash: "field", "scalar" or "group"
+/// @returns {string | Error} String representation of the hash
+#[wasm_bindgen(js_name = hashKeccak512)]
+pub fn hash_keccak512(input: JsValue, destination_type: &str) -> Result<String, String> {
+    hash_digest(input, destination_type, CurrentNetwork::hash_keccak512, CurrentNetwork::hash_to_group_bhp512)
+}
+
+/// Compute the raw Keccak512 digest of a byte array
+///
+/// @param {Uint8Array} bytes The bytes to hash
+/// @returns {Uint8Array | Error} The digest bytes
+#[wasm_bindgen(js_name = hashKeccak512Bytes)]
+pub fn hash_keccak512_bytes(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    digest_bytes(bytes, CurrentNetwork::hash_keccak512)
+}
+
+/// Hash an Aleo value with SHA3-256, matching the `hash.sha3_256` instruction
+///
+/// @param {string | Array<string>} input String representation of the Aleo value to hash, or an array of values
+/// which are concatenated bit-wise before hashing
+/// @param {string} destination_type The type of the resulting hash: "field", "scalar" or "group"
+/// @returns {string | Error} String representation of the hash
+#[wasm_bindgen(js_name = hashSha3_256)]
+pub fn hash_sha3_256(input: JsValue, destination_type: &str) -> Result<String, String> {
+    hash_digest(input, destination_type, CurrentNetwork::hash_sha3_256, CurrentNetwork::hash_to_group_bhp256)
+}
+
+/// Compute the raw SHA3-256 digest of a byte array
+///
+/// @param {Uint8Array} bytes The bytes to hash
+/// @returns {Uint8Array | Error} The digest bytes
+#[wasm_bindgen(js_name = hashSha3_256Bytes)]
+pub fn hash_sha3_256_bytes(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    digest_bytes(bytes, CurrentNetwork::hash_sha3_256)
+}
+
+/// Hash an Aleo value with SHA3-384, matching the `hash.sha3_384` instruction
+///
+/// @param {string | Array<string>} input String representation of the Aleo value to hash, or an array of values
+/// which are concatenated bit-wise before hashing
+/// @param {string} destination_type The type of the resulting hash: "field", "scalar" or "group"
+/// @returns {string | Error} String representation of the hash
+#[wasm_bindgen(js_name = hashSha3_384)]
+pub fn hash_sha3_384(input: JsValue, destination_type: &str) -> Result<String, String> {
+    hash_digest(input, destination_type, CurrentNetwork::hash_sha3_384, CurrentNetwork::hash_to_group_bhp512)
+}
+
+/// Compute the raw SHA3-384 digest of a byte array
+///
+/// @param {Uint8Array} bytes The bytes to hash
+/// @returns {Uint8Array | Error} The digest bytes
+#[wasm_bindgen(js_name = hashSha3_384Bytes)]
+pub fn hash_sha3_384_bytes(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    digest_bytes(bytes, CurrentNetwork::hash_sha3_384)
+}
+
+/// Hash an Aleo value with SHA3-512, matching the `hash.sha3_512` instruction
+///
+/// @param {string | Array<string>} input String representation of the Aleo value to hash, or an array of values
+/// which are concatenated bit-wise before hashing
+/// @param {string} destination_type The type of the resulting hash: "field", "scalar" or "group"
+/// @returns {string | Error} String representation of the hash
+#[wasm_bindgen(js_name = hashSha3_512)]
+pub fn hash_sha3_512(input: JsValue, destination_type: &str) -> Result<String, String> {
+    hash_digest(input, destination_type, CurrentNetwork::hash_sha3_512, CurrentNetwork::hash_to_group_bhp512)
+}
+
+/// Compute the raw SHA3-512 digest of a byte array
+///
+/// @param {Uint8Array} bytes The bytes to hash
+/// @returns {Uint8Array | Error} The digest bytes
+#[wasm_bindgen(js_name = hashSha3_512Bytes)]
+pub fn hash_sha3_512_bytes(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    digest_bytes(bytes, CurrentNetwork::hash_sha3_512)
+}
+
+// Hash the little endian bits of a value with one of the BHP hashers
+fn hash_bits(
+    input: JsValue,
+    destination_type: &str,
+    hasher: fn(&[bool]) -> anyhow::Result<GroupNative>,
+) -> Result<String, String> {
+    let destination = parse_destination(destination_type)?;
+    let bits = parse_inputs(input)?.iter().flat_map(|value| value.to_bits_le()).collect::<Vec<_>>();
+    cast_hash(hasher(&bits).map_err(|e| e.to_string())?, destination)
+}
+
+// Hash the little endian bits of raw bytes with one of the BHP hashers
+fn hash_bytes(
+    bytes: &[u8],
+    destination_type: &str,
+    hasher: fn(&[bool]) -> anyhow::Result<GroupNative>,
+) -> Result<String, String> {
+    let destination = parse_destination(destination_type)?;
+    cast_hash(hasher(&bytes_to_bits_le(bytes)).map_err(|e| e.to_string())?, destination)
+}
+
+// Hash the field elements of a value with one of the Poseidon hashers
+fn hash_fields(
+    input: JsValue,
+    destination_type: &str,
+    hasher: fn(&[FieldNative]) -> anyhow::Result<GroupNative>,
+) -> Result<String, String> {
+    let destination = parse_destination(destination_type)?;
+    let mut fields = vec![];
+    for value in parse_inputs(input)? {
+        fields.extend(value.to_fields().map_err(|e| e.to_string())?);
+    }
+    cast_hash(hasher(&fields).map_err(|e| e.to_string())?, destination)
+}
+
+// Hash a value with Keccak or SHA3 and compress the digest with BHP as the hash instructions do
+fn hash_digest(
+    input: JsValue,
+    destination_type: &str,
+    digest: fn(&[bool]) -> anyhow::Result<Vec<bool>>,
+    hasher: fn(&[bool]) -> anyhow::Result<GroupNative>,
+) -> Result<String, String> {
+    let destination = parse_destination(destination_type)?;
+    let bits = parse_inputs(input)?.iter().flat_map(|value| value.to_bits_le()).collect::<Vec<_>>();
+    let digest = digest(&bits).map_err(|e| e.to_string())?;
+    cast_hash(hasher(&digest).map_err(|e| e.to_string())?, destination)
+}
+
+// Compute a Keccak or SHA3 digest over raw bytes, reading and writing bits little endian within each byte
+fn digest_bytes(bytes: &[u8], digest: fn(&[bool]) -> anyhow::Result<Vec<bool>>) -> Result<Vec<u8>, String> {
+    let digest = digest(&bytes_to_bits_le(bytes)).map_err(|e| e.to_string())?;
+    Ok(digest.chunks(8).map(|bits| bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | *bit as u8)).collect())
+}
+
+fn bytes_to_bits_le(bytes: &[u8]) -> Vec<bool> {
+    bytes.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect()
+}
+
+// Parse either a single value or an array of values to be hashed together
+fn parse_inputs(input: JsValue) -> Result<Vec<PlaintextNative>, String> {
+    if let Some(input) = input.as_string() {
+        return Ok(vec![parse_input(&input)?]);
+    }
+    let inputs = input.dyn_into::<Array>().map_err(|_| "Hash input must be a string or an array of strings")?;
+    (0..inputs.length())
+        .map(|index| inputs.get(index).as_string().ok_or_else(|| format!("Invalid hash input at index {index}")))
+        .map(|input| parse_input(&input?))
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<PlaintextNative, String> {
+    PlaintextNative::from_str(input).map_err(|_| format!("Invalid Aleo value '{input}'"))
+}
+
+fn parse_destination(destination_type: &str) -> Result<LiteralType, String> {
+    match LiteralType::from_str(destination_type) {
+        Ok(destination @ (LiteralType::Field | LiteralType::Scalar | LiteralType::Group)) => Ok(destination),
+        _ => Err(format!("Invalid hash destination type '{destination_type}', expected field, scalar or group")),
+    }
+}
+
+// The hash instructions hash to a group element and lossily cast it to the destination type
+fn cast_hash(hash: GroupNative, destination: LiteralType) -> Result<String, String> {
+    LiteralNative::Group(hash).cast_lossy(destination).map(|hash| hash.to_string()).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const INPUT: &str = "{ owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3, amount: 100u64 }";
+
+    #[wasm_bindgen_test]
+    fn test_hash_destination_types() {
+        for hash in [
+            hash_bhp256,
+            hash_bhp512,
+            hash_bhp768,
+            hash_bhp1024,
+            hash_poseidon2,
+            hash_poseidon4,
+            hash_poseidon8,
+            hash_keccak256,
+            hash_keccak384,
+            hash_keccak512,
+            hash_sha3_256,
+            hash_sha3_384,
+            hash_sha3_512,
+        ] {
+            let group = GroupNative::from_str(&hash(INPUT.into(), "group").unwrap()).unwrap();
+            let field = FieldNative::from_str(&hash(INPUT.into(), "field").unwrap()).unwrap();
+            assert_eq!(field, group.to_x_coordinate());
+            assert!(hash(INPUT.into(), "scalar").unwrap().ends_with("scalar"));
+            assert_eq!(hash(INPUT.into(), "field").unwrap(), hash(INPUT.into(), "field").unwrap());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_hash_invalid_arguments() {
+        assert!(hash_poseidon2(INPUT.into(), "u64").is_err());
+        assert!(hash_poseidon2("not a value".into(), "field").is_err());
+        assert_ne!(hash_poseidon2("1u64".into(), "field").unwrap(), hash_poseidon2("2u64".into(), "field").unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_hash_bytes() {
+        // Digests of the empty message
+        assert_eq!(
+            hex::encode(hash_keccak256_bytes(&[]).unwrap()),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            hex::encode(hash_sha3_256_bytes(&[]).unwrap()),
+            "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"
+        );
+        assert_eq!(hash_keccak384_bytes(b"aleo").unwrap().len(), 48);
+        assert_eq!(hash_sha3_512_bytes(b"aleo").unwrap().len(), 64);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_hash_bhp_bytes() {
+        let bytes = 123456789u64.to_le_bytes();
+        assert_eq!(bytes_to_bits_le(&bytes), 123456789u64.to_bits_le());
+
+        let expected = CurrentNetwork::hash_to_group_bhp256(&123456789u64.to_bits_le()).unwrap();
+        assert_eq!(hash_bhp256_bytes(&bytes, "group").unwrap(), expected.to_string());
+        assert_eq!(hash_bhp256_bytes(&bytes, "field").unwrap(), expected.to_x_coordinate().to_string());
+        for hash in [hash_bhp256_bytes, hash_bhp512_bytes, hash_bhp768_bytes, hash_bhp1024_bytes] {
+            assert!(hash(b"aleo", "scalar").unwrap().ends_with("scalar"));
+            assert!(hash(b"aleo", "u8").is_err());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_hash_multiple_inputs() {
+        let inputs = || Array::of2(&"1u64".into(), &"2field".into()).into();
+        let values = [PlaintextNative::from_str("1u64").unwrap(), PlaintextNative::from_str("2field").unwrap()];
+
+        let bits = values.iter().flat_map(|value| value.to_bits_le()).collect::<Vec<_>>();
+        let expected = CurrentNetwork::hash_to_group_bhp256(&bits).unwrap();
+        assert_eq!(hash_bhp256(inputs(), "group").unwrap(), expected.to_string());
+
+        let fields = values.iter().flat_map(|value| value.to_fields().unwrap()).collect::<Vec<_>>();
+        let expected = CurrentNetwork::hash_to_group_psd2(&fields).unwrap();
+        assert_eq!(hash_poseidon2(inputs(), "group").unwrap(), expected.to_string());
+
+        assert_ne!(hash_keccak256(inputs(), "field").unwrap(), hash_keccak256("1u64".into(), "field").unwrap());
+        assert!(hash_sha3_256(Array::of2(&"1u64".into(), &JsValue::from(2)).into(), "field").is_err());
+        assert!(hash_bhp256(JsValue::from(1), "field").is_err());
+    }
+}
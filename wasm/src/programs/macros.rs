@@ -31,8 +31,8 @@ macro_rules! process_inputs {
 }
 
 #[macro_export]
-macro_rules! execute_program {
-    ($process:expr, $inputs:expr, $program_string:expr, $function_id_string:expr, $private_key:expr, $proving_key:expr, $verifying_key:expr, $rng:expr) => {{
+macro_rules! load_program {
+    ($process:expr, $program_string:expr, $function_id_string:expr, $proving_key:expr, $verifying_key:expr) => {{
         if (($proving_key.is_some() && $verifying_key.is_none())
             || ($proving_key.is_none() && $verifying_key.is_some()))
         {
@@ -62,7 +62,10 @@ macro_rules! execute_program {
             }
         }
 
-        if let Some(proving_key) = $proving_key {
+        let (proving_key, verifying_key) =
+            Self::provide_function_keys($process, program.id(), &function_name, $proving_key, $verifying_key).await?;
+
+        if let Some(proving_key) = proving_key {
             if Self::contains_key($process, program.id(), &function_name) {
                 log(&format!("Proving & verifying keys were specified for {program_id} - {function_name:?} but a key already exists in the cache. Using cached keys"));
             } else {
@@ -70,12 +73,22 @@ macro_rules! execute_program {
                 $process
                     .insert_proving_key(program.id(), &function_name, ProvingKeyNative::from(proving_key))
                     .map_err(|e| e.to_string())?;
-                if let Some(verifying_key) = $verifying_key {
+                if let Some(verifying_key) = verifying_key {
                     $process.insert_verifying_key(program.id(), &function_name, VerifyingKeyNative::from(verifying_key)).map_err(|e| e.to_string())?;
                 }
             }
         };
 
+        (program, function_name)
+    }};
+}
+
+#[macro_export]
+macro_rules! execute_program {
+    ($process:expr, $inputs:expr, $program_string:expr, $function_id_string:expr, $private_key:expr, $proving_key:expr, $verifying_key:expr, $rng:expr) => {{
+        let (program, function_name) =
+            $crate::load_program!($process, $program_string, $function_id_string, $proving_key, $verifying_key);
+
         log("Creating authorization");
         let authorization = $process
             .authorize::<CurrentAleo, _>(
@@ -96,9 +109,73 @@ macro_rules! execute_program {
     }};
 }
 
+#[macro_export]
+macro_rules! execute_authorization {
+    ($process:expr, $authorization:expr, $program_string:expr, $function_id_string:expr, $proving_key:expr, $verifying_key:expr) => {{
+        let (program, function_name) =
+            $crate::load_program!($process, $program_string, $function_id_string, $proving_key, $verifying_key);
+
+        log("Checking the external authorization");
+        let request = $authorization.peek_next().map_err(|e| e.to_string())?;
+        if request.program_id() != program.id() || request.function_name() != &function_name {
+            return Err(format!(
+                "The authorization provided is for {}/{}, expected {}/{}",
+                request.program_id(),
+                request.function_name(),
+                program.id(),
+                function_name
+            ));
+        }
+
+        log("Executing program");
+        let result = $process
+            .execute::<CurrentAleo>($authorization)
+            .map_err(|err| err.to_string())?;
+
+        result
+    }};
+}
+
 #[macro_export]
 macro_rules! execute_fee {
     ($process:expr, $private_key:expr, $fee_record:expr, $minimum_cost_fee_microcredits:expr, $priority_fee:expr, $submission_url:expr, $fee_proving_key:expr, $fee_verifying_key:expr, $execution_id:expr, $rng:expr) => {{
+        $crate::execute_fee!($process, $private_key, $fee_record, $minimum_cost_fee_microcredits, $priority_fee, $submission_url, $fee_proving_key, $fee_verifying_key, $execution_id, $rng, None::<$crate::OfflineQuery>)
+    }};
+    ($process:expr, $private_key:expr, $fee_record:expr, $minimum_cost_fee_microcredits:expr, $priority_fee:expr, $submission_url:expr, $fee_proving_key:expr, $fee_verifying_key:expr, $execution_id:expr, $rng:expr, $offline_query:expr) => {{
+        let offline_query: Option<$crate::OfflineQuery> = $offline_query;
+        $crate::insert_fee_keys!($process, $fee_record.is_some(), $fee_proving_key, $fee_verifying_key);
+
+        log("Authorizing Fee");
+        let fee_authorization = match $fee_record {
+            Some(fee_record) => {
+                let fee_record_native = RecordPlaintextNative::from_str(&fee_record.to_string()).unwrap();
+                $process.authorize_fee_private::<CurrentAleo, _>(
+                    $private_key,
+                    fee_record_native,
+                    $minimum_cost_fee_microcredits,
+                    $priority_fee,
+                    $execution_id,
+                    $rng,
+                ).map_err(|e| e.to_string())?
+            }
+            None => {
+                let address = $private_key.to_address().to_string();
+                let fee_microcredits = ($minimum_cost_fee_microcredits).saturating_add($priority_fee);
+                // The public balance cannot be fetched when the caller provides the state offline
+                if offline_query.is_none() {
+                    Self::check_public_fee_balance($submission_url, &address, fee_microcredits).await?;
+                }
+                $process.authorize_fee_public::<CurrentAleo, _>($private_key, $minimum_cost_fee_microcredits, $priority_fee, $execution_id, $rng).map_err(|e| e.to_string())?
+            }
+        };
+
+        $crate::prove_fee!($process, fee_authorization, $submission_url, $execution_id, offline_query)
+    }};
+}
+
+#[macro_export]
+macro_rules! insert_fee_keys {
+    ($process:expr, $fee_private:expr, $fee_proving_key:expr, $fee_verifying_key:expr) => {{
         if (($fee_proving_key.is_some() && $fee_verifying_key.is_none())
             || ($fee_proving_key.is_none() && $fee_verifying_key.is_some()))
         {
@@ -108,53 +185,47 @@ macro_rules! execute_fee {
             );
         }
 
-        if let Some(fee_proving_key) = $fee_proving_key {
-            let credits = ProgramIDNative::from_str("credits.aleo").unwrap();
-            let fee = if $fee_record.is_some() {
-                IdentifierNative::from_str("fee_private").unwrap()
-            } else {
-                IdentifierNative::from_str("fee_public").unwrap()
-            };
+        let credits = ProgramIDNative::from_str("credits.aleo").unwrap();
+        let fee = if $fee_private {
+            IdentifierNative::from_str("fee_private").unwrap()
+        } else {
+            IdentifierNative::from_str("fee_public").unwrap()
+        };
+        let (fee_proving_key, fee_verifying_key) =
+            Self::provide_function_keys($process, &credits, &fee, $fee_proving_key, $fee_verifying_key).await?;
+
+        if let Some(fee_proving_key) = fee_proving_key {
             if Self::contains_key($process, &credits, &fee) {
                 log("Fee proving & verifying keys were specified but a key already exists in the cache. Using cached keys");
             } else {
                 log("Inserting externally provided fee proving and verifying keys");
                 $process
                     .insert_proving_key(&credits, &fee, ProvingKeyNative::from(fee_proving_key)).map_err(|e| e.to_string())?;
-                if let Some(fee_verifying_key) = $fee_verifying_key {
+                if let Some(fee_verifying_key) = fee_verifying_key {
                     $process
                         .insert_verifying_key(&credits, &fee, VerifyingKeyNative::from(fee_verifying_key))
                         .map_err(|e| e.to_string())?;
                 }
             }
         };
-        
-        log("Authorizing Fee");
-        let fee_authorization = match $fee_record {
-            Some(fee_record) => {
-                let fee_record_native = RecordPlaintextNative::from_str(&fee_record.to_string()).unwrap();
-                $process.authorize_fee_private::<CurrentAleo, _>(
-                    $private_key,
-                    fee_record_native,
-                    $minimum_cost_fee_microcredits,
-                    $priority_fee,
-                    $execution_id,
-                    $rng,
-                ).map_err(|e| e.to_string())?
-            }
-            None => {
-                $process.authorize_fee_public::<CurrentAleo, _>($private_key, $minimum_cost_fee_microcredits, $priority_fee, $execution_id, $rng).map_err(|e| e.to_string())?
-            }
-        };
+    }};
+}
 
+#[macro_export]
+macro_rules! prove_fee {
+    ($process:expr, $fee_authorization:expr, $submission_url:expr, $execution_id:expr) => {{
+        $crate::prove_fee!($process, $fee_authorization, $submission_url, $execution_id, None::<$crate::OfflineQuery>)
+    }};
+    ($process:expr, $fee_authorization:expr, $submission_url:expr, $execution_id:expr, $offline_query:expr) => {{
         log("Executing fee");
         let (_, mut trace) = $process
-            .execute::<CurrentAleo>(fee_authorization)
+            .execute::<CurrentAleo>($fee_authorization)
             .map_err(|err| err.to_string())?;
 
-        let query = QueryNative::from($submission_url);
+        let query = Self::query($submission_url, $offline_query);
         trace.prepare_async(query).await.map_err(|err| err.to_string())?;
-        let fee = trace.prove_fee::<CurrentAleo, _>(&mut StdRng::from_entropy()).map_err(|e|e.to_string())?;
+        let fee = $crate::with_thread_budget("proving", || trace.prove_fee::<CurrentAleo, _>(&mut StdRng::from_entropy()))
+            .map_err(|e| e.to_string())?;
 
         log("Verifying fee execution");
         $process.verify_fee(&fee, $execution_id).map_err(|e| e.to_string())?;
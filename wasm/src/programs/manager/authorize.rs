@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{
+    log,
+    process_inputs,
+    types::{AuthorizationNative, CurrentAleo, FieldNative, IdentifierNative, ProcessNative, ProgramNative},
+    Authorization,
+    PrivateKey,
+    RecordPlaintext,
+};
+
+use js_sys::{Array, Object};
+use rand::{rngs::StdRng, SeedableRng};
+use std::str::FromStr;
+use wasm_bindgen::JsValue;
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Authorize a function execution without proving it. The authorization can be sent to
+    /// another machine to be proven and turned into a transaction
+    ///
+    /// @param private_key The private key of the caller
+    /// @param program The source code of the program being executed
+    /// @param function The name of the function to authorize
+    /// @param inputs A javascript array of inputs to the function
+    /// @param imports (optional) Provide a list of imports to use for the function authorization
+    /// in the form of a javascript object where the keys are a string of the program name and the
+    /// values are a string representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
+    /// @returns {Authorization | Error}
+    pub fn authorize(
+        private_key: &PrivateKey,
+        program: &str,
+        function: &str,
+        inputs: Array,
+        imports: Option<Object>,
+    ) -> Result<Authorization, String> {
+        log(&format!("Authorizing function: {function}"));
+        let mut process = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let authorization = Self::authorize_in_process(&mut process, private_key, program, function, inputs, imports)?;
+        Ok(Authorization::from(authorization))
+    }
+
+    /// Evaluate a function without proving it and return its outputs. This previews the result of
+    /// an execution instantly, but the outputs are not checked against the ledger, so finalize
+    /// logic is not run and record inputs are not checked for being unspent
+    ///
+    /// @param private_key The private key of the caller
+    /// @param program The source code of the program being evaluated
+    /// @param function The name of the function to evaluate
+    /// @param inputs A javascript array of inputs to the function
+    /// @param imports (optional) Provide a list of imports to use for the function evaluation in
+    /// the form of a javascript object where the keys are a string of the program name and the
+    /// values are a string representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
+    /// @returns {Array | Error} The outputs of the function as strings
+    pub fn evaluate(
+        private_key: &PrivateKey,
+        program: &str,
+        function: &str,
+        inputs: Array,
+        imports: Option<Object>,
+    ) -> Result<Array, String> {
+        log(&format!("Evaluating function: {function}"));
+        let mut process = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let authorization = Self::authorize_in_process(&mut process, private_key, program, function, inputs, imports)?;
+        let response = process.evaluate::<CurrentAleo>(authorization).map_err(|err| err.to_string())?;
+        Ok(response.outputs().iter().map(|output| JsValue::from_str(&output.to_string())).collect())
+    }
+
+    /// Authorize the fee of an execution or deployment without proving it
+    ///
+    /// @param private_key The private key paying the fee
+    /// @param base_fee_microcredits {bigint} The base fee in microcredits
+    /// @param priority_fee_microcredits {bigint} The priority fee in microcredits
+    /// @param id The execution or deployment id the fee is paid for
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @returns {Authorization | Error}
+    #[wasm_bindgen(js_name = authorizeFee)]
+    pub fn authorize_fee(
+        private_key: &PrivateKey,
+        base_fee_microcredits: u64,
+        priority_fee_microcredits: u64,
+        id: &str,
+        fee_record: Option<RecordPlaintext>,
+    ) -> Result<Authorization, String> {
+        log("Authorizing fee");
+        let process = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let id = FieldNative::from_str(id).map_err(|_| format!("Invalid execution or deployment id: {id}"))?;
+        let rng = &mut StdRng::from_entropy();
+        let authorization = match fee_record {
+            Some(fee_record) => {
+                if fee_record.microcredits() < base_fee_microcredits.saturating_add(priority_fee_microcredits) {
+                    return Err("Fee record does not have enough credits to pay the specified fee".to_string());
+                }
+                process.authorize_fee_private::<CurrentAleo, _>(
+                    private_key,
+                    (*fee_record).clone(),
+                    base_fee_microcredits,
+                    priority_fee_microcredits,
+                    id,
+                    rng,
+                )
+            }
+            None => process.authorize_fee_public::<CurrentAleo, _>(
+                private_key,
+                base_fee_microcredits,
+                priority_fee_microcredits,
+                id,
+                rng,
+            ),
+        }
+        .map_err(|err| err.to_string())?;
+        Ok(Authorization::from(authorization))
+    }
+}
+
+impl ProgramManager {
+    // Add a program and its imports to the process and authorize a call to one of its functions
+    fn authorize_in_process(
+        process: &mut ProcessNative,
+        private_key: &PrivateKey,
+        program: &str,
+        function: &str,
+        inputs: Array,
+        imports: Option<Object>,
+    ) -> Result<AuthorizationNative, String> {
+        log("Check program imports are valid and add them to the process");
+        let program = ProgramNative::from_str(program).map_err(|err| err.to_string())?;
+        ProgramManager::resolve_imports(process, &program, imports)?;
+        if !process.contains_program(program.id()) {
+            process.add_program(&program).map_err(|err| err.to_string())?;
+        }
+
+        let function_name = IdentifierNative::from_str(function).map_err(|err| err.to_string())?;
+        let inputs = process_inputs!(inputs);
+        let rng = &mut StdRng::from_entropy();
+        process
+            .authorize::<CurrentAleo, _>(private_key, program.id(), function_name, inputs.iter(), rng)
+            .map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const HELLO_PROGRAM: &str = r#"program hello_authorize.aleo;
+
+function hello:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+"#;
+
+    #[wasm_bindgen_test]
+    fn test_authorize_without_proving() {
+        let private_key =
+            PrivateKey::from_string("APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6").unwrap();
+        let inputs = Array::of2(&JsValue::from_str("5u32"), &JsValue::from_str("5u32"));
+
+        let authorization = ProgramManager::authorize(&private_key, HELLO_PROGRAM, "hello", inputs, None).unwrap();
+        assert_eq!(authorization.program_id().unwrap(), "hello_authorize.aleo");
+        assert_eq!(authorization.function_name().unwrap(), "hello");
+        assert_eq!(authorization.len(), 1);
+
+        let parsed = Authorization::from_string(&authorization.to_string()).unwrap();
+        assert_eq!(parsed.to_string(), authorization.to_string());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_evaluate_without_proving() {
+        let private_key =
+            PrivateKey::from_string("APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6").unwrap();
+        let inputs = Array::of2(&JsValue::from_str("5u32"), &JsValue::from_str("6u32"));
+
+        let outputs = ProgramManager::evaluate(&private_key, HELLO_PROGRAM, "hello", inputs, None).unwrap();
+        assert_eq!(outputs.length(), 1);
+        assert_eq!(outputs.get(0).as_string().unwrap(), "11u32");
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::Transaction;
+
+use reqwest::StatusCode;
+use serde_json::Value;
+
+/// Default interval between requests for the status of a broadcast transaction
+const DEFAULT_POLL_INTERVAL_MS: u32 = 5_000;
+/// Default time to wait for a broadcast transaction to be confirmed
+const DEFAULT_TIMEOUT_MS: u32 = 300_000;
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Broadcast a transaction to the node without retrying
+    ///
+    /// @param {string} url The url of the Aleo network node to broadcast the transaction to
+    /// @param {Transaction} transaction The transaction to broadcast
+    /// @returns {string | Error} The id of the transaction accepted by the node
+    #[wasm_bindgen(js_name = broadcastTransaction)]
+    pub async fn broadcast_transaction(url: &str, transaction: &Transaction) -> Result<String, String> {
+        Self::broadcast(url, transaction, Some(0)).await
+    }
+
+    /// Broadcast a transaction to the node, retrying with exponential backoff when the node cannot
+    /// be reached, is overloaded or fails with a server error
+    ///
+    /// When the node rejects the transaction the error names the reason in parentheses, one of
+    /// "duplicate", "invalid_id", "invalid_proof", "invalid_fee", "insufficient_balance",
+    /// "unknown_state_root", "too_large" or "rejected" when the reason is not recognized, followed
+    /// by the response of the node.
+    ///
+    /// @param {string} url The url of the Aleo network node to broadcast the transaction to
+    /// @param {Transaction} transaction The transaction to broadcast
+    /// @param {number | undefined} max_retries (optional) Number of retries after a transient
+    /// failure, 3 by default
+    /// @returns {string | Error} The id of the transaction accepted by the node
+    #[wasm_bindgen]
+    pub async fn broadcast(url: &str, transaction: &Transaction, max_retries: Option<u32>) -> Result<String, String> {
+        AleoNetworkClient::new(url).broadcast(transaction, max_retries).await
+    }
+
+    /// Wait until a broadcast transaction is confirmed in a block or rejected, or until the timeout
+    /// expires, and find the height of the block including it. Errors other than the node not
+    /// knowing the transaction yet end the wait
+    ///
+    /// @param {string} url The url of the Aleo network node to request the transaction status from
+    /// @param {string} transaction_id The id of the broadcast transaction
+    /// @param {number | undefined} timeout_ms (optional) Milliseconds to wait for the transaction,
+    /// 300000 by default
+    /// @param {number | undefined} poll_interval_ms (optional) Milliseconds between status
+    /// requests, 5000 by default
+    /// @param {AbortSignal | undefined} abort_signal (optional) Stop waiting for the transaction
+    /// @returns {Object | Error} The status of the transaction, one of "accepted", "rejected" or
+    /// "pending" if it was not included before the timeout, and the height of the block including
+    /// it in the form \{ status: string, blockHeight: number | undefined \}
+    #[wasm_bindgen(js_name = waitForConfirmation)]
+    pub async fn wait_for_confirmation(
+        url: &str,
+        transaction_id: &str,
+        timeout_ms: Option<u32>,
+        poll_interval_ms: Option<u32>,
+        abort_signal: Option<AbortSignal>,
+    ) -> Result<Object, String> {
+        let (status, block_height) =
+            Self::confirmation(url, transaction_id, timeout_ms, poll_interval_ms, abort_signal).await?;
+        let confirmation = Object::new();
+        Reflect::set(&confirmation, &"status".into(), &status.into()).map_err(|_| "Failed to set property")?;
+        let block_height = block_height.map_or(JsValue::UNDEFINED, JsValue::from);
+        Reflect::set(&confirmation, &"blockHeight".into(), &block_height).map_err(|_| "Failed to set property")?;
+        Ok(confirmation)
+    }
+}
+
+impl ProgramManager {
+    // Poll the status of a broadcast transaction until it is in a block or the timeout expires
+    async fn confirmation(
+        url: &str,
+        transaction_id: &str,
+        timeout_ms: Option<u32>,
+        poll_interval_ms: Option<u32>,
+        abort_signal: Option<AbortSignal>,
+    ) -> Result<(&'static str, Option<u32>), String> {
+        let deadline = js_sys::Date::now() + f64::from(timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS));
+        let poll_interval_ms = poll_interval_ms.unwrap_or(DEFAULT_POLL_INTERVAL_MS);
+        loop {
+            Self::check_aborted(abort_signal.as_ref()).await?;
+            if let Some(status) = confirmed_status(url, transaction_id).await? {
+                return Ok((status, block_height(url, transaction_id).await));
+            }
+            let remaining = deadline - js_sys::Date::now();
+            if remaining <= 0.0 {
+                return Ok(("pending", None));
+            }
+            sleep(poll_interval_ms.min(remaining as u32)).await?;
+        }
+    }
+}
+
+// Get the status of a transaction once it is included in a block, "accepted" or "rejected", or
+// None while the node does not know the transaction
+async fn confirmed_status(url: &str, transaction_id: &str) -> Result<Option<&'static str>, String> {
+    let path = format!("transaction/confirmed/{transaction_id}");
+    let (status, confirmed) = AleoNetworkClient::new(url).get_response(&path).await?;
+    if !status.is_success() {
+        if is_not_found(status, &confirmed) {
+            return Ok(None);
+        }
+        return Err(format!("Failed to fetch {path} - the node responded with {status}: {confirmed}"));
+    }
+    let confirmed = serde_json::from_str::<Value>(&confirmed)
+        .map_err(|e| format!("The node returned an invalid transaction: {e}"))?;
+    match confirmed.get("type").and_then(Value::as_str) {
+        Some(kind) if kind.starts_with("accepted") => Ok(Some("accepted")),
+        Some(kind) if kind.starts_with("rejected") => Ok(Some("rejected")),
+        _ => Err(format!("The node returned an unknown status for {transaction_id}")),
+    }
+}
+
+// Check whether a failed response means the node does not know the transaction. Nodes respond
+// with 404 or describe the transaction as missing until it is included in a block
+fn is_not_found(status: StatusCode, response: &str) -> bool {
+    let response = response.to_lowercase();
+    status == StatusCode::NOT_FOUND || response.contains("not found") || response.contains("missing")
+}
+
+// Find the height of the block including a transaction, if the node can tell
+async fn block_height(url: &str, transaction_id: &str) -> Option<u32> {
+    let client = AleoNetworkClient::new(url);
+    let block_hash = client.get(&format!("find/blockHash/{transaction_id}")).await.ok()?;
+    let block_hash = serde_json::from_str::<String>(&block_hash).ok()?;
+    serde_json::from_str::<u32>(&client.get(&format!("height/{block_hash}")).await.ok()?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_is_not_found() {
+        assert!(is_not_found(StatusCode::NOT_FOUND, ""));
+        assert!(is_not_found(StatusCode::INTERNAL_SERVER_ERROR, "Missing transaction for ID at1..."));
+        assert!(!is_not_found(StatusCode::INTERNAL_SERVER_ERROR, "Database error"));
+        assert!(!is_not_found(StatusCode::UNAUTHORIZED, "invalid api key"));
+    }
+}
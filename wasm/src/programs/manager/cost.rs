@@ -21,6 +21,7 @@ use crate::{
     types::{
         CurrentAleo,
         CurrentNetwork,
+        ExecutionNative,
         ProcessNative,
         ProgramNative,
     },
@@ -100,35 +101,22 @@ impl ProgramManager {
         );
 
         log("Preparing inclusion proofs for execution");
-        let query = QueryNative::from(url);
+        let query = Self::query(url, None);
         trace.prepare_async(query).await.map_err(|err| err.to_string())?;
 
         log("Proving execution");
         let program = ProgramNative::from_str(program).map_err(|err| err.to_string())?;
         let locator = program.id().to_string().add("/").add(function);
-        let execution = trace
-            .prove_execution::<CurrentAleo, _>(&locator, &mut StdRng::from_entropy())
-            .map_err(|e| e.to_string())?;
+        let execution = crate::with_thread_budget("proving", || {
+            trace.prove_execution::<CurrentAleo, _>(&locator, &mut StdRng::from_entropy())
+        })
+        .map_err(|e| e.to_string())?;
 
         // Get the storage cost in bytes for the program execution
         let storage_cost = execution.size_in_bytes().map_err(|e| e.to_string())?;
 
         // Compute the finalize cost in microcredits.
-        let mut finalize_cost = 0u64;
-        // Iterate over the transitions to accumulate the finalize cost.
-        for transition in execution.transitions() {
-            // Retrieve the function name.
-            let function_name = transition.function_name();
-            // Retrieve the finalize cost.
-            let cost = match program.get_function(function_name).map_err(|e| e.to_string())?.finalize_logic() {
-                Some(finalize) => cost_in_microcredits(finalize).map_err(|e| e.to_string())?,
-                None => continue,
-            };
-            // Accumulate the finalize cost.
-            finalize_cost = finalize_cost
-                .checked_add(cost)
-                .ok_or("The finalize cost computation overflowed for an execution".to_string())?;
-        }
+        let finalize_cost = execution_finalize_cost(process, &execution)?;
         let minimum_fee_cost = finalize_cost + storage_cost;
         let json_object = serde_json::json!({
             "minimum_execution_cost":minimum_fee_cost,
@@ -138,4 +126,26 @@ impl ProgramManager {
         
         Ok(json_object.to_string())
     }
-}
\ No newline at end of file
+}
+
+/// Compute the finalize cost in microcredits of an execution. The finalize logic of each transition
+/// is looked up in the program of the transition, so calls to imported programs are included.
+pub(crate) fn execution_finalize_cost(process: &ProcessNative, execution: &ExecutionNative) -> Result<u64, String> {
+    let mut finalize_cost = 0u64;
+    // Iterate over the transitions to accumulate the finalize cost.
+    for transition in execution.transitions() {
+        // Retrieve the program and function of the transition.
+        let program = process.get_program(transition.program_id()).map_err(|e| e.to_string())?;
+        let function = program.get_function(transition.function_name()).map_err(|e| e.to_string())?;
+        // Retrieve the finalize cost.
+        let cost = match function.finalize_logic() {
+            Some(finalize) => cost_in_microcredits(finalize).map_err(|e| e.to_string())?,
+            None => continue,
+        };
+        // Accumulate the finalize cost.
+        finalize_cost = finalize_cost
+            .checked_add(cost)
+            .ok_or("The finalize cost computation overflowed for an execution".to_string())?;
+    }
+    Ok(finalize_cost)
+}
@@ -22,6 +22,7 @@ use crate::{
     types::{
         CurrentAleo,
         CurrentNetwork,
+        DeploymentNative,
         ProcessNative,
         ProgramIDNative,
         ProgramNative,
@@ -42,6 +43,8 @@ use std::str::FromStr;
 impl ProgramManager {
     /// Deploy an Aleo program
     ///
+    /// @deprecated Converting floating point credits to microcredits is lossy, use
+    /// buildDeploymentTransactionMicrocredits instead
     /// @param private_key The private key of the sender
     /// @param program The source code of the program being deployed
     /// @param imports A javascript object holding the source code of any imported programs in the
@@ -49,7 +52,7 @@ impl ProgramManager {
     /// Note that all imported programs must be deployed on chain before the main program in order
     /// for the deployment to succeed
     /// @param fee_credits The amount of credits to pay as a fee
-    /// @param fee_record The record to spend the fee from
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
     /// @param url The url of the Aleo network node to send the transaction to
     /// @param imports (optional) Provide a list of imports to use for the program deployment in the
     /// form of a javascript object where the keys are a string of the program name and the values
@@ -69,62 +72,83 @@ impl ProgramManager {
         fee_proving_key: Option<ProvingKey>,
         fee_verifying_key: Option<VerifyingKey>,
     ) -> Result<Transaction, String> {
-        log("Creating deployment transaction");
-        // Convert fee to microcredits and check that the fee record has enough credits to pay it
-        let priority_fee = match &fee_record {
-            Some(fee_record) => Self::validate_amount(priority_fee, fee_record, true)?,
-            None => (priority_fee * 1_000_000.0) as u64,
-        };
-
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
-        let process = &mut process_native;
-
-        log("Checking program has a valid name");
-        let program = ProgramNative::from_str(program).map_err(|err| err.to_string())?;
-
-        log("Checking program imports are valid and add them to the process");
-        ProgramManager::resolve_imports(process, &program, imports)?;
-        let rng = &mut StdRng::from_entropy();
-
-        log("Creating deployment");
-        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).map_err(|err| err.to_string())?;
-        if deployment.program().functions().is_empty() {
-            return Err("Attempted to create an empty transaction deployment".to_string());
-        }
-
-        log("Ensuring the fee is sufficient to pay for the deployment");
-        let (minimum_deployment_cost, (_, _)) =
-            deployment_cost::<CurrentNetwork>(&deployment).map_err(|err| err.to_string())?;
-        
-
-        let deployment_id = deployment.to_deployment_id().map_err(|e| e.to_string())?;
-
-        let fee = execute_fee!(
-            process,
+        Self::deploy_microcredits(
             private_key,
+            program,
+            Self::credits_to_microcredits(priority_fee),
             fee_record,
-            minimum_deployment_cost,
-            priority_fee,
             url,
+            imports,
             fee_proving_key,
             fee_verifying_key,
-            deployment_id,
-            rng
-        );
+        )
+        .await
+    }
 
-        // Create the program owner
-        let owner = ProgramOwnerNative::new(private_key, deployment_id, &mut StdRng::from_entropy())
-            .map_err(|err| err.to_string())?;
+    /// Deploy an Aleo program with a priority fee in microcredits
+    ///
+    /// @param private_key The private key of the sender
+    /// @param program The source code of the program being deployed
+    /// @param priority_fee_microcredits {bigint} The amount of microcredits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param imports (optional) Provide a list of imports to use for the program deployment in the
+    /// form of a javascript object where the keys are a string of the program name and the values
+    /// are a string representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildDeploymentTransactionMicrocredits)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn deploy_microcredits(
+        private_key: &PrivateKey,
+        program: &str,
+        priority_fee_microcredits: u64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        imports: Option<Object>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        let mut options = DeployOptions::new(private_key, program, url);
+        options.priority_fee_microcredits = priority_fee_microcredits;
+        options.fee_record = fee_record;
+        options.imports = imports;
+        options.fee_proving_key = fee_proving_key;
+        options.fee_verifying_key = fee_verifying_key;
+        Self::build_deployment(options).await
+    }
 
-        log("Verifying the deployment and fees");
-        process
-            .verify_deployment::<CurrentAleo, _>(&deployment, &mut StdRng::from_entropy())
-            .map_err(|err| err.to_string())?;
+    /// Deploy an Aleo program, broadcast the deployment to the node and wait for it to be included
+    /// in a block
+    ///
+    /// @param {DeployOptions} options The options of the deployment, the abort signal also stops
+    /// waiting for the deployment
+    /// @param timeout_ms (optional) Milliseconds to wait for the deployment to be included in a
+    /// block, 300000 by default
+    /// @param poll_interval_ms (optional) Milliseconds between requests for the status of the
+    /// deployment, 5000 by default
+    /// @returns {Object | Error} The transaction id and the status of the deployment, one of
+    /// "accepted", "rejected" or "pending" if it was not included in a block in time, and the
+    /// height of the block including it in the form
+    /// \{ transactionId: string, status: string, blockHeight: number | undefined \}
+    #[wasm_bindgen(js_name = deployAndWait)]
+    pub async fn deploy_and_wait(
+        options: &DeployOptions,
+        timeout_ms: Option<u32>,
+        poll_interval_ms: Option<u32>,
+    ) -> Result<Object, String> {
+        let (url, abort_signal) = (options.url.clone(), options.abort_signal.clone());
+        let transaction = Self::build_deployment(options.clone()).await?;
 
-        log("Creating deployment transaction");
-        Ok(Transaction::from(
-            TransactionNative::from_deployment(owner, deployment, fee).map_err(|err| err.to_string())?,
-        ))
+        log("Broadcasting deployment transaction");
+        let transaction_id = Self::broadcast_transaction(&url, &transaction).await?;
+
+        log(&format!("Waiting for deployment transaction {transaction_id} to be included in a block"));
+        let result =
+            Self::wait_for_confirmation(&url, &transaction_id, timeout_ms, poll_interval_ms, abort_signal).await?;
+        Reflect::set(&result, &"transactionId".into(), &transaction_id.into()).map_err(|_| "Failed to set property")?;
+        Ok(result)
     }
 
     /// Estimate the fee for a program deployment
@@ -164,6 +188,51 @@ impl ProgramManager {
         Ok(minimum_deployment_cost)
     }
 
+    /// Verify that a deployment was generated faithfully from the program it deploys
+    ///
+    /// The circuit of each function in the program is synthesized locally and checked against
+    /// the verifying key in the deployment, and the certificate of each verifying key is checked
+    /// against the locally synthesized circuit. Wallets can use this to check a program fetched
+    /// from the network before calling it.
+    ///
+    /// @param deployment The deployment as a JSON string, or a deploy transaction containing it
+    /// @param imports (optional) Provide a list of imports of the deployed program in the form of
+    /// a javascript object where the keys are a string of the program name and the values are a
+    /// string representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
+    /// @param url (optional) The url of the Aleo network node to fetch missing imports from when
+    /// fetching imports is enabled
+    /// @returns {boolean | Error} True if the verifying keys and certificates match the program,
+    /// false if a certificate does not verify, or an error if the deployment could not be checked
+    #[wasm_bindgen(js_name = verifyDeployment)]
+    pub async fn verify_deployment(
+        deployment: &str,
+        imports: Option<Object>,
+        url: Option<String>,
+    ) -> Result<bool, String> {
+        let deployment = match DeploymentNative::from_str(deployment) {
+            Ok(deployment) => deployment,
+            Err(_) => TransactionNative::from_str(deployment)
+                .map_err(|_| "The deployment must be a deployment or a deploy transaction".to_string())?
+                .deployment()
+                .cloned()
+                .ok_or("The transaction is not a deploy transaction")?,
+        };
+        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let process = &mut process_native;
+
+        log("Check program imports are valid and add them to the process");
+        match url {
+            Some(url) => {
+                ProgramManager::resolve_imports_from_network(process, deployment.program(), imports, &url).await?
+            }
+            None => ProgramManager::resolve_imports(process, deployment.program(), imports)?,
+        };
+
+        log("Verify the deployment against the synthesized circuits");
+        let result = process.verify_deployment::<CurrentAleo, _>(&deployment, &mut StdRng::from_entropy());
+        Ok(verification_result(result, "Deployment"))
+    }
+
     /// Estimate the component of the deployment cost which comes from the fee for the program name.
     /// Note that this cost does not represent the entire cost of deployment. It is additional to
     /// the cost of the size (in bytes) of the deployment.
@@ -185,3 +254,96 @@ impl ProgramManager {
         Ok(namespace_cost)
     }
 }
+
+impl ProgramManager {
+    // Build a deployment transaction from its options
+    pub(crate) async fn build_deployment(options: DeployOptions) -> Result<Transaction, String> {
+        let DeployOptions {
+            private_key,
+            program,
+            url,
+            priority_fee_microcredits,
+            fee_record,
+            imports,
+            fee_proving_key,
+            fee_verifying_key,
+            record_provider,
+            abort_signal,
+            on_progress,
+        } = options;
+        let (private_key, program, url) = (&private_key, program.as_str(), url.as_str());
+        log("Creating deployment transaction");
+        // Check that the fee record has enough credits to pay the priority fee
+        if let Some(fee_record) = &fee_record {
+            Self::validate_microcredits(priority_fee_microcredits, fee_record, true)?;
+        }
+
+        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let process = &mut process_native;
+
+        log("Checking program has a valid name");
+        let program = ProgramNative::from_str(program).map_err(|err| err.to_string())?;
+
+        log("Checking program imports are valid and add them to the process");
+        ProgramManager::resolve_imports_from_network(process, &program, imports, url).await?;
+        let rng = &mut StdRng::from_entropy();
+
+        Self::check_aborted(abort_signal.as_ref()).await?;
+        Self::report_progress(on_progress.as_ref(), "synthesis", 10);
+
+        log("Creating deployment");
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).map_err(|err| err.to_string())?;
+        if deployment.program().functions().is_empty() {
+            return Err("Attempted to create an empty transaction deployment".to_string());
+        }
+
+        log("Ensuring the fee is sufficient to pay for the deployment");
+        let (minimum_deployment_cost, (_, _)) =
+            deployment_cost::<CurrentNetwork>(&deployment).map_err(|err| err.to_string())?;
+
+        // The fee record is selected once the base fee is known so that it covers the whole fee
+        let fee_microcredits = minimum_deployment_cost.saturating_add(priority_fee_microcredits);
+        let fee_record = Self::provide_fee_record(
+            record_provider.as_ref().map(|record_provider| record_provider as &dyn RecordProvider),
+            fee_microcredits,
+            fee_record,
+        )
+        .await?;
+        if let Some(fee_record) = &fee_record {
+            Self::validate_microcredits(fee_microcredits, fee_record, true)?;
+        }
+
+        let deployment_id = deployment.to_deployment_id().map_err(|e| e.to_string())?;
+
+        Self::check_aborted(abort_signal.as_ref()).await?;
+        Self::report_progress(on_progress.as_ref(), "fee", 60);
+
+        let fee = execute_fee!(
+            process,
+            private_key,
+            fee_record,
+            minimum_deployment_cost,
+            priority_fee_microcredits,
+            url,
+            fee_proving_key,
+            fee_verifying_key,
+            deployment_id,
+            rng
+        );
+
+        // Create the program owner
+        let owner = ProgramOwnerNative::new(private_key, deployment_id, &mut StdRng::from_entropy())
+            .map_err(|err| err.to_string())?;
+
+        Self::report_progress(on_progress.as_ref(), "verification", 90);
+        log("Verifying the deployment and fees");
+        process
+            .verify_deployment::<CurrentAleo, _>(&deployment, &mut StdRng::from_entropy())
+            .map_err(|err| err.to_string())?;
+
+        log("Creating deployment transaction");
+        let transaction = TransactionNative::from_deployment(owner, deployment, fee).map_err(|err| err.to_string())?;
+        Self::report_progress(on_progress.as_ref(), "done", 100);
+        Ok(Transaction::from(transaction))
+    }
+}
@@ -22,6 +22,7 @@ use crate::{
     execute_program,
     log,
     process_inputs,
+    record::RecordFinder,
     types::{CurrentAleo, IdentifierNative, ProcessNative, ProgramNative, RecordPlaintextNative, TransactionNative},
     ExecutionResponse,
     PrivateKey,
@@ -47,7 +48,8 @@ impl ProgramManager {
     /// @param {boolean} cache Cache the proving and verifying keys in the Execution response.
     /// If this is set to 'true' the keys synthesized will be stored in the Execution Response
     /// and the `ProvingKey` and `VerifyingKey` can be retrieved from the response via the `.getKeys()`
-    /// method.
+    /// method. The keys are also added to the key cache of the module, which can be managed with
+    /// `cachedKeys()`, `evictKey()` and `clearKeyCache()`.
     /// @param {Object | undefined} imports (optional) Provide a list of imports to use for the function execution in the
     /// form of a javascript object where the keys are a string of the program name and the values
     /// are a string representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
@@ -88,16 +90,24 @@ impl ProgramManager {
             rng
         );
 
-        let process_native = if cache { Some(process_native) } else { None };
+        let process_native = if cache {
+            let function_name = IdentifierNative::from_str(function).map_err(|e| e.to_string())?;
+            Self::cache_function_keys(&process_native, program_native.id(), &function_name)?;
+            Some(process_native)
+        } else {
+            None
+        };
 
         if prove_execution {
             log("Preparing inclusion proofs for execution");
-            let query = QueryNative::from("https://vm.aleo.org/api");
+            let query = Self::query("https://vm.aleo.org/api", None);
             trace.prepare_async(query).await.map_err(|err| err.to_string())?;
 
             log("Proving execution");
             let locator = program_native.id().to_string().add("/").add(function);
-            let execution = trace.prove_execution::<CurrentAleo, _>(&locator, rng).map_err(|e| e.to_string())?;
+            let execution =
+                crate::with_thread_budget("proving", || trace.prove_execution::<CurrentAleo, _>(&locator, rng))
+                    .map_err(|e| e.to_string())?;
             Ok(ExecutionResponse::from((response, execution, process_native)))
         } else {
             Ok(ExecutionResponse::from((response, process_native)))
@@ -106,12 +116,14 @@ impl ProgramManager {
 
     /// Execute Aleo function and create an Aleo execution transaction
     ///
+    /// @deprecated Converting floating point credits to microcredits is lossy, use
+    /// buildExecutionTransactionMicrocredits instead
     /// @param private_key The private key of the sender
     /// @param program The source code of the program being executed
     /// @param function The name of the function to execute
     /// @param inputs A javascript array of inputs to the function
     /// @param fee_credits The amount of credits to pay as a fee
-    /// @param fee_record The record to spend the fee from
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
     /// @param url The url of the Aleo network node to send the transaction to
     /// If this is set to 'true' the keys synthesized (or passed in as optional parameters via the
     /// `proving_key` and `verifying_key` arguments) will be stored in the ProgramManager's memory
@@ -141,85 +153,141 @@ impl ProgramManager {
         fee_proving_key: Option<ProvingKey>,
         fee_verifying_key: Option<VerifyingKey>,
     ) -> Result<Transaction, String> {
-        log(&format!("Executing function: {program} {function} {priority_fee} on-chain"));
-        let priority_fee = match &fee_record {
-            Some(fee_record) => Self::validate_amount(priority_fee, fee_record, true)?,
-            None => (priority_fee * 1_000_000.0) as u64,
-        };
-
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
-        let process = &mut process_native;
-
-        log("Check program imports are valid and add them to the process");
-        let program_native = ProgramNative::from_str(program).map_err(|e| e.to_string())?;
-        ProgramManager::resolve_imports(process, &program_native, imports)?;
-        let rng = &mut StdRng::from_entropy();
-
-        log("Executing program");
-        let (_, mut trace) = execute_program!(
-            process,
-            process_inputs!(inputs),
+        Self::execute_microcredits(
+            private_key,
             program,
             function,
-            private_key,
+            inputs,
+            Self::credits_to_microcredits(priority_fee),
+            fee_record,
+            url,
+            imports,
             proving_key,
             verifying_key,
-            rng
-        );
-
-        log("Preparing inclusion proofs for execution");
-        let query = QueryNative::from(url);
-        trace.prepare_async(query).await.map_err(|err| err.to_string())?;
-
-        log("Proving execution");
-        let program = ProgramNative::from_str(program).map_err(|err| err.to_string())?;
-        let locator = program.id().to_string().add("/").add(function);
-        let execution = trace
-            .prove_execution::<CurrentAleo, _>(&locator, &mut StdRng::from_entropy())
-            .map_err(|e| e.to_string())?;
-        let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
-
-        // Get the storage cost in bytes for the program execution
-        let storage_cost = execution.size_in_bytes().map_err(|e| e.to_string())?;
+            fee_proving_key,
+            fee_verifying_key,
+        )
+        .await
+    }
 
-        // Compute the finalize cost in microcredits.
-        let mut finalize_cost = 0u64;
-        // Iterate over the transitions to accumulate the finalize cost.
-        for transition in execution.transitions() {
-            // Retrieve the function name.
-            let function_name = transition.function_name();
-            // Retrieve the finalize cost.
-            let cost = match program.get_function(function_name).map_err(|e| e.to_string())?.finalize_logic() {
-                Some(finalize) => cost_in_microcredits(finalize).map_err(|e| e.to_string())?,
-                None => continue,
-            };
-            // Accumulate the finalize cost.
-            finalize_cost = finalize_cost
-                .checked_add(cost)
-                .ok_or("The finalize cost computation overflowed for an execution".to_string())?;
-        }
-        let minimum_fee_cost = finalize_cost + storage_cost;
+    /// Execute Aleo function and create an Aleo execution transaction with a priority fee in
+    /// microcredits
+    ///
+    /// @param private_key The private key of the sender
+    /// @param program The source code of the program being executed
+    /// @param function The name of the function to execute
+    /// @param inputs A javascript array of inputs to the function
+    /// @param priority_fee_microcredits {bigint} The amount of microcredits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param imports (optional) Provide a list of imports to use for the function execution in the
+    /// form of a javascript object where the keys are a string of the program name and the values
+    /// are a string representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
+    /// @param proving_key (optional) Provide a verifying key to use for the function execution
+    /// @param verifying_key (optional) Provide a verifying key to use for the function execution
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildExecutionTransactionMicrocredits)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn execute_microcredits(
+        private_key: &PrivateKey,
+        program: &str,
+        function: &str,
+        inputs: Array,
+        priority_fee_microcredits: u64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        imports: Option<Object>,
+        proving_key: Option<ProvingKey>,
+        verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        let mut options = ExecuteOptions::new(private_key, program, function, inputs, url);
+        options.priority_fee_microcredits = priority_fee_microcredits;
+        options.fee_record = fee_record;
+        options.imports = imports;
+        options.proving_key = proving_key;
+        options.verifying_key = verifying_key;
+        options.fee_proving_key = fee_proving_key;
+        options.fee_verifying_key = fee_verifying_key;
+        Self::build_execution(options).await
+    }
 
-        log(&format!("Executing fee {minimum_fee_cost} (storage_cost:{storage_cost} finalize_cost:{finalize_cost})"));
-        let fee = execute_fee!(
-            process,
+    /// Execute a function of a program deployed on the network and create an Aleo execution
+    /// transaction. The program and its imports are fetched from the network and the fee record
+    /// is selected with the record provider, or found in the recent blocks if it is omitted
+    ///
+    /// @deprecated Converting floating point credits to microcredits is lossy, use
+    /// executeWithProviderMicrocredits instead
+    /// @param private_key The private key of the sender
+    /// @param program_id The id of the deployed program, e.g. "hello.aleo"
+    /// @param function The name of the function to execute
+    /// @param inputs A javascript array of inputs to the function
+    /// @param fee_credits The amount of credits to pay as a priority fee
+    /// @param url The url of the Aleo network node to fetch the program from and send the
+    /// transaction to
+    /// @param record_provider (optional) Provide the record to pay the fee from
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = executeWithProvider)]
+    pub async fn execute_with_provider(
+        private_key: &PrivateKey,
+        program_id: &str,
+        function: &str,
+        inputs: Array,
+        fee_credits: f64,
+        url: &str,
+        record_provider: Option<JsRecordProvider>,
+    ) -> Result<Transaction, String> {
+        let fee_microcredits = Self::credits_to_microcredits(fee_credits);
+        Self::execute_with_provider_microcredits(
             private_key,
-            fee_record,
-            minimum_fee_cost,
-            priority_fee,
+            program_id,
+            function,
+            inputs,
+            fee_microcredits,
             url,
-            fee_proving_key,
-            fee_verifying_key,
-            execution_id,
-            rng
-        );
-
-        // Verify the execution
-        process.verify_execution(&execution).map_err(|err| err.to_string())?;
+            record_provider,
+        )
+        .await
+    }
 
-        log("Creating execution transaction");
-        let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
-        Ok(Transaction::from(transaction))
+    /// Execute a function of a program deployed on the network and create an Aleo execution
+    /// transaction with a priority fee in microcredits. The program and its imports are fetched
+    /// from the network and the fee record is selected with the record provider, or found in the
+    /// recent blocks if it is omitted
+    ///
+    /// @param private_key The private key of the sender
+    /// @param program_id The id of the deployed program, e.g. "hello.aleo"
+    /// @param function The name of the function to execute
+    /// @param inputs A javascript array of inputs to the function
+    /// @param priority_fee_microcredits {bigint} The amount of microcredits to pay as a priority fee
+    /// @param url The url of the Aleo network node to fetch the program from and send the
+    /// transaction to
+    /// @param record_provider (optional) Provide the record to pay the fee from
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = executeWithProviderMicrocredits)]
+    pub async fn execute_with_provider_microcredits(
+        private_key: &PrivateKey,
+        program_id: &str,
+        function: &str,
+        inputs: Array,
+        priority_fee_microcredits: u64,
+        url: &str,
+        record_provider: Option<JsRecordProvider>,
+    ) -> Result<Transaction, String> {
+        log(&format!("Fetching {program_id} and its imports from the network"));
+        let program = Self::fetch_program(url, program_id).await?;
+        let program_native = ProgramNative::from_str(&program).map_err(|e| e.to_string())?;
+        let imports = Self::fetch_imports(url, &program_native, None).await?;
+
+        let mut options = ExecuteOptions::new(private_key, &program, function, inputs, url);
+        options.priority_fee_microcredits = priority_fee_microcredits;
+        options.imports = Some(imports);
+        options.find_records = true;
+        options.record_provider = record_provider;
+        Self::build_execution(options).await
     }
 
     /// Estimate Fee for Aleo function execution. Note if "cache" is set to true, the proving and
@@ -279,34 +347,17 @@ impl ProgramManager {
         // Execute the program
         let program = ProgramNative::from_str(program).map_err(|err| err.to_string())?;
         let locator = program.id().to_string().add("/").add(function);
-        let query = QueryNative::from(url);
+        let query = Self::query(url, None);
         trace.prepare_async(query).await.map_err(|err| err.to_string())?;
-        let execution = trace.prove_execution::<CurrentAleo, _>(&locator, rng).map_err(|e| e.to_string())?;
+        let execution = crate::with_thread_budget("proving", || trace.prove_execution::<CurrentAleo, _>(&locator, rng))
+            .map_err(|e| e.to_string())?;
 
         // Get the storage cost in bytes for the program execution
         log("Estimating cost");
         let storage_cost = execution.size_in_bytes().map_err(|e| e.to_string())?;
 
         // Compute the finalize cost in microcredits.
-        let mut finalize_cost = 0u64;
-        // Iterate over the transitions to accumulate the finalize cost.
-        for transition in execution.transitions() {
-            // Retrieve the function name, program id, and program.
-            let function_name = transition.function_name();
-            let program_id = transition.program_id();
-            let program = process.get_program(program_id).map_err(|e| e.to_string())?;
-
-            // Calculate the finalize cost for the function identified in the transition
-            let cost = match &program.get_function(function_name).map_err(|e| e.to_string())?.finalize_logic() {
-                Some(finalize) => cost_in_microcredits(finalize).map_err(|e| e.to_string())?,
-                None => continue,
-            };
-
-            // Accumulate the finalize cost.
-            finalize_cost = finalize_cost
-                .checked_add(cost)
-                .ok_or("The finalize cost computation overflowed for an execution".to_string())?;
-        }
+        let finalize_cost = execution_finalize_cost(process, &execution)?;
         Ok(storage_cost + finalize_cost)
     }
 
@@ -332,3 +383,121 @@ impl ProgramManager {
         }
     }
 }
+
+impl ProgramManager {
+    // Build an execution transaction from its options
+    pub(crate) async fn build_execution(options: ExecuteOptions) -> Result<Transaction, String> {
+        let ExecuteOptions {
+            private_key,
+            program,
+            function,
+            inputs,
+            url,
+            priority_fee_microcredits,
+            fee_record,
+            imports,
+            proving_key,
+            verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+            find_records,
+            record_provider,
+            abort_signal,
+            on_progress,
+            offline_query,
+        } = options;
+        let (private_key, program, function, url) = (&private_key, program.as_str(), function.as_str(), url.as_str());
+        log(&format!("Executing function: {program} {function} {priority_fee_microcredits} on-chain"));
+        if let Some(fee_record) = &fee_record {
+            Self::validate_microcredits(priority_fee_microcredits, fee_record, true)?;
+        }
+
+        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let process = &mut process_native;
+
+        log("Check program imports are valid and add them to the process");
+        let program_native = ProgramNative::from_str(program).map_err(|e| e.to_string())?;
+        ProgramManager::resolve_imports_from_network(process, &program_native, imports, url).await?;
+        let rng = &mut StdRng::from_entropy();
+
+        Self::check_aborted(abort_signal.as_ref()).await?;
+        Self::report_progress(on_progress.as_ref(), "synthesis", 10);
+
+        log("Executing program");
+        let (_, mut trace) = execute_program!(
+            process,
+            process_inputs!(inputs),
+            program,
+            function,
+            private_key,
+            proving_key,
+            verifying_key,
+            rng
+        );
+
+        Self::check_aborted(abort_signal.as_ref()).await?;
+        Self::report_progress(on_progress.as_ref(), "inclusion", 40);
+
+        log("Preparing inclusion proofs for execution");
+        trace.prepare_async(Self::query(url, offline_query.clone())).await.map_err(|err| err.to_string())?;
+
+        Self::check_aborted(abort_signal.as_ref()).await?;
+        Self::report_progress(on_progress.as_ref(), "proving", 50);
+
+        log("Proving execution");
+        let program = ProgramNative::from_str(program).map_err(|err| err.to_string())?;
+        let locator = program.id().to_string().add("/").add(function);
+        let execution = crate::with_thread_budget("proving", || {
+            trace.prove_execution::<CurrentAleo, _>(&locator, &mut StdRng::from_entropy())
+        })
+        .map_err(|e| e.to_string())?;
+        let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
+
+        // Get the storage cost in bytes for the program execution
+        let storage_cost = execution.size_in_bytes().map_err(|e| e.to_string())?;
+
+        // Compute the finalize cost in microcredits.
+        let finalize_cost = execution_finalize_cost(process, &execution)?;
+        let minimum_fee_cost = finalize_cost + storage_cost;
+
+        // The fee record is selected once the base fee is known so that it covers the whole fee
+        let record_finder = RecordFinder::new(private_key, url, None);
+        let record_provider: Option<&dyn RecordProvider> = match (&record_provider, find_records) {
+            (Some(record_provider), _) => Some(record_provider),
+            (None, true) => Some(&record_finder),
+            _ => None,
+        };
+        let fee_microcredits = minimum_fee_cost.saturating_add(priority_fee_microcredits);
+        let fee_record = Self::provide_fee_record(record_provider, fee_microcredits, fee_record).await?;
+        if let Some(fee_record) = &fee_record {
+            Self::validate_microcredits(fee_microcredits, fee_record, true)?;
+        }
+
+        Self::check_aborted(abort_signal.as_ref()).await?;
+        Self::report_progress(on_progress.as_ref(), "fee", 80);
+
+        log(&format!("Executing fee {minimum_fee_cost} (storage_cost:{storage_cost} finalize_cost:{finalize_cost})"));
+        let fee = execute_fee!(
+            process,
+            private_key,
+            fee_record,
+            minimum_fee_cost,
+            priority_fee_microcredits,
+            url,
+            fee_proving_key,
+            fee_verifying_key,
+            execution_id,
+            rng,
+            offline_query
+        );
+
+        // Verify the execution
+        Self::report_progress(on_progress.as_ref(), "verification", 95);
+        process.verify_execution(&execution).map_err(|err| err.to_string())?;
+
+        log("Creating execution transaction");
+        let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
+        Self::report_progress(on_progress.as_ref(), "done", 100);
+        Ok(Transaction::from(transaction))
+    }
+}
@@ -36,11 +36,13 @@ impl ProgramManager {
     /// Join two records together to create a new record with an amount of credits equal to the sum
     /// of the credits of the two original records
     ///
+    /// @deprecated Converting floating point credits to microcredits is lossy, use
+    /// buildJoinTransactionMicrocredits instead
     /// @param private_key The private key of the sender
     /// @param record_1 The first record to combine
     /// @param record_2 The second record to combine
     /// @param fee_credits The amount of credits to pay as a fee
-    /// @param fee_record The record to spend the fee from
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
     /// @param url The url of the Aleo network node to send the transaction to
     /// @param join_proving_key (optional) Provide a proving key to use for the join function
     /// @param join_verifying_key (optional) Provide a verifying key to use for the join function
@@ -60,11 +62,54 @@ impl ProgramManager {
         join_verifying_key: Option<VerifyingKey>,
         fee_proving_key: Option<ProvingKey>,
         fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        Self::join_microcredits(
+            private_key,
+            record_1,
+            record_2,
+            Self::credits_to_microcredits(priority_fee),
+            fee_record,
+            url,
+            join_proving_key,
+            join_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+        )
+        .await
+    }
+
+    /// Join two records together to create a new record with an amount of credits equal to the sum
+    /// of the credits of the two original records, paying a priority fee in microcredits
+    ///
+    /// @param private_key The private key of the sender
+    /// @param record_1 The first record to combine
+    /// @param record_2 The second record to combine
+    /// @param priority_fee_microcredits {bigint} The amount of microcredits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param join_proving_key (optional) Provide a proving key to use for the join function
+    /// @param join_verifying_key (optional) Provide a verifying key to use for the join function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error} Transaction object
+    #[wasm_bindgen(js_name = buildJoinTransactionMicrocredits)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn join_microcredits(
+        private_key: &PrivateKey,
+        record_1: RecordPlaintext,
+        record_2: RecordPlaintext,
+        priority_fee_microcredits: u64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        join_proving_key: Option<ProvingKey>,
+        join_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
     ) -> Result<Transaction, String> {
         log("Executing join program");
         let priority_fee = match &fee_record {
-            Some(fee_record) => Self::validate_amount(priority_fee, fee_record, true)?,
-            None => (priority_fee * 1_000_000.0) as u64,
+            Some(fee_record) => Self::validate_microcredits(priority_fee_microcredits, fee_record, true)?,
+            None => priority_fee_microcredits,
         };
         let rng = &mut StdRng::from_entropy();
 
@@ -107,11 +152,13 @@ impl ProgramManager {
         );
 
         log("Preparing inclusion proof for the join execution");
-        let query = QueryNative::from(url);
+        let query = Self::query(url, None);
         trace.prepare_async(query).await.map_err(|err| err.to_string())?;
 
         log("Proving the join execution");
-        let execution = trace.prove_execution::<CurrentAleo, _>("credits.aleo/join", rng).map_err(|e| e.to_string())?;
+        let execution =
+            crate::with_thread_budget("proving", || trace.prove_execution::<CurrentAleo, _>("credits.aleo/join", rng))
+                .map_err(|e| e.to_string())?;
         let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
 
         log("Verifying the join execution");
@@ -120,23 +167,8 @@ impl ProgramManager {
         // Get the storage cost in bytes for the program execution
         let storage_cost = execution.size_in_bytes().map_err(|e| e.to_string())?;
 
-        let program = ProgramNative::from_str(&program).map_err(|err| err.to_string())?;
         // Compute the finalize cost in microcredits.
-        let mut finalize_cost = 0u64;
-        // Iterate over the transitions to accumulate the finalize cost.
-        for transition in execution.transitions() {
-            // Retrieve the function name.
-            let function_name = transition.function_name();
-            // Retrieve the finalize cost.
-            let cost = match program.get_function(function_name).map_err(|e| e.to_string())?.finalize_logic() {
-                Some(finalize) => cost_in_microcredits(finalize).map_err(|e| e.to_string())?,
-                None => continue,
-            };
-            // Accumulate the finalize cost.
-            finalize_cost = finalize_cost
-                .checked_add(cost)
-                .ok_or("The finalize cost computation overflowed for an execution".to_string())?;
-        }
+        let finalize_cost = execution_finalize_cost(process, &execution)?;
         let minimum_fee_cost = finalize_cost + storage_cost;
 
         log("Executing the fee");
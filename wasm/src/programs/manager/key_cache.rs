@@ -0,0 +1,334 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{log, types::CurrentAleo};
+
+use js_sys::Array;
+use rand::{rngs::StdRng, SeedableRng};
+
+/// Proving and verifying keys of a function cached along with the program they were created for
+struct CachedKeys {
+    program: ProgramNative,
+    proving_key: ProvingKey,
+    verifying_key: VerifyingKey,
+    size: Cell<Option<usize>>,
+}
+
+impl CachedKeys {
+    // Get the combined size of the keys in bytes, serializing them the first time it is requested
+    // if it was not known when they were cached
+    fn size(&self) -> usize {
+        if let Some(size) = self.size.get() {
+            return size;
+        }
+        let size = [self.proving_key.to_bytes(), self.verifying_key.to_bytes()]
+            .iter()
+            .map(|bytes| bytes.as_ref().map_or(0, Vec::len))
+            .sum();
+        self.size.set(Some(size));
+        size
+    }
+}
+
+thread_local! {
+    static KEY_CACHE: RefCell<HashMap<String, CachedKeys>> = RefCell::new(HashMap::new());
+}
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Synthesize the proving and verifying keys of a function ahead of time without executing it
+    ///
+    /// The keys are kept in the key cache of the module, so later executions and transfers of
+    /// the function skip synthesis, and are returned so they can be stored by the caller and
+    /// passed to a KeyProvider in later sessions.
+    ///
+    /// @param program {string} The source code of the program the function belongs to
+    /// @param function_name {string} The name of the function to synthesize keys for
+    /// @param imports {Object | undefined} The imports of the program in the form of a javascript
+    /// object where the keys are a string of the program name and the values are a string
+    /// representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
+    /// @returns {KeyPair | Error} The proving and verifying keys of the function
+    #[wasm_bindgen(js_name = synthesizeKeys)]
+    pub fn synthesize_keys(program: &str, function_name: &str, imports: Option<Object>) -> Result<KeyPair, String> {
+        let program = ProgramNative::from_str(program).map_err(|e| e.to_string())?;
+        let function_name = IdentifierNative::from_str(function_name).map_err(|e| e.to_string())?;
+        let mut process = ProcessNative::load_web().map_err(|e| e.to_string())?;
+        if !process.contains_program(program.id()) {
+            Self::resolve_imports(&mut process, &program, imports)?;
+            process.add_program(&program).map_err(|e| e.to_string())?;
+        }
+
+        log(&format!("Synthesizing keys for {}/{function_name}", program.id()));
+        process
+            .synthesize_key::<CurrentAleo, _>(program.id(), &function_name, &mut StdRng::from_entropy())
+            .map_err(|e| e.to_string())?;
+        let (proving_key, verifying_key) = Self::cache_function_keys(&process, program.id(), &function_name)?;
+        Ok(KeyPair::new(proving_key, verifying_key))
+    }
+
+    /// List the functions whose keys are in the key cache of the module
+    ///
+    /// @returns {Array} The cached keys in the form
+    /// \{ programId: string, functionName: string, size: number \} where size is the combined
+    /// size of the proving and verifying key in bytes
+    #[wasm_bindgen(js_name = cachedKeys)]
+    pub fn cached_keys() -> Result<Array, String> {
+        KEY_CACHE.with(|cache| {
+            let cache = cache.borrow();
+            let mut ids = cache.keys().collect::<Vec<_>>();
+            ids.sort();
+            ids.into_iter()
+                .map(|id| {
+                    let (program_id, function_name) = id.split_once('/').unwrap_or((id, ""));
+                    let entry = Object::new();
+                    Reflect::set(&entry, &"programId".into(), &program_id.into())
+                        .map_err(|_| "Failed to set property")?;
+                    Reflect::set(&entry, &"functionName".into(), &function_name.into())
+                        .map_err(|_| "Failed to set property")?;
+                    Reflect::set(&entry, &"size".into(), &cache[id].size().into())
+                        .map_err(|_| "Failed to set property")?;
+                    Ok(entry)
+                })
+                .collect::<Result<Array, String>>()
+        })
+    }
+
+    /// Get the combined size in bytes of all keys in the key cache of the module
+    ///
+    /// @returns {number} The size of the key cache in bytes
+    #[wasm_bindgen(js_name = keyCacheSize)]
+    pub fn key_cache_size() -> usize {
+        KEY_CACHE.with(|cache| cache.borrow().values().map(CachedKeys::size).sum())
+    }
+
+    /// Remove the keys of a function from the key cache of the module
+    ///
+    /// @param program_id {string} The id of the program the function belongs to
+    /// @param function_name {string} The name of the function
+    /// @returns {boolean} True if the keys were in the cache
+    #[wasm_bindgen(js_name = evictKey)]
+    pub fn evict_key(program_id: &str, function_name: &str) -> bool {
+        KEY_CACHE.with(|cache| cache.borrow_mut().remove(&format!("{program_id}/{function_name}")).is_some())
+    }
+
+    /// Remove all keys from the key cache of the module
+    #[wasm_bindgen(js_name = clearKeyCache)]
+    pub fn clear_key_cache() {
+        KEY_CACHE.with(|cache| {
+            let mut cache = cache.borrow_mut();
+            cache.clear();
+            cache.shrink_to_fit();
+        });
+    }
+}
+
+impl ProgramManager {
+    // Add the keys of a function loaded in the process to the key cache
+    pub(crate) fn cache_function_keys(
+        process: &ProcessNative,
+        program_id: &ProgramIDNative,
+        function_name: &IdentifierNative,
+    ) -> Result<(ProvingKey, VerifyingKey), String> {
+        let program = process.get_program(program_id).map_err(|e| e.to_string())?;
+        let proving_key = process.get_proving_key(*program_id, *function_name).map_err(|e| e.to_string())?;
+        let verifying_key = process.get_verifying_key(*program_id, *function_name).map_err(|e| e.to_string())?;
+        let (proving_key, verifying_key) = (ProvingKey::from(proving_key), VerifyingKey::from(verifying_key));
+        Self::insert_cached_keys(program, function_name, proving_key.clone(), verifying_key.clone())?;
+        Ok((proving_key, verifying_key))
+    }
+
+    // Add the keys of a function to the key cache, storing them in IndexedDB as well when key
+    // persistence is enabled and in the key cache directory when one is set. The keys are only
+    // serialized when they are stored
+    pub(crate) fn insert_cached_keys(
+        program: &ProgramNative,
+        function_name: &IdentifierNative,
+        proving_key: ProvingKey,
+        verifying_key: VerifyingKey,
+    ) -> Result<(), String> {
+        #[cfg(feature = "node")]
+        let stored = Self::key_persistence_enabled() || Self::key_cache_directory_set();
+        #[cfg(not(feature = "node"))]
+        let stored = Self::key_persistence_enabled();
+        let mut size = None;
+        if stored {
+            let proving_key_bytes = proving_key.to_bytes()?;
+            let verifying_key_bytes = verifying_key.to_bytes()?;
+            size = Some(proving_key_bytes.len() + verifying_key_bytes.len());
+            #[cfg(feature = "node")]
+            Self::write_key_files(program, function_name, &proving_key_bytes, &verifying_key_bytes);
+            Self::persist_keys(program, function_name, proving_key_bytes, verifying_key_bytes)?;
+        }
+        Self::restore_cached_keys(program.clone(), function_name, proving_key, verifying_key, size);
+        Ok(())
+    }
+
+    // Add keys to the key cache without persisting them. The size is computed when it is first
+    // requested if it is not known
+    pub(crate) fn restore_cached_keys(
+        program: ProgramNative,
+        function_name: &IdentifierNative,
+        proving_key: ProvingKey,
+        verifying_key: VerifyingKey,
+        size: Option<usize>,
+    ) {
+        let id = key_cache_id(program.id(), function_name);
+        let keys = CachedKeys { program, proving_key, verifying_key, size: Cell::new(size) };
+        KEY_CACHE.with(|cache| cache.borrow_mut().insert(id, keys));
+    }
+
+    // Get the program, function name and keys of every function in the key cache
+    pub(crate) fn key_cache_entries() -> Vec<(ProgramNative, String, ProvingKey, VerifyingKey)> {
+        KEY_CACHE.with(|cache| {
+            cache
+                .borrow()
+                .iter()
+                .map(|(id, keys)| {
+                    let function_name = id.split_once('/').map_or("", |(_, function_name)| function_name);
+                    (
+                        keys.program.clone(),
+                        function_name.to_string(),
+                        keys.proving_key.clone(),
+                        keys.verifying_key.clone(),
+                    )
+                })
+                .collect()
+        })
+    }
+
+    // Get the cached keys of a function if they were created for the program loaded in the process,
+    // reading them from the key cache directory without caching them when they are not in memory
+    pub(crate) fn cached_function_keys(
+        process: &ProcessNative,
+        program_id: &ProgramIDNative,
+        function_name: &IdentifierNative,
+    ) -> Option<(ProvingKey, VerifyingKey)> {
+        let program = process.get_program(program_id).ok()?;
+        let cached = KEY_CACHE.with(|cache| {
+            cache
+                .borrow()
+                .get(&key_cache_id(program_id, function_name))
+                .filter(|keys| &keys.program == program)
+                .map(|keys| (keys.proving_key.clone(), keys.verifying_key.clone()))
+        });
+        // Keys read from the key cache directory are not kept in memory, so the directory can hold
+        // more keys than the process can.
+        #[cfg(feature = "node")]
+        if cached.is_none() {
+            return Self::read_key_files(program, function_name);
+        }
+        cached
+    }
+}
+
+// Identify the keys of a function in the key cache
+fn key_cache_id(program_id: &ProgramIDNative, function_name: &IdentifierNative) -> String {
+    format!("{program_id}/{function_name}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const CACHE_PROGRAM: &str = r#"program key_cache_test.aleo;
+
+function double:
+    input r0 as u32.public;
+    add r0 r0 into r1;
+    output r1 as u32.private;
+
+function triple:
+    input r0 as u32.public;
+    add r0 r0 into r1;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+"#;
+
+    // The same program id as CACHE_PROGRAM with a different body
+    const CHANGED_PROGRAM: &str = r#"program key_cache_test.aleo;
+
+function double:
+    input r0 as u32.public;
+    mul r0 2u32 into r1;
+    output r1 as u32.private;
+"#;
+
+    // Load a program into a new process
+    fn process_with(program: &str) -> (ProcessNative, ProgramIDNative) {
+        let mut process = ProcessNative::load_web().unwrap();
+        let program = ProgramNative::from_str(program).unwrap();
+        process.add_program(&program).unwrap();
+        (process, *program.id())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_key_cache() {
+        ProgramManager::clear_key_cache();
+        assert_eq!(ProgramManager::key_cache_size(), 0);
+        assert_eq!(ProgramManager::cached_keys().unwrap().length(), 0);
+
+        // Ensure synthesized keys are cached and accounted for.
+        let mut double = ProgramManager::synthesize_keys(CACHE_PROGRAM, "double", None).unwrap();
+        let double_size = double.proving_key().unwrap().to_bytes().unwrap().len()
+            + double.verifying_key().unwrap().to_bytes().unwrap().len();
+        assert_eq!(ProgramManager::key_cache_size(), double_size);
+        ProgramManager::synthesize_keys(CACHE_PROGRAM, "triple", None).unwrap();
+        let cached = ProgramManager::cached_keys().unwrap();
+        assert_eq!(cached.length(), 2);
+        let field = |index, name: &str| Reflect::get(&cached.get(index), &name.into()).unwrap();
+        assert_eq!(field(0, "programId").as_string().unwrap(), "key_cache_test.aleo");
+        assert_eq!(field(0, "functionName").as_string().unwrap(), "double");
+        assert_eq!(field(0, "size").as_f64().unwrap() as usize, double_size);
+        assert_eq!(field(1, "functionName").as_string().unwrap(), "triple");
+        let triple_size = field(1, "size").as_f64().unwrap() as usize;
+        assert_eq!(ProgramManager::key_cache_size(), double_size + triple_size);
+
+        // Ensure evicting keys only removes the keys of the function.
+        assert!(ProgramManager::evict_key("key_cache_test.aleo", "double"));
+        assert!(!ProgramManager::evict_key("key_cache_test.aleo", "double"));
+        assert!(!ProgramManager::evict_key("unknown.aleo", "triple"));
+        assert_eq!(ProgramManager::key_cache_size(), triple_size);
+        assert_eq!(ProgramManager::cached_keys().unwrap().length(), 1);
+
+        ProgramManager::clear_key_cache();
+        assert_eq!(ProgramManager::key_cache_size(), 0);
+        assert_eq!(ProgramManager::cached_keys().unwrap().length(), 0);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_cached_function_keys_program_mismatch() {
+        ProgramManager::clear_key_cache();
+        ProgramManager::synthesize_keys(CACHE_PROGRAM, "double", None).unwrap();
+        let function_name = IdentifierNative::from_str("double").unwrap();
+
+        // Ensure the keys are used for the program they were created for.
+        let (process, program_id) = process_with(CACHE_PROGRAM);
+        assert!(ProgramManager::cached_function_keys(&process, &program_id, &function_name).is_some());
+
+        // Ensure the keys are not used for a different program with the same id.
+        let (process, program_id) = process_with(CHANGED_PROGRAM);
+        assert!(ProgramManager::cached_function_keys(&process, &program_id, &function_name).is_none());
+
+        // Ensure no keys are found for a program which is not loaded.
+        let process = ProcessNative::load_web().unwrap();
+        assert!(ProgramManager::cached_function_keys(&process, &program_id, &function_name).is_none());
+        ProgramManager::clear_key_cache();
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{log, Program};
+
+use js_sys::Uint8Array;
+
+#[wasm_bindgen(module = "fs")]
+extern "C" {
+    #[wasm_bindgen(js_name = existsSync)]
+    fn exists_sync(path: &str) -> bool;
+
+    #[wasm_bindgen(catch, js_name = mkdirSync)]
+    fn mkdir_sync(path: &str, options: &JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_name = readFileSync)]
+    fn read_file_sync(path: &str) -> Result<Uint8Array, JsValue>;
+
+    #[wasm_bindgen(catch, js_name = renameSync)]
+    fn rename_sync(from: &str, to: &str) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(catch, js_name = writeFileSync)]
+    fn write_file_sync(path: &str, data: &[u8]) -> Result<(), JsValue>;
+}
+
+thread_local! {
+    static KEY_DIRECTORY: RefCell<Option<String>> = RefCell::new(None);
+}
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Set the directory the key cache is backed by in NodeJS
+    ///
+    /// Keys added to the key cache are written to the directory, and keys missing from the key
+    /// cache are read from it before they are requested from the key provider or synthesized, so
+    /// the keys are shared by every process using the same directory. Keys read from the directory
+    /// are used without being added to the key cache, so memory use does not grow with the number
+    /// of keys in the directory. Files are named after the program id, function name and program
+    /// checksum.
+    ///
+    /// @param directory {string | undefined} The directory to store keys in, created if it does not
+    /// exist, or undefined to stop using a directory
+    #[wasm_bindgen(js_name = setKeyCacheDirectory)]
+    pub fn set_key_cache_directory(directory: Option<String>) -> Result<(), String> {
+        if let Some(directory) = &directory {
+            let options = Object::new();
+            Reflect::set(&options, &"recursive".into(), &true.into()).map_err(|_| "Failed to set property")?;
+            mkdir_sync(directory, &options).map_err(|e| format!("Failed to create {directory}: {e:?}"))?;
+        }
+        KEY_DIRECTORY.with(|key_directory| *key_directory.borrow_mut() = directory);
+        Ok(())
+    }
+}
+
+impl ProgramManager {
+    // Check whether keys added to the key cache are written to a key cache directory
+    pub(crate) fn key_cache_directory_set() -> bool {
+        KEY_DIRECTORY.with(|key_directory| key_directory.borrow().is_some())
+    }
+
+    // Write the keys of a function to the key cache directory if one is set
+    pub(crate) fn write_key_files(
+        program: &ProgramNative,
+        function_name: &IdentifierNative,
+        proving_key: &[u8],
+        verifying_key: &[u8],
+    ) {
+        let result = key_file_path(program, function_name).and_then(|path| {
+            let Some(path) = path else {
+                return Ok(());
+            };
+            // Write to a temporary file first so other processes never read a partially written key
+            for (extension, bytes) in [("prover", proving_key), ("verifier", verifying_key)] {
+                let (temporary, target) = (format!("{path}.{extension}.tmp"), format!("{path}.{extension}"));
+                write_file_sync(&temporary, bytes).map_err(|e| format!("Failed to write {temporary}: {e:?}"))?;
+                rename_sync(&temporary, &target).map_err(|e| format!("Failed to write {target}: {e:?}"))?;
+            }
+            Ok(())
+        });
+        if let Err(error) = result {
+            log(&format!(
+                "Failed to write the keys of {}/{function_name} to the key cache directory: {error}",
+                program.id()
+            ));
+        }
+    }
+
+    // Read the keys of a function from the key cache directory if one is set and it holds them
+    pub(crate) fn read_key_files(
+        program: &ProgramNative,
+        function_name: &IdentifierNative,
+    ) -> Option<(ProvingKey, VerifyingKey)> {
+        let path = key_file_path(program, function_name).ok().flatten()?;
+        let (prover, verifier) = (format!("{path}.prover"), format!("{path}.verifier"));
+        if !exists_sync(&prover) || !exists_sync(&verifier) {
+            return None;
+        }
+        let result = (|| {
+            let proving_key = read_file_sync(&prover).map_err(|e| format!("Failed to read {prover}: {e:?}"))?.to_vec();
+            let verifying_key =
+                read_file_sync(&verifier).map_err(|e| format!("Failed to read {verifier}: {e:?}"))?.to_vec();
+            Ok::<_, String>((ProvingKey::from_bytes(&proving_key)?, VerifyingKey::from_bytes(&verifying_key)?))
+        })();
+        result.map_err(|error| log(&format!("Ignoring the keys in the key cache directory: {error}"))).ok()
+    }
+}
+
+// Get the path of the key files of a function without their extension
+fn key_file_path(program: &ProgramNative, function_name: &IdentifierNative) -> Result<Option<String>, String> {
+    let Some(directory) = KEY_DIRECTORY.with(|key_directory| key_directory.borrow().clone()) else {
+        return Ok(None);
+    };
+    let checksum = Program::from(program.clone()).checksum()?;
+    Ok(Some(format!("{}/{}.{function_name}.{checksum}", directory.trim_end_matches('/'), program.id())))
+}
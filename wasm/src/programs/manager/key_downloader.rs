@@ -0,0 +1,366 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{log, programs::proving_key::ChunkedBytes};
+
+use js_sys::Uint8Array;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use web_sys::{ReadableStreamDefaultReader, Response};
+
+/// Location of the proving and verifying keys of the credits.aleo functions
+const DEFAULT_KEY_URL: &str = "https://testnet3.parameters.aleo.org";
+
+/// Length of a hex encoded SHA-256 checksum
+const CHECKSUM_LENGTH: usize = 64;
+
+/// Shortest checksum suffix of a file name accepted in place of a checksum, as on the official CDN
+const MIN_CHECKSUM_SUFFIX_LENGTH: usize = 7;
+
+/// Downloader for the proving and verifying keys published on a CDN, such as the keys of the
+/// credits.aleo functions
+///
+/// Interrupted downloads are resumed with range requests from the last byte received, and every
+/// key is checked against its full SHA-256 checksum before it is deserialized. Key files on the
+/// official CDN are named e.g. `transfer_private.prover.<checksum prefix>`, where the suffix is the
+/// start of the hex encoded SHA-256 checksum of the file. Checking only that suffix can be enabled
+/// with `setAllowChecksumSuffix`, but it protects the integrity of the file far less than the full
+/// checksum.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct KeyDownloader {
+    url: String,
+    max_retries: u32,
+    allow_checksum_suffix: bool,
+    on_progress: Option<Function>,
+}
+
+#[wasm_bindgen]
+impl KeyDownloader {
+    /// Create a downloader for the keys published at a url
+    ///
+    /// @param {string | undefined} url (optional) The url of the CDN, the official location of the
+    /// credits.aleo keys by default
+    /// @returns {KeyDownloader}
+    #[wasm_bindgen(constructor)]
+    pub fn new(url: Option<String>) -> KeyDownloader {
+        let url = url.as_deref().unwrap_or(DEFAULT_KEY_URL).trim_end_matches('/').to_string();
+        Self { url, max_retries: 5, allow_checksum_suffix: false, on_progress: None }
+    }
+
+    /// Set the number of times an interrupted download is resumed before it fails
+    ///
+    /// @param {number} max_retries Maximum number of resumptions, 5 by default
+    #[wasm_bindgen(js_name = setMaxRetries)]
+    pub fn set_max_retries(&mut self, max_retries: u32) {
+        self.max_retries = max_retries;
+    }
+
+    /// Allow downloading a key without a checksum by checking it against the checksum suffix of
+    /// its file name, which must have at least 7 hex characters
+    ///
+    /// @param {boolean} allow_checksum_suffix Whether to check the suffix, false by default
+    #[wasm_bindgen(js_name = setAllowChecksumSuffix)]
+    pub fn set_allow_checksum_suffix(&mut self, allow_checksum_suffix: bool) {
+        self.allow_checksum_suffix = allow_checksum_suffix;
+    }
+
+    /// Set the callback invoked with the name of the file being downloaded, the number of bytes
+    /// received and the size of the file, which is undefined if the CDN does not report it
+    ///
+    /// @param {Function | undefined} on_progress The callback, or undefined to remove it
+    #[wasm_bindgen(js_name = setProgressCallback)]
+    pub fn set_progress_callback(&mut self, on_progress: Option<Function>) {
+        self.on_progress = on_progress;
+    }
+
+    /// Download a proving key
+    ///
+    /// @param {string} file The name of the key file on the CDN, e.g. "join.prover.30895cc"
+    /// @param {string | undefined} checksum (optional) The hex encoded SHA-256 checksum of the file,
+    /// required unless checking the checksum suffix of the file name is allowed
+    /// @returns {ProvingKey | Error}
+    #[wasm_bindgen(js_name = provingKey)]
+    pub async fn proving_key(&self, file: &str, checksum: Option<String>) -> Result<ProvingKey, String> {
+        ProvingKey::from_chunks(self.download(file, checksum.as_deref()).await?)
+    }
+
+    /// Download a verifying key
+    ///
+    /// @param {string} file The name of the key file on the CDN, e.g. "join.verifier.<checksum prefix>"
+    /// @param {string | undefined} checksum (optional) The hex encoded SHA-256 checksum of the file,
+    /// required unless checking the checksum suffix of the file name is allowed
+    /// @returns {VerifyingKey | Error}
+    #[wasm_bindgen(js_name = verifyingKey)]
+    pub async fn verifying_key(&self, file: &str, checksum: Option<String>) -> Result<VerifyingKey, String> {
+        VerifyingKey::from_bytes(&self.download(file, checksum.as_deref()).await?.into_vec())
+    }
+}
+
+impl KeyDownloader {
+    // Download a file, resuming after interruptions, and verify its checksum. The file is kept in
+    // the chunks it was received in so that large keys are never copied into a single array
+    async fn download(&self, file: &str, checksum: Option<&str>) -> Result<ChunkedBytes, String> {
+        let expected = Checksum::parse(file, checksum, self.allow_checksum_suffix)?;
+
+        let url = format!("{}/{file}", self.url);
+        let mut bytes = ChunkedBytes::default();
+        let mut hasher = Sha256::new();
+        let mut total = None;
+        let mut retries = 0;
+        loop {
+            match self.receive(&url, file, &mut bytes, &mut hasher, &mut total).await {
+                Ok(()) => break,
+                Err(error) if retries < self.max_retries => {
+                    retries += 1;
+                    log(&format!("Resuming the download of {file} from byte {}: {error}", bytes.len()));
+                }
+                Err(error) => return Err(format!("Failed to download {file}: {error}")),
+            }
+        }
+
+        let digest = hex::encode(hasher.finalize());
+        if !expected.matches(&digest) {
+            return Err(format!("The checksum of {file} is {digest}, expected {expected}"));
+        }
+        Ok(bytes)
+    }
+
+    // Request the bytes of a file from the offset already received and append them as they arrive
+    async fn receive(
+        &self,
+        url: &str,
+        file: &str,
+        bytes: &mut ChunkedBytes,
+        hasher: &mut Sha256,
+        total: &mut Option<usize>,
+    ) -> Result<(), String> {
+        let fetch = Reflect::get(&js_sys::global(), &"fetch".into())
+            .ok()
+            .and_then(|fetch| fetch.dyn_into::<Function>().ok())
+            .ok_or_else(|| "fetch is not available in this environment".to_string())?;
+        let init = Object::new();
+        if !bytes.is_empty() {
+            let headers = Object::new();
+            Reflect::set(&headers, &"Range".into(), &format!("bytes={}-", bytes.len()).into())
+                .map_err(|_| "Failed to set property")?;
+            Reflect::set(&init, &"headers".into(), &headers).map_err(|_| "Failed to set property")?;
+        }
+        let response = fetch.call2(&JsValue::NULL, &url.into(), &init).map_err(|e| format!("{e:?}"))?;
+        let response = JsFuture::from(Promise::from(response))
+            .await
+            .map_err(|e| format!("{e:?}"))?
+            .dyn_into::<Response>()
+            .map_err(|_| "fetch did not return a response".to_string())?;
+
+        let headers = response.headers();
+        let header = |name| headers.get(name).ok().flatten();
+        let resume =
+            Resume::from_response(response.status(), bytes.len(), header("Content-Length"), header("Content-Range"))?;
+        match resume {
+            Resume::Restart(size) => {
+                bytes.clear();
+                *hasher = Sha256::new();
+                *total = size;
+            }
+            Resume::Append(size) => *total = size,
+        }
+
+        let reader = response
+            .body()
+            .ok_or_else(|| "The response has no body".to_string())?
+            .get_reader()
+            .unchecked_into::<ReadableStreamDefaultReader>();
+        loop {
+            let chunk = JsFuture::from(reader.read()).await.map_err(|e| format!("{e:?}"))?;
+            if Reflect::get(&chunk, &"done".into()).map_or(true, |done| done.is_truthy()) {
+                break;
+            }
+            let value = Reflect::get(&chunk, &"value".into()).map_err(|_| "Failed to read the response")?;
+            let chunk = Uint8Array::new(&value).to_vec();
+            hasher.update(&chunk);
+            bytes.push(chunk);
+            self.report_progress(file, bytes.len(), *total);
+        }
+        match total {
+            Some(total) if bytes.len() < *total => Err(format!("Received {} of {total} bytes", bytes.len())),
+            _ => Ok(()),
+        }
+    }
+
+    // Report the progress of a download to the progress callback
+    fn report_progress(&self, file: &str, received: usize, total: Option<usize>) {
+        if let Some(on_progress) = &self.on_progress {
+            let total = total.map_or(JsValue::UNDEFINED, JsValue::from);
+            if on_progress.call3(&JsValue::NULL, &file.into(), &received.into(), &total).is_err() {
+                log("The progress callback threw an error");
+            }
+        }
+    }
+}
+
+// The checksum a downloaded file is checked against
+#[derive(Debug, PartialEq, Eq)]
+enum Checksum {
+    // The full hex encoded SHA-256 checksum of the file
+    Full(String),
+    // The start of the hex encoded checksum, taken from the suffix of the file name
+    Suffix(String),
+}
+
+impl Checksum {
+    // Get the checksum given for a file, or the checksum suffix of its name if that is allowed
+    fn parse(file: &str, checksum: Option<&str>, allow_suffix: bool) -> Result<Self, String> {
+        let is_hex = |checksum: &str| checksum.chars().all(|c| c.is_ascii_hexdigit());
+        match checksum {
+            Some(checksum) => {
+                let checksum = checksum.trim().to_lowercase();
+                if checksum.len() != CHECKSUM_LENGTH || !is_hex(&checksum) {
+                    return Err(format!("The checksum of {file} must be {CHECKSUM_LENGTH} hex characters"));
+                }
+                Ok(Self::Full(checksum))
+            }
+            None if allow_suffix => file
+                .rsplit_once('.')
+                .map(|(_, suffix)| suffix.to_lowercase())
+                .filter(|suffix| suffix.len() >= MIN_CHECKSUM_SUFFIX_LENGTH && is_hex(suffix))
+                .map(Self::Suffix)
+                .ok_or_else(|| {
+                    format!("{file} has no checksum suffix of at least {MIN_CHECKSUM_SUFFIX_LENGTH} hex characters")
+                }),
+            None => Err(format!("No checksum was given for {file}")),
+        }
+    }
+
+    // Check the hex encoded SHA-256 digest of a file against the checksum
+    fn matches(&self, digest: &str) -> bool {
+        match self {
+            Self::Full(checksum) => digest == checksum,
+            Self::Suffix(suffix) => digest.starts_with(suffix.as_str()),
+        }
+    }
+}
+
+impl fmt::Display for Checksum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Full(checksum) => write!(f, "{checksum}"),
+            Self::Suffix(suffix) => write!(f, "{suffix}..."),
+        }
+    }
+}
+
+// How to continue a download with the bytes of a response
+#[derive(Debug, PartialEq, Eq)]
+enum Resume {
+    // Discard the bytes received so far, the response is the whole file of the given size
+    Restart(Option<usize>),
+    // Append the response to the bytes received so far, the file has the given size
+    Append(Option<usize>),
+}
+
+impl Resume {
+    // Decide how to continue from the status and headers of a response to a request for the bytes
+    // after those already received
+    fn from_response(
+        status: u16,
+        received: usize,
+        content_length: Option<String>,
+        content_range: Option<String>,
+    ) -> Result<Self, String> {
+        match status {
+            // The CDN ignored the range and sent the whole file
+            200 => Ok(Self::Restart(content_length.and_then(|length| length.parse().ok()))),
+            206 => {
+                // The range is sent as "bytes <start>-<end>/<size>", where the size may be "*"
+                let range =
+                    content_range.ok_or_else(|| "The CDN sent a partial response without a range".to_string())?;
+                let (start, size) = range
+                    .strip_prefix("bytes ")
+                    .and_then(|range| range.split_once('-'))
+                    .and_then(|(start, rest)| Some((start.parse::<usize>().ok()?, rest.rsplit_once('/')?.1)))
+                    .ok_or_else(|| format!("The CDN sent an invalid range: {range}"))?;
+                if start != received {
+                    return Err(format!("The CDN resumed the download from byte {start} instead of {received}"));
+                }
+                Ok(Self::Append(size.parse().ok()))
+            }
+            status => Err(format!("The CDN responded with {status}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const DIGEST: &str = "30895cc8a1c2e2ba1e6c8a6b7d3b4c1f0e9d8c7b6a5f4e3d2c1b0a9f8e7d6c5b";
+
+    #[wasm_bindgen_test]
+    fn test_full_checksum() {
+        let checksum = Checksum::parse("join.prover.30895cc", Some(DIGEST.to_uppercase().as_str()), false).unwrap();
+        assert_eq!(checksum, Checksum::Full(DIGEST.to_string()));
+        assert!(checksum.matches(DIGEST));
+        assert!(!checksum.matches(&DIGEST.replace('b', "a")));
+
+        for invalid in ["", "30895cc", &DIGEST[1..], DIGEST.replace('c', "g").as_str()] {
+            let error = Checksum::parse("join.prover.30895cc", Some(invalid), true).unwrap_err();
+            assert_eq!(error, "The checksum of join.prover.30895cc must be 64 hex characters");
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_checksum_suffix() {
+        let error = Checksum::parse("join.prover.30895cc", None, false).unwrap_err();
+        assert_eq!(error, "No checksum was given for join.prover.30895cc");
+
+        let checksum = Checksum::parse("join.prover.30895CC", None, true).unwrap();
+        assert_eq!(checksum, Checksum::Suffix("30895cc".to_string()));
+        assert!(checksum.matches(DIGEST));
+        assert!(!checksum.matches(&DIGEST.replacen('3', "4", 1)));
+
+        for file in ["join.prover.30895c", "join.prover", "join.prover.30895cg"] {
+            assert!(Checksum::parse(file, None, true).is_err());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_resume_from_response() {
+        // A complete response restarts the download even if bytes were received
+        let resume = Resume::from_response(200, 100, Some("1000".to_string()), None).unwrap();
+        assert_eq!(resume, Resume::Restart(Some(1000)));
+        assert_eq!(Resume::from_response(200, 0, None, None).unwrap(), Resume::Restart(None));
+
+        // A partial response is appended if it starts at the first missing byte
+        let range = Some("bytes 100-999/1000".to_string());
+        assert_eq!(
+            Resume::from_response(206, 100, Some("900".to_string()), range).unwrap(),
+            Resume::Append(Some(1000))
+        );
+        let range = Some("bytes 100-999/*".to_string());
+        assert_eq!(Resume::from_response(206, 100, None, range).unwrap(), Resume::Append(None));
+
+        let error = Resume::from_response(206, 50, None, Some("bytes 100-999/1000".to_string())).unwrap_err();
+        assert_eq!(error, "The CDN resumed the download from byte 100 instead of 50");
+        assert!(Resume::from_response(206, 100, None, None).is_err());
+        assert!(Resume::from_response(206, 100, None, Some("100-999/1000".to_string())).is_err());
+        assert_eq!(Resume::from_response(416, 100, None, None).unwrap_err(), "The CDN responded with 416");
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{
+    log,
+    types::{CurrentAleo, VerifyingKeyNative},
+};
+
+use futures::future::LocalBoxFuture;
+use js_sys::{Array, Function, Reflect, Uint8Array};
+use rand::{rngs::StdRng, SeedableRng};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen::{JsCast, JsValue};
+
+#[wasm_bindgen(typescript_custom_section)]
+const KEY_PROVIDER_INTERFACE: &str = r#"
+/**
+ * Source of the proving and verifying keys of program functions, e.g. a CDN, IndexedDB or assets
+ * embedded in the application. Keys may be returned as ProvingKey and VerifyingKey objects or as
+ * their byte representation. Resolving to undefined makes the program manager synthesize the keys.
+ */
+export interface KeyProvider {
+    functionKeys(
+        programId: string,
+        functionName: string,
+    ): Promise<[ProvingKey | Uint8Array, VerifyingKey | Uint8Array] | undefined>;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Key provider implemented in javascript
+    #[wasm_bindgen(typescript_type = "KeyProvider")]
+    pub type JsKeyProvider;
+
+    #[wasm_bindgen(method, catch, js_name = functionKeys)]
+    async fn request_function_keys(
+        this: &JsKeyProvider,
+        program_id: &str,
+        function_name: &str,
+    ) -> Result<JsValue, JsValue>;
+}
+
+/// Source of the proving and verifying keys of program functions
+pub trait KeyProvider {
+    /// Find the proving and verifying key of a function, returning None if the keys should be synthesized
+    fn function_keys<'a>(
+        &'a self,
+        program_id: &'a str,
+        function_name: &'a str,
+    ) -> LocalBoxFuture<'a, Result<Option<(ProvingKey, VerifyingKey)>, String>>;
+}
+
+impl KeyProvider for JsKeyProvider {
+    fn function_keys<'a>(
+        &'a self,
+        program_id: &'a str,
+        function_name: &'a str,
+    ) -> LocalBoxFuture<'a, Result<Option<(ProvingKey, VerifyingKey)>, String>> {
+        Box::pin(async move {
+            let keys = self.request_function_keys(program_id, function_name).await.map_err(|e| {
+                format!("The key provider failed to find the keys for {program_id}/{function_name}: {e:?}")
+            })?;
+            if keys.is_undefined() || keys.is_null() {
+                return Ok(None);
+            }
+            let keys = keys
+                .dyn_into::<Array>()
+                .map_err(|_| "The key provider must resolve to a [provingKey, verifyingKey] pair".to_string())?;
+            let proving_key = ProvingKey::from_bytes(&key_bytes(&keys.get(0))?)?;
+            let verifying_key = VerifyingKey::from_bytes(&key_bytes(&keys.get(1))?)?;
+            Ok(Some((proving_key, verifying_key)))
+        })
+    }
+}
+
+// Get the bytes of a key returned either as a Uint8Array or as an object with a toBytes method
+fn key_bytes(key: &JsValue) -> Result<Vec<u8>, String> {
+    if let Some(bytes) = key.dyn_ref::<Uint8Array>() {
+        return Ok(bytes.to_vec());
+    }
+    let to_bytes = Reflect::get(key, &"toBytes".into())
+        .ok()
+        .and_then(|to_bytes| to_bytes.dyn_into::<Function>().ok())
+        .ok_or_else(|| "The key provider must resolve to keys or their byte representation".to_string())?;
+    let bytes = to_bytes.call0(key).map_err(|e| format!("Failed to serialize the provided key: {e:?}"))?;
+    Ok(Uint8Array::new(&bytes).to_vec())
+}
+
+thread_local! {
+    static KEY_PROVIDER: RefCell<Option<Rc<dyn KeyProvider>>> = RefCell::new(None);
+}
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Set the key provider consulted for the proving and verifying keys of a function before they
+    /// are synthesized. Keys passed explicitly to a ProgramManager method take precedence.
+    ///
+    /// @param key_provider {KeyProvider | undefined} The key provider, or undefined to remove it
+    #[wasm_bindgen(js_name = setKeyProvider)]
+    pub fn set_key_provider(key_provider: Option<JsKeyProvider>) {
+        Self::set_native_key_provider(key_provider.map(|key_provider| Rc::new(key_provider) as Rc<dyn KeyProvider>));
+    }
+}
+
+impl ProgramManager {
+    /// Set the key provider consulted by the program manager
+    pub fn set_native_key_provider(key_provider: Option<Rc<dyn KeyProvider>>) {
+        KEY_PROVIDER.with(|provider| *provider.borrow_mut() = key_provider);
+    }
+
+    // Request the keys of a function from the key cache or the key provider if they were neither
+    // provided nor loaded in the process
+    pub(crate) async fn provide_function_keys(
+        process: &ProcessNative,
+        program_id: &ProgramIDNative,
+        function_name: &IdentifierNative,
+        proving_key: Option<ProvingKey>,
+        verifying_key: Option<VerifyingKey>,
+    ) -> Result<(Option<ProvingKey>, Option<VerifyingKey>), String> {
+        if proving_key.is_some() || verifying_key.is_some() || Self::contains_key(process, program_id, function_name) {
+            return Ok((proving_key, verifying_key));
+        }
+        if let Some((proving_key, verifying_key)) = Self::cached_function_keys(process, program_id, function_name) {
+            log(&format!("Using cached keys for {program_id}/{function_name}"));
+            return Ok((Some(proving_key), Some(verifying_key)));
+        }
+        // Clone the provider so that no borrow is held across the await
+        let key_provider = KEY_PROVIDER.with(|provider| provider.borrow().clone());
+        let Some(key_provider) = key_provider else {
+            return Ok((None, None));
+        };
+        log(&format!("Requesting keys for {program_id}/{function_name} from the key provider"));
+        let keys = key_provider.function_keys(&program_id.to_string(), &function_name.to_string()).await?;
+        if let (Some((proving_key, verifying_key)), true) = (&keys, Self::key_persistence_enabled()) {
+            let program = process.get_program(program_id).map_err(|e| e.to_string())?;
+            Self::insert_cached_keys(program, function_name, proving_key.clone(), verifying_key.clone())?;
+        }
+        Ok(keys.map_or((None, None), |(proving_key, verifying_key)| (Some(proving_key), Some(verifying_key))))
+    }
+
+    // Make sure the process has the verifying key of a function, asking the key provider for it
+    // and synthesizing it if it was not provided
+    pub(crate) async fn ensure_verifying_key(
+        process: &mut ProcessNative,
+        program_id: &ProgramIDNative,
+        function_name: &IdentifierNative,
+        verifying_key: Option<VerifyingKey>,
+    ) -> Result<(), String> {
+        let stack = process.get_stack(program_id).map_err(|e| e.to_string())?;
+        if stack.contains_verifying_key(function_name) {
+            return Ok(());
+        }
+        let verifying_key = match verifying_key {
+            Some(verifying_key) => Some(verifying_key),
+            None => Self::provide_function_keys(process, program_id, function_name, None, None).await?.1,
+        };
+        match verifying_key {
+            Some(verifying_key) => process
+                .insert_verifying_key(program_id, function_name, VerifyingKeyNative::from(verifying_key))
+                .map_err(|e| e.to_string()),
+            None => {
+                log(&format!("Synthesizing the verifying key for {program_id}/{function_name}"));
+                process
+                    .synthesize_key::<CurrentAleo, _>(program_id, function_name, &mut StdRng::from_entropy())
+                    .map_err(|e| e.to_string())
+            }
+        }
+    }
+}
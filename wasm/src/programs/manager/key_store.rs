@@ -0,0 +1,277 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{log, Program};
+
+use js_sys::{Array, Uint8Array};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{IdbDatabase, IdbFactory, IdbObjectStore, IdbRequest, IdbTransactionMode};
+
+/// Name of the IndexedDB database keys are persisted in unless another is given
+const DEFAULT_DATABASE: &str = "aleo-keys";
+/// Name of the object store holding the persisted keys
+const KEY_STORE_NAME: &str = "keys";
+
+thread_local! {
+    static KEY_STORE: RefCell<Option<IdbDatabase>> = RefCell::new(None);
+}
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Persist the keys added to the key cache in IndexedDB and load the keys persisted in earlier
+    /// sessions into the key cache
+    ///
+    /// Once enabled, keys synthesized by `synthesizeKeys`, cached by executions with `cache` set
+    /// and returned by the key provider are stored under their program id, function name and
+    /// program checksum, so the keys of credits.aleo and other programs are neither downloaded
+    /// nor synthesized again after a page refresh.
+    ///
+    /// @param database {string | undefined} (optional) The name of the IndexedDB database, "aleo-keys"
+    /// by default
+    /// @returns {number | Error} The number of functions whose keys were loaded from the database
+    #[wasm_bindgen(js_name = enableKeyPersistence)]
+    pub async fn enable_key_persistence(database: Option<String>) -> Result<usize, String> {
+        let database = open_database(database.as_deref().unwrap_or(DEFAULT_DATABASE)).await?;
+        let entries =
+            request_result(&object_store(&database, IdbTransactionMode::Readonly)?.get_all().map_err(js_error)?)
+                .await?;
+        let mut loaded = 0;
+        for entry in Array::from(&entries).iter() {
+            match restore_entry(&entry) {
+                Ok(()) => loaded += 1,
+                Err(error) => log(&format!("Skipping persisted keys: {error}")),
+            }
+        }
+        KEY_STORE.with(|store| *store.borrow_mut() = Some(database));
+        Ok(loaded)
+    }
+
+    /// Stop persisting keys in IndexedDB. The keys already persisted are kept for later sessions
+    #[wasm_bindgen(js_name = disableKeyPersistence)]
+    pub fn disable_key_persistence() {
+        if let Some(database) = KEY_STORE.with(|store| store.borrow_mut().take()) {
+            database.close();
+        }
+    }
+
+    /// Delete all keys persisted in IndexedDB
+    ///
+    /// @returns {Promise<void> | Error}
+    #[wasm_bindgen(js_name = clearPersistedKeys)]
+    pub async fn clear_persisted_keys() -> Result<(), String> {
+        let database = KEY_STORE
+            .with(|store| store.borrow().clone())
+            .ok_or_else(|| "Key persistence is not enabled".to_string())?;
+        let store = object_store(&database, IdbTransactionMode::Readwrite)?;
+        request_result(&store.clear().map_err(js_error)?).await.map(|_| ())
+    }
+}
+
+impl ProgramManager {
+    /// Check whether keys added to the key cache are persisted in IndexedDB
+    pub(crate) fn key_persistence_enabled() -> bool {
+        KEY_STORE.with(|store| store.borrow().is_some())
+    }
+
+    // Store the keys of a function in IndexedDB in the background when key persistence is enabled
+    pub(crate) fn persist_keys(
+        program: &ProgramNative,
+        function_name: &IdentifierNative,
+        proving_key: Vec<u8>,
+        verifying_key: Vec<u8>,
+    ) -> Result<(), String> {
+        let Some(database) = KEY_STORE.with(|store| store.borrow().clone()) else {
+            return Ok(());
+        };
+        let checksum = Program::from(program.clone()).checksum()?;
+        let id = format!("{}/{function_name}/{checksum}", program.id());
+
+        let entry = Object::new();
+        for (name, value) in [
+            ("programId", JsValue::from(program.id().to_string())),
+            ("functionName", JsValue::from(function_name.to_string())),
+            ("checksum", JsValue::from(checksum)),
+            ("program", JsValue::from(program.to_string())),
+            ("provingKey", Uint8Array::from(proving_key.as_slice()).into()),
+            ("verifyingKey", Uint8Array::from(verifying_key.as_slice()).into()),
+        ] {
+            Reflect::set(&entry, &name.into(), &value).map_err(|_| "Failed to set property")?;
+        }
+
+        spawn_local(async move {
+            let result = match object_store(&database, IdbTransactionMode::Readwrite) {
+                Ok(store) => match store.put_with_key(&entry, &JsValue::from_str(&id)) {
+                    Ok(request) => request_result(&request).await.map(|_| ()),
+                    Err(error) => Err(js_error(error)),
+                },
+                Err(error) => Err(error),
+            };
+            if let Err(error) = result {
+                log(&format!("Failed to persist the keys of {id}: {error}"));
+            }
+        });
+        Ok(())
+    }
+}
+
+// Open the key database, creating the key store the first time it is opened
+async fn open_database(name: &str) -> Result<IdbDatabase, String> {
+    let factory = Reflect::get(&js_sys::global(), &"indexedDB".into())
+        .ok()
+        .and_then(|factory| factory.dyn_into::<IdbFactory>().ok())
+        .ok_or_else(|| "IndexedDB is not available in this environment".to_string())?;
+    let request = factory.open_with_u32(name, 1).map_err(js_error)?;
+    let on_upgrade_needed = Closure::<dyn FnMut(JsValue)>::new({
+        let request = request.clone();
+        move |_| {
+            let database = request.result().ok().and_then(|database| database.dyn_into::<IdbDatabase>().ok());
+            if database.map_or(true, |database| database.create_object_store(KEY_STORE_NAME).is_err()) {
+                log("Failed to create the key store");
+            }
+        }
+    });
+    request.set_onupgradeneeded(Some(on_upgrade_needed.as_ref().unchecked_ref()));
+    let database = request_result(&request).await;
+    request.set_onupgradeneeded(None);
+    database?.dyn_into::<IdbDatabase>().map_err(|_| "Failed to open the key database".to_string())
+}
+
+// Get the key store within a new transaction
+fn object_store(database: &IdbDatabase, mode: IdbTransactionMode) -> Result<IdbObjectStore, String> {
+    database
+        .transaction_with_str_and_mode(KEY_STORE_NAME, mode)
+        .and_then(|transaction| transaction.object_store(KEY_STORE_NAME))
+        .map_err(js_error)
+}
+
+// Wait for an IndexedDB request to complete and get its result
+async fn request_result(request: &IdbRequest) -> Result<JsValue, String> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        request.set_onsuccess(Some(&resolve));
+        request.set_onerror(Some(&reject));
+    });
+    JsFuture::from(promise).await.map_err(js_error)?;
+    request.result().map_err(js_error)
+}
+
+// Load a persisted entry into the key cache, checking it was stored for the program it contains
+fn restore_entry(entry: &JsValue) -> Result<(), String> {
+    let field = |name: &str| Reflect::get(entry, &name.into()).map_err(|_| format!("The entry has no {name}"));
+    let text = |name: &str| field(name)?.as_string().ok_or_else(|| format!("The {name} of the entry is invalid"));
+
+    let program = ProgramNative::from_str(&text("program")?).map_err(|e| e.to_string())?;
+    let checksum = text("checksum")?;
+    if Program::from(program.clone()).checksum()? != checksum {
+        return Err(format!("The checksum of {} does not match the persisted checksum", program.id()));
+    }
+    let function_name = IdentifierNative::from_str(&text("functionName")?).map_err(|e| e.to_string())?;
+    let proving_key = Uint8Array::new(&field("provingKey")?).to_vec();
+    let verifying_key = Uint8Array::new(&field("verifyingKey")?).to_vec();
+    let size = proving_key.len() + verifying_key.len();
+
+    ProgramManager::restore_cached_keys(
+        program,
+        &function_name,
+        ProvingKey::from_bytes(&proving_key)?,
+        VerifyingKey::from_bytes(&verifying_key)?,
+        Some(size),
+    );
+    Ok(())
+}
+
+fn js_error(error: JsValue) -> String {
+    format!("IndexedDB request failed: {error:?}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const STORE_PROGRAM: &str = r#"program key_store_test.aleo;
+
+function double:
+    input r0 as u32.public;
+    add r0 r0 into r1;
+    output r1 as u32.private;
+"#;
+
+    // Create a persisted entry holding the keys of a function of a program
+    fn entry(program: &str, checksum: &str, function_name: &str) -> Object {
+        let mut keys = ProgramManager::synthesize_keys(STORE_PROGRAM, "double", None).unwrap();
+        let entry = Object::new();
+        for (name, value) in [
+            ("programId", JsValue::from("key_store_test.aleo")),
+            ("functionName", JsValue::from(function_name)),
+            ("checksum", JsValue::from(checksum)),
+            ("program", JsValue::from(program)),
+            ("provingKey", Uint8Array::from(keys.proving_key().unwrap().to_bytes().unwrap().as_slice()).into()),
+            ("verifyingKey", Uint8Array::from(keys.verifying_key().unwrap().to_bytes().unwrap().as_slice()).into()),
+        ] {
+            Reflect::set(&entry, &name.into(), &value).unwrap();
+        }
+        entry
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_persistence_disabled() {
+        assert!(!ProgramManager::key_persistence_enabled());
+        assert_eq!(ProgramManager::clear_persisted_keys().await.err().unwrap(), "Key persistence is not enabled");
+
+        // Ensure keys are not persisted while persistence is disabled.
+        let program = ProgramNative::from_str(STORE_PROGRAM).unwrap();
+        let function_name = IdentifierNative::from_str("double").unwrap();
+        assert!(ProgramManager::persist_keys(&program, &function_name, vec![1], vec![2]).is_ok());
+        ProgramManager::disable_key_persistence();
+    }
+
+    #[wasm_bindgen_test]
+    fn test_restore_entry() {
+        let checksum = Program::from_string(STORE_PROGRAM).unwrap().checksum().unwrap();
+        ProgramManager::clear_key_cache();
+
+        // Ensure entries stored for another version of the program are skipped.
+        let stale = entry(STORE_PROGRAM, "0field", "double");
+        ProgramManager::clear_key_cache();
+        let error = restore_entry(&stale).err().unwrap();
+        assert_eq!(error, "The checksum of key_store_test.aleo does not match the persisted checksum");
+        assert_eq!(ProgramManager::key_cache_size(), 0);
+
+        // Ensure invalid entries are skipped.
+        let invalid = entry(STORE_PROGRAM, &checksum, "not a function");
+        ProgramManager::clear_key_cache();
+        assert!(restore_entry(&invalid).is_err());
+        assert!(restore_entry(&Object::new()).is_err());
+        assert_eq!(ProgramManager::key_cache_size(), 0);
+
+        // Ensure a valid entry is loaded into the key cache with the size of the persisted keys.
+        let valid = entry(STORE_PROGRAM, &checksum, "double");
+        let size = Uint8Array::new(&Reflect::get(&valid, &"provingKey".into()).unwrap()).length() as usize
+            + Uint8Array::new(&Reflect::get(&valid, &"verifyingKey".into()).unwrap()).length() as usize;
+        ProgramManager::clear_key_cache();
+        restore_entry(&valid).unwrap();
+        assert_eq!(ProgramManager::key_cache_size(), size);
+        let cached = ProgramManager::cached_keys().unwrap();
+        assert_eq!(cached.length(), 1);
+        let function_name = Reflect::get(&cached.get(0), &"functionName".into()).unwrap();
+        assert_eq!(function_name.as_string().unwrap(), "double");
+        ProgramManager::clear_key_cache();
+    }
+}
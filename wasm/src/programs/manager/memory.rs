@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use js_sys::WebAssembly;
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Report the memory held by the caches of the module and the size of the wasm memory
+    ///
+    /// The universal SRS and the stacks of a program are created by snarkVM for each operation and
+    /// cannot be measured separately, so they are only reflected in the size of the wasm memory. The
+    /// wasm memory never shrinks, but memory released by the caches is reused by later operations.
+    ///
+    /// @returns {Object | Error} The sizes in bytes in the form \{ keys: number, keyCount: number,
+    /// programs: number, programCount: number, wasmMemory: number \}
+    #[wasm_bindgen(js_name = memoryReport)]
+    pub fn memory_report() -> Result<Object, String> {
+        let (programs, program_count) = PROGRAM_CACHE.with(|cache| {
+            let cache = cache.borrow();
+            (cache.values().map(String::len).sum::<usize>(), cache.len())
+        });
+        let wasm_memory = wasm_bindgen::memory().unchecked_into::<WebAssembly::Memory>().buffer();
+        let wasm_memory = wasm_memory.unchecked_into::<js_sys::ArrayBuffer>().byte_length();
+
+        let report = Object::new();
+        for (name, value) in [
+            ("keys", JsValue::from(Self::key_cache_size())),
+            ("keyCount", JsValue::from(Self::cached_keys()?.length())),
+            ("programs", JsValue::from(programs)),
+            ("programCount", JsValue::from(program_count)),
+            ("wasmMemory", JsValue::from(wasm_memory)),
+        ] {
+            Reflect::set(&report, &name.into(), &value).map_err(|_| "Failed to set property")?;
+        }
+        Ok(report)
+    }
+
+    /// Release all memory held by the caches of the module, i.e. the cached function keys and the
+    /// programs fetched from the network. Keys persisted in IndexedDB or a key cache directory are
+    /// kept and loaded again when needed
+    #[wasm_bindgen(js_name = releaseMemory)]
+    pub fn release_memory() {
+        Self::clear_key_cache();
+        PROGRAM_CACHE.with(|cache| {
+            let mut cache = cache.borrow_mut();
+            cache.clear();
+            cache.shrink_to_fit();
+        });
+    }
+}
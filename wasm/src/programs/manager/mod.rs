@@ -14,6 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod authorize;
+pub use authorize::*;
+
+pub mod broadcast;
+pub use broadcast::*;
+
 pub mod deploy;
 pub use deploy::*;
 
@@ -23,16 +29,67 @@ pub use execute::*;
 pub mod join;
 pub use join::*;
 
+pub mod key_cache;
+pub use key_cache::*;
+
+#[cfg(feature = "node")]
+pub mod key_directory;
+#[cfg(feature = "node")]
+pub use key_directory::*;
+
+pub mod key_downloader;
+pub use key_downloader::*;
+
+pub mod key_provider;
+pub use key_provider::*;
+
+pub mod key_store;
+pub use key_store::*;
+
+pub mod memory;
+pub use memory::*;
+
+pub mod options;
+pub use options::*;
+
+pub mod prover_client;
+pub use prover_client::*;
+
+pub mod query_provider;
+pub use query_provider::*;
+
+pub mod rebuild;
+pub use rebuild::*;
+
+pub mod record_provider;
+pub use record_provider::*;
+
+pub mod signer;
+pub use signer::*;
+
+pub mod snapshot;
+pub use snapshot::*;
+
 pub mod split;
 pub use split::*;
 
+pub mod staking;
+pub use staking::*;
+
+pub mod sweep;
+pub use sweep::*;
+
 pub mod transfer;
 pub use transfer::*;
 
+pub mod verify;
+pub use verify::*;
+
 pub mod cost;
 pub use cost::*;
 
 use crate::{
+    network::sleep,
     types::{
         cost_in_microcredits,
         deployment_cost,
@@ -44,32 +101,50 @@ use crate::{
         QueryNative,
         VerifyingKeyNative,
     },
+    AleoNetworkClient,
     KeyPair,
     PrivateKey,
+    Program,
     ProvingKey,
     RecordPlaintext,
     VerifyingKey,
 };
 
-use js_sys::{Object, Reflect};
-use std::str::FromStr;
-use wasm_bindgen::prelude::wasm_bindgen;
+use js_sys::{Function, Object, Promise, Reflect};
+use std::{
+    cell::{Cell, RefCell},
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+use wasm_bindgen::{prelude::wasm_bindgen, JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::AbortSignal;
 
 #[wasm_bindgen]
 #[derive(Clone)]
 pub struct ProgramManager;
 
+thread_local! {
+    static FETCH_IMPORTS: Cell<bool> = Cell::new(false);
+    static PROGRAM_CACHE: RefCell<HashMap<String, String>> = RefCell::new(HashMap::new());
+}
+
 #[wasm_bindgen]
 impl ProgramManager {
     /// Validate that an amount being paid from a record is greater than zero and that the record
     /// has enough credits to pay the amount
     pub(crate) fn validate_amount(credits: f64, amount: &RecordPlaintext, fee: bool) -> Result<u64, String> {
+        Self::validate_microcredits(Self::credits_to_microcredits(credits), amount, fee)
+    }
+
+    /// Validate that an amount of microcredits being paid from a record is greater than zero and
+    /// that the record has enough microcredits to pay the amount
+    pub(crate) fn validate_microcredits(microcredits: u64, amount: &RecordPlaintext, fee: bool) -> Result<u64, String> {
         let name = if fee { "Fee" } else { "Amount" };
 
-        if credits <= 0.0 {
+        if microcredits == 0 {
             return Err(format!("{name} must be greater than zero to deploy or execute a program"));
         }
-        let microcredits = (credits * 1_000_000.0f64) as u64;
         if amount.microcredits() < microcredits {
             return Err(format!("{name} record does not have enough credits to pay the specified fee"));
         }
@@ -77,6 +152,12 @@ impl ProgramManager {
         Ok(microcredits)
     }
 
+    /// Convert an amount of credits into microcredits, rounding to the nearest microcredit so that
+    /// amounts such as 0.3 credits are not truncated to 299999 microcredits
+    pub(crate) fn credits_to_microcredits(credits: f64) -> u64 {
+        (credits * 1_000_000.0f64).round() as u64
+    }
+
     /// Synthesize proving and verifying keys for a program
     ///
     /// @param program {string} The program source code of the program to synthesize keys for
@@ -150,6 +231,159 @@ impl ProgramManager {
             Ok(())
         }
     }
+
+    /// Yield to the event loop so that an abort requested while the previous stage was running is
+    /// observed, then return an error if the operation was aborted
+    pub(crate) async fn check_aborted(abort_signal: Option<&AbortSignal>) -> Result<(), String> {
+        if let Some(abort_signal) = abort_signal {
+            sleep(0).await?;
+            if abort_signal.aborted() {
+                return Err("The operation was aborted".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// Report the progress of a long running operation to the optional javascript callback as the
+    /// name of the stage which is starting and the percentage of the operation completed so far.
+    /// The stages are "keys", "synthesis", "inclusion", "proving", "fee", "verification" and "done"
+    pub(crate) fn report_progress(on_progress: Option<&Function>, stage: &str, percent: u8) {
+        if let Some(on_progress) = on_progress {
+            if on_progress.call2(&JsValue::NULL, &JsValue::from_str(stage), &JsValue::from(percent)).is_err() {
+                crate::log("The progress callback threw an error");
+            }
+        }
+    }
+
+    /// Check that the public balance of an address covers a fee before the fee is proven. Callers
+    /// providing the state through an offline query skip the check, as the node may be unreachable
+    pub(crate) async fn check_public_fee_balance(url: &str, address: &str, fee_microcredits: u64) -> Result<(), String> {
+        let balance = Self::public_balance(url, address).await?;
+        if balance < fee_microcredits {
+            return Err(format!(
+                "The public balance of {address} is {balance} microcredits, which does not cover the fee of {fee_microcredits} microcredits"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Fetch the source code of a deployed program from the node. Deployed programs cannot change,
+    /// so the source code is cached for the lifetime of the module
+    pub(crate) async fn fetch_program(url: &str, program_id: &str) -> Result<String, String> {
+        let client = AleoNetworkClient::new(url);
+        let url = format!("{}/testnet3/program/{program_id}", client.host());
+        if let Some(program) = PROGRAM_CACHE.with(|cache| cache.borrow().get(&url).cloned()) {
+            return Ok(program);
+        }
+        let program = client.get_program(program_id).await?;
+        PROGRAM_CACHE.with(|cache| cache.borrow_mut().insert(url, program.clone()));
+        Ok(program)
+    }
+
+    /// Complete the imports of a program with the missing imports fetched from the node, including
+    /// nested imports, in the form of a javascript object where the keys are the program ids and
+    /// the values are the source code. Imports provided by the caller take precedence
+    pub(crate) async fn fetch_imports(
+        url: &str,
+        program: &ProgramNative,
+        imports: Option<Object>,
+    ) -> Result<Object, String> {
+        let imports = match imports {
+            Some(imports) => Object::assign(&Object::new(), &imports),
+            None => Object::new(),
+        };
+        let mut visited = HashSet::new();
+        let mut pending = program.imports().keys().map(|program_id| program_id.to_string()).collect::<Vec<_>>();
+        while let Some(program_id) = pending.pop() {
+            if program_id == "credits.aleo" || !visited.insert(program_id.clone()) {
+                continue;
+            }
+            let key = JsValue::from_str(&program_id);
+            let import = match Reflect::get(&imports, &key).ok().and_then(|import| import.as_string()) {
+                Some(import) => import,
+                None => {
+                    let import = Self::fetch_program(url, &program_id).await?;
+                    Reflect::set(&imports, &key, &JsValue::from_str(&import))
+                        .map_err(|_| format!("Failed to add {program_id} to the imports"))?;
+                    import
+                }
+            };
+            let import_native = ProgramNative::from_str(&import).map_err(|e| e.to_string())?;
+            pending.extend(import_native.imports().keys().map(|program_id| program_id.to_string()));
+        }
+        Ok(imports)
+    }
+
+    /// Resolve the imports of a program, fetching the imports missing from the ones provided from
+    /// the node when fetching imports is enabled
+    pub(crate) async fn resolve_imports_from_network(
+        process: &mut ProcessNative,
+        program: &ProgramNative,
+        imports: Option<Object>,
+        url: &str,
+    ) -> Result<(), String> {
+        let imports = match FETCH_IMPORTS.with(Cell::get) {
+            true => Some(Self::fetch_imports(url, program, imports).await?),
+            false => imports,
+        };
+        Self::resolve_imports(process, program, imports)
+    }
+
+    /// Fetch the imports of a program missing from the imports provided from the node at the url,
+    /// including nested imports
+    ///
+    /// @param {string} url The url of the Aleo network node to fetch the imports from
+    /// @param {string} program The source code of the program
+    /// @param {Object | undefined} imports (optional) The imports already known in the form of a
+    /// javascript object where the keys are a string of the program name and the values are a
+    /// string representing the program source code
+    /// @returns {Object | Error} All imports of the program
+    #[wasm_bindgen(js_name = fetchImports)]
+    pub async fn fetch_program_imports(url: &str, program: &str, imports: Option<Object>) -> Result<Object, String> {
+        let program = ProgramNative::from_str(program).map_err(|e| e.to_string())?;
+        Self::fetch_imports(url, &program, imports).await
+    }
+
+    /// Enable or disable fetching the imports of a program which were not provided from the node
+    /// when building deployments and executions
+    ///
+    /// @param {boolean} enabled Whether missing imports are fetched from the network
+    #[wasm_bindgen(js_name = setFetchImports)]
+    pub fn set_fetch_imports(enabled: bool) {
+        FETCH_IMPORTS.with(|fetch_imports| fetch_imports.set(enabled));
+    }
+
+    /// Determine whether a program deployed on the network matches an audited copy by comparing
+    /// the checksum of the deployed program with the checksum of the audited copy
+    ///
+    /// @param {string} url The url of the Aleo network node to fetch the program from
+    /// @param {string} program_id The id of the deployed program
+    /// @param {string} checksum The checksum of the audited copy from Program.checksum
+    /// @returns {boolean | Error} True if the deployed program matches the audited copy
+    #[wasm_bindgen(js_name = verifyProgramChecksum)]
+    pub async fn verify_program_checksum(url: &str, program_id: &str, checksum: &str) -> Result<bool, String> {
+        let program = Program::from_string(&Self::fetch_program(url, program_id).await?)?;
+        if program.id() != program_id {
+            return Err(format!("The node returned {} when fetching {program_id}", program.id()));
+        }
+        program.matches_checksum(checksum)
+    }
+
+    /// Get the public balance of an address in microcredits from the node
+    pub(crate) async fn public_balance(url: &str, address: &str) -> Result<u64, String> {
+        let balance = AleoNetworkClient::new(url)
+            .get(&format!("program/credits.aleo/mapping/account/{address}"))
+            .await
+            .map_err(|e| format!("Failed to fetch the public balance: {e}"))?;
+        // The node responds with a string such as "100u64", or null if the address has no public balance
+        match balance.trim().trim_matches('"') {
+            "null" => Ok(0),
+            balance => balance
+                .trim_end_matches("u64")
+                .parse::<u64>()
+                .map_err(|_| format!("The node returned an invalid public balance: {balance}")),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -206,6 +440,13 @@ function add_and_double:
     output r3 as u32.private;
 "#;
 
+    #[wasm_bindgen_test]
+    fn test_credits_to_microcredits() {
+        assert_eq!(ProgramManager::credits_to_microcredits(0.3), 300_000);
+        assert_eq!(ProgramManager::credits_to_microcredits(1.000001), 1_000_001);
+        assert_eq!(ProgramManager::credits_to_microcredits(-1.0), 0);
+    }
+
     #[wasm_bindgen_test]
     fn test_import_resolution() {
         let imports = Object::new();
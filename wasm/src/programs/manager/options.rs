@@ -0,0 +1,542 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{OfflineQuery, PrivateKey, ProvingKey, RecordPlaintext, Transaction, VerifyingKey};
+
+use js_sys::{Array, Function, Object};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Named options for building a transfer transaction
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct TransferOptions {
+    pub(crate) private_key: PrivateKey,
+    pub(crate) amount_microcredits: u64,
+    pub(crate) recipient: String,
+    pub(crate) transfer_type: String,
+    pub(crate) url: String,
+    pub(crate) amount_record: Option<RecordPlaintext>,
+    pub(crate) priority_fee_microcredits: u64,
+    pub(crate) fee_record: Option<RecordPlaintext>,
+    pub(crate) transfer_proving_key: Option<ProvingKey>,
+    pub(crate) transfer_verifying_key: Option<VerifyingKey>,
+    pub(crate) fee_proving_key: Option<ProvingKey>,
+    pub(crate) fee_verifying_key: Option<VerifyingKey>,
+    pub(crate) find_records: bool,
+    pub(crate) record_provider: Option<JsRecordProvider>,
+    pub(crate) abort_signal: Option<AbortSignal>,
+    pub(crate) on_progress: Option<Function>,
+    pub(crate) offline_query: Option<OfflineQuery>,
+}
+
+#[wasm_bindgen]
+impl TransferOptions {
+    /// Create options for a transfer with no priority fee, paid publicly, using the default keys
+    ///
+    /// @param {PrivateKey} private_key The private key of the sender
+    /// @param {bigint} amount_microcredits The amount of microcredits to send
+    /// @param {string} recipient The recipient of the transfer
+    /// @param {string} transfer_type The type of the transfer (options: "private", "public", "public_as_signer", "private_to_public", "public_to_private")
+    /// @param {string} url The url of the Aleo network node to send the transaction to
+    /// @returns {TransferOptions}
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        private_key: &PrivateKey,
+        amount_microcredits: u64,
+        recipient: &str,
+        transfer_type: &str,
+        url: &str,
+    ) -> TransferOptions {
+        Self {
+            private_key: private_key.clone(),
+            amount_microcredits,
+            recipient: recipient.to_string(),
+            transfer_type: transfer_type.to_string(),
+            url: url.to_string(),
+            amount_record: None,
+            priority_fee_microcredits: 0,
+            fee_record: None,
+            transfer_proving_key: None,
+            transfer_verifying_key: None,
+            fee_proving_key: None,
+            fee_verifying_key: None,
+            find_records: false,
+            record_provider: None,
+            abort_signal: None,
+            on_progress: None,
+            offline_query: None,
+        }
+    }
+
+    /// Set the record to fund the amount of a private transfer from
+    ///
+    /// @param {RecordPlaintext} amount_record
+    #[wasm_bindgen(js_name = setAmountRecord)]
+    pub fn set_amount_record(&mut self, amount_record: &RecordPlaintext) {
+        self.amount_record = Some(amount_record.clone());
+    }
+
+    /// Set the priority fee to pay on top of the base fee
+    ///
+    /// @param {bigint} priority_fee_microcredits
+    #[wasm_bindgen(js_name = setPriorityFee)]
+    pub fn set_priority_fee(&mut self, priority_fee_microcredits: u64) {
+        self.priority_fee_microcredits = priority_fee_microcredits;
+    }
+
+    /// Set the record to pay the fee from, the fee is paid publicly if it is not set
+    ///
+    /// @param {RecordPlaintext} fee_record
+    #[wasm_bindgen(js_name = setFeeRecord)]
+    pub fn set_fee_record(&mut self, fee_record: &RecordPlaintext) {
+        self.fee_record = Some(fee_record.clone());
+    }
+
+    /// Set the proving and verifying keys to use for the transfer function
+    ///
+    /// @param {ProvingKey} proving_key
+    /// @param {VerifyingKey} verifying_key
+    #[wasm_bindgen(js_name = setTransferKeys)]
+    pub fn set_transfer_keys(&mut self, proving_key: &ProvingKey, verifying_key: &VerifyingKey) {
+        self.transfer_proving_key = Some(proving_key.clone());
+        self.transfer_verifying_key = Some(verifying_key.clone());
+    }
+
+    /// Set the proving and verifying keys to use for the fee execution
+    ///
+    /// @param {ProvingKey} proving_key
+    /// @param {VerifyingKey} verifying_key
+    #[wasm_bindgen(js_name = setFeeKeys)]
+    pub fn set_fee_keys(&mut self, proving_key: &ProvingKey, verifying_key: &VerifyingKey) {
+        self.fee_proving_key = Some(proving_key.clone());
+        self.fee_verifying_key = Some(verifying_key.clone());
+    }
+
+    /// Search the recent blocks for unspent records when the amount or fee records are not set
+    ///
+    /// @param {boolean} find_records
+    #[wasm_bindgen(js_name = setFindRecords)]
+    pub fn set_find_records(&mut self, find_records: bool) {
+        self.find_records = find_records;
+    }
+
+    /// Set the provider of the records to use when the amount or fee records are not set
+    ///
+    /// @param {RecordProvider} record_provider
+    #[wasm_bindgen(js_name = setRecordProvider)]
+    pub fn set_record_provider(&mut self, record_provider: JsRecordProvider) {
+        self.record_provider = Some(record_provider);
+    }
+
+    /// Set the signal to abort the operation with between the synthesis and proving stages
+    ///
+    /// @param {AbortSignal} abort_signal
+    #[wasm_bindgen(js_name = setAbortSignal)]
+    pub fn set_abort_signal(&mut self, abort_signal: AbortSignal) {
+        self.abort_signal = Some(abort_signal);
+    }
+
+    /// Set the callback invoked with the name and completion percentage of each stage
+    ///
+    /// @param {Function} on_progress
+    #[wasm_bindgen(js_name = setProgressCallback)]
+    pub fn set_progress_callback(&mut self, on_progress: Function) {
+        self.on_progress = Some(on_progress);
+    }
+
+    /// Set the state to build the transaction from instead of querying the node at the url
+    ///
+    /// @param {OfflineQuery} offline_query
+    #[wasm_bindgen(js_name = setOfflineQuery)]
+    pub fn set_offline_query(&mut self, offline_query: &OfflineQuery) {
+        self.offline_query = Some(offline_query.clone());
+    }
+}
+
+/// Named options for building an execution transaction
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct ExecuteOptions {
+    pub(crate) private_key: PrivateKey,
+    pub(crate) program: String,
+    pub(crate) function: String,
+    pub(crate) inputs: Array,
+    pub(crate) url: String,
+    pub(crate) priority_fee_microcredits: u64,
+    pub(crate) fee_record: Option<RecordPlaintext>,
+    pub(crate) imports: Option<Object>,
+    pub(crate) proving_key: Option<ProvingKey>,
+    pub(crate) verifying_key: Option<VerifyingKey>,
+    pub(crate) fee_proving_key: Option<ProvingKey>,
+    pub(crate) fee_verifying_key: Option<VerifyingKey>,
+    pub(crate) find_records: bool,
+    pub(crate) record_provider: Option<JsRecordProvider>,
+    pub(crate) abort_signal: Option<AbortSignal>,
+    pub(crate) on_progress: Option<Function>,
+    pub(crate) offline_query: Option<OfflineQuery>,
+}
+
+#[wasm_bindgen]
+impl ExecuteOptions {
+    /// Create options for an execution with no priority fee, paid publicly, using the default keys
+    ///
+    /// @param {PrivateKey} private_key The private key of the caller
+    /// @param {string} program The source code of the program being executed
+    /// @param {string} function The name of the function to execute
+    /// @param {Array} inputs The inputs to the function
+    /// @param {string} url The url of the Aleo network node to send the transaction to
+    /// @returns {ExecuteOptions}
+    #[wasm_bindgen(constructor)]
+    pub fn new(private_key: &PrivateKey, program: &str, function: &str, inputs: Array, url: &str) -> ExecuteOptions {
+        Self {
+            private_key: private_key.clone(),
+            program: program.to_string(),
+            function: function.to_string(),
+            inputs,
+            url: url.to_string(),
+            priority_fee_microcredits: 0,
+            fee_record: None,
+            imports: None,
+            proving_key: None,
+            verifying_key: None,
+            fee_proving_key: None,
+            fee_verifying_key: None,
+            find_records: false,
+            record_provider: None,
+            abort_signal: None,
+            on_progress: None,
+            offline_query: None,
+        }
+    }
+
+    /// Set the priority fee to pay on top of the base fee
+    ///
+    /// @param {bigint} priority_fee_microcredits
+    #[wasm_bindgen(js_name = setPriorityFee)]
+    pub fn set_priority_fee(&mut self, priority_fee_microcredits: u64) {
+        self.priority_fee_microcredits = priority_fee_microcredits;
+    }
+
+    /// Set the record to pay the fee from, the fee is paid publicly if it is not set
+    ///
+    /// @param {RecordPlaintext} fee_record
+    #[wasm_bindgen(js_name = setFeeRecord)]
+    pub fn set_fee_record(&mut self, fee_record: &RecordPlaintext) {
+        self.fee_record = Some(fee_record.clone());
+    }
+
+    /// Set the imports of the program in the form of a javascript object where the keys are the
+    /// program names and the values are the program source code
+    ///
+    /// @param {Object} imports
+    #[wasm_bindgen(js_name = setImports)]
+    pub fn set_imports(&mut self, imports: Object) {
+        self.imports = Some(imports);
+    }
+
+    /// Set the proving and verifying keys to use for the function execution
+    ///
+    /// @param {ProvingKey} proving_key
+    /// @param {VerifyingKey} verifying_key
+    #[wasm_bindgen(js_name = setKeys)]
+    pub fn set_keys(&mut self, proving_key: &ProvingKey, verifying_key: &VerifyingKey) {
+        self.proving_key = Some(proving_key.clone());
+        self.verifying_key = Some(verifying_key.clone());
+    }
+
+    /// Set the proving and verifying keys to use for the fee execution
+    ///
+    /// @param {ProvingKey} proving_key
+    /// @param {VerifyingKey} verifying_key
+    #[wasm_bindgen(js_name = setFeeKeys)]
+    pub fn set_fee_keys(&mut self, proving_key: &ProvingKey, verifying_key: &VerifyingKey) {
+        self.fee_proving_key = Some(proving_key.clone());
+        self.fee_verifying_key = Some(verifying_key.clone());
+    }
+
+    /// Search the recent blocks for an unspent record to pay the fee from when the fee record is
+    /// not set
+    ///
+    /// @param {boolean} find_records
+    #[wasm_bindgen(js_name = setFindRecords)]
+    pub fn set_find_records(&mut self, find_records: bool) {
+        self.find_records = find_records;
+    }
+
+    /// Set the provider of the record to pay the fee from when the fee record is not set
+    ///
+    /// @param {RecordProvider} record_provider
+    #[wasm_bindgen(js_name = setRecordProvider)]
+    pub fn set_record_provider(&mut self, record_provider: JsRecordProvider) {
+        self.record_provider = Some(record_provider);
+    }
+
+    /// Set the signal to abort the operation with between the synthesis and proving stages
+    ///
+    /// @param {AbortSignal} abort_signal
+    #[wasm_bindgen(js_name = setAbortSignal)]
+    pub fn set_abort_signal(&mut self, abort_signal: AbortSignal) {
+        self.abort_signal = Some(abort_signal);
+    }
+
+    /// Set the callback invoked with the name and completion percentage of each stage
+    ///
+    /// @param {Function} on_progress
+    #[wasm_bindgen(js_name = setProgressCallback)]
+    pub fn set_progress_callback(&mut self, on_progress: Function) {
+        self.on_progress = Some(on_progress);
+    }
+
+    /// Set the state to build the transaction from instead of querying the node at the url
+    ///
+    /// @param {OfflineQuery} offline_query
+    #[wasm_bindgen(js_name = setOfflineQuery)]
+    pub fn set_offline_query(&mut self, offline_query: &OfflineQuery) {
+        self.offline_query = Some(offline_query.clone());
+    }
+}
+
+/// Named options for building a deployment transaction
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct DeployOptions {
+    pub(crate) private_key: PrivateKey,
+    pub(crate) program: String,
+    pub(crate) url: String,
+    pub(crate) priority_fee_microcredits: u64,
+    pub(crate) fee_record: Option<RecordPlaintext>,
+    pub(crate) imports: Option<Object>,
+    pub(crate) fee_proving_key: Option<ProvingKey>,
+    pub(crate) fee_verifying_key: Option<VerifyingKey>,
+    pub(crate) record_provider: Option<JsRecordProvider>,
+    pub(crate) abort_signal: Option<AbortSignal>,
+    pub(crate) on_progress: Option<Function>,
+}
+
+#[wasm_bindgen]
+impl DeployOptions {
+    /// Create options for a deployment with no priority fee, paid publicly, using the default keys
+    ///
+    /// @param {PrivateKey} private_key The private key of the deployer
+    /// @param {string} program The source code of the program being deployed
+    /// @param {string} url The url of the Aleo network node to send the transaction to
+    /// @returns {DeployOptions}
+    #[wasm_bindgen(constructor)]
+    pub fn new(private_key: &PrivateKey, program: &str, url: &str) -> DeployOptions {
+        Self {
+            private_key: private_key.clone(),
+            program: program.to_string(),
+            url: url.to_string(),
+            priority_fee_microcredits: 0,
+            fee_record: None,
+            imports: None,
+            fee_proving_key: None,
+            fee_verifying_key: None,
+            record_provider: None,
+            abort_signal: None,
+            on_progress: None,
+        }
+    }
+
+    /// Set the priority fee to pay on top of the base fee
+    ///
+    /// @param {bigint} priority_fee_microcredits
+    #[wasm_bindgen(js_name = setPriorityFee)]
+    pub fn set_priority_fee(&mut self, priority_fee_microcredits: u64) {
+        self.priority_fee_microcredits = priority_fee_microcredits;
+    }
+
+    /// Set the record to pay the fee from, the fee is paid publicly if it is not set
+    ///
+    /// @param {RecordPlaintext} fee_record
+    #[wasm_bindgen(js_name = setFeeRecord)]
+    pub fn set_fee_record(&mut self, fee_record: &RecordPlaintext) {
+        self.fee_record = Some(fee_record.clone());
+    }
+
+    /// Set the imports of the program in the form of a javascript object where the keys are the
+    /// program names and the values are the program source code
+    ///
+    /// @param {Object} imports
+    #[wasm_bindgen(js_name = setImports)]
+    pub fn set_imports(&mut self, imports: Object) {
+        self.imports = Some(imports);
+    }
+
+    /// Set the proving and verifying keys to use for the fee execution
+    ///
+    /// @param {ProvingKey} proving_key
+    /// @param {VerifyingKey} verifying_key
+    #[wasm_bindgen(js_name = setFeeKeys)]
+    pub fn set_fee_keys(&mut self, proving_key: &ProvingKey, verifying_key: &VerifyingKey) {
+        self.fee_proving_key = Some(proving_key.clone());
+        self.fee_verifying_key = Some(verifying_key.clone());
+    }
+
+    /// Set the provider of the record to pay the fee from when the fee record is not set
+    ///
+    /// @param {RecordProvider} record_provider
+    #[wasm_bindgen(js_name = setRecordProvider)]
+    pub fn set_record_provider(&mut self, record_provider: JsRecordProvider) {
+        self.record_provider = Some(record_provider);
+    }
+
+    /// Set the signal to abort the operation with between the synthesis and proving stages
+    ///
+    /// @param {AbortSignal} abort_signal
+    #[wasm_bindgen(js_name = setAbortSignal)]
+    pub fn set_abort_signal(&mut self, abort_signal: AbortSignal) {
+        self.abort_signal = Some(abort_signal);
+    }
+
+    /// Set the callback invoked with the name and completion percentage of each stage
+    ///
+    /// @param {Function} on_progress
+    #[wasm_bindgen(js_name = setProgressCallback)]
+    pub fn set_progress_callback(&mut self, on_progress: Function) {
+        self.on_progress = Some(on_progress);
+    }
+}
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Send credits from one Aleo account to another using named options
+    ///
+    /// @param {TransferOptions} options The options of the transfer
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildTransferTransactionWithOptions)]
+    pub async fn transfer_with_options(options: &TransferOptions) -> Result<Transaction, String> {
+        Self::build_transfer(options.clone()).await
+    }
+
+    /// Execute an arbitrary function of a program on chain using named options
+    ///
+    /// @param {ExecuteOptions} options The options of the execution
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildExecutionTransactionWithOptions)]
+    pub async fn execute_with_options(options: &ExecuteOptions) -> Result<Transaction, String> {
+        Self::build_execution(options.clone()).await
+    }
+
+    /// Deploy an Aleo program using named options
+    ///
+    /// @param {DeployOptions} options The options of the deployment
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildDeploymentTransactionWithOptions)]
+    pub async fn deploy_with_options(options: &DeployOptions) -> Result<Transaction, String> {
+        Self::build_deployment(options.clone()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const RECORD: &str = "{  owner: aleo184vuwr5u7u0ha5f5k44067dd2uaqewxx6pe5ltha5pv99wvhfqxqv339h4.private,  microcredits: 2000000u64.private,  _nonce: 4106205762862305308495708971985748592380064201230396559307556388725936304984group.public}";
+    const RECIPIENT: &str = "aleo184vuwr5u7u0ha5f5k44067dd2uaqewxx6pe5ltha5pv99wvhfqxqv339h4";
+    const URL: &str = "https://api.explorer.aleo.org/v1";
+
+    #[wasm_bindgen_test]
+    fn test_transfer_options() {
+        let private_key = PrivateKey::new();
+        let mut options = TransferOptions::new(&private_key, 500_000, RECIPIENT, "private", URL);
+        assert_eq!(options.private_key, private_key);
+        assert_eq!(options.amount_microcredits, 500_000);
+        assert_eq!(options.recipient, RECIPIENT);
+        assert_eq!(options.transfer_type, "private");
+        assert_eq!(options.url, URL);
+
+        // Ensure the transfer is paid publicly with no priority fee by default.
+        assert_eq!(options.priority_fee_microcredits, 0);
+        assert!(options.amount_record.is_none() && options.fee_record.is_none());
+        assert!(options.transfer_proving_key.is_none() && options.transfer_verifying_key.is_none());
+        assert!(options.fee_proving_key.is_none() && options.fee_verifying_key.is_none());
+        assert!(!options.find_records && options.record_provider.is_none());
+        assert!(options.abort_signal.is_none() && options.on_progress.is_none() && options.offline_query.is_none());
+
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        options.set_amount_record(&record);
+        options.set_priority_fee(100);
+        options.set_fee_record(&record);
+        options.set_find_records(true);
+        options.set_progress_callback(Function::new_no_args(""));
+        assert_eq!(options.amount_record.as_ref().unwrap().to_string(), record.to_string());
+        assert_eq!(options.priority_fee_microcredits, 100);
+        assert_eq!(options.fee_record.as_ref().unwrap().to_string(), record.to_string());
+        assert!(options.find_records);
+        assert!(options.on_progress.is_some());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_execute_options() {
+        let private_key = PrivateKey::new();
+        let inputs = Array::of2(&"1u32".into(), &"2u32".into());
+        let mut options = ExecuteOptions::new(&private_key, "program hello.aleo;", "main", inputs, URL);
+        assert_eq!(options.private_key, private_key);
+        assert_eq!(options.program, "program hello.aleo;");
+        assert_eq!(options.function, "main");
+        assert_eq!(options.inputs.length(), 2);
+        assert_eq!(options.url, URL);
+
+        // Ensure the execution is paid publicly with no priority fee by default.
+        assert_eq!(options.priority_fee_microcredits, 0);
+        assert!(options.fee_record.is_none() && options.imports.is_none());
+        assert!(options.proving_key.is_none() && options.verifying_key.is_none());
+        assert!(options.fee_proving_key.is_none() && options.fee_verifying_key.is_none());
+        assert!(!options.find_records && options.record_provider.is_none());
+        assert!(options.abort_signal.is_none() && options.on_progress.is_none() && options.offline_query.is_none());
+
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        options.set_priority_fee(100);
+        options.set_fee_record(&record);
+        options.set_imports(Object::new());
+        options.set_find_records(true);
+        options.set_record_provider(Object::new().unchecked_into());
+        assert_eq!(options.priority_fee_microcredits, 100);
+        assert_eq!(options.fee_record.as_ref().unwrap().to_string(), record.to_string());
+        assert!(options.imports.is_some());
+        assert!(options.find_records && options.record_provider.is_some());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_deploy_options() {
+        let private_key = PrivateKey::new();
+        let mut options = DeployOptions::new(&private_key, "program hello.aleo;", URL);
+        assert_eq!(options.private_key, private_key);
+        assert_eq!(options.program, "program hello.aleo;");
+        assert_eq!(options.url, URL);
+
+        // Ensure the deployment is paid publicly with no priority fee by default.
+        assert_eq!(options.priority_fee_microcredits, 0);
+        assert!(options.fee_record.is_none() && options.imports.is_none());
+        assert!(options.fee_proving_key.is_none() && options.fee_verifying_key.is_none());
+        assert!(options.record_provider.is_none());
+        assert!(options.abort_signal.is_none() && options.on_progress.is_none());
+
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        options.set_priority_fee(100);
+        options.set_fee_record(&record);
+        options.set_imports(Object::new());
+        options.set_progress_callback(Function::new_no_args(""));
+        assert_eq!(options.priority_fee_microcredits, 100);
+        assert_eq!(options.fee_record.as_ref().unwrap().to_string(), record.to_string());
+        assert!(options.imports.is_some() && options.on_progress.is_some());
+    }
+}
@@ -0,0 +1,310 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{
+    log,
+    types::{
+        ExecutionNative,
+        FeeNative,
+        IdentifierNative,
+        ProcessNative,
+        ProgramIDNative,
+        ProgramNative,
+        TransactionNative,
+    },
+    Authorization,
+    Execution,
+    PrivateKey,
+    RecordPlaintext,
+    Transaction,
+};
+
+use js_sys::Object;
+use serde_json::{json, Value};
+use std::str::FromStr;
+
+/// Client for a remote proving service which proves authorizations created locally
+///
+/// The service is expected to accept `POST {url}/prove` with the JSON body
+/// `{ "authorization": string }` and respond with `{ "id": string }`, and to report the status of
+/// the job at `GET {url}/prove/{id}` as `{ "status": "pending" | "completed" | "failed",
+/// "result"?: string, "error"?: string }` where the result is the proven execution or fee.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct ProverClient {
+    url: String,
+    api_key: Option<String>,
+    poll_interval_ms: u32,
+    max_polls: u32,
+}
+
+#[wasm_bindgen]
+impl ProverClient {
+    /// Create a client for a remote proving service
+    ///
+    /// @param {string} url The url of the proving service
+    /// @param {string | undefined} api_key (optional) Key sent as a bearer token with every request
+    /// @returns {ProverClient}
+    #[wasm_bindgen(constructor)]
+    pub fn new(url: &str, api_key: Option<String>) -> ProverClient {
+        Self { url: url.trim_end_matches('/').to_string(), api_key, poll_interval_ms: 1000, max_polls: 600 }
+    }
+
+    /// Set the interval between requests for the status of a proving job
+    ///
+    /// @param {number} poll_interval_ms Interval in milliseconds, 1000 by default
+    #[wasm_bindgen(js_name = setPollInterval)]
+    pub fn set_poll_interval(&mut self, poll_interval_ms: u32) {
+        self.poll_interval_ms = poll_interval_ms;
+    }
+
+    /// Set the number of status requests after which a proving job is considered failed
+    ///
+    /// @param {number} max_polls Maximum number of status requests, 600 by default
+    #[wasm_bindgen(js_name = setMaxPolls)]
+    pub fn set_max_polls(&mut self, max_polls: u32) {
+        self.max_polls = max_polls;
+    }
+
+    /// Prove an execution authorization with the proving service and verify the returned execution
+    ///
+    /// @param {Authorization} authorization The authorization of the execution
+    /// @param {string} program The source code of the program being executed
+    /// @param {Object | undefined} imports (optional) The imports of the program in the form of a
+    /// javascript object where the keys are the program names and the values are the source code
+    /// @param {VerifyingKey | undefined} verifying_key (optional) The verifying key of the function,
+    /// the key provider is asked for missing keys and they are synthesized as a last resort
+    /// @returns {Execution | Error}
+    #[wasm_bindgen(js_name = proveExecution)]
+    pub async fn prove_execution(
+        &self,
+        authorization: &Authorization,
+        program: &str,
+        imports: Option<Object>,
+        verifying_key: Option<VerifyingKey>,
+    ) -> Result<Execution, String> {
+        let mut process = Self::load_process(program, imports)?;
+        Ok(Execution::from(self.prove_and_verify_execution(&mut process, authorization, verifying_key).await?))
+    }
+
+    /// Prove an execution authorization and the fee for it with the proving service, verify both
+    /// proofs and assemble the transaction. The fee is authorized locally with the private key
+    ///
+    /// @param {PrivateKey} private_key The private key paying the fee
+    /// @param {Authorization} authorization The authorization of the execution
+    /// @param {string} program The source code of the program being executed
+    /// @param {bigint} priority_fee_microcredits The priority fee in microcredits
+    /// @param {RecordPlaintext | undefined} fee_record The record to spend the fee from, the fee is
+    /// paid publicly if omitted
+    /// @param {Object | undefined} imports (optional) The imports of the program
+    /// @param {VerifyingKey | undefined} verifying_key (optional) The verifying key of the function
+    /// @param {VerifyingKey | undefined} fee_verifying_key (optional) The verifying key of the fee
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildTransaction)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn build_transaction(
+        &self,
+        private_key: &PrivateKey,
+        authorization: &Authorization,
+        program: &str,
+        priority_fee_microcredits: u64,
+        fee_record: Option<RecordPlaintext>,
+        imports: Option<Object>,
+        verifying_key: Option<VerifyingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        let mut process = Self::load_process(program, imports)?;
+        let execution = self.prove_and_verify_execution(&mut process, authorization, verifying_key).await?;
+        let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
+
+        // Get the storage cost in bytes for the program execution
+        let storage_cost = execution.size_in_bytes().map_err(|e| e.to_string())?;
+
+        // Compute the finalize cost in microcredits.
+        let finalize_cost = execution_finalize_cost(&process, &execution)?;
+        let minimum_fee_cost = finalize_cost + storage_cost;
+
+        log(&format!("Authorizing a fee of {minimum_fee_cost} microcredits"));
+        let fee_function = if fee_record.is_some() { "fee_private" } else { "fee_public" };
+        let fee_authorization = ProgramManager::authorize_fee(
+            private_key,
+            minimum_fee_cost,
+            priority_fee_microcredits,
+            &execution_id.to_string(),
+            fee_record,
+        )?;
+
+        log("Requesting the fee proof from the proving service");
+        let fee = self.prove(&fee_authorization).await?;
+        let fee = FeeNative::from_str(&fee).map_err(|e| format!("The proving service returned an invalid fee: {e}"))?;
+        let credits = ProgramIDNative::from_str("credits.aleo").unwrap();
+        let fee_function = IdentifierNative::from_str(fee_function).unwrap();
+        Self::ensure_verifying_key(&mut process, &credits, &fee_function, fee_verifying_key).await?;
+        process
+            .verify_fee(&fee, execution_id)
+            .map_err(|e| format!("The fee returned by the proving service is invalid: {e}"))?;
+
+        log("Creating execution transaction");
+        let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
+        Ok(Transaction::from(transaction))
+    }
+}
+
+impl ProverClient {
+    // Load the program being executed and its imports into a new process
+    fn load_process(program: &str, imports: Option<Object>) -> Result<ProcessNative, String> {
+        let mut process = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let program = ProgramNative::from_str(program).map_err(|err| err.to_string())?;
+        ProgramManager::resolve_imports(&mut process, &program, imports)?;
+        if !process.contains_program(program.id()) {
+            process.add_program(&program).map_err(|err| err.to_string())?;
+        }
+        Ok(process)
+    }
+
+    // Prove an execution authorization remotely and verify the execution against the local keys
+    async fn prove_and_verify_execution(
+        &self,
+        process: &mut ProcessNative,
+        authorization: &Authorization,
+        verifying_key: Option<VerifyingKey>,
+    ) -> Result<ExecutionNative, String> {
+        let request = authorization.peek_next().map_err(|e| e.to_string())?;
+        let (program_id, function_name) = (*request.program_id(), *request.function_name());
+
+        log("Requesting the execution proof from the proving service");
+        let execution = self.prove(authorization).await?;
+        let execution = ExecutionNative::from_str(&execution)
+            .map_err(|e| format!("The proving service returned an invalid execution: {e}"))?;
+        Self::ensure_execution_matches(authorization, &execution)?;
+
+        log("Verifying the execution returned by the proving service");
+        let mut verifying_key = verifying_key;
+        for transition in execution.transitions() {
+            let key = match (transition.program_id(), transition.function_name()) {
+                (id, function) if id == &program_id && function == &function_name => verifying_key.take(),
+                _ => None,
+            };
+            ProgramManager::ensure_verifying_key(process, transition.program_id(), transition.function_name(), key)
+                .await?;
+        }
+        process
+            .verify_execution(&execution)
+            .map_err(|e| format!("The execution returned by the proving service is invalid: {e}"))?;
+        Ok(execution)
+    }
+
+    // Check that an execution proves exactly the transitions of the submitted authorization, so the
+    // proving service cannot substitute another valid execution such as a replay of an older one
+    fn ensure_execution_matches(authorization: &Authorization, execution: &ExecutionNative) -> Result<(), String> {
+        let expected = authorization.transitions().into_keys().collect::<Vec<_>>();
+        let returned = execution.transitions().map(|transition| *transition.id()).collect::<Vec<_>>();
+        if expected != returned {
+            return Err("The execution returned by the proving service does not match the authorization".to_string());
+        }
+        Ok(())
+    }
+
+    // Submit an authorization to the proving service and wait for the proof
+    async fn prove(&self, authorization: &Authorization) -> Result<String, String> {
+        let client = reqwest::Client::new();
+        let body = json!({ "authorization": authorization.to_string() }).to_string();
+        let request = client.post(format!("{}/prove", self.url)).header("Content-Type", "application/json").body(body);
+        let job = self.send(request).await?;
+        let id = job
+            .get("id")
+            .and_then(Value::as_str)
+            .ok_or_else(|| "The proving service did not return a job id".to_string())?
+            .to_string();
+
+        for _ in 0..self.max_polls {
+            let status = self.send(client.get(format!("{}/prove/{id}", self.url))).await?;
+            match status.get("status").and_then(Value::as_str) {
+                Some("completed") => {
+                    return status
+                        .get("result")
+                        .and_then(Value::as_str)
+                        .map(ToString::to_string)
+                        .ok_or_else(|| "The proving service did not return a result".to_string());
+                }
+                Some("failed") => {
+                    let error = status.get("error").and_then(Value::as_str).unwrap_or("unknown error");
+                    return Err(format!("The proving service failed to prove job {id}: {error}"));
+                }
+                _ => sleep(self.poll_interval_ms).await?,
+            }
+        }
+        Err(format!("The proving service did not complete job {id} after {} status requests", self.max_polls))
+    }
+
+    // Send a request to the proving service and parse the JSON response
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<Value, String> {
+        let request = match &self.api_key {
+            Some(api_key) => request.bearer_auth(api_key),
+            None => request,
+        };
+        let response = request.send().await.map_err(|e| format!("Failed to reach the proving service: {e}"))?;
+        if !response.status().is_success() {
+            return Err(format!("The proving service responded with {}", response.status()));
+        }
+        let response =
+            response.text().await.map_err(|e| format!("Failed to read the proving service response: {e}"))?;
+        serde_json::from_str(&response).map_err(|e| format!("The proving service returned invalid JSON: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::StateRootNative;
+
+    use js_sys::Array;
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::*;
+
+    const HELLO_PROGRAM: &str = r#"program hello_prover.aleo;
+
+function hello:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+"#;
+
+    fn authorize(a: &str, b: &str) -> Authorization {
+        let private_key =
+            PrivateKey::from_string("APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6").unwrap();
+        let inputs = Array::of2(&JsValue::from_str(a), &JsValue::from_str(b));
+        ProgramManager::authorize(&private_key, HELLO_PROGRAM, "hello", inputs, None).unwrap()
+    }
+
+    fn execution_of(authorization: &Authorization) -> ExecutionNative {
+        let transitions = authorization.transitions().into_values();
+        ExecutionNative::from(transitions, StateRootNative::default(), None).unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_execution_must_match_authorization() {
+        let authorization = authorize("5u32", "5u32");
+        let other = authorize("5u32", "6u32");
+
+        assert!(ProverClient::ensure_execution_matches(&authorization, &execution_of(&authorization)).is_ok());
+        let error = ProverClient::ensure_execution_matches(&authorization, &execution_of(&other)).err().unwrap();
+        assert_eq!(error, "The execution returned by the proving service does not match the authorization");
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{
+    types::{CurrentNetwork, FieldNative, QueryTrait, StatePathNative, StateRootNative},
+    OfflineQuery,
+};
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use futures::future::LocalBoxFuture;
+use std::{cell::RefCell, rc::Rc};
+
+#[wasm_bindgen(typescript_custom_section)]
+const QUERY_PROVIDER_INTERFACE: &str = r#"
+/**
+ * Source of the ledger state used to prepare the inclusion proofs of executions and fees, e.g. a
+ * proxy, a cache or a local light client. Both methods resolve to the string representation of
+ * the global state root and of the state path of a record commitment respectively.
+ */
+export interface QueryProvider {
+    stateRoot(): Promise<string>;
+    statePath(commitment: string): Promise<string>;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Query provider implemented in javascript
+    #[wasm_bindgen(typescript_type = "QueryProvider")]
+    pub type JsQueryProvider;
+
+    #[wasm_bindgen(method, catch, js_name = stateRoot)]
+    async fn request_state_root(this: &JsQueryProvider) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = statePath)]
+    async fn request_state_path(this: &JsQueryProvider, commitment: &str) -> Result<JsValue, JsValue>;
+}
+
+/// Source of the ledger state used to prepare inclusion proofs
+pub trait QueryProvider {
+    /// Get the current global state root
+    fn state_root(&self) -> LocalBoxFuture<'_, Result<StateRootNative, String>>;
+
+    /// Get the state path of a record commitment
+    fn state_path<'a>(&'a self, commitment: &'a FieldNative) -> LocalBoxFuture<'a, Result<StatePathNative, String>>;
+}
+
+impl QueryProvider for JsQueryProvider {
+    fn state_root(&self) -> LocalBoxFuture<'_, Result<StateRootNative, String>> {
+        Box::pin(async move {
+            let state_root = self
+                .request_state_root()
+                .await
+                .map_err(|e| format!("The query provider failed to find the state root: {e:?}"))?
+                .as_string()
+                .ok_or_else(|| "The query provider must resolve to the string representation of a state root".to_string())?;
+            StateRootNative::from_str(&state_root).map_err(|e| format!("Invalid state root: {e}"))
+        })
+    }
+
+    fn state_path<'a>(&'a self, commitment: &'a FieldNative) -> LocalBoxFuture<'a, Result<StatePathNative, String>> {
+        Box::pin(async move {
+            let state_path = self
+                .request_state_path(&commitment.to_string())
+                .await
+                .map_err(|e| format!("The query provider failed to find the state path for {commitment}: {e:?}"))?
+                .as_string()
+                .ok_or_else(|| "The query provider must resolve to the string representation of a state path".to_string())?;
+            StatePathNative::from_str(&state_path).map_err(|e| format!("Invalid state path: {e}"))
+        })
+    }
+}
+
+thread_local! {
+    static QUERY_PROVIDER: RefCell<Option<Rc<dyn QueryProvider>>> = RefCell::new(None);
+}
+
+/// Query used by the program manager to prepare inclusion proofs
+pub(crate) enum ManagerQuery {
+    Rest(QueryNative),
+    Provider(Rc<dyn QueryProvider>),
+    Offline(OfflineQuery),
+}
+
+#[async_trait(?Send)]
+impl QueryTrait<CurrentNetwork> for ManagerQuery {
+    fn current_state_root(&self) -> anyhow::Result<StateRootNative> {
+        match self {
+            Self::Rest(query) => query.current_state_root(),
+            Self::Provider(_) => Err(anyhow!("The query provider only supports asynchronous queries")),
+            Self::Offline(query) => query.current_state_root(),
+        }
+    }
+
+    async fn current_state_root_async(&self) -> anyhow::Result<StateRootNative> {
+        match self {
+            Self::Rest(query) => query.current_state_root_async().await,
+            Self::Provider(provider) => provider.state_root().await.map_err(|e| anyhow!(e)),
+            Self::Offline(query) => query.current_state_root_async().await,
+        }
+    }
+
+    fn get_state_path_for_commitment(&self, commitment: &FieldNative) -> anyhow::Result<StatePathNative> {
+        match self {
+            Self::Rest(query) => query.get_state_path_for_commitment(commitment),
+            Self::Provider(_) => Err(anyhow!("The query provider only supports asynchronous queries")),
+            Self::Offline(query) => query.get_state_path_for_commitment(commitment),
+        }
+    }
+
+    async fn get_state_path_for_commitment_async(&self, commitment: &FieldNative) -> anyhow::Result<StatePathNative> {
+        match self {
+            Self::Rest(query) => query.get_state_path_for_commitment_async(commitment).await,
+            Self::Provider(provider) => provider.state_path(commitment).await.map_err(|e| anyhow!(e)),
+            Self::Offline(query) => query.get_state_path_for_commitment_async(commitment).await,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Set the query provider used instead of the node at the url of a ProgramManager method to
+    /// prepare the inclusion proofs of executions and fees
+    ///
+    /// @param query_provider {QueryProvider | undefined} The query provider, or undefined to remove it
+    #[wasm_bindgen(js_name = setQueryProvider)]
+    pub fn set_query_provider(query_provider: Option<JsQueryProvider>) {
+        Self::set_native_query_provider(
+            query_provider.map(|query_provider| Rc::new(query_provider) as Rc<dyn QueryProvider>),
+        );
+    }
+}
+
+impl ProgramManager {
+    /// Set the query provider used by the program manager
+    pub fn set_native_query_provider(query_provider: Option<Rc<dyn QueryProvider>>) {
+        QUERY_PROVIDER.with(|provider| *provider.borrow_mut() = query_provider);
+    }
+
+    // Get the query to prepare inclusion proofs with, preferring the state provided by the caller,
+    // then the query provider and finally the node at the url
+    pub(crate) fn query(url: &str, offline_query: Option<OfflineQuery>) -> ManagerQuery {
+        if let Some(offline_query) = offline_query {
+            return ManagerQuery::Offline(offline_query);
+        }
+        match QUERY_PROVIDER.with(|provider| provider.borrow().clone()) {
+            Some(provider) => ManagerQuery::Provider(provider),
+            None => ManagerQuery::Rest(QueryNative::from(url)),
+        }
+    }
+}
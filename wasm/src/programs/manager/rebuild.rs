@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{
+    execute_fee,
+    log,
+    types::{CurrentAleo, RecordPlaintextNative, TransactionNative},
+    PrivateKey,
+    RecordPlaintext,
+    Transaction,
+};
+
+use rand::{rngs::StdRng, SeedableRng};
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Rebuild an execution transaction with a higher fee, e.g. one which is not included in a
+    /// block because its priority fee is too low
+    ///
+    /// The execution of the transaction is verified and reused, so only the fee is proven again.
+    /// The execution stays valid as long as the node still accepts its global state root and its
+    /// input records have not been spent.
+    ///
+    /// @deprecated Converting floating point credits to microcredits is lossy, use
+    /// rebuildWithFeeMicrocredits instead
+    /// @param private_key The private key of the account paying the fee
+    /// @param transaction The execution transaction to rebuild
+    /// @param priority_fee The amount of credits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @param url The url of the Aleo network node to fetch the programs executed from and to send
+    /// the transaction to
+    /// @param imports (optional) Programs executed by the transaction in the form of a javascript
+    /// object where the keys are a string of the program name and the values are a string
+    /// representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error} The transaction with the same execution and the new fee
+    #[wasm_bindgen(js_name = rebuildWithFee)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn rebuild_with_fee(
+        private_key: &PrivateKey,
+        transaction: &Transaction,
+        priority_fee: f64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        imports: Option<Object>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        Self::rebuild_with_fee_microcredits(
+            private_key,
+            transaction,
+            Self::credits_to_microcredits(priority_fee),
+            fee_record,
+            url,
+            imports,
+            fee_proving_key,
+            fee_verifying_key,
+        )
+        .await
+    }
+
+    /// Rebuild an execution transaction with a higher fee, e.g. one which is not included in a
+    /// block because its priority fee is too low
+    ///
+    /// The execution of the transaction is verified and reused, so only the fee is proven again.
+    /// The execution stays valid as long as the node still accepts its global state root and its
+    /// input records have not been spent.
+    ///
+    /// @param private_key The private key of the account paying the fee
+    /// @param transaction The execution transaction to rebuild
+    /// @param priority_fee_microcredits {bigint} The amount of microcredits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @param url The url of the Aleo network node to fetch the programs executed from and to send
+    /// the transaction to
+    /// @param imports (optional) Programs executed by the transaction in the form of a javascript
+    /// object where the keys are a string of the program name and the values are a string
+    /// representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error} The transaction with the same execution and the new fee
+    #[wasm_bindgen(js_name = rebuildWithFeeMicrocredits)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn rebuild_with_fee_microcredits(
+        private_key: &PrivateKey,
+        transaction: &Transaction,
+        priority_fee_microcredits: u64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        imports: Option<Object>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        let TransactionNative::Execute(_, execution, _) = TransactionNative::from(transaction.clone()) else {
+            return Err("Only execution transactions can be rebuilt with a new fee".to_string());
+        };
+        let previous_fee = transaction.fee_amount()?;
+        let priority_fee = match &fee_record {
+            Some(fee_record) => Self::validate_microcredits(priority_fee_microcredits, fee_record, true)?,
+            None => priority_fee_microcredits,
+        };
+
+        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let process = &mut process_native;
+        let rng = &mut StdRng::from_entropy();
+
+        log("Verifying the execution to reuse");
+        Self::load_execution_programs(process, &execution, url, imports.as_ref()).await?;
+        process
+            .verify_execution(&execution)
+            .map_err(|err| format!("The execution cannot be reused and must be executed again: {err}"))?;
+        let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
+
+        // The minimum fee covers the storage of the execution and the finalize logic of its transitions
+        let storage_cost = execution.size_in_bytes().map_err(|e| e.to_string())?;
+        let finalize_cost = execution_finalize_cost(process, &execution)?;
+        let minimum_fee_cost = finalize_cost + storage_cost;
+        ensure_fee_increase(minimum_fee_cost.saturating_add(priority_fee), previous_fee)?;
+
+        log(&format!("Executing fee {minimum_fee_cost} with priority fee {priority_fee}"));
+        let fee = execute_fee!(
+            process,
+            private_key,
+            fee_record,
+            minimum_fee_cost,
+            priority_fee,
+            url,
+            fee_proving_key,
+            fee_verifying_key,
+            execution_id,
+            rng
+        );
+
+        log("Creating execution transaction");
+        let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
+        Ok(Transaction::from(transaction))
+    }
+}
+
+// Check that the fee of a rebuilt transaction is higher than the fee it replaces
+fn ensure_fee_increase(fee: u64, previous_fee: u64) -> Result<(), String> {
+    if fee <= previous_fee {
+        return Err(format!(
+            "The new fee of {fee} microcredits must be higher than the previous fee of {previous_fee} microcredits"
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::programs::transaction::tests::TRANSACTION_STRING;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_ensure_fee_increase() {
+        assert!(ensure_fee_increase(3023389, 3023388).is_ok());
+        assert_eq!(
+            ensure_fee_increase(3023388, 3023388).err().unwrap(),
+            "The new fee of 3023388 microcredits must be higher than the previous fee of 3023388 microcredits"
+        );
+        assert!(ensure_fee_increase(1000, 3023388).is_err());
+        assert!(ensure_fee_increase(u64::MAX, 3023388).is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_rebuild_rejects_non_execution() {
+        // Create a fee transaction from the fee of an execution transaction.
+        let TransactionNative::Execute(_, _, Some(fee)) =
+            TransactionNative::from(Transaction::from_string(TRANSACTION_STRING).unwrap())
+        else {
+            panic!("The transaction is not an execution paying a fee");
+        };
+        let transaction = Transaction::from(TransactionNative::from_fee(fee).unwrap());
+
+        let private_key = PrivateKey::new();
+        let error = ProgramManager::rebuild_with_fee_microcredits(
+            &private_key,
+            &transaction,
+            1_000_000,
+            None,
+            "https://api.explorer.aleo.org/v1",
+            None,
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+        assert_eq!(error, "Only execution transactions can be rebuilt with a new fee");
+    }
+}
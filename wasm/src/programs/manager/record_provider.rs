@@ -0,0 +1,223 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{
+    log,
+    record::{RecordFinder, RecordSelection},
+    RecordPlaintext,
+};
+
+use futures::future::LocalBoxFuture;
+use js_sys::{Array, Object, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+
+#[wasm_bindgen(typescript_custom_section)]
+const RECORD_PROVIDER_INTERFACE: &str = r#"
+/**
+ * Source of the credits records used to fund transfers and fees, e.g. a custodial database, an
+ * indexer or a local record store. One distinct record must be found for each amount, either as a
+ * RecordPlaintext or its string representation. The search parameters hold the strategy used to
+ * select the record for each amount: the largest record or the smallest record holding the amount.
+ */
+export interface RecordProvider {
+    findCreditsRecords(
+        microcredits: number[],
+        unspent: boolean,
+        nonces: string[],
+        searchParameters?: { selections?: ("largestFirst" | "exactFit")[] },
+    ): Promise<(RecordPlaintext | string)[]>;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Record provider implemented in javascript
+    #[wasm_bindgen(typescript_type = "RecordProvider")]
+    pub type JsRecordProvider;
+
+    #[wasm_bindgen(method, catch, js_name = findCreditsRecords)]
+    async fn request_credits_records(
+        this: &JsRecordProvider,
+        microcredits: Array,
+        unspent: bool,
+        nonces: Array,
+        search_parameters: Object,
+    ) -> Result<JsValue, JsValue>;
+}
+
+/// Source of the credits records used to fund transfers and fees
+pub trait RecordProvider {
+    /// Find distinct unspent credits records holding at least each of the amounts of microcredits,
+    /// selecting the record for each amount with its strategy
+    fn find_credits_records<'a>(
+        &'a self,
+        amounts: &'a [(u64, RecordSelection)],
+    ) -> LocalBoxFuture<'a, Result<Vec<RecordPlaintext>, String>>;
+}
+
+impl RecordProvider for JsRecordProvider {
+    fn find_credits_records<'a>(
+        &'a self,
+        amounts: &'a [(u64, RecordSelection)],
+    ) -> LocalBoxFuture<'a, Result<Vec<RecordPlaintext>, String>> {
+        Box::pin(async move {
+            let microcredits =
+                amounts.iter().map(|(microcredits, _)| JsValue::from_f64(*microcredits as f64)).collect();
+            let selections = amounts
+                .iter()
+                .map(|(_, selection)| match selection {
+                    RecordSelection::LargestFirst => JsValue::from_str("largestFirst"),
+                    RecordSelection::ExactFit => JsValue::from_str("exactFit"),
+                })
+                .collect::<Array>();
+            let search_parameters = Object::new();
+            Reflect::set(&search_parameters, &JsValue::from_str("selections"), &selections)
+                .map_err(|_| "Failed to set property")?;
+            let records = self
+                .request_credits_records(microcredits, true, Array::new(), search_parameters)
+                .await
+                .map_err(|e| format!("The record provider failed to find records: {e:?}"))?
+                .dyn_into::<Array>()
+                .map_err(|_| "The record provider must resolve to an array of records".to_string())?;
+            if records.length() as usize != amounts.len() {
+                return Err(format!(
+                    "The record provider returned {} records for {} amounts",
+                    records.length(),
+                    amounts.len()
+                ));
+            }
+            records
+                .iter()
+                .map(|record| {
+                    // Records may be returned as strings or as objects such as RecordPlaintext.
+                    let record =
+                        record.as_string().unwrap_or_else(|| record.unchecked_ref::<Object>().to_string().into());
+                    RecordPlaintext::from_string(&record)
+                })
+                .collect()
+        })
+    }
+}
+
+impl RecordProvider for RecordFinder {
+    fn find_credits_records<'a>(
+        &'a self,
+        amounts: &'a [(u64, RecordSelection)],
+    ) -> LocalBoxFuture<'a, Result<Vec<RecordPlaintext>, String>> {
+        Box::pin(self.select_credits_records(amounts))
+    }
+}
+
+impl ProgramManager {
+    // Find a record holding the whole fee, the base fee and the priority fee, with a record provider
+    // if the fee record was omitted
+    pub(crate) async fn provide_fee_record(
+        record_provider: Option<&dyn RecordProvider>,
+        fee_microcredits: u64,
+        fee_record: Option<RecordPlaintext>,
+    ) -> Result<Option<RecordPlaintext>, String> {
+        match (record_provider, fee_record) {
+            (Some(record_provider), None) => {
+                log(&format!("Requesting a record holding the fee of {fee_microcredits} microcredits"));
+                let amounts = [(fee_microcredits, RecordSelection::LargestFirst)];
+                let mut records = record_provider.find_credits_records(&amounts).await?;
+                // Paying publicly instead would spend credits the caller did not intend to use.
+                let fee_record = records.pop().ok_or("The record provider did not return a record to pay the fee")?;
+                Ok(Some(fee_record))
+            }
+            (_, fee_record) => Ok(fee_record),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use js_sys::Function;
+    use wasm_bindgen_test::*;
+
+    // Create a record provider which selects from records holding 100, 500 and 250 microcredits
+    fn js_record_provider() -> JsRecordProvider {
+        let find_credits_records = Function::new_with_args(
+            "microcredits, unspent, nonces, searchParameters",
+            r#"
+            const candidates = [100, 500, 250];
+            return Promise.resolve(microcredits.map((amount, i) => {
+                const sufficient = candidates.filter((candidate) => candidate >= amount);
+                const selected = searchParameters.selections[i] === "exactFit"
+                    ? Math.min(...sufficient)
+                    : Math.max(...sufficient);
+                return `{ owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3.private, microcredits: ${selected}u64.private, _nonce: 0group.public }`;
+            }));
+            "#,
+        );
+        let provider = Object::new();
+        Reflect::set(&provider, &JsValue::from_str("findCreditsRecords"), &find_credits_records).unwrap();
+        provider.unchecked_into()
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_js_record_provider_selection() {
+        let provider = js_record_provider();
+
+        // Check the selection strategy of each amount is passed to the provider.
+        let amounts = [(200, RecordSelection::LargestFirst)];
+        let records = provider.find_credits_records(&amounts).await.unwrap();
+        assert_eq!(records[0].microcredits(), 500);
+        let amounts = [(200, RecordSelection::ExactFit)];
+        let records = provider.find_credits_records(&amounts).await.unwrap();
+        assert_eq!(records[0].microcredits(), 250);
+        let amounts = [(200, RecordSelection::ExactFit), (200, RecordSelection::LargestFirst)];
+        let records = provider.find_credits_records(&amounts).await.unwrap();
+        assert_eq!((records[0].microcredits(), records[1].microcredits()), (250, 500));
+    }
+
+    // Record provider which has no records to offer
+    struct EmptyRecordProvider;
+
+    impl RecordProvider for EmptyRecordProvider {
+        fn find_credits_records<'a>(
+            &'a self,
+            _amounts: &'a [(u64, RecordSelection)],
+        ) -> LocalBoxFuture<'a, Result<Vec<RecordPlaintext>, String>> {
+            Box::pin(async { Ok(vec![]) })
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_provide_fee_record() {
+        // Check the record is requested for the whole fee.
+        let provider = js_record_provider();
+        let record = ProgramManager::provide_fee_record(Some(&provider), 300, None).await.unwrap().unwrap();
+        assert_eq!(record.microcredits(), 500);
+
+        // Check a fee record provided by the caller is used as is.
+        let fee_record = RecordPlaintext::from_string(
+            "{ owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3.private, microcredits: 100u64.private, _nonce: 0group.public }",
+        )
+        .unwrap();
+        let record = ProgramManager::provide_fee_record(Some(&provider), 300, Some(fee_record)).await.unwrap().unwrap();
+        assert_eq!(record.microcredits(), 100);
+
+        // Check the fee is not paid publicly when the provider has no record.
+        let error = ProgramManager::provide_fee_record(Some(&EmptyRecordProvider), 300, None).await.err().unwrap();
+        assert_eq!(error, "The record provider did not return a record to pay the fee");
+        assert!(ProgramManager::provide_fee_record(None, 300, None).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,368 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use core::ops::Add;
+
+use crate::{
+    execute_authorization,
+    insert_fee_keys,
+    log,
+    prove_fee,
+    types::{AuthorizationNative, CurrentAleo, FieldNative, ProcessNative, ProgramNative, TransactionNative},
+    RecordPlaintext,
+    Transaction,
+};
+
+use js_sys::{Array, Object};
+use rand::{rngs::StdRng, SeedableRng};
+use std::str::FromStr;
+use wasm_bindgen::JsValue;
+
+#[wasm_bindgen(typescript_custom_section)]
+const SIGNER_INTERFACE: &str = r#"
+/**
+ * External signer which authorizes executions without exposing key material to the SDK, e.g. a
+ * hardware wallet. Both methods resolve to the string representation of an Authorization.
+ */
+export interface Signer {
+    authorize(programId: string, functionName: string, inputs: string[]): Promise<string>;
+    authorizeFee(
+        baseFeeMicrocredits: bigint,
+        priorityFeeMicrocredits: bigint,
+        executionId: string,
+        feeRecord?: string,
+    ): Promise<string>;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    /// External signer implemented in javascript
+    #[wasm_bindgen(typescript_type = "Signer")]
+    pub type Signer;
+
+    #[wasm_bindgen(method, catch)]
+    async fn authorize(this: &Signer, program_id: &str, function_name: &str, inputs: Array)
+    -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = authorizeFee)]
+    async fn authorize_fee(
+        this: &Signer,
+        base_fee_microcredits: u64,
+        priority_fee_microcredits: u64,
+        execution_id: &str,
+        fee_record: Option<String>,
+    ) -> Result<JsValue, JsValue>;
+}
+
+impl Signer {
+    // Request an authorization for a function execution from the signer
+    async fn request_authorization(
+        &self,
+        program_id: &str,
+        function_name: &str,
+        inputs: Array,
+    ) -> Result<AuthorizationNative, String> {
+        let authorization = self
+            .authorize(program_id, function_name, inputs)
+            .await
+            .map_err(|e| format!("The signer failed to authorize {program_id}/{function_name}: {e:?}"))?;
+        Self::parse_authorization(authorization)
+    }
+
+    // Request an authorization for a fee execution from the signer
+    async fn request_fee_authorization(
+        &self,
+        base_fee_microcredits: u64,
+        priority_fee_microcredits: u64,
+        execution_id: &str,
+        fee_record: Option<String>,
+    ) -> Result<AuthorizationNative, String> {
+        let authorization = self
+            .authorize_fee(base_fee_microcredits, priority_fee_microcredits, execution_id, fee_record)
+            .await
+            .map_err(|e| format!("The signer failed to authorize the fee: {e:?}"))?;
+        Self::parse_authorization(authorization)
+    }
+
+    // Parse the authorization string returned by the signer
+    fn parse_authorization(authorization: JsValue) -> Result<AuthorizationNative, String> {
+        let authorization = authorization
+            .as_string()
+            .ok_or_else(|| "The signer must resolve to the string representation of an authorization".to_string())?;
+        AuthorizationNative::from_str(&authorization).map_err(|e| format!("Invalid authorization: {e}"))
+    }
+}
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Execute Aleo function and create an Aleo execution transaction, requesting the
+    /// authorizations for the execution and fee from an external signer instead of a private key
+    ///
+    /// @param signer External signer authorizing the execution and fee
+    /// @param program The source code of the program being executed
+    /// @param function The name of the function to execute
+    /// @param inputs A javascript array of inputs to the function
+    /// @param priority_fee The amount of credits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param imports (optional) Provide a list of imports to use for the function execution in the
+    /// form of a javascript object where the keys are a string of the program name and the values
+    /// are a string representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
+    /// @param proving_key (optional) Provide a proving key to use for the function execution
+    /// @param verifying_key (optional) Provide a verifying key to use for the function execution
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildExecutionTransactionWithSigner)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn execute_with_signer(
+        signer: &Signer,
+        program: &str,
+        function: &str,
+        inputs: Array,
+        priority_fee: f64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        imports: Option<Object>,
+        proving_key: Option<ProvingKey>,
+        verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        log(&format!("Executing function: {program} {function} {priority_fee} on-chain with an external signer"));
+        let priority_fee = match &fee_record {
+            Some(fee_record) => Self::validate_amount(priority_fee, fee_record, true)?,
+            None => Self::credits_to_microcredits(priority_fee),
+        };
+        Self::execute_signed(
+            signer,
+            program,
+            function,
+            inputs,
+            priority_fee,
+            fee_record,
+            url,
+            imports,
+            proving_key,
+            verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+        )
+        .await
+    }
+
+    /// Send credits from one Aleo account to another, requesting the authorizations for the
+    /// transfer and fee from an external signer instead of a private key
+    ///
+    /// @param signer External signer authorizing the transfer and fee
+    /// @param amount_credits The amount of credits to send
+    /// @param recipient The recipient of the transaction
+    /// @param transfer_type The type of the transfer (options: "private", "public", "public_as_signer", "private_to_public", "public_to_private")
+    /// @param amount_record The record to fund the amount from
+    /// @param priority_fee The amount of credits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param transfer_proving_key (optional) Provide a proving key to use for the transfer function
+    /// @param transfer_verifying_key (optional) Provide a verifying key to use for the transfer function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildTransferTransactionWithSigner)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn transfer_with_signer(
+        signer: &Signer,
+        amount_credits: f64,
+        recipient: &str,
+        transfer_type: &str,
+        amount_record: Option<RecordPlaintext>,
+        priority_fee: f64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        transfer_proving_key: Option<ProvingKey>,
+        transfer_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        log("Executing transfer program with an external signer");
+        let priority_fee = match &fee_record {
+            Some(fee_record) => Self::validate_amount(priority_fee, fee_record, true)?,
+            None => Self::credits_to_microcredits(priority_fee),
+        };
+        let amount_microcredits = Self::credits_to_microcredits(amount_credits);
+        if amount_microcredits == 0 {
+            return Err("Amount must be greater than zero to deploy or execute a program".to_string());
+        }
+        if let Some(amount_record) = &amount_record {
+            Self::validate_microcredits(amount_microcredits, amount_record, false)?;
+        }
+        let (transfer_type, inputs) =
+            Self::transfer_inputs(transfer_type, amount_record, recipient, amount_microcredits)?;
+        let program = ProgramNative::credits().map_err(|e| e.to_string())?.to_string();
+        Self::execute_signed(
+            signer,
+            &program,
+            transfer_type,
+            inputs,
+            priority_fee,
+            fee_record,
+            url,
+            None,
+            transfer_proving_key,
+            transfer_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+        )
+        .await
+    }
+}
+
+impl ProgramManager {
+    // Build an execution transaction using authorizations provided by an external signer
+    #[allow(clippy::too_many_arguments)]
+    async fn execute_signed(
+        signer: &Signer,
+        program: &str,
+        function: &str,
+        inputs: Array,
+        priority_fee: u64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        imports: Option<Object>,
+        proving_key: Option<ProvingKey>,
+        verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let process = &mut process_native;
+
+        log("Check program imports are valid and add them to the process");
+        let program_native = ProgramNative::from_str(program).map_err(|e| e.to_string())?;
+        ProgramManager::resolve_imports_from_network(process, &program_native, imports, url).await?;
+
+        log("Requesting an authorization from the signer");
+        let program_id = program_native.id().to_string();
+        let authorization = signer.request_authorization(&program_id, function, inputs).await?;
+
+        let (_, mut trace) =
+            execute_authorization!(process, authorization, program, function, proving_key, verifying_key);
+
+        log("Preparing inclusion proofs for execution");
+        let query = Self::query(url, None);
+        trace.prepare_async(query).await.map_err(|err| err.to_string())?;
+
+        log("Proving execution");
+        let locator = program_id.add("/").add(function);
+        let execution = crate::with_thread_budget("proving", || {
+            trace.prove_execution::<CurrentAleo, _>(&locator, &mut StdRng::from_entropy())
+        })
+        .map_err(|e| e.to_string())?;
+        let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
+
+        // Get the storage cost in bytes for the program execution
+        let storage_cost = execution.size_in_bytes().map_err(|e| e.to_string())?;
+
+        // Compute the finalize cost in microcredits.
+        let finalize_cost = execution_finalize_cost(process, &execution)?;
+        let minimum_fee_cost = finalize_cost + storage_cost;
+
+        log(&format!("Requesting a fee authorization for {minimum_fee_cost} microcredits from the signer"));
+        insert_fee_keys!(process, fee_record.is_some(), fee_proving_key, fee_verifying_key);
+        let fee_record = fee_record.map(|fee_record| fee_record.to_string());
+        let fee_authorization = signer
+            .request_fee_authorization(minimum_fee_cost, priority_fee, &execution_id.to_string(), fee_record.clone())
+            .await?;
+        Self::check_fee_authorization(
+            &fee_authorization,
+            fee_record.as_deref(),
+            minimum_fee_cost,
+            priority_fee,
+            &execution_id,
+        )?;
+        let fee = prove_fee!(process, fee_authorization, url, execution_id);
+
+        // Verify the execution
+        process.verify_execution(&execution).map_err(|err| err.to_string())?;
+
+        log("Creating execution transaction");
+        let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
+        Ok(Transaction::from(transaction))
+    }
+
+    // Check a fee authorization from the signer pays the requested fee for the execution before it
+    // is proven, so a signer can't substitute another fee function, amount, record or execution
+    fn check_fee_authorization(
+        authorization: &AuthorizationNative,
+        fee_record: Option<&str>,
+        base_fee: u64,
+        priority_fee: u64,
+        execution_id: &FieldNative,
+    ) -> Result<(), String> {
+        let function = if fee_record.is_some() { "fee_private" } else { "fee_public" };
+        if authorization.len() != 1 {
+            return Err(format!("The signer must authorize a single credits.aleo/{function} request"));
+        }
+        let request = authorization.peek_next().map_err(|e| e.to_string())?;
+        if request.program_id().to_string() != "credits.aleo" || request.function_name().to_string() != function {
+            return Err(format!(
+                "The signer authorized {}/{} instead of credits.aleo/{function}",
+                request.program_id(),
+                request.function_name()
+            ));
+        }
+        // The inputs of fee_private are the fee record followed by the inputs of fee_public
+        let mut expected = fee_record.map(ToString::to_string).into_iter().collect::<Vec<_>>();
+        expected.extend([format!("{base_fee}u64"), format!("{priority_fee}u64"), execution_id.to_string()]);
+        let inputs = request.inputs().iter().map(ToString::to_string).collect::<Vec<_>>();
+        if inputs != expected {
+            return Err(format!(
+                "The fee authorized by the signer does not pay {base_fee} microcredits with a priority fee of {priority_fee} microcredits for execution {execution_id}"
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::PrivateKey;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_check_fee_authorization() {
+        let private_key = PrivateKey::new();
+        let execution_id = FieldNative::from_str("1field").unwrap();
+        let authorization = ProgramManager::authorize_fee(&private_key, 100, 5, "1field", None).unwrap();
+
+        // Check the authorization of the requested fee is accepted.
+        assert!(ProgramManager::check_fee_authorization(&authorization, None, 100, 5, &execution_id).is_ok());
+
+        // Check authorizations of another fee function, amount or execution are rejected.
+        let fee_record = "{ owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3.private, microcredits: 1000u64.private, _nonce: 0group.public }";
+        assert!(
+            ProgramManager::check_fee_authorization(&authorization, Some(fee_record), 100, 5, &execution_id).is_err()
+        );
+        assert!(ProgramManager::check_fee_authorization(&authorization, None, 101, 5, &execution_id).is_err());
+        assert!(ProgramManager::check_fee_authorization(&authorization, None, 100, 0, &execution_id).is_err());
+        let other_execution_id = FieldNative::from_str("2field").unwrap();
+        assert!(ProgramManager::check_fee_authorization(&authorization, None, 100, 5, &other_execution_id).is_err());
+    }
+}
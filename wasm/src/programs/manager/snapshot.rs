@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::types::ToBytes;
+
+/// Version of the snapshot format, written as the first byte of a snapshot
+const SNAPSHOT_VERSION: u8 = 1;
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Serialize the programs and keys the module has loaded so a respawned web worker or a
+    /// reloaded page can resume without fetching programs or synthesizing keys again
+    ///
+    /// The snapshot holds the function keys in the key cache along with the programs they were
+    /// created for, and the programs fetched from the network. It can be stored in IndexedDB or
+    /// sent to another worker and loaded with `restore`.
+    ///
+    /// @returns {Uint8Array | Error} The snapshot of the module state
+    #[wasm_bindgen]
+    pub fn snapshot() -> Result<Vec<u8>, String> {
+        let mut bytes = vec![SNAPSHOT_VERSION];
+
+        let keys = Self::key_cache_entries();
+        write_len(&mut bytes, keys.len());
+        for (program, function_name, proving_key, verifying_key) in keys {
+            write_bytes(&mut bytes, program.to_string().as_bytes());
+            write_bytes(&mut bytes, function_name.as_bytes());
+            write_entry(&mut bytes, |bytes| {
+                proving_key.write_le(bytes).map_err(|_| "Failed to serialize proving key".to_string())
+            })?;
+            write_entry(&mut bytes, |bytes| {
+                verifying_key.write_le(bytes).map_err(|_| "Failed to serialize verifying key".to_string())
+            })?;
+        }
+
+        let programs = PROGRAM_CACHE.with(|cache| cache.borrow().clone());
+        write_len(&mut bytes, programs.len());
+        for (url, program) in programs {
+            write_bytes(&mut bytes, url.as_bytes());
+            write_bytes(&mut bytes, program.as_bytes());
+        }
+        Ok(bytes)
+    }
+
+    /// Load a snapshot created by `snapshot` into the caches of the module
+    ///
+    /// The snapshot is checked in full before anything is loaded, so the caches are left unchanged
+    /// when it is invalid.
+    ///
+    /// @param snapshot {Uint8Array} The snapshot of the module state
+    /// @returns {number | Error} The number of functions whose keys were restored
+    #[wasm_bindgen]
+    pub fn restore(snapshot: &[u8]) -> Result<usize, String> {
+        let mut reader = SnapshotReader(snapshot);
+        if reader.take(1)? != [SNAPSHOT_VERSION] {
+            return Err("The snapshot was created by an unsupported version of the SDK".to_string());
+        }
+
+        let mut keys = Vec::new();
+        for _ in 0..reader.len()? {
+            let program = ProgramNative::from_str(reader.text()?).map_err(|e| e.to_string())?;
+            let function_name = IdentifierNative::from_str(reader.text()?).map_err(|e| e.to_string())?;
+            let proving_key = reader.entry()?;
+            let verifying_key = reader.entry()?;
+            let size = proving_key.len() + verifying_key.len();
+            let (proving_key, verifying_key) =
+                (ProvingKey::from_bytes(proving_key)?, VerifyingKey::from_bytes(verifying_key)?);
+            keys.push((program, function_name, proving_key, verifying_key, size));
+        }
+
+        let mut programs = Vec::new();
+        for _ in 0..reader.len()? {
+            programs.push((reader.text()?.to_string(), reader.text()?.to_string()));
+        }
+        if !reader.0.is_empty() {
+            return Err("The snapshot has trailing bytes".to_string());
+        }
+
+        let restored = keys.len();
+        for (program, function_name, proving_key, verifying_key, size) in keys {
+            Self::restore_cached_keys(program, &function_name, proving_key, verifying_key, Some(size));
+        }
+        PROGRAM_CACHE.with(|cache| cache.borrow_mut().extend(programs));
+        Ok(restored)
+    }
+}
+
+// Append a count to a snapshot
+fn write_len(bytes: &mut Vec<u8>, len: usize) {
+    bytes.extend_from_slice(&(len as u64).to_le_bytes());
+}
+
+// Append bytes to a snapshot prefixed with their length
+fn write_bytes(bytes: &mut Vec<u8>, entry: &[u8]) {
+    write_len(bytes, entry.len());
+    bytes.extend_from_slice(entry);
+}
+
+// Append an entry to a snapshot prefixed with its length, writing it in place to avoid copying keys
+fn write_entry(bytes: &mut Vec<u8>, write: impl FnOnce(&mut Vec<u8>) -> Result<(), String>) -> Result<(), String> {
+    let start = bytes.len();
+    write_len(bytes, 0);
+    write(bytes)?;
+    let len = (bytes.len() - start - 8) as u64;
+    bytes[start..start + 8].copy_from_slice(&len.to_le_bytes());
+    Ok(())
+}
+
+// Reader of the counts and length prefixed entries of a snapshot
+struct SnapshotReader<'a>(&'a [u8]);
+
+impl<'a> SnapshotReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        if self.0.len() < len {
+            return Err("The snapshot is truncated".to_string());
+        }
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    fn len(&mut self) -> Result<usize, String> {
+        let mut len = [0u8; 8];
+        len.copy_from_slice(self.take(8)?);
+        usize::try_from(u64::from_le_bytes(len)).map_err(|_| "The snapshot is invalid".to_string())
+    }
+
+    fn entry(&mut self) -> Result<&'a [u8], String> {
+        let len = self.len()?;
+        self.take(len)
+    }
+
+    fn text(&mut self) -> Result<&'a str, String> {
+        std::str::from_utf8(self.entry()?).map_err(|_| "The snapshot contains invalid text".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_snapshot_roundtrip() {
+        let snapshot = ProgramManager::snapshot().unwrap();
+        assert_eq!(snapshot[0], SNAPSHOT_VERSION);
+        assert_eq!(
+            ProgramManager::restore(&snapshot).unwrap(),
+            ProgramManager::cached_keys().unwrap().length() as usize
+        );
+        assert_eq!(ProgramManager::snapshot().unwrap().len(), snapshot.len());
+
+        assert!(ProgramManager::restore(&snapshot[..snapshot.len() - 1]).is_err());
+        assert!(ProgramManager::restore(&[SNAPSHOT_VERSION + 1]).is_err());
+        assert!(ProgramManager::restore(&[]).is_err());
+    }
+}
@@ -34,6 +34,8 @@ use std::{ops::Add, str::FromStr};
 impl ProgramManager {
     /// Split an Aleo credits record into two separate records. This function does not require a fee.
     ///
+    /// @deprecated Converting floating point credits to microcredits is lossy, use
+    /// buildSplitTransactionMicrocredits instead
     /// @param private_key The private key of the sender
     /// @param split_amount The amount of the credit split. This amount will be subtracted from the
     /// value of the record and two new records will be created with the split amount and the remainder
@@ -51,9 +53,42 @@ impl ProgramManager {
         url: &str,
         split_proving_key: Option<ProvingKey>,
         split_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        let split_microcredits = Self::credits_to_microcredits(split_amount);
+        Self::split_microcredits(
+            private_key,
+            split_microcredits,
+            amount_record,
+            url,
+            split_proving_key,
+            split_verifying_key,
+        )
+        .await
+    }
+
+    /// Split an Aleo credits record into two separate records by an amount of microcredits. This
+    /// function does not require a fee.
+    ///
+    /// @param private_key The private key of the sender
+    /// @param split_microcredits {bigint} The amount of microcredits of the split. This amount will
+    /// be subtracted from the value of the record and two new records will be created with the
+    /// split amount and the remainder
+    /// @param amount_record The record to split
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param split_proving_key (optional) Provide a proving key to use for the split function
+    /// @param split_verifying_key (optional) Provide a verifying key to use for the split function
+    /// @returns {Transaction | Error} Transaction object
+    #[wasm_bindgen(js_name = buildSplitTransactionMicrocredits)]
+    pub async fn split_microcredits(
+        private_key: &PrivateKey,
+        split_microcredits: u64,
+        amount_record: RecordPlaintext,
+        url: &str,
+        split_proving_key: Option<ProvingKey>,
+        split_verifying_key: Option<VerifyingKey>,
     ) -> Result<Transaction, String> {
         log("Executing split program");
-        let amount_microcredits = Self::validate_amount(split_amount, &amount_record, false)?;
+        let amount_microcredits = Self::validate_microcredits(split_microcredits, &amount_record, false)?;
 
         log("Setup the program and inputs");
         let program = ProgramNative::credits().unwrap().to_string();
@@ -78,12 +113,13 @@ impl ProgramManager {
         );
 
         log("Preparing the inclusion proof for the split execution");
-        let query = QueryNative::from(url);
+        let query = Self::query(url, None);
         trace.prepare_async(query).await.map_err(|err| err.to_string())?;
 
         log("Proving the split execution");
         let execution =
-            trace.prove_execution::<CurrentAleo, _>("credits.aleo/split", rng).map_err(|e| e.to_string())?;
+            crate::with_thread_budget("proving", || trace.prove_execution::<CurrentAleo, _>("credits.aleo/split", rng))
+                .map_err(|e| e.to_string())?;
 
         log("Verifying the split execution");
         process.verify_execution(&execution).map_err(|err| err.to_string())?;
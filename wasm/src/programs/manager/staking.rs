@@ -0,0 +1,661 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{
+    execute_fee,
+    execute_program,
+    log,
+    process_inputs,
+    types::{CurrentAleo, IdentifierNative, ProcessNative, ProgramNative, RecordPlaintextNative, TransactionNative},
+    PrivateKey,
+    RecordPlaintext,
+    Transaction,
+};
+
+use js_sys::Array;
+use rand::{rngs::StdRng, SeedableRng};
+use std::{ops::Add, str::FromStr};
+use wasm_bindgen::JsValue;
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Bond credits from the public balance of the sender to a validator
+    ///
+    /// @deprecated Converting floating point credits to microcredits is lossy, use
+    /// buildBondPublicTransactionMicrocredits instead
+    /// @param private_key The private key of the delegator
+    /// @param validator The address of the validator to bond to
+    /// @param amount_credits The amount of credits to bond
+    /// @param priority_fee The amount of credits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param bond_proving_key (optional) Provide a proving key to use for the bond_public function
+    /// @param bond_verifying_key (optional) Provide a verifying key to use for the bond_public function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildBondPublicTransaction)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn bond_public(
+        private_key: &PrivateKey,
+        validator: &str,
+        amount_credits: f64,
+        priority_fee: f64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        bond_proving_key: Option<ProvingKey>,
+        bond_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        Self::bond_public_microcredits(
+            private_key,
+            validator,
+            Self::credits_to_microcredits(amount_credits),
+            Self::credits_to_microcredits(priority_fee),
+            fee_record,
+            url,
+            bond_proving_key,
+            bond_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+        )
+        .await
+    }
+
+    /// Bond credits from the public balance of the sender to a validator
+    ///
+    /// @param private_key The private key of the delegator
+    /// @param validator The address of the validator to bond to
+    /// @param amount_microcredits {bigint} The amount of microcredits to bond
+    /// @param priority_fee_microcredits {bigint} The amount of microcredits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param bond_proving_key (optional) Provide a proving key to use for the bond_public function
+    /// @param bond_verifying_key (optional) Provide a verifying key to use for the bond_public function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildBondPublicTransactionMicrocredits)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn bond_public_microcredits(
+        private_key: &PrivateKey,
+        validator: &str,
+        amount_microcredits: u64,
+        priority_fee_microcredits: u64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        bond_proving_key: Option<ProvingKey>,
+        bond_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        log("Executing bond_public");
+        let amount_microcredits = Self::validate_public_amount(amount_microcredits)?;
+        let inputs = Array::new();
+        inputs.push(&JsValue::from_str(validator));
+        inputs.push(&JsValue::from_str(&amount_microcredits.to_string().add("u64")));
+        Self::execute_credits_function(
+            private_key,
+            "bond_public",
+            inputs,
+            priority_fee_microcredits,
+            fee_record,
+            url,
+            bond_proving_key,
+            bond_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+        )
+        .await
+    }
+
+    /// Unbond credits bonded by the sender. The credits can be claimed with `claimUnbondPublic`
+    /// once the unbonding period has passed
+    ///
+    /// @deprecated Converting floating point credits to microcredits is lossy, use
+    /// buildUnbondPublicTransactionMicrocredits instead
+    /// @param private_key The private key of the delegator
+    /// @param amount_credits The amount of credits to unbond
+    /// @param priority_fee The amount of credits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param unbond_proving_key (optional) Provide a proving key to use for the unbond_public function
+    /// @param unbond_verifying_key (optional) Provide a verifying key to use for the unbond_public function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildUnbondPublicTransaction)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn unbond_public(
+        private_key: &PrivateKey,
+        amount_credits: f64,
+        priority_fee: f64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        unbond_proving_key: Option<ProvingKey>,
+        unbond_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        Self::unbond_public_microcredits(
+            private_key,
+            Self::credits_to_microcredits(amount_credits),
+            Self::credits_to_microcredits(priority_fee),
+            fee_record,
+            url,
+            unbond_proving_key,
+            unbond_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+        )
+        .await
+    }
+
+    /// Unbond credits bonded by the sender. The credits can be claimed with `claimUnbondPublic`
+    /// once the unbonding period has passed
+    ///
+    /// @param private_key The private key of the delegator
+    /// @param amount_microcredits {bigint} The amount of microcredits to unbond
+    /// @param priority_fee_microcredits {bigint} The amount of microcredits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param unbond_proving_key (optional) Provide a proving key to use for the unbond_public function
+    /// @param unbond_verifying_key (optional) Provide a verifying key to use for the unbond_public function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildUnbondPublicTransactionMicrocredits)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn unbond_public_microcredits(
+        private_key: &PrivateKey,
+        amount_microcredits: u64,
+        priority_fee_microcredits: u64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        unbond_proving_key: Option<ProvingKey>,
+        unbond_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        log("Executing unbond_public");
+        let amount_microcredits = Self::validate_public_amount(amount_microcredits)?;
+        let inputs = Array::new();
+        inputs.push(&JsValue::from_str(&amount_microcredits.to_string().add("u64")));
+        Self::execute_credits_function(
+            private_key,
+            "unbond_public",
+            inputs,
+            priority_fee_microcredits,
+            fee_record,
+            url,
+            unbond_proving_key,
+            unbond_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+        )
+        .await
+    }
+
+    /// Claim the credits unbonded by the sender into its public balance
+    ///
+    /// @deprecated Converting floating point credits to microcredits is lossy, use
+    /// buildClaimUnbondPublicTransactionMicrocredits instead
+    /// @param private_key The private key of the delegator
+    /// @param priority_fee The amount of credits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param claim_proving_key (optional) Provide a proving key to use for the claim_unbond_public function
+    /// @param claim_verifying_key (optional) Provide a verifying key to use for the claim_unbond_public function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildClaimUnbondPublicTransaction)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn claim_unbond_public(
+        private_key: &PrivateKey,
+        priority_fee: f64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        claim_proving_key: Option<ProvingKey>,
+        claim_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        Self::claim_unbond_public_microcredits(
+            private_key,
+            Self::credits_to_microcredits(priority_fee),
+            fee_record,
+            url,
+            claim_proving_key,
+            claim_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+        )
+        .await
+    }
+
+    /// Claim the credits unbonded by the sender into its public balance
+    ///
+    /// @param private_key The private key of the delegator
+    /// @param priority_fee_microcredits {bigint} The amount of microcredits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param claim_proving_key (optional) Provide a proving key to use for the claim_unbond_public function
+    /// @param claim_verifying_key (optional) Provide a verifying key to use for the claim_unbond_public function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildClaimUnbondPublicTransactionMicrocredits)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn claim_unbond_public_microcredits(
+        private_key: &PrivateKey,
+        priority_fee_microcredits: u64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        claim_proving_key: Option<ProvingKey>,
+        claim_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        log("Executing claim_unbond_public");
+        Self::execute_credits_function(
+            private_key,
+            "claim_unbond_public",
+            Array::new(),
+            priority_fee_microcredits,
+            fee_record,
+            url,
+            claim_proving_key,
+            claim_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+        )
+        .await
+    }
+}
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Bond credits from the public balance of the sender as a validator. This requires a network
+    /// whose credits.aleo program defines `bond_validator`
+    ///
+    /// @deprecated Converting floating point credits to microcredits is lossy, use
+    /// buildBondValidatorTransactionMicrocredits instead
+    /// @param private_key The private key of the validator
+    /// @param withdrawal The address which unbonded credits are withdrawn to
+    /// @param amount_credits The amount of credits to bond
+    /// @param commission The commission percentage of the validator, from 0 to 100
+    /// @param priority_fee The amount of credits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param bond_proving_key (optional) Provide a proving key to use for the bond_validator function
+    /// @param bond_verifying_key (optional) Provide a verifying key to use for the bond_validator function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildBondValidatorTransaction)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn bond_validator(
+        private_key: &PrivateKey,
+        withdrawal: &str,
+        amount_credits: f64,
+        commission: u8,
+        priority_fee: f64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        bond_proving_key: Option<ProvingKey>,
+        bond_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        Self::bond_validator_microcredits(
+            private_key,
+            withdrawal,
+            Self::credits_to_microcredits(amount_credits),
+            commission,
+            Self::credits_to_microcredits(priority_fee),
+            fee_record,
+            url,
+            bond_proving_key,
+            bond_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+        )
+        .await
+    }
+
+    /// Bond credits from the public balance of the sender as a validator. This requires a network
+    /// whose credits.aleo program defines `bond_validator`
+    ///
+    /// @param private_key The private key of the validator
+    /// @param withdrawal The address which unbonded credits are withdrawn to
+    /// @param amount_microcredits {bigint} The amount of microcredits to bond
+    /// @param commission The commission percentage of the validator, from 0 to 100
+    /// @param priority_fee_microcredits {bigint} The amount of microcredits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param bond_proving_key (optional) Provide a proving key to use for the bond_validator function
+    /// @param bond_verifying_key (optional) Provide a verifying key to use for the bond_validator function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildBondValidatorTransactionMicrocredits)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn bond_validator_microcredits(
+        private_key: &PrivateKey,
+        withdrawal: &str,
+        amount_microcredits: u64,
+        commission: u8,
+        priority_fee_microcredits: u64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        bond_proving_key: Option<ProvingKey>,
+        bond_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        log("Executing bond_validator");
+        if commission > 100 {
+            return Err(format!("The commission must be a percentage from 0 to 100, but {commission} was provided"));
+        }
+        let amount_microcredits = Self::validate_public_amount(amount_microcredits)?;
+        let inputs = Array::new();
+        inputs.push(&JsValue::from_str(withdrawal));
+        inputs.push(&JsValue::from_str(&amount_microcredits.to_string().add("u64")));
+        inputs.push(&JsValue::from_str(&commission.to_string().add("u8")));
+        Self::execute_credits_function(
+            private_key,
+            "bond_validator",
+            inputs,
+            priority_fee_microcredits,
+            fee_record,
+            url,
+            bond_proving_key,
+            bond_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+        )
+        .await
+    }
+
+    /// Open or close a validator to new delegators
+    ///
+    /// @deprecated Converting floating point credits to microcredits is lossy, use
+    /// buildSetValidatorStateTransactionMicrocredits instead
+    /// @param private_key The private key of the validator
+    /// @param is_open Whether the validator accepts new delegators
+    /// @param priority_fee The amount of credits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param set_state_proving_key (optional) Provide a proving key to use for the set_validator_state function
+    /// @param set_state_verifying_key (optional) Provide a verifying key to use for the set_validator_state function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildSetValidatorStateTransaction)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn set_validator_state(
+        private_key: &PrivateKey,
+        is_open: bool,
+        priority_fee: f64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        set_state_proving_key: Option<ProvingKey>,
+        set_state_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        Self::set_validator_state_microcredits(
+            private_key,
+            is_open,
+            Self::credits_to_microcredits(priority_fee),
+            fee_record,
+            url,
+            set_state_proving_key,
+            set_state_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+        )
+        .await
+    }
+
+    /// Open or close a validator to new delegators
+    ///
+    /// @param private_key The private key of the validator
+    /// @param is_open Whether the validator accepts new delegators
+    /// @param priority_fee_microcredits {bigint} The amount of microcredits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param set_state_proving_key (optional) Provide a proving key to use for the set_validator_state function
+    /// @param set_state_verifying_key (optional) Provide a verifying key to use for the set_validator_state function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildSetValidatorStateTransactionMicrocredits)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn set_validator_state_microcredits(
+        private_key: &PrivateKey,
+        is_open: bool,
+        priority_fee_microcredits: u64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        set_state_proving_key: Option<ProvingKey>,
+        set_state_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        log("Executing set_validator_state");
+        let inputs = Array::new();
+        inputs.push(&JsValue::from_str(&is_open.to_string()));
+        Self::execute_credits_function(
+            private_key,
+            "set_validator_state",
+            inputs,
+            priority_fee_microcredits,
+            fee_record,
+            url,
+            set_state_proving_key,
+            set_state_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+        )
+        .await
+    }
+
+    /// Unbond all credits bonded by a delegator to the validator of the sender, e.g. to remove a
+    /// delegator from a closed validator
+    ///
+    /// @deprecated Converting floating point credits to microcredits is lossy, use
+    /// buildUnbondDelegatorAsValidatorTransactionMicrocredits instead
+    /// @param private_key The private key of the validator
+    /// @param delegator The address of the delegator to unbond
+    /// @param priority_fee The amount of credits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param unbond_proving_key (optional) Provide a proving key to use for the unbond_delegator_as_validator function
+    /// @param unbond_verifying_key (optional) Provide a verifying key to use for the unbond_delegator_as_validator function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildUnbondDelegatorAsValidatorTransaction)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn unbond_delegator_as_validator(
+        private_key: &PrivateKey,
+        delegator: &str,
+        priority_fee: f64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        unbond_proving_key: Option<ProvingKey>,
+        unbond_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        Self::unbond_delegator_as_validator_microcredits(
+            private_key,
+            delegator,
+            Self::credits_to_microcredits(priority_fee),
+            fee_record,
+            url,
+            unbond_proving_key,
+            unbond_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+        )
+        .await
+    }
+
+    /// Unbond all credits bonded by a delegator to the validator of the sender, e.g. to remove a
+    /// delegator from a closed validator
+    ///
+    /// @param private_key The private key of the validator
+    /// @param delegator The address of the delegator to unbond
+    /// @param priority_fee_microcredits {bigint} The amount of microcredits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param unbond_proving_key (optional) Provide a proving key to use for the unbond_delegator_as_validator function
+    /// @param unbond_verifying_key (optional) Provide a verifying key to use for the unbond_delegator_as_validator function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildUnbondDelegatorAsValidatorTransactionMicrocredits)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn unbond_delegator_as_validator_microcredits(
+        private_key: &PrivateKey,
+        delegator: &str,
+        priority_fee_microcredits: u64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        unbond_proving_key: Option<ProvingKey>,
+        unbond_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        log("Executing unbond_delegator_as_validator");
+        let inputs = Array::new();
+        inputs.push(&JsValue::from_str(delegator));
+        Self::execute_credits_function(
+            private_key,
+            "unbond_delegator_as_validator",
+            inputs,
+            priority_fee_microcredits,
+            fee_record,
+            url,
+            unbond_proving_key,
+            unbond_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+        )
+        .await
+    }
+}
+
+impl ProgramManager {
+    /// Check that the credits.aleo program of this network version defines a function
+    pub(crate) fn ensure_credits_function(function: &str) -> Result<(), String> {
+        let identifier = IdentifierNative::from_str(function).map_err(|e| e.to_string())?;
+        if !ProgramNative::credits().map_err(|e| e.to_string())?.contains_function(&identifier) {
+            return Err(format!("{function} is not supported by the credits.aleo program of this network"));
+        }
+        Ok(())
+    }
+
+    /// Validate that an amount paid from the public balance is greater than zero
+    pub(crate) fn validate_public_amount(microcredits: u64) -> Result<u64, String> {
+        if microcredits == 0 {
+            return Err("Amount must be greater than zero".to_string());
+        }
+        Ok(microcredits)
+    }
+
+    /// Execute a credits.aleo function and pay the fee for it, privately if a fee record is given
+    /// and publicly otherwise
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn execute_credits_function(
+        private_key: &PrivateKey,
+        function: &str,
+        inputs: Array,
+        priority_fee_microcredits: u64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        proving_key: Option<ProvingKey>,
+        verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        Self::ensure_credits_function(function)?;
+        let priority_fee = match &fee_record {
+            Some(fee_record) => Self::validate_microcredits(priority_fee_microcredits, fee_record, true)?,
+            None => priority_fee_microcredits,
+        };
+
+        let program = ProgramNative::credits().unwrap().to_string();
+        let rng = &mut StdRng::from_entropy();
+        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let process = &mut process_native;
+
+        log(&format!("Executing credits.aleo/{function}"));
+        let (_, mut trace) = execute_program!(
+            process,
+            process_inputs!(inputs),
+            &program,
+            function,
+            private_key,
+            proving_key,
+            verifying_key,
+            rng
+        );
+
+        log("Preparing the inclusion proof for the execution");
+        let query = Self::query(url, None);
+        trace.prepare_async(query).await.map_err(|err| err.to_string())?;
+
+        let program =
+            ProgramNative::from_str(&program).map_err(|_| "The program ID provided was invalid".to_string())?;
+        let locator = program.id().to_string().add("/").add(function);
+        let execution = crate::with_thread_budget("proving", || trace.prove_execution::<CurrentAleo, _>(&locator, rng))
+            .map_err(|e| e.to_string())?;
+        let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
+
+        log("Verifying the execution");
+        process.verify_execution(&execution).map_err(|err| err.to_string())?;
+
+        // Get the storage cost in bytes and the finalize cost in microcredits of the execution
+        let storage_cost = execution.size_in_bytes().map_err(|e| e.to_string())?;
+        let finalize_cost = execution_finalize_cost(process, &execution)?;
+        let minimum_fee_cost = finalize_cost + storage_cost;
+
+        log("Executing the fee");
+        let fee = execute_fee!(
+            process,
+            private_key,
+            fee_record,
+            minimum_fee_cost,
+            priority_fee,
+            url,
+            fee_proving_key,
+            fee_verifying_key,
+            execution_id,
+            rng
+        );
+
+        log(&format!("Creating execution transaction for credits.aleo/{function}"));
+        let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
+        Ok(Transaction::from(transaction))
+    }
+}
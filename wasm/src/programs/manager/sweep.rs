@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{log, record::RecordFinder, types::ProgramNative, PrivateKey};
+
+use js_sys::Array;
+use wasm_bindgen::JsValue;
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Build the transactions which move the entire balance of an account to a recipient. Each
+    /// unspent credits record found in the recent blocks is sent in full with a private transfer and
+    /// the remaining public balance is sent with a public transfer. The fees of all transactions are
+    /// paid from the public balance, and the public transfer sends what is left after its own fee
+    /// and the actual fees of the record transfers, so nothing remains in the account.
+    ///
+    /// Proving keys are requested from the key provider set with `setKeyProvider` if one is set.
+    ///
+    /// @param private_key The private key of the account to sweep
+    /// @param recipient The address to send the balance to
+    /// @param url The url of the Aleo network node to send the transactions to
+    /// @param priority_fee The amount of credits to pay as a priority fee for each transaction
+    /// @param blocks_to_scan (optional) Number of recent blocks to search for unspent records
+    /// @returns {Array<Transaction> | Error} The transactions to broadcast
+    #[wasm_bindgen(js_name = buildSweepTransactions)]
+    pub async fn sweep(
+        private_key: &PrivateKey,
+        recipient: &str,
+        url: &str,
+        priority_fee: f64,
+        blocks_to_scan: Option<u32>,
+    ) -> Result<Array, String> {
+        log("Sweeping the balance of the account");
+        let priority_fee_microcredits = Self::credits_to_microcredits(priority_fee);
+
+        let records = RecordFinder::new(private_key, url, blocks_to_scan).unspent_credits_records().await?;
+        let records: Vec<_> = records.into_iter().filter(|record| record.microcredits() > 0).collect();
+        let address = private_key.to_address().to_string();
+        let public_balance = Self::public_balance(url, &address).await?;
+        log(&format!("Sweeping {} records and a public balance of {public_balance} microcredits", records.len()));
+
+        let transactions = Array::new();
+        let record_count = records.len() as u64;
+        let mut record_fees = 0u64;
+        for record in records {
+            let microcredits = record.microcredits();
+            let (function, inputs) = Self::transfer_inputs("private", Some(record), recipient, microcredits)?;
+            let transaction = Self::execute_credits_function(
+                private_key,
+                function,
+                inputs,
+                priority_fee_microcredits,
+                None,
+                url,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await?;
+            let fee = transaction.fee_amount()?;
+            // Every record transfer costs the same, so stop before proving the others if the public
+            // balance can't pay for all of them.
+            if transactions.length() == 0 && fee.saturating_mul(record_count) > public_balance {
+                return Err(format!(
+                    "The public balance of {public_balance} microcredits does not cover the fees of {fee} microcredits for sweeping each of {record_count} records"
+                ));
+            }
+            record_fees = record_fees.saturating_add(fee);
+            transactions.push(&JsValue::from(transaction));
+        }
+
+        let remaining = public_balance.saturating_sub(record_fees);
+        if remaining > 0 {
+            // The cost of a public transfer doesn't depend on its amount, so estimate it with the
+            // remaining balance before sending what is left after the fee.
+            let credits = ProgramNative::credits().map_err(|e| e.to_string())?.to_string();
+            let (function, inputs) = Self::transfer_inputs("public", None, recipient, remaining)?;
+            let fee = Self::estimate_execution_fee(private_key, &credits, function, inputs, url, None, None, None)
+                .await?
+                .saturating_add(priority_fee_microcredits);
+            if remaining > fee {
+                let public_amount = remaining - fee;
+                log(&format!("Sweeping {public_amount} public microcredits with a fee of {fee} microcredits"));
+                let (function, inputs) = Self::transfer_inputs("public", None, recipient, public_amount)?;
+                let transaction = Self::execute_credits_function(
+                    private_key,
+                    function,
+                    inputs,
+                    priority_fee_microcredits,
+                    None,
+                    url,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+                transactions.push(&JsValue::from(transaction));
+            }
+        }
+        if transactions.length() == 0 {
+            return Err("There is no balance to sweep".to_string());
+        }
+        Ok(transactions)
+    }
+}
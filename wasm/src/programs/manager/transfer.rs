@@ -21,7 +21,16 @@ use crate::{
     execute_program,
     log,
     process_inputs,
-    types::{CurrentAleo, IdentifierNative, ProcessNative, ProgramNative, RecordPlaintextNative, TransactionNative},
+    record::{RecordFinder, RecordSelection},
+    types::{
+        AddressNative,
+        CurrentAleo,
+        IdentifierNative,
+        ProcessNative,
+        ProgramNative,
+        RecordPlaintextNative,
+        TransactionNative,
+    },
     PrivateKey,
     RecordPlaintext,
     Transaction,
@@ -30,18 +39,21 @@ use crate::{
 use js_sys::Array;
 use rand::{rngs::StdRng, SeedableRng};
 use std::{ops::Add, str::FromStr};
+use wasm_bindgen::JsValue;
 
 #[wasm_bindgen]
 impl ProgramManager {
     /// Send credits from one Aleo account to another
     ///
+    /// @deprecated Converting floating point credits to microcredits is lossy, use
+    /// buildTransferTransactionMicrocredits instead
     /// @param private_key The private key of the sender
     /// @param amount_credits The amount of credits to send
     /// @param recipient The recipient of the transaction
-    /// @param transfer_type The type of the transfer (options: "private", "public", "private_to_public", "public_to_private")
+    /// @param transfer_type The type of the transfer (options: "private", "public", "public_as_signer", "private_to_public", "public_to_private")
     /// @param amount_record The record to fund the amount from
     /// @param fee_credits The amount of credits to pay as a fee
-    /// @param fee_record The record to spend the fee from
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
     /// @param url The url of the Aleo network node to send the transaction to
     /// @param transfer_verifying_key (optional) Provide a verifying key to use for the transfer
     /// function
@@ -64,15 +76,125 @@ impl ProgramManager {
         fee_proving_key: Option<ProvingKey>,
         fee_verifying_key: Option<VerifyingKey>,
     ) -> Result<Transaction, String> {
+        if amount_credits <= 0.0 {
+            return Err("Amount must be greater than zero to deploy or execute a program".to_string());
+        }
+        Self::transfer_microcredits(
+            private_key,
+            Self::credits_to_microcredits(amount_credits),
+            recipient,
+            transfer_type,
+            amount_record,
+            Self::credits_to_microcredits(priority_fee),
+            fee_record,
+            url,
+            transfer_proving_key,
+            transfer_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+        )
+        .await
+    }
+
+    /// Send microcredits from one Aleo account to another
+    ///
+    /// @param private_key The private key of the sender
+    /// @param amount_microcredits {bigint} The amount of microcredits to send
+    /// @param recipient The recipient of the transaction
+    /// @param transfer_type The type of the transfer (options: "private", "public", "public_as_signer", "private_to_public", "public_to_private")
+    /// @param amount_record The record to fund the amount from
+    /// @param priority_fee_microcredits {bigint} The amount of microcredits to pay as a priority fee
+    /// @param fee_record The record to spend the fee from, the fee is paid publicly if omitted
+    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param transfer_verifying_key (optional) Provide a verifying key to use for the transfer
+    /// function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = buildTransferTransactionMicrocredits)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn transfer_microcredits(
+        private_key: &PrivateKey,
+        amount_microcredits: u64,
+        recipient: &str,
+        transfer_type: &str,
+        amount_record: Option<RecordPlaintext>,
+        priority_fee_microcredits: u64,
+        fee_record: Option<RecordPlaintext>,
+        url: &str,
+        transfer_proving_key: Option<ProvingKey>,
+        transfer_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        let mut options = TransferOptions::new(private_key, amount_microcredits, recipient, transfer_type, url);
+        options.amount_record = amount_record;
+        options.priority_fee_microcredits = priority_fee_microcredits;
+        options.fee_record = fee_record;
+        options.transfer_proving_key = transfer_proving_key;
+        options.transfer_verifying_key = transfer_verifying_key;
+        options.fee_proving_key = fee_proving_key;
+        options.fee_verifying_key = fee_verifying_key;
+        Self::build_transfer(options).await
+    }
+
+    // Build a transfer transaction from its options
+    pub(crate) async fn build_transfer(options: TransferOptions) -> Result<Transaction, String> {
+        let TransferOptions {
+            private_key,
+            amount_microcredits,
+            recipient,
+            transfer_type,
+            url,
+            amount_record,
+            priority_fee_microcredits,
+            fee_record,
+            transfer_proving_key,
+            transfer_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+            find_records,
+            record_provider,
+            abort_signal,
+            on_progress,
+            offline_query,
+        } = options;
+        let (private_key, recipient, transfer_type, url) =
+            (&private_key, recipient.as_str(), transfer_type.as_str(), url.as_str());
         log("Executing transfer program");
-        let priority_fee = match &fee_record {
-            Some(fee_record) => Self::validate_amount(priority_fee, fee_record, true)?,
-            None => (priority_fee * 1_000_000.0) as u64,
+        let record_finder = RecordFinder::new(private_key, url, None);
+        let record_provider: Option<&dyn RecordProvider> = match (&record_provider, find_records) {
+            (Some(record_provider), _) => Some(record_provider),
+            (None, true) => Some(&record_finder),
+            _ => None,
         };
-        let amount_microcredits = match &amount_record {
-            Some(amount_record) => Self::validate_amount(amount_credits, amount_record, true)?,
-            None => (amount_credits * 1_000_000.0) as u64,
+        let (amount_record, fee_record) = match record_provider {
+            Some(record_provider) => {
+                Self::find_transfer_records(
+                    record_provider,
+                    transfer_type,
+                    amount_microcredits,
+                    amount_record,
+                    priority_fee_microcredits,
+                    fee_record,
+                )
+                .await?
+            }
+            None => (amount_record, fee_record),
         };
+        let priority_fee = match &fee_record {
+            Some(fee_record) => Self::validate_microcredits(priority_fee_microcredits, fee_record, true)?,
+            None => priority_fee_microcredits,
+        };
+        if amount_microcredits == 0 {
+            return Err("Amount must be greater than zero to deploy or execute a program".to_string());
+        }
+        if let Some(amount_record) = &amount_record {
+            Self::validate_microcredits(amount_microcredits, amount_record, false)?;
+        }
+
+        Self::check_aborted(abort_signal.as_ref()).await?;
+        Self::report_progress(on_progress.as_ref(), "keys", 5);
 
         log("Setup the program and inputs");
         let program = ProgramNative::credits().unwrap().to_string();
@@ -81,41 +203,8 @@ impl ProgramManager {
         log("Transfer Type is:");
         log(transfer_type);
 
-        let (transfer_type, inputs) = match transfer_type {
-            "private" | "transfer_private" | "transferPrivate" => {
-                if amount_record.is_none() {
-                    return Err("Amount record must be provided for private transfers".to_string());
-                }
-                let inputs = Array::new_with_length(3);
-                inputs.set(0u32, wasm_bindgen::JsValue::from_str(&amount_record.unwrap().to_string()));
-                inputs.set(1u32, wasm_bindgen::JsValue::from_str(recipient));
-                inputs.set(2u32, wasm_bindgen::JsValue::from_str(&amount_microcredits.to_string().add("u64")));
-                ("transfer_private", inputs)
-            }
-            "private_to_public" | "privateToPublic" | "transfer_private_to_public" | "transferPrivateToPublic" => {
-                if amount_record.is_none() {
-                    return Err("Amount record must be provided for private transfers".to_string());
-                }
-                let inputs = Array::new_with_length(3);
-                inputs.set(0u32, wasm_bindgen::JsValue::from_str(&amount_record.unwrap().to_string()));
-                inputs.set(1u32, wasm_bindgen::JsValue::from_str(recipient));
-                inputs.set(2u32, wasm_bindgen::JsValue::from_str(&amount_microcredits.to_string().add("u64")));
-                ("transfer_private_to_public", inputs)
-            }
-            "public" | "transfer_public" | "transferPublic" => {
-                let inputs = Array::new_with_length(2);
-                inputs.set(0u32, wasm_bindgen::JsValue::from_str(recipient));
-                inputs.set(1u32, wasm_bindgen::JsValue::from_str(&amount_microcredits.to_string().add("u64")));
-                ("transfer_public", inputs)
-            }
-            "public_to_private" | "publicToPrivate" | "transfer_public_to_private" | "transferPublicToPrivate" => {
-                let inputs = Array::new_with_length(2);
-                inputs.set(0u32, wasm_bindgen::JsValue::from_str(recipient));
-                inputs.set(1u32, wasm_bindgen::JsValue::from_str(&amount_microcredits.to_string().add("u64")));
-                ("transfer_public_to_private", inputs)
-            }
-            _ => return Err("Invalid transfer type".to_string()),
-        };
+        let (transfer_type, inputs) =
+            Self::transfer_inputs(transfer_type, amount_record, recipient, amount_microcredits)?;
 
         let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
         let process = &mut process_native;
@@ -136,6 +225,8 @@ impl ProgramManager {
                 .map_err(|e| e.to_string())?;
         }
 
+        Self::report_progress(on_progress.as_ref(), "synthesis", 15);
+
         log("Executing transfer function");
         let (_, mut trace) = execute_program!(
             process,
@@ -148,20 +239,25 @@ impl ProgramManager {
             rng
         );
 
+        Self::check_aborted(abort_signal.as_ref()).await?;
+        Self::report_progress(on_progress.as_ref(), "inclusion", 40);
+
         log("Preparing the inclusion proof for the transfer execution");
-        let query = QueryNative::from(url);
-        trace.prepare_async(query).await.map_err(|err| err.to_string())?;
+        trace.prepare_async(Self::query(url, offline_query.clone())).await.map_err(|err| err.to_string())?;
+
+        Self::check_aborted(abort_signal.as_ref()).await?;
+        Self::report_progress(on_progress.as_ref(), "proving", 50);
 
         let program = ProgramNative::from_str(&program).map_err(|_| "The program ID provided was invalid".to_string())?;
 
         let locator = program.id().to_string().add("/").add(&transfer_type);
         log(&format!("transfer trace prove_execution locator {locator}"));
         // Prove the execution and fee
-        let execution = trace
-            .prove_execution::<CurrentAleo, _>(&locator, rng)
+        let execution = crate::with_thread_budget("proving", || trace.prove_execution::<CurrentAleo, _>(&locator, rng))
             .map_err(|e| e.to_string())?;
         let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
 
+        Self::report_progress(on_progress.as_ref(), "verification", 70);
         log("Verifying the transfer execution");
         process.verify_execution(&execution).map_err(|err| err.to_string())?;
 
@@ -169,24 +265,13 @@ impl ProgramManager {
         let storage_cost = execution.size_in_bytes().map_err(|e| e.to_string())?;
 
         // Compute the finalize cost in microcredits.
-        let mut finalize_cost = 0u64;
-        // Iterate over the transitions to accumulate the finalize cost.
-        for transition in execution.transitions() {
-            // Retrieve the function name.
-            let function_name = transition.function_name();
-            // Retrieve the finalize cost.
-            let cost = match program.get_function(function_name).map_err(|e| e.to_string())?.finalize_logic() {
-                Some(finalize) => cost_in_microcredits(finalize).map_err(|e| e.to_string())?,
-                None => continue,
-            };
-            // Accumulate the finalize cost.
-            finalize_cost = finalize_cost
-                .checked_add(cost)
-                .ok_or("The finalize cost computation overflowed for an execution".to_string())?;
-        }
+        let finalize_cost = execution_finalize_cost(process, &execution)?;
         
         let minimum_fee_cost = finalize_cost + storage_cost;
 
+        Self::check_aborted(abort_signal.as_ref()).await?;
+        Self::report_progress(on_progress.as_ref(), "fee", 80);
+
         log("Executing the fee");
         let fee = execute_fee!(
             process,
@@ -198,11 +283,159 @@ impl ProgramManager {
             fee_proving_key,
             fee_verifying_key,
             execution_id,
-            rng
+            rng,
+            offline_query
         );
 
         log("Creating execution transaction for transfer");
         let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
+        Self::report_progress(on_progress.as_ref(), "done", 100);
         Ok(Transaction::from(transaction))
     }
+
+    // Find unspent records for the amount of a private transfer and the fee if they were omitted
+    async fn find_transfer_records(
+        record_provider: &dyn RecordProvider,
+        transfer_type: &str,
+        amount_microcredits: u64,
+        amount_record: Option<RecordPlaintext>,
+        priority_fee_microcredits: u64,
+        fee_record: Option<RecordPlaintext>,
+    ) -> Result<(Option<RecordPlaintext>, Option<RecordPlaintext>), String> {
+        let requires_amount_record = matches!(
+            transfer_type,
+            "private"
+                | "transfer_private"
+                | "transferPrivate"
+                | "private_to_public"
+                | "privateToPublic"
+                | "transfer_private_to_public"
+                | "transferPrivateToPublic"
+        );
+        let mut amounts = Vec::new();
+        if requires_amount_record && amount_record.is_none() {
+            amounts.push((amount_microcredits, RecordSelection::ExactFit));
+        }
+        // The fee is not known before the execution, so use the largest record to cover it.
+        if fee_record.is_none() {
+            amounts.push((priority_fee_microcredits, RecordSelection::LargestFirst));
+        }
+        if amounts.is_empty() {
+            return Ok((amount_record, fee_record));
+        }
+
+        log("Searching for unspent records to fund the transfer");
+        let mut records = record_provider.find_credits_records(&amounts).await?.into_iter();
+        let amount_record = match amount_record {
+            None if requires_amount_record => records.next(),
+            amount_record => amount_record,
+        };
+        Ok((amount_record, fee_record.or_else(|| records.next())))
+    }
+
+    /// Get the name of the credits.aleo function and the inputs for a transfer, rejecting
+    /// arguments that are inconsistent with the transfer type before any proving starts
+    pub(crate) fn transfer_inputs(
+        transfer_type: &str,
+        amount_record: Option<RecordPlaintext>,
+        recipient: &str,
+        amount_microcredits: u64,
+    ) -> Result<(&'static str, Array), String> {
+        // Map the transfer type to the credits.aleo function and whether it spends a record
+        let (function, private) = match transfer_type {
+            "private" | "transfer_private" | "transferPrivate" => ("transfer_private", true),
+            "private_to_public" | "privateToPublic" | "transfer_private_to_public" | "transferPrivateToPublic" => {
+                ("transfer_private_to_public", true)
+            }
+            "public" | "transfer_public" | "transferPublic" => ("transfer_public", false),
+            "public_as_signer" | "publicAsSigner" | "transfer_public_as_signer" | "transferPublicAsSigner" => {
+                // The function was added to credits.aleo after this network version, so check it exists.
+                Self::ensure_credits_function("transfer_public_as_signer")?;
+                ("transfer_public_as_signer", false)
+            }
+            "public_to_private" | "publicToPrivate" | "transfer_public_to_private" | "transferPublicToPrivate" => {
+                ("transfer_public_to_private", false)
+            }
+            _ => return Err("Invalid transfer type".to_string()),
+        };
+
+        if amount_microcredits == 0 {
+            return Err("Amount must be greater than zero".to_string());
+        }
+        AddressNative::from_str(recipient).map_err(|_| format!("Invalid recipient address: {recipient}"))?;
+
+        let recipient = JsValue::from_str(recipient);
+        let amount = JsValue::from_str(&format!("{amount_microcredits}u64"));
+        let inputs = match (private, amount_record) {
+            (true, Some(amount_record)) => {
+                Array::of3(&JsValue::from_str(&amount_record.to_string()), &recipient, &amount)
+            }
+            (true, None) => return Err("Amount record must be provided for private transfers".to_string()),
+            (false, None) => Array::of2(&recipient, &amount),
+            (false, Some(_)) => {
+                return Err("Amount record must not be provided for transfers funded by the public balance".to_string());
+            }
+        };
+        Ok((function, inputs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const RECIPIENT: &str = "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3";
+
+    const RECORD: &str = r"{
+  owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3.private,
+  microcredits: 1500000000000000u64.private,
+  _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public
+}";
+
+    fn inputs_to_strings(inputs: &Array) -> Vec<String> {
+        inputs.iter().map(|input| input.as_string().unwrap()).collect()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_private_transfer_inputs() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        for (transfer_type, expected) in
+            [("private", "transfer_private"), ("privateToPublic", "transfer_private_to_public")]
+        {
+            let (function, inputs) =
+                ProgramManager::transfer_inputs(transfer_type, Some(record.clone()), RECIPIENT, 100).unwrap();
+            assert_eq!(function, expected);
+            assert_eq!(inputs_to_strings(&inputs), vec![
+                RECORD.to_string(),
+                RECIPIENT.to_string(),
+                "100u64".to_string()
+            ]);
+
+            let error = ProgramManager::transfer_inputs(transfer_type, None, RECIPIENT, 100).unwrap_err();
+            assert_eq!(error, "Amount record must be provided for private transfers");
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_public_transfer_inputs() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        for (transfer_type, expected) in
+            [("public", "transfer_public"), ("public_to_private", "transfer_public_to_private")]
+        {
+            let (function, inputs) = ProgramManager::transfer_inputs(transfer_type, None, RECIPIENT, 100).unwrap();
+            assert_eq!(function, expected);
+            assert_eq!(inputs_to_strings(&inputs), vec![RECIPIENT.to_string(), "100u64".to_string()]);
+
+            assert!(ProgramManager::transfer_inputs(transfer_type, Some(record.clone()), RECIPIENT, 100).is_err());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_invalid_transfer_inputs() {
+        assert!(ProgramManager::transfer_inputs("unknown", None, RECIPIENT, 100).is_err());
+        assert!(ProgramManager::transfer_inputs("public", None, "aleo1invalid", 100).is_err());
+        assert!(ProgramManager::transfer_inputs("public", None, RECIPIENT, 0).is_err());
+    }
 }
@@ -0,0 +1,162 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{
+    log,
+    types::{CurrentAleo, ExecutionNative, FeeNative, TransactionNative},
+    Transaction,
+};
+
+use rand::{rngs::StdRng, SeedableRng};
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Verify the proofs of a transaction, e.g. one submitted by a user before relaying it to the
+    /// network
+    ///
+    /// Deployments are verified against the circuits synthesized from the deployed program and
+    /// executions against the verifying keys of the functions executed, which are taken from the
+    /// key cache or the key provider, or synthesized when neither has them. The fee is verified
+    /// for both. Programs executed by the transaction and the imports of a deployed program are
+    /// fetched from the node unless they are provided. This does not check that the state root of
+    /// the transaction or its records are valid on the network.
+    ///
+    /// @param transaction {Transaction} The transaction to verify
+    /// @param url {string} The url of the Aleo network node to fetch programs from
+    /// @param imports {Object | undefined} (optional) Programs used by the transaction in the form
+    /// of a javascript object where the keys are a string of the program name and the values are
+    /// a string representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
+    /// @returns {boolean | Error} True if the proofs of the transaction are valid, false if a proof
+    /// does not verify, or an error if the transaction could not be checked
+    #[wasm_bindgen(js_name = verifyTransaction)]
+    pub async fn verify_transaction(
+        transaction: &Transaction,
+        url: &str,
+        imports: Option<Object>,
+    ) -> Result<bool, String> {
+        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let process = &mut process_native;
+
+        let result = match TransactionNative::from(transaction.clone()) {
+            TransactionNative::Deploy(_, _, deployment, fee) => {
+                log("Check program imports are valid and add them to the process");
+                let imports = Self::fetch_imports(url, deployment.program(), imports).await?;
+                Self::resolve_imports(process, deployment.program(), Some(imports))?;
+                Self::ensure_fee_verifying_key(process, &fee).await?;
+
+                log("Verify the deployment against the synthesized circuits and its fee");
+                process
+                    .verify_deployment::<CurrentAleo, _>(&deployment, &mut StdRng::from_entropy())
+                    .and_then(|()| process.verify_fee(&fee, deployment.to_deployment_id()?))
+            }
+            TransactionNative::Execute(_, execution, fee) => {
+                log("Load the programs executed by the transaction and their verifying keys");
+                Self::load_execution_programs(process, &execution, url, imports.as_ref()).await?;
+                if let Some(fee) = &fee {
+                    Self::ensure_fee_verifying_key(process, fee).await?;
+                }
+
+                log("Verify the execution and its fee");
+                process.verify_execution(&execution).and_then(|()| match &fee {
+                    Some(fee) => process.verify_fee(fee, execution.to_execution_id()?),
+                    None => Ok(()),
+                })
+            }
+            TransactionNative::Fee(..) => {
+                return Err("Rejected fee transactions cannot be verified without the ledger".to_string());
+            }
+        };
+
+        Ok(verification_result(result, "Transaction"))
+    }
+}
+
+// Map the result of verifying a transaction or deployment to whether it is valid. The programs and
+// keys it is checked against are loaded before, so any error from the verification itself means
+// the transaction or deployment is invalid
+pub(crate) fn verification_result(result: anyhow::Result<()>, subject: &str) -> bool {
+    match result {
+        Ok(()) => true,
+        Err(error) => {
+            log(&format!("{subject} verification failed: {error:#}"));
+            false
+        }
+    }
+}
+
+impl ProgramManager {
+    // Make sure the process has the verifying key of the fee function paid with
+    async fn ensure_fee_verifying_key(process: &mut ProcessNative, fee: &FeeNative) -> Result<(), String> {
+        let transition = fee.transition();
+        Self::ensure_verifying_key(process, transition.program_id(), transition.function_name(), None).await
+    }
+
+    // Load the programs executed by an execution and the verifying keys of the functions executed
+    // into the process, taking programs from the imports provided or from the node
+    pub(crate) async fn load_execution_programs(
+        process: &mut ProcessNative,
+        execution: &ExecutionNative,
+        url: &str,
+        imports: Option<&Object>,
+    ) -> Result<(), String> {
+        for transition in execution.transitions() {
+            let program_id = transition.program_id();
+            if !process.contains_program(program_id) {
+                let program = Self::transition_program(url, &program_id.to_string(), imports).await?;
+                let program_imports = Self::fetch_imports(url, &program, imports.cloned()).await?;
+                Self::resolve_imports(process, &program, Some(program_imports))?;
+                process.add_program(&program).map_err(|err| err.to_string())?;
+            }
+            Self::ensure_verifying_key(process, program_id, transition.function_name(), None).await?;
+        }
+        Ok(())
+    }
+
+    // Get a program executed by a transaction from the imports provided or from the node
+    async fn transition_program(
+        url: &str,
+        program_id: &str,
+        imports: Option<&Object>,
+    ) -> Result<ProgramNative, String> {
+        let provided = imports.and_then(|imports| Reflect::get(imports, &program_id.into()).ok()?.as_string());
+        let program = match provided {
+            Some(program) => program,
+            None => Self::fetch_program(url, program_id).await?,
+        };
+        ProgramNative::from_str(&program).map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_verification_result() {
+        assert!(verification_result(Ok(()), "Transaction"));
+        assert!(!verification_result(Err(anyhow::anyhow!("Failed to verify proof")), "Transaction"));
+        let certificate = anyhow::anyhow!("The certificate for 'main' is invalid");
+        assert!(!verification_result(Err(certificate), "Deployment"));
+
+        // Structural failures of a tampered transaction are reported as invalid too.
+        let mismatch = anyhow::anyhow!("Transition ID mismatch in the execution");
+        assert!(!verification_result(Err(mismatch), "Transaction"));
+    }
+}
@@ -0,0 +1,516 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::types::{
+    CurrentNetwork,
+    FieldNative,
+    FromBytes,
+    LiteralNative,
+    Network,
+    PlaintextNative,
+    ToBits,
+    ToBytes,
+    ToFields,
+};
+
+use js_sys::Array;
+use serde_json::{json, Value};
+use std::{fmt, str::FromStr};
+use wasm_bindgen::prelude::*;
+
+/// Maximum depth of a Merkle tree, which bounds the leaf index of a proof to 32 bits
+const MAX_DEPTH: u8 = 32;
+
+/// Hash functions a Merkle tree can hash its leaves and nodes with. They hash exactly like the hash
+/// functions of the same name, taking the x-coordinate of the hash as a field
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MerkleHasher {
+    Bhp256,
+    Bhp512,
+    Bhp768,
+    Bhp1024,
+    Poseidon2,
+    Poseidon4,
+    Poseidon8,
+}
+
+impl MerkleHasher {
+    const ALL: [MerkleHasher; 7] = [
+        MerkleHasher::Bhp256,
+        MerkleHasher::Bhp512,
+        MerkleHasher::Bhp768,
+        MerkleHasher::Bhp1024,
+        MerkleHasher::Poseidon2,
+        MerkleHasher::Poseidon4,
+        MerkleHasher::Poseidon8,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            MerkleHasher::Bhp256 => "bhp256",
+            MerkleHasher::Bhp512 => "bhp512",
+            MerkleHasher::Bhp768 => "bhp768",
+            MerkleHasher::Bhp1024 => "bhp1024",
+            MerkleHasher::Poseidon2 => "poseidon2",
+            MerkleHasher::Poseidon4 => "poseidon4",
+            MerkleHasher::Poseidon8 => "poseidon8",
+        }
+    }
+
+    fn from_name(name: &str) -> Result<Self, String> {
+        Self::ALL.into_iter().find(|hasher| hasher.name() == name).ok_or_else(|| {
+            format!("Invalid Merkle tree hasher '{name}', expected one of bhp256, bhp512, bhp768, bhp1024, poseidon2, poseidon4 or poseidon8")
+        })
+    }
+
+    fn id(self) -> u8 {
+        Self::ALL.iter().position(|hasher| *hasher == self).unwrap_or_default() as u8
+    }
+
+    fn from_id(id: u8) -> Result<Self, String> {
+        Self::ALL.get(id as usize).copied().ok_or_else(|| format!("Invalid Merkle tree hasher id {id}"))
+    }
+
+    // Hash the concatenation of the values
+    fn hash(self, values: &[PlaintextNative]) -> Result<FieldNative, String> {
+        let bits = || values.iter().flat_map(|value| value.to_bits_le()).collect::<Vec<_>>();
+        let fields = || {
+            let mut fields = vec![];
+            for value in values {
+                fields.extend(value.to_fields().map_err(|e| e.to_string())?);
+            }
+            Ok::<_, String>(fields)
+        };
+        let hash = match self {
+            MerkleHasher::Bhp256 => CurrentNetwork::hash_to_group_bhp256(&bits()),
+            MerkleHasher::Bhp512 => CurrentNetwork::hash_to_group_bhp512(&bits()),
+            MerkleHasher::Bhp768 => CurrentNetwork::hash_to_group_bhp768(&bits()),
+            MerkleHasher::Bhp1024 => CurrentNetwork::hash_to_group_bhp1024(&bits()),
+            MerkleHasher::Poseidon2 => CurrentNetwork::hash_to_group_psd2(&fields()?),
+            MerkleHasher::Poseidon4 => CurrentNetwork::hash_to_group_psd4(&fields()?),
+            MerkleHasher::Poseidon8 => CurrentNetwork::hash_to_group_psd8(&fields()?),
+        };
+        Ok(hash.map_err(|e| e.to_string())?.to_x_coordinate())
+    }
+
+    fn hash_leaf(self, leaf: &str) -> Result<FieldNative, String> {
+        let leaf = PlaintextNative::from_str(leaf).map_err(|_| format!("Invalid Merkle tree leaf '{leaf}'"))?;
+        self.hash(&[leaf])
+    }
+
+    fn hash_children(self, left: FieldNative, right: FieldNative) -> Result<FieldNative, String> {
+        self.hash(&[LiteralNative::Field(left).into(), LiteralNative::Field(right).into()])
+    }
+}
+
+/// A Merkle tree of a fixed depth over Aleo values. Each leaf is hashed with the leaf hasher and
+/// each pair of children is hashed together with the path hasher, exactly like hashing the value
+/// or the array `[left, right]` of fields with the hash function of the same name into a field.
+/// Leaves past the last one are the zero field
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleTree {
+    depth: u8,
+    leaf_hasher: MerkleHasher,
+    path_hasher: MerkleHasher,
+    // Hashes of the non empty nodes of each level, starting with the leaves and ending with the root
+    levels: Vec<Vec<FieldNative>>,
+    // Hash of an empty subtree at each level
+    empty: Vec<FieldNative>,
+}
+
+#[wasm_bindgen]
+impl MerkleTree {
+    /// Build a Merkle tree over Aleo values
+    ///
+    /// @param {Array<string>} leaves String representations of the Aleo values of the leaves
+    /// @param {number} depth Depth of the tree between 1 and 32, the tree holds up to 2^depth leaves
+    /// @param {string} leaf_hasher Hash function of the leaves: "bhp256", "bhp512", "bhp768", "bhp1024",
+    /// "poseidon2", "poseidon4" or "poseidon8"
+    /// @param {string} path_hasher Hash function of the nodes, one of the same hash functions
+    /// @returns {MerkleTree | Error}
+    #[wasm_bindgen(constructor)]
+    pub fn new(leaves: Array, depth: u8, leaf_hasher: &str, path_hasher: &str) -> Result<MerkleTree, String> {
+        let leaf_hasher = MerkleHasher::from_name(leaf_hasher)?;
+        let path_hasher = MerkleHasher::from_name(path_hasher)?;
+        let leaves = (0..leaves.length())
+            .map(|index| {
+                let leaf = leaves.get(index).as_string().ok_or_else(|| format!("Invalid leaf at index {index}"))?;
+                leaf_hasher.hash_leaf(&leaf)
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        Self::from_leaf_hashes(depth, leaf_hasher, path_hasher, leaves)
+    }
+
+    /// Get the root of the tree
+    ///
+    /// @returns {string} The root as a field
+    pub fn root(&self) -> String {
+        self.root_hash().to_string()
+    }
+
+    /// Get the depth of the tree
+    ///
+    /// @returns {number} The depth of the tree
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Get the number of leaves in the tree
+    ///
+    /// @returns {number} The number of leaves
+    #[wasm_bindgen(js_name = leafCount)]
+    pub fn leaf_count(&self) -> u32 {
+        self.levels[0].len() as u32
+    }
+
+    /// Create a proof that a leaf is in the tree
+    ///
+    /// @param {number} index Index of the leaf
+    /// @returns {MerkleProof | Error} The siblings on the path from the leaf to the root
+    pub fn prove(&self, index: u32) -> Result<MerkleProof, String> {
+        if index >= self.leaf_count() {
+            return Err(format!("Leaf index {index} is out of bounds for {} leaves", self.leaf_count()));
+        }
+        let siblings = (0..self.depth as usize)
+            .map(|level| {
+                let sibling = (index as usize >> level) ^ 1;
+                self.levels[level].get(sibling).copied().unwrap_or(self.empty[level])
+            })
+            .collect();
+        Ok(MerkleProof { index, siblings })
+    }
+
+    /// Verify that a leaf is in a tree with the given root, using the hashers of this tree
+    ///
+    /// @param {string} root The root of the tree as a field
+    /// @param {MerkleProof} proof The proof of the leaf
+    /// @param {string} leaf String representation of the Aleo value of the leaf
+    /// @returns {boolean | Error} True if the proof is valid for the leaf and the root
+    pub fn verify(&self, root: &str, proof: &MerkleProof, leaf: &str) -> Result<bool, String> {
+        let root = FieldNative::from_str(root).map_err(|_| format!("Invalid Merkle tree root '{root}'"))?;
+        if proof.siblings.len() != self.depth as usize {
+            return Ok(false);
+        }
+        let mut hash = self.leaf_hasher.hash_leaf(leaf)?;
+        for (level, sibling) in proof.siblings.iter().enumerate() {
+            hash = match (proof.index >> level) & 1 {
+                0 => self.path_hasher.hash_children(hash, *sibling)?,
+                _ => self.path_hasher.hash_children(*sibling, hash)?,
+            };
+        }
+        Ok(hash == root)
+    }
+
+    /// Get the tree as a byte array
+    ///
+    /// @returns {Uint8Array | Error} The depth, the hashers and the leaf hashes of the tree
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        let mut bytes = vec![self.depth, self.leaf_hasher.id(), self.path_hasher.id()];
+        self.leaf_count().write_le(&mut bytes).map_err(|e| e.to_string())?;
+        for leaf in &self.levels[0] {
+            leaf.write_le(&mut bytes).map_err(|e| e.to_string())?;
+        }
+        Ok(bytes)
+    }
+
+    /// Create a tree from the byte array returned by toBytes
+    ///
+    /// @param {Uint8Array} bytes Byte representation of the tree
+    /// @returns {MerkleTree | Error}
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<MerkleTree, String> {
+        let mut reader = bytes;
+        let mut read_u8 = || u8::read_le(&mut reader).map_err(|_| "Invalid Merkle tree bytes".to_string());
+        let (depth, leaf_hasher, path_hasher) = (read_u8()?, read_u8()?, read_u8()?);
+        let leaf_count = u32::read_le(&mut reader).map_err(|_| "Invalid Merkle tree bytes".to_string())?;
+        if (leaf_count as u64) > 1 << depth.min(MAX_DEPTH) {
+            return Err("Invalid Merkle tree bytes".to_string());
+        }
+        let leaves = (0..leaf_count)
+            .map(|_| FieldNative::read_le(&mut reader).map_err(|_| "Invalid Merkle tree leaf bytes".to_string()))
+            .collect::<Result<Vec<_>, String>>()?;
+        if !reader.is_empty() {
+            return Err("Invalid Merkle tree bytes".to_string());
+        }
+        Self::from_leaf_hashes(depth, MerkleHasher::from_id(leaf_hasher)?, MerkleHasher::from_id(path_hasher)?, leaves)
+    }
+
+    /// Get the tree as a JSON string
+    ///
+    /// @returns {string} JSON string of the form \{ "depth": number, "leaf_hasher": string,
+    /// "path_hasher": string, "leaves": Array<string> \} where the leaves are the leaf hashes
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        json!({
+            "depth": self.depth,
+            "leaf_hasher": self.leaf_hasher.name(),
+            "path_hasher": self.path_hasher.name(),
+            "leaves": self.levels[0].iter().map(|leaf| leaf.to_string()).collect::<Vec<_>>(),
+        })
+        .to_string()
+    }
+
+    /// Create a tree from the JSON string returned by toString
+    ///
+    /// @param {string} tree JSON string of the tree
+    /// @returns {MerkleTree | Error}
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(tree: &str) -> Result<MerkleTree, String> {
+        MerkleTree::from_str(tree)
+    }
+}
+
+impl MerkleTree {
+    fn from_leaf_hashes(
+        depth: u8,
+        leaf_hasher: MerkleHasher,
+        path_hasher: MerkleHasher,
+        leaves: Vec<FieldNative>,
+    ) -> Result<Self, String> {
+        if !(1..=MAX_DEPTH).contains(&depth) {
+            return Err(format!("Merkle tree depth must be between 1 and {MAX_DEPTH}"));
+        }
+        if leaves.len() as u64 > 1 << depth {
+            return Err(format!("A Merkle tree of depth {depth} holds at most {} leaves", 1u64 << depth));
+        }
+
+        let mut empty = vec![FieldNative::from_u16(0)];
+        for level in 0..depth as usize {
+            empty.push(path_hasher.hash_children(empty[level], empty[level])?);
+        }
+
+        let mut levels = vec![leaves];
+        for level in 0..depth as usize {
+            let parents = levels[level]
+                .chunks(2)
+                .map(|children| path_hasher.hash_children(children[0], *children.get(1).unwrap_or(&empty[level])))
+                .collect::<Result<Vec<_>, String>>()?;
+            levels.push(parents);
+        }
+        Ok(Self { depth, leaf_hasher, path_hasher, levels, empty })
+    }
+
+    fn root_hash(&self) -> FieldNative {
+        self.levels[self.depth as usize].first().copied().unwrap_or(self.empty[self.depth as usize])
+    }
+}
+
+impl fmt::Display for MerkleTree {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_string())
+    }
+}
+
+impl FromStr for MerkleTree {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = serde_json::from_str::<Value>(s).map_err(|e| format!("Invalid Merkle tree: {e}"))?;
+        let field = |name: &str| json.get(name).ok_or_else(|| format!("The Merkle tree is missing the {name}"));
+        let depth = field("depth")?.as_u64().and_then(|depth| u8::try_from(depth).ok());
+        let hasher = |name: &str| {
+            MerkleHasher::from_name(field(name)?.as_str().ok_or_else(|| format!("Invalid Merkle tree {name}"))?)
+        };
+        let leaves = field("leaves")?
+            .as_array()
+            .ok_or_else(|| "Invalid Merkle tree leaves".to_string())?
+            .iter()
+            .map(|leaf| {
+                leaf.as_str()
+                    .and_then(|leaf| FieldNative::from_str(leaf).ok())
+                    .ok_or_else(|| "Invalid Merkle tree leaf hash".to_string())
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        Self::from_leaf_hashes(
+            depth.ok_or_else(|| "Invalid Merkle tree depth".to_string())?,
+            hasher("leaf_hasher")?,
+            hasher("path_hasher")?,
+            leaves,
+        )
+    }
+}
+
+/// Proof that a leaf is in a Merkle tree, made of the siblings on the path from the leaf to the root
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleProof {
+    index: u32,
+    siblings: Vec<FieldNative>,
+}
+
+#[wasm_bindgen]
+impl MerkleProof {
+    /// Get the index of the leaf
+    ///
+    /// @returns {number} The index of the leaf
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Get the siblings on the path from the leaf to the root
+    ///
+    /// @returns {Array<string>} The siblings as fields, starting at the leaves
+    pub fn siblings(&self) -> Array {
+        self.siblings.iter().map(|sibling| JsValue::from_str(&sibling.to_string())).collect()
+    }
+
+    /// Get the proof as a byte array
+    ///
+    /// @returns {Uint8Array | Error} The index and the siblings of the proof
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        let mut bytes = vec![];
+        self.index.write_le(&mut bytes).map_err(|e| e.to_string())?;
+        (self.siblings.len() as u8).write_le(&mut bytes).map_err(|e| e.to_string())?;
+        for sibling in &self.siblings {
+            sibling.write_le(&mut bytes).map_err(|e| e.to_string())?;
+        }
+        Ok(bytes)
+    }
+
+    /// Create a proof from the byte array returned by toBytes
+    ///
+    /// @param {Uint8Array} bytes Byte representation of the proof
+    /// @returns {MerkleProof | Error}
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<MerkleProof, String> {
+        let mut reader = bytes;
+        let index = u32::read_le(&mut reader).map_err(|_| "Invalid Merkle proof bytes".to_string())?;
+        let length = u8::read_le(&mut reader).map_err(|_| "Invalid Merkle proof bytes".to_string())?;
+        if length > MAX_DEPTH {
+            return Err("Invalid Merkle proof bytes".to_string());
+        }
+        let siblings = (0..length)
+            .map(|_| FieldNative::read_le(&mut reader).map_err(|_| "Invalid Merkle proof sibling bytes".to_string()))
+            .collect::<Result<Vec<_>, String>>()?;
+        if !reader.is_empty() {
+            return Err("Invalid Merkle proof bytes".to_string());
+        }
+        Ok(MerkleProof { index, siblings })
+    }
+
+    /// Get the proof as a JSON string
+    ///
+    /// @returns {string} JSON string of the form \{ "index": number, "siblings": Array<string> \}
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        json!({
+            "index": self.index,
+            "siblings": self.siblings.iter().map(|sibling| sibling.to_string()).collect::<Vec<_>>(),
+        })
+        .to_string()
+    }
+
+    /// Create a proof from the JSON string returned by toString
+    ///
+    /// @param {string} proof JSON string of the proof
+    /// @returns {MerkleProof | Error}
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(proof: &str) -> Result<MerkleProof, String> {
+        MerkleProof::from_str(proof)
+    }
+}
+
+impl fmt::Display for MerkleProof {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_string())
+    }
+}
+
+impl FromStr for MerkleProof {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = serde_json::from_str::<Value>(s).map_err(|e| format!("Invalid Merkle proof: {e}"))?;
+        let index = json.get("index").and_then(|index| index.as_u64()).and_then(|index| u32::try_from(index).ok());
+        let siblings = json
+            .get("siblings")
+            .and_then(|siblings| siblings.as_array())
+            .ok_or_else(|| "Invalid Merkle proof siblings".to_string())?
+            .iter()
+            .map(|sibling| {
+                sibling
+                    .as_str()
+                    .and_then(|sibling| FieldNative::from_str(sibling).ok())
+                    .ok_or_else(|| "Invalid Merkle proof sibling".to_string())
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok(Self { index: index.ok_or_else(|| "Invalid Merkle proof index".to_string())?, siblings })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hash_bhp256, hash_poseidon2};
+
+    use wasm_bindgen_test::*;
+
+    const LEAVES: [&str; 3] = ["1u64", "2u64", "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3"];
+
+    fn leaves() -> Array {
+        LEAVES.iter().map(|leaf| JsValue::from_str(leaf)).collect()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_merkle_tree_matches_hash_functions() {
+        let tree = MerkleTree::new(leaves(), 2, "bhp256", "poseidon2").unwrap();
+        let node = |left: String, right: String| {
+            hash_poseidon2(Array::of2(&left.into(), &right.into()).into(), "field").unwrap()
+        };
+        let leaf = |leaf: &str| hash_bhp256(leaf.into(), "field").unwrap();
+        let left = node(leaf(LEAVES[0]), leaf(LEAVES[1]));
+        let right = node(leaf(LEAVES[2]), "0field".to_string());
+        assert_eq!(tree.root(), node(left, right));
+        assert_eq!(tree.leaf_count(), 3);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_merkle_tree_prove_and_verify() {
+        let tree = MerkleTree::new(leaves(), 3, "poseidon4", "bhp512").unwrap();
+        let root = tree.root();
+        for (index, leaf) in LEAVES.iter().enumerate() {
+            let proof = tree.prove(index as u32).unwrap();
+            assert_eq!(proof.siblings().length(), 3);
+            assert!(tree.verify(&root, &proof, leaf).unwrap());
+            assert!(!tree.verify(&root, &proof, "3u64").unwrap());
+        }
+        assert!(tree.prove(3).is_err());
+
+        let proof = tree.prove(1).unwrap();
+        assert_eq!(MerkleProof::from_string(&proof.to_string()).unwrap(), proof);
+        assert_eq!(MerkleProof::from_bytes(&proof.to_bytes().unwrap()).unwrap(), proof);
+        assert_eq!(MerkleTree::from_string(&tree.to_string()).unwrap(), tree);
+        assert_eq!(MerkleTree::from_bytes(&tree.to_bytes().unwrap()).unwrap(), tree);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_merkle_tree_invalid_arguments() {
+        assert!(MerkleTree::new(leaves(), 1, "bhp256", "bhp256").is_err());
+        assert!(MerkleTree::new(leaves(), 0, "bhp256", "bhp256").is_err());
+        assert!(MerkleTree::new(leaves(), 33, "bhp256", "bhp256").is_err());
+        assert!(MerkleTree::new(leaves(), 2, "sha3_256", "bhp256").is_err());
+        assert!(MerkleTree::new(Array::of1(&"not a value".into()), 2, "bhp256", "bhp256").is_err());
+
+        let tree = MerkleTree::new(Array::new(), 32, "poseidon2", "poseidon2").unwrap();
+        assert_eq!(tree.leaf_count(), 0);
+        assert!(tree.prove(0).is_err());
+    }
+}
@@ -16,20 +16,41 @@
 
 mod macros;
 
+pub mod authorization;
+pub use authorization::*;
+
+pub mod byte_buffer;
+pub use byte_buffer::*;
+
+pub mod diff;
+pub use diff::*;
+
+pub mod hash;
+pub use hash::*;
+
 pub mod key_pair;
 pub use key_pair::*;
 
+pub mod merkle_tree;
+pub use merkle_tree::*;
+
 #[cfg(feature = "browser")]
 pub mod manager;
 #[cfg(feature = "browser")]
 pub use manager::*;
 
+pub mod offline_query;
+pub use offline_query::*;
+
 pub mod response;
 pub use response::*;
 
 pub mod program;
 pub use program::*;
 
+pub mod validation;
+pub use validation::*;
+
 pub mod execution;
 pub use execution::*;
 
@@ -39,5 +60,11 @@ pub use proving_key::*;
 pub mod transaction;
 pub use transaction::*;
 
+pub mod transition;
+pub use transition::*;
+
 pub mod verifying_key;
 pub use verifying_key::*;
+
+pub mod wallet_request;
+pub use wallet_request::*;
@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::types::{CurrentNetwork, FieldNative, QueryTrait, StatePathNative, StateRootNative};
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use indexmap::IndexMap;
+use serde_json::{json, Map, Value};
+use std::{fmt, str::FromStr};
+use wasm_bindgen::prelude::*;
+
+/// State of the ledger provided by the caller to build transactions without a network connection
+///
+/// The query holds the global state root the transaction is built against and the state paths
+/// of the record commitments being spent, which can be fetched ahead of time from the
+/// `/testnet3/stateRoot/latest` and `/testnet3/statePath/{commitment}` endpoints of a node.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct OfflineQuery {
+    state_root: StateRootNative,
+    state_paths: IndexMap<FieldNative, StatePathNative>,
+}
+
+#[wasm_bindgen]
+impl OfflineQuery {
+    /// Create an offline query for a global state root
+    ///
+    /// @param {string} state_root The global state root the transaction is built against
+    /// @returns {OfflineQuery | Error}
+    #[wasm_bindgen(constructor)]
+    pub fn new(state_root: &str) -> Result<OfflineQuery, String> {
+        let state_root = StateRootNative::from_str(state_root).map_err(|e| format!("Invalid state root: {e}"))?;
+        Ok(Self { state_root, state_paths: IndexMap::new() })
+    }
+
+    /// Add the state path of a record commitment being spent
+    ///
+    /// @param {string} commitment The commitment of the record
+    /// @param {string} state_path The state path of the commitment
+    #[wasm_bindgen(js_name = addStatePath)]
+    pub fn add_state_path(&mut self, commitment: &str, state_path: &str) -> Result<(), String> {
+        let commitment = FieldNative::from_str(commitment).map_err(|e| format!("Invalid commitment: {e}"))?;
+        let state_path = StatePathNative::from_str(state_path).map_err(|e| format!("Invalid state path: {e}"))?;
+        self.state_paths.insert(commitment, state_path);
+        Ok(())
+    }
+
+    /// Get the offline query as a JSON string
+    ///
+    /// @returns {string} JSON string of the form \{ "state_root": string, "state_paths": \{ [commitment]: string \} \}
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        let state_paths = self
+            .state_paths
+            .iter()
+            .map(|(commitment, state_path)| (commitment.to_string(), Value::String(state_path.to_string())))
+            .collect::<Map<_, _>>();
+        json!({ "state_root": self.state_root.to_string(), "state_paths": state_paths }).to_string()
+    }
+
+    /// Create an offline query from the JSON string returned by toString
+    ///
+    /// @param {string} offline_query JSON string of the offline query
+    /// @returns {OfflineQuery | Error}
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(offline_query: &str) -> Result<OfflineQuery, String> {
+        OfflineQuery::from_str(offline_query)
+    }
+}
+
+impl fmt::Display for OfflineQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_string())
+    }
+}
+
+impl FromStr for OfflineQuery {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = serde_json::from_str::<Value>(s).map_err(|e| format!("Invalid offline query: {e}"))?;
+        let state_root =
+            json["state_root"].as_str().ok_or_else(|| "The offline query is missing the state root".to_string())?;
+        let mut offline_query = Self::new(state_root)?;
+        if let Some(state_paths) = json["state_paths"].as_object() {
+            for (commitment, state_path) in state_paths {
+                let state_path =
+                    state_path.as_str().ok_or_else(|| format!("Invalid state path for commitment {commitment}"))?;
+                offline_query.add_state_path(commitment, state_path)?;
+            }
+        }
+        Ok(offline_query)
+    }
+}
+
+#[async_trait(?Send)]
+impl QueryTrait<CurrentNetwork> for OfflineQuery {
+    fn current_state_root(&self) -> anyhow::Result<StateRootNative> {
+        Ok(self.state_root)
+    }
+
+    async fn current_state_root_async(&self) -> anyhow::Result<StateRootNative> {
+        Ok(self.state_root)
+    }
+
+    fn get_state_path_for_commitment(&self, commitment: &FieldNative) -> anyhow::Result<StatePathNative> {
+        self.state_paths.get(commitment).cloned().ok_or_else(|| anyhow!("No state path was provided for {commitment}"))
+    }
+
+    async fn get_state_path_for_commitment_async(&self, commitment: &FieldNative) -> anyhow::Result<StatePathNative> {
+        self.get_state_path_for_commitment(commitment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const STATE_ROOT: &str = "ar1ekees06ce437zyrpy3xryal7wpfsw2zlsvwrr0rrfv3ywc8ehcrsg0tlrf";
+
+    #[wasm_bindgen_test]
+    fn test_offline_query_to_and_from_string() {
+        let offline_query = OfflineQuery::new(STATE_ROOT).unwrap();
+        assert_eq!(offline_query.current_state_root().unwrap().to_string(), STATE_ROOT);
+
+        let parsed = OfflineQuery::from_string(&offline_query.to_string()).unwrap();
+        assert_eq!(parsed.to_string(), offline_query.to_string());
+        assert!(parsed.get_state_path_for_commitment(&FieldNative::from_str("1field").unwrap()).is_err());
+    }
+}
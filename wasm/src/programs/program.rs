@@ -14,7 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::types::{CurrentNetwork, EntryType, IdentifierNative, PlaintextType, ProgramNative, ValueType};
+use crate::types::{
+    CurrentNetwork,
+    EntryType,
+    IdentifierNative,
+    Network,
+    PlaintextType,
+    ProgramNative,
+    ToBits,
+    ToBytes,
+    ValueType,
+};
 
 use js_sys::{Array, Object, Reflect};
 use std::{ops::Deref, str::FromStr};
@@ -135,58 +145,76 @@ impl Program {
         let function_inputs = Array::new_with_length(function.inputs().len() as u32);
         for (index, input) in function.inputs().iter().enumerate() {
             let register = JsValue::from_str(&input.register().to_string());
-            match input.value_type() {
-                ValueType::Constant(plaintext) => {
-                    function_inputs.set(index as u32, {
-                        let input = self.get_plaintext_input(plaintext, Some("constant".to_string()), None)?;
-                        Reflect::set(&input, &"register".into(), &register).map_err(|_| "Failed to set property")?;
-                        input.into()
-                    });
-                }
-                ValueType::Public(plaintext) => {
-                    function_inputs.set(index as u32, {
-                        let input = self.get_plaintext_input(plaintext, Some("public".to_string()), None)?;
-                        Reflect::set(&input, &"register".into(), &register).map_err(|_| "Failed to set property")?;
-                        input.into()
-                    });
-                }
-                ValueType::Private(plaintext) => {
-                    function_inputs.set(index as u32, {
-                        let input = self.get_plaintext_input(plaintext, Some("private".to_string()), None)?;
-                        Reflect::set(&input, &"register".into(), &register).map_err(|_| "Failed to set property")?;
-                        input.into()
-                    });
-                }
-                ValueType::Record(identifier) => {
-                    function_inputs.set(index as u32, {
-                        let input = self.get_record_members(identifier.to_string())?;
-                        Reflect::set(&input, &"register".into(), &register).map_err(|_| "Failed to set property")?;
-                        input.into()
-                    });
-                }
-                ValueType::ExternalRecord(locator) => {
-                    let input = Object::new();
-                    let value_type = JsValue::from_str("external_record");
-                    Reflect::set(&input, &"type".into(), &value_type).map_err(|_| "Failed to set property")?;
-                    Reflect::set(&input, &"locator".into(), &locator.to_string().into())
-                        .map_err(|_| "Failed to set property")?;
-                    Reflect::set(&input, &"register".into(), &register).map_err(|_| "Failed to set property")?;
-                    function_inputs.set(index as u32, input.into());
-                }
-                ValueType::Future(locator) => {
-                    let input = Object::new();
-                    let value_type = JsValue::from_str("future");
-                    Reflect::set(&input, &"type".into(), &value_type).map_err(|_| "Failed to set property")?;
-                    Reflect::set(&input, &"locator".into(), &locator.to_string().into())
-                        .map_err(|_| "Failed to set property")?;
-                    Reflect::set(&input, &"register".into(), &register).map_err(|_| "Failed to set property")?;
-                    function_inputs.set(index as u32, input.into());
-                }
-            }
+            function_inputs.set(index as u32, self.get_value_type(input.value_type(), register)?.into());
         }
         Ok(function_inputs)
     }
 
+    /// Get a javascript object representation of the function outputs and types, in the same
+    /// format as the function inputs with the operand of each output in place of its register
+    ///
+    /// @param {string} function_name Name of the function to get outputs for
+    /// @returns {Array | Error} Array of function outputs
+    ///
+    /// @example
+    /// const expected_outputs = [
+    ///     {
+    ///       type:"record",
+    ///       record:"Token",
+    ///       members:[ ... ],
+    ///       register:"r3"
+    ///     }
+    /// ];
+    ///
+    /// const program = aleo_wasm.Program.fromString(TOKEN_ISSUE);
+    /// const issue_outputs = program.getFunctionOutputs("issue");
+    /// console.log(issue_outputs === expected_outputs); // Output should be "true"
+    #[wasm_bindgen(js_name = "getFunctionOutputs")]
+    pub fn get_function_outputs(&self, function_name: String) -> Result<Array, String> {
+        let function_id = IdentifierNative::from_str(&function_name).map_err(|e| e.to_string())?;
+        let function = self
+            .0
+            .functions()
+            .get(&function_id)
+            .ok_or_else(|| format!("function {} not found in {}", function_name, self.0.id()))?;
+        let function_outputs = Array::new_with_length(function.outputs().len() as u32);
+        for (index, output) in function.outputs().iter().enumerate() {
+            let register = JsValue::from_str(&output.operand().to_string());
+            function_outputs.set(index as u32, self.get_value_type(output.value_type(), register)?.into());
+        }
+        Ok(function_outputs)
+    }
+
+    // Get a function input or output type as a javascript object (this function is not part of
+    // the public API)
+    fn get_value_type(&self, value_type: &ValueType<CurrentNetwork>, register: JsValue) -> Result<Object, String> {
+        let value = match value_type {
+            ValueType::Constant(plaintext) => {
+                self.get_plaintext_input(plaintext, Some("constant".to_string()), None)?
+            }
+            ValueType::Public(plaintext) => self.get_plaintext_input(plaintext, Some("public".to_string()), None)?,
+            ValueType::Private(plaintext) => self.get_plaintext_input(plaintext, Some("private".to_string()), None)?,
+            ValueType::Record(identifier) => self.get_record_members(identifier.to_string())?,
+            ValueType::ExternalRecord(locator) => {
+                let value = Object::new();
+                Reflect::set(&value, &"type".into(), &"external_record".into())
+                    .map_err(|_| "Failed to set property")?;
+                Reflect::set(&value, &"locator".into(), &locator.to_string().into())
+                    .map_err(|_| "Failed to set property")?;
+                value
+            }
+            ValueType::Future(locator) => {
+                let value = Object::new();
+                Reflect::set(&value, &"type".into(), &"future".into()).map_err(|_| "Failed to set property")?;
+                Reflect::set(&value, &"locator".into(), &locator.to_string().into())
+                    .map_err(|_| "Failed to set property")?;
+                value
+            }
+        };
+        Reflect::set(&value, &"register".into(), &register).map_err(|_| "Failed to set property")?;
+        Ok(value)
+    }
+
     /// Get a the list of a program's mappings and the names/types of their keys and values.
     ///
     /// @returns {Array | Error} - An array of objects representing the mappings in the program
@@ -406,6 +434,36 @@ impl Program {
         Ok(struct_members)
     }
 
+    /// Get the structs defined in the program and their members
+    ///
+    /// @returns {Array | Error} Array of objects of the form \{ name, members \} where the members
+    /// are in the format returned by getStructMembers
+    #[wasm_bindgen(js_name = "getStructs")]
+    pub fn get_structs(&self) -> Result<Array, String> {
+        let structs = Array::new();
+        for name in self.0.structs().keys() {
+            let program_struct = Object::new();
+            Reflect::set(&program_struct, &"name".into(), &name.to_string().into())
+                .map_err(|_| "Failed to set property")?;
+            Reflect::set(&program_struct, &"members".into(), &self.get_struct_members(name.to_string())?.into())
+                .map_err(|_| "Failed to set property")?;
+            structs.push(&program_struct);
+        }
+        Ok(structs)
+    }
+
+    /// Get the records defined in the program and their members
+    ///
+    /// @returns {Array | Error} Array of record objects in the format returned by getRecordMembers
+    #[wasm_bindgen(js_name = "getRecords")]
+    pub fn get_records(&self) -> Result<Array, String> {
+        let records = Array::new();
+        for name in self.0.records().keys() {
+            records.push(&self.get_record_members(name.to_string())?);
+        }
+        Ok(records)
+    }
+
     /// Get the credits.aleo program
     ///
     /// @returns {Program} The credits.aleo program
@@ -422,6 +480,28 @@ impl Program {
         self.0.id().to_string()
     }
 
+    /// Get the checksum of the program, the BHP1024 hash of the program bytes
+    ///
+    /// Comparing the checksum of a program fetched from the network with the checksum of an
+    /// audited copy detects any difference between the two
+    ///
+    /// @returns {string | Error} The checksum of the program as a field element
+    #[wasm_bindgen]
+    pub fn checksum(&self) -> Result<String, String> {
+        let bytes = self.0.to_bytes_le().map_err(|e| e.to_string())?;
+        let checksum = CurrentNetwork::hash_bhp1024(&bytes.to_bits_le()).map_err(|e| e.to_string())?;
+        Ok(checksum.to_string())
+    }
+
+    /// Determine whether the program has the expected checksum
+    ///
+    /// @param {string} checksum The expected checksum of the program, e.g. of an audited copy
+    /// @returns {boolean | Error} True if the checksum of the program is the expected one
+    #[wasm_bindgen(js_name = "matchesChecksum")]
+    pub fn matches_checksum(&self, checksum: &str) -> Result<bool, String> {
+        Ok(self.checksum()? == checksum.trim())
+    }
+
     /// Determine equality with another program
     ///
     /// @param {Program} other The other program to compare
@@ -623,6 +703,49 @@ function add_and_double:
         assert_eq!(format!("{:?}", members), expected);
     }
 
+    #[wasm_bindgen_test]
+    fn test_get_outputs() {
+        let credits = Program::from(ProgramNative::credits().unwrap());
+        let outputs = credits.get_function_outputs("transfer_public".to_string()).unwrap();
+        let expected = r#"Array { obj: Object { obj: JsValue([Object({"type":"future","locator":"credits.aleo/transfer_public","register":"r2"})]) } }"#;
+        assert_eq!(format!("{:?}", outputs), expected);
+
+        let token_issue = Program::from_string(TOKEN_ISSUE).unwrap();
+        let outputs = token_issue.get_function_outputs("issue".to_string()).unwrap();
+        assert_eq!(outputs.length(), 1);
+        let output = outputs.get(0);
+        assert_eq!(Reflect::get(&output, &"record".into()).unwrap().as_string().unwrap(), "Token");
+        assert_eq!(Reflect::get(&output, &"register".into()).unwrap().as_string().unwrap(), "r3");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_get_structs_and_records() {
+        let program = Program::from_string(TOKEN_ISSUE).unwrap();
+        let structs = program.get_structs().unwrap();
+        let expected = r#"Array { obj: Object { obj: JsValue([Object({"name":"token_metadata","members":[{"name":"token_id","type":"u32"},{"name":"version","type":"u32"}]})]) } }"#;
+        assert_eq!(format!("{:?}", structs), expected);
+
+        let records = program.get_records().unwrap();
+        assert_eq!(records.length(), 1);
+        assert_eq!(
+            format!("{:?}", records.get(0)),
+            format!("{:?}", JsValue::from(program.get_record_members("Token".to_string()).unwrap()))
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_checksum() {
+        let program = Program::from_string(TOKEN_ISSUE).unwrap();
+        let checksum = program.checksum().unwrap();
+        assert!(checksum.ends_with("field"));
+        assert_eq!(checksum, Program::from_string(TOKEN_ISSUE).unwrap().checksum().unwrap());
+        assert!(program.matches_checksum(&checksum).unwrap());
+
+        let credits = Program::get_credits_program();
+        assert_ne!(credits.checksum().unwrap(), checksum);
+        assert!(!credits.matches_checksum(&checksum).unwrap());
+    }
+
     #[wasm_bindgen_test]
     fn test_program_from_methods() {
         // Test the from_string creates a valid object and to_string matches the source string
@@ -14,10 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::types::{FromBytes, ProvingKeyNative, ToBytes};
+use crate::{
+    types::{FromBytes, ProvingKeyNative, ToBytes},
+    ByteBuffer,
+};
 
-use std::ops::Deref;
-use wasm_bindgen::prelude::wasm_bindgen;
+use js_sys::{Reflect, Uint8Array};
+use std::{collections::VecDeque, io, ops::Deref};
+use wasm_bindgen::{prelude::wasm_bindgen, JsCast};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{ReadableStream, ReadableStreamDefaultReader};
 
 /// Proving key for a function within an Aleo program
 #[wasm_bindgen]
@@ -35,6 +41,27 @@ impl ProvingKey {
         Ok(Self(ProvingKeyNative::from_bytes_le(bytes).map_err(|e| e.to_string())?))
     }
 
+    /// Construct a proving key from a stream of its byte representation, such as the body of a
+    /// fetch response. The chunks of the stream are released as the key is deserialized, so the
+    /// key and its bytes are never held in memory in full at the same time
+    ///
+    /// @param {ReadableStream} stream Stream of the byte representation of a proving key
+    /// @returns {Promise<ProvingKey> | Error}
+    #[wasm_bindgen(js_name = "fromStream")]
+    pub async fn from_stream(stream: ReadableStream) -> Result<ProvingKey, String> {
+        let reader = stream.get_reader().unchecked_into::<ReadableStreamDefaultReader>();
+        let mut key_reader = ProvingKeyReader::new();
+        loop {
+            let chunk = JsFuture::from(reader.read()).await.map_err(|e| format!("Failed to read the stream: {e:?}"))?;
+            if Reflect::get(&chunk, &"done".into()).map_or(true, |done| done.is_truthy()) {
+                break;
+            }
+            let value = Reflect::get(&chunk, &"value".into()).map_err(|_| "Failed to read the stream".to_string())?;
+            key_reader.chunks.push(Uint8Array::new(&value).to_vec());
+        }
+        key_reader.finish()
+    }
+
     /// Return the byte representation of a proving key
     ///
     /// @returns {Uint8Array | Error} Byte array representation of a proving key
@@ -43,6 +70,27 @@ impl ProvingKey {
         self.0.to_bytes_le().map_err(|_| "Failed to serialize proving key".to_string())
     }
 
+    /// Construct a proving key from bytes held in wasm memory without copying them across the JS boundary
+    ///
+    /// @param {ByteBuffer} buffer Buffer holding the byte representation of a proving key
+    /// @returns {ProvingKey | Error}
+    #[wasm_bindgen(js_name = "fromBytesView")]
+    pub fn from_bytes_view(buffer: &ByteBuffer) -> Result<ProvingKey, String> {
+        Self::from_bytes(buffer)
+    }
+
+    /// Write the byte representation of the proving key into a buffer in wasm memory, replacing its
+    /// contents, so it can be read through a view of the buffer without another copy
+    ///
+    /// @param {ByteBuffer} buffer The buffer to write the proving key into
+    /// @returns {number | Error} The number of bytes written
+    #[wasm_bindgen(js_name = "toBytesInto")]
+    pub fn to_bytes_into(&self, buffer: &mut ByteBuffer) -> Result<usize, String> {
+        buffer.truncate(0);
+        self.0.write_le(&mut **buffer).map_err(|_| "Failed to serialize proving key".to_string())?;
+        Ok(buffer.len())
+    }
+
     /// Create a copy of the proving key
     ///
     /// @returns {ProvingKey} A copy of the proving key
@@ -52,6 +100,127 @@ impl ProvingKey {
     }
 }
 
+impl ProvingKey {
+    /// Construct a proving key from its byte representation split into chunks, releasing each chunk
+    /// once it has been deserialized
+    pub(crate) fn from_chunks(mut chunks: ChunkedBytes) -> Result<ProvingKey, String> {
+        Ok(Self(ProvingKeyNative::read_le(&mut chunks).map_err(|e| e.to_string())?))
+    }
+}
+
+/// Reader of a proving key fed with chunks of its byte representation, to deserialize large keys
+/// without first concatenating their bytes into a single array
+///
+/// @example
+/// const reader = new ProvingKeyReader();
+/// for await (const chunk of response.body) {
+///     reader.push(chunk);
+/// }
+/// const provingKey = reader.finish();
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct ProvingKeyReader {
+    chunks: ChunkedBytes,
+}
+
+#[wasm_bindgen]
+impl ProvingKeyReader {
+    /// Create an empty proving key reader
+    ///
+    /// @returns {ProvingKeyReader}
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> ProvingKeyReader {
+        Self::default()
+    }
+
+    /// Append the next chunk of the byte representation of the proving key
+    ///
+    /// @param {Uint8Array} chunk The next bytes of the proving key
+    #[wasm_bindgen]
+    pub fn push(&mut self, chunk: Vec<u8>) {
+        self.chunks.push(chunk);
+    }
+
+    /// Get the number of bytes received so far
+    ///
+    /// @returns {number} The number of bytes received
+    #[wasm_bindgen]
+    pub fn len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Determine whether no bytes have been received yet
+    ///
+    /// @returns {boolean} True if no bytes have been received
+    #[wasm_bindgen(js_name = "isEmpty")]
+    pub fn is_empty(&self) -> bool {
+        self.chunks.len() == 0
+    }
+
+    /// Deserialize the proving key from the bytes received, consuming the reader
+    ///
+    /// @returns {ProvingKey | Error}
+    #[wasm_bindgen]
+    pub fn finish(self) -> Result<ProvingKey, String> {
+        ProvingKey::from_chunks(self.chunks)
+    }
+}
+
+/// Bytes held as a queue of chunks which are dropped as soon as they have been read
+#[derive(Default)]
+pub(crate) struct ChunkedBytes {
+    chunks: VecDeque<Vec<u8>>,
+    offset: usize,
+    len: usize,
+}
+
+impl ChunkedBytes {
+    /// Append a chunk of bytes
+    pub(crate) fn push(&mut self, chunk: Vec<u8>) {
+        self.len += chunk.len();
+        if !chunk.is_empty() {
+            self.chunks.push_back(chunk);
+        }
+    }
+
+    /// Get the number of bytes which have not been read
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Remove all bytes
+    pub(crate) fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Concatenate the bytes which have not been read
+    pub(crate) fn into_vec(mut self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.len);
+        while let Some(chunk) = self.chunks.pop_front() {
+            bytes.extend_from_slice(&chunk[self.offset..]);
+            self.offset = 0;
+        }
+        bytes
+    }
+}
+
+impl io::Read for ChunkedBytes {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        let Some(chunk) = self.chunks.front() else {
+            return Ok(0);
+        };
+        let read = buffer.len().min(chunk.len() - self.offset);
+        buffer[..read].copy_from_slice(&chunk[self.offset..self.offset + read]);
+        self.offset += read;
+        self.len -= read;
+        if self.offset == chunk.len() {
+            self.chunks.pop_front();
+            self.offset = 0;
+        }
+        Ok(read)
+    }
+}
+
 impl Deref for ProvingKey {
     type Target = ProvingKeyNative;
 
@@ -91,5 +260,25 @@ mod tests {
         let fee_proving_key = ProvingKey::from_bytes(&fee_proving_key_bytes).unwrap();
         let bytes = fee_proving_key.to_bytes().unwrap();
         assert_eq!(bytes, fee_proving_key_bytes);
+
+        let mut reader = ProvingKeyReader::new();
+        fee_proving_key_bytes.chunks(65536).for_each(|chunk| reader.push(chunk.to_vec()));
+        assert_eq!(reader.len(), fee_proving_key_bytes.len());
+        assert_eq!(reader.finish().unwrap(), fee_proving_key);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_chunked_bytes() {
+        let mut chunks = ChunkedBytes::default();
+        chunks.push(vec![1, 2, 3]);
+        chunks.push(vec![]);
+        chunks.push(vec![4, 5]);
+        assert_eq!(chunks.len(), 5);
+
+        let mut buffer = [0u8; 4];
+        io::Read::read_exact(&mut chunks, &mut buffer).unwrap();
+        assert_eq!(buffer, [1, 2, 3, 4]);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks.into_vec(), vec![5]);
     }
 }
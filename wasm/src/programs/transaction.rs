@@ -14,10 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::types::TransactionNative;
+use crate::{
+    account::ViewKey,
+    types::{FromBytes, ToBytes, TransactionNative},
+    ByteBuffer,
+    RecordPlaintext,
+    Transition,
+};
 
+use js_sys::Array;
 use std::str::FromStr;
-use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 
 /// Webassembly Representation of an Aleo transaction
 ///
@@ -48,6 +55,46 @@ impl Transaction {
         self.0.to_string()
     }
 
+    /// Create a transaction from its byte representation
+    ///
+    /// @param {Uint8Array} bytes Byte representation of a transaction
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Transaction, String> {
+        Ok(Self(TransactionNative::from_bytes_le(bytes).map_err(|e| e.to_string())?))
+    }
+
+    /// Get the byte representation of the transaction, a more compact form than its string
+    /// representation for size constrained channels. Channels which carry CBOR messages can send
+    /// it as a CBOR byte string
+    ///
+    /// @returns {Uint8Array | Error} Byte representation of the transaction
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        self.0.to_bytes_le().map_err(|_| "Failed to serialize transaction".to_string())
+    }
+
+    /// Create a transaction from bytes held in wasm memory without copying them across the JS boundary
+    ///
+    /// @param {ByteBuffer} buffer Buffer holding the byte representation of a transaction
+    /// @returns {Transaction | Error}
+    #[wasm_bindgen(js_name = fromBytesView)]
+    pub fn from_bytes_view(buffer: &ByteBuffer) -> Result<Transaction, String> {
+        Ok(Self(TransactionNative::from_bytes_le(buffer).map_err(|e| e.to_string())?))
+    }
+
+    /// Write the byte representation of the transaction into a buffer in wasm memory, replacing its
+    /// contents, so it can be read through a view of the buffer without another copy
+    ///
+    /// @param {ByteBuffer} buffer The buffer to write the transaction into
+    /// @returns {number | Error} The number of bytes written
+    #[wasm_bindgen(js_name = toBytesInto)]
+    pub fn to_bytes_into(&self, buffer: &mut ByteBuffer) -> Result<usize, String> {
+        buffer.truncate(0);
+        self.0.write_le(&mut **buffer).map_err(|_| "Failed to serialize transaction".to_string())?;
+        Ok(buffer.len())
+    }
+
     /// Get the id of the transaction. This is the merkle root of the transaction's inclusion proof.
     ///
     /// This value can be used to query the status of the transaction on the Aleo Network to see
@@ -60,6 +107,70 @@ impl Transaction {
         self.0.id().to_string()
     }
 
+    /// Get the id of the transaction, the same value as `transactionId`
+    ///
+    /// @returns {string} Transaction id
+    #[wasm_bindgen]
+    pub fn id(&self) -> String {
+        self.transaction_id()
+    }
+
+    /// Compute the id of the transaction from its contents without contacting a node, hashing
+    /// them the same way consensus does. Transactions are checked against their id when they are
+    /// created from a string, so this matches `id` for every transaction object.
+    ///
+    /// @returns {string | Error} Transaction id computed from the contents of the transaction
+    #[wasm_bindgen(js_name = computeId)]
+    pub fn compute_id(&self) -> Result<String, String> {
+        let transaction = match &self.0 {
+            TransactionNative::Deploy(_, owner, deployment, fee) => {
+                TransactionNative::from_deployment(*owner, *deployment.clone(), fee.clone())
+            }
+            TransactionNative::Execute(_, execution, fee) => {
+                TransactionNative::from_execution(execution.clone(), fee.clone())
+            }
+            TransactionNative::Fee(_, fee) => TransactionNative::from_fee(fee.clone()),
+        };
+        Ok(transaction.map_err(|e| e.to_string())?.id().to_string())
+    }
+
+    /// Get the fee paid by the transaction, including the priority fee
+    ///
+    /// @returns {bigint | Error} The fee in microcredits
+    #[wasm_bindgen(js_name = feeAmount)]
+    pub fn fee_amount(&self) -> Result<u64, String> {
+        Ok(*self.0.fee_amount().map_err(|e| e.to_string())?)
+    }
+
+    /// Get the size of the proof of the execution of an execute transaction
+    ///
+    /// @returns {number | undefined | Error} The size of the proof in bytes, or undefined if the
+    /// transaction is not an execution or its execution has no proof
+    #[wasm_bindgen(js_name = executionProofSize)]
+    pub fn execution_proof_size(&self) -> Result<Option<usize>, String> {
+        self.0
+            .execution()
+            .and_then(|execution| execution.proof())
+            .map(|proof| proof.to_bytes_le().map(|bytes| bytes.len()).map_err(|e| e.to_string()))
+            .transpose()
+    }
+
+    /// Determine whether the transaction deploys a program
+    ///
+    /// @returns {boolean} True if the transaction is a deployment
+    #[wasm_bindgen(js_name = isDeploy)]
+    pub fn is_deploy(&self) -> bool {
+        self.0.is_deploy()
+    }
+
+    /// Determine whether the transaction executes a function
+    ///
+    /// @returns {boolean} True if the transaction is an execution
+    #[wasm_bindgen(js_name = isExecute)]
+    pub fn is_execute(&self) -> bool {
+        self.0.is_execute()
+    }
+
     /// Get the type of the transaction (will return "deploy" or "execute")
     ///
     /// @returns {string} Transaction type
@@ -71,6 +182,46 @@ impl Transaction {
             TransactionNative::Fee(..) => "fee".to_string(),
         }
     }
+
+    /// Get the transitions of the transaction, including the fee transition
+    ///
+    /// @returns {Array} Array of Transition objects
+    pub fn transitions(&self) -> Array {
+        self.0.transitions().cloned().map(Transition::from).map(JsValue::from).collect()
+    }
+
+    /// Find and decrypt the records created by the transaction which are owned by a view key
+    ///
+    /// @param {ViewKey} view_key View key of the account to find records for
+    /// @returns {Array | Error} Array of RecordPlaintext objects owned by the view key
+    #[wasm_bindgen(js_name = ownedRecords)]
+    pub fn owned_records(&self, view_key: &ViewKey) -> Result<Array, String> {
+        self.0
+            .transitions()
+            .flat_map(|transition| transition.records())
+            .filter(|(_, record)| record.is_owner(view_key))
+            .map(|(_, record)| {
+                let record = record.decrypt(view_key).map_err(|e| e.to_string())?;
+                Ok(JsValue::from(RecordPlaintext::from(record)))
+            })
+            .collect()
+    }
+
+    /// Decode the inputs and outputs of every transition of the transaction into typed values.
+    /// Public and constant values are always decoded, private values are decrypted if the view key
+    /// belongs to the caller of the transition and record outputs if the view key owns them.
+    ///
+    /// @param {ViewKey | undefined} view_key (optional) View key used to decrypt private values
+    /// @returns {Array | Error} Array of `{ id, programId, functionName, inputs, outputs }` objects
+    /// where each input and output is a `{ type, id, value }` object
+    #[wasm_bindgen(js_name = decodedInputs)]
+    pub fn decoded_inputs(&self, view_key: Option<ViewKey>) -> Result<Array, String> {
+        self.0
+            .transitions()
+            .cloned()
+            .map(|transition| Ok(JsValue::from(Transition::from(transition).decode(view_key.as_ref())?)))
+            .collect()
+    }
 }
 
 impl From<Transaction> for TransactionNative {
@@ -94,10 +245,10 @@ impl FromStr for Transaction {
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
     use wasm_bindgen_test::*;
-    const TRANSACTION_STRING: &str = "{\"type\":\"execute\",\"id\":\"at1nxeg7yttrgn2usx9kvprf4m6l90hf46xeaydtzcd9g75nus89spshz3nq3\",\"execution\":{\"transitions\":[{\"id\":\"as1elwd3rrs6usm29au4m7930dw34qc9fgpcft2cttpr22v0wqtcugq3w9ju2\",\"program\":\"credits.aleo\",\"function\":\"transfer_public\",\"inputs\":[{\"type\":\"public\",\"id\":\"5907511150006977506230980075690536919685375158466705529320990737854928887990field\",\"value\":\"aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8\"},{\"type\":\"public\",\"id\":\"7982283145747718203249182128016464244397256863721538973871034171457064208262field\",\"value\":\"1u64\"}],\"outputs\":[{\"type\":\"future\",\"id\":\"4008617251745541310278402942725515740438336136760958012618696224551610246151field\",\"value\":\"{\\n  program_id: credits.aleo,\\n  function_name: transfer_public,\\n  arguments: [\\n    aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8,\\n    aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8,\\n    1u64\\n  ]\\n}\"}],\"tpk\":\"426663056102511765227671053009534797451999908535901481531983199661563048450group\",\"tcm\":\"1534997465320715735425891873359081363532823476989438880960500286633204303186field\"}],\"global_state_root\":\"ar1sdke9pp2kymvegysll924rkwq2sdp7u3v0dny8rvn0zyqupgmczs8t5x5g\",\"proof\":\"proof1qyqsqqqqqqqqqqqpqqqqqqqqqqqrsqlml9y8ntderna5vml8cm8zg0gd3xt557tg96qfkscekp5q38wr69qj46r5c287aqhcpkczf8qpq8kdxuec8lvv4yvdm4k4kgkzmr6mntmj58y799htwlf5avw88keq8fayp788ydtfmnje97w7rl8r6qd50yh8klpyhj7ywpe647rxhdrpy2qfvhxezkg05rzmx9t7j3zl6rgmd4hd4q38nwq25e3qp0nezgqq2g9tdkw2rp04vkt46gkp9x8ye3ecuxc9j0zqyw2egp8p7ztavrpflqlnze8tnyty95s5lq3fsmsqu5qy3masdcgtj95enct9wphfxk5ghqxdxzwz33txfpchx7uffyagxn6chlwhryc26ay3pegsjkxsrxmg84ugcacz20u9pjyrxrxrgjl44mqcshpug9dpkfvfq09s79eu9ufqf72w38zskq6e6l2ymvums8j333cugwse7kt8c75qduf0xjkt6zkgxhkpkf5kvncxr8dfxh3yuw3wv0kqggdzpah3rqmnqd7fqqdgejndua4e6mfk22hdj6fkmg005e2pchgjev3zpdu072r28gh9vvj6xkd36yf8a0nzxgrmt29zgwqtvknatj0zqls23yppgh0nt93p7zk69w66dhcu2khpzh5t8vwq6zgus3mhyjqytmqn2dj5caz7vrgp9krg7kdmrhran88w57x93l3y20xkxpdk64mpy7may527xuq6surcp6tva0e3eze7gx06t2gpd3e675srqq2rad0ycdj05l08rfgacpr3jcjw5layydprd9rc09xnrq58qtwssyry8nf7zwglewhycp30qxtm2mdd3087c7ncfkyg48qp53s9f2eghlfhcenfv9lwx0nvaw3qggmurtcd5gvx5qs6hpp8qphmrddjnmqvrgx86k4fajnj25y7gagvl09hvrmlec0gq4d3plc7qupgr62wsrl767vqtcgdz9eulgw4xsgn5xen9xpss7k57m63hcynmxz9m339thtd8t7v7d4p4la6l8sg2z6mgjgkayc4kmr5mww503xztklyc53vettl58040pm44mrxfgx8pz0kqx4zyqnsyjq4vyz4htlpe5fxqjcxyzq98d8u5dfary8q7j3sn2gw9g6rhpeh2jgmengr7a2qfmpfeszsy4z8r0r4u2xfnmx2hmq0qvqqqqqqqqqqq94jt9tgluuakrl50al544e27fd3h2am03dzknp358ctpkyq5xe4zzecjujs6l5eggjvr4f3p6h3qqqwsuc6vgxxdwze52ejnvprvxte69ejl3egtg49z3hs0k3350y0m3xnyxyelzdv4y9xdrvl8rr2qzvpqx5af4pdjgw7mkg0w4pph6l9708emuw2999wdav5jl9dql5x6wtqryl0a34w3ya2h88p0rnmzwdz3qz50dflxwxvh5nwpeac4xlwz9taup49gl4ly87cmju3vuvkkue6qqqqwmc8ml\"},\"fee\":{\"transition\":{\"id\":\"as1l6uhn2qtvu53ker3mc5htl8zez0kzlr8p56wrds2kj2pnhgsyvys0yl06h\",\"program\":\"credits.aleo\",\"function\":\"fee_public\",\"inputs\":[{\"type\":\"public\",\"id\":\"2996685536981548557454514078993156178337218831466422725062883491278181936893field\",\"value\":\"3023388u64\"},{\"type\":\"public\",\"id\":\"5905563935613274059571167025428691034361018068179412971300387031818027999760field\",\"value\":\"4378343675180703452881652483301549197321065542531755166623110530046157373401field\"}],\"outputs\":[{\"type\":\"future\",\"id\":\"2432320784444893954958591507626737090900611232878405555838091152358395595911field\",\"value\":\"{\\n  program_id: credits.aleo,\\n  function_name: fee_public,\\n  arguments: [\\n    aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8,\\n    3023388u64\\n  ]\\n}\"}],\"tpk\":\"7056720889980672352920575743315541922748301367116937819254368455578705794867group\",\"tcm\":\"1714869696957207071175089592056335794264687593708571261524376592074954518596field\"},\"global_state_root\":\"ar1sdke9pp2kymvegysll924rkwq2sdp7u3v0dny8rvn0zyqupgmczs8t5x5g\",\"proof\":\"proof1qyqsqqqqqqqqqqqpqqqqqqqqqqq8a7fc3jujtcjjeuuyc3z2gy5vle4f2h2zuys067arlpfpu9jxd8ag0rfs9hzjdny4mxqdrugx0pgpqxunzeu5sjmt643wtqjv7xq5j0jnma7qva6535x0faq9nl2t72cry74jx9vjmnxg27uxa5c6rjafgqgllxguqhvaxd3en3vwg5xmrdukrgrj4kfm6smc9nzrj3d5j3gfnvn4au0929fsa7sxnxr43klhgxq9j028mt9xncd2re4um2c5lzsgt95vkr8gw2xlg0k6utvqf8k0cn6znur0smw9zhzcelhrnpf43rqqn96x0g2nhpw5k5un2ugy4j85kwkccg0qvfn0wlwwgw0hu8m9n2qvxrt5alsh32lrhrd8uyp7yenczse8arlj7579lu24ru9u5fd9f46rvd5h8wwrs5ye03pwcfesc2nmfpl5m2hytqhztajznm0gamw9sz2yc9mqt44sp23tkzz7lhwns23wcra736vehapel3nxcu0jn2tfze5x40flnlqqcca3atxkpfr66q0dy5df23s866d4t3a4wh4hf4zmq68jzfa7v9u7g2gaa9vdslqmcl3h5va3lw5htskn7lrzf2nlqqq2p02fhjjrwlssv583qzjzuj4yyfpt2n2kvq4vqjmlx2ss9mz2vwmkf8kjsyc3wvpay5xa2ar9y6cpjekusmcveue56m2xd5pfqrqc7n03yds8lxk86d9vqrj52njehgpmr79pumzkegxv5cnqgdhl8l7s8scg5fm7396dnmnrue5j7rxyuz89uzlxgqh2p33jqnep205vkhah7nt9rt5rjqf4xjnfs2f9xe0gz967hydmky7vga84k34zec4uwvvjfxa4a0l7vg5uprlx9yje7wpqs60ttksl8sx3duakdhha7kajh9p4ps5pad6mmcua2y0j75c6jxs2jmzek2htm4e2ym7h8rq7eukpvvpw9f9yt70tazyzfxu3nmw2lcr9fj26lj4n6uaesa87ss0vdutpkkxnnnq8krk3v32kdmtqptdlxpcm8nzcmy7j08za6c93emdec3kvnchr2ntyj7fmdpazen9s4zcazqd8zp5k7xkrkqkkew9zqlf29fajj57lhhthcqhhv3f0dgvpcq5q0cu6xfxj8hpq7f3p2m3lcv7kf99pcy9w3nj0tttnwwkkcn0cw0ssqvqqqqqqqqqqqmv3vn28xnfyewqa6vzsdvuhg9fdy7lsrl79fvg0tp2z72n93hrpc22retjd9r5s3xz2wyevvqkzqqqpu2rktjv5jq97lmpu624e6ewlwe3j9dgz3pa20y49zxm5zx08mlsp7365398jxrjfc59zhmat5xcqq9y78vt8n4nvendus5aly2afpx96gctxac47j0zge206xvurag7se0x5z7c6yc98sgr6tprp249nz6pfv579zjqdhk0z4fcyxnct4r428h0j6nxzvyl4hyx87a8ud94dqqqqfs8ws3\"}}";
+    pub(crate) const TRANSACTION_STRING: &str = "{\"type\":\"execute\",\"id\":\"at1nxeg7yttrgn2usx9kvprf4m6l90hf46xeaydtzcd9g75nus89spshz3nq3\",\"execution\":{\"transitions\":[{\"id\":\"as1elwd3rrs6usm29au4m7930dw34qc9fgpcft2cttpr22v0wqtcugq3w9ju2\",\"program\":\"credits.aleo\",\"function\":\"transfer_public\",\"inputs\":[{\"type\":\"public\",\"id\":\"5907511150006977506230980075690536919685375158466705529320990737854928887990field\",\"value\":\"aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8\"},{\"type\":\"public\",\"id\":\"7982283145747718203249182128016464244397256863721538973871034171457064208262field\",\"value\":\"1u64\"}],\"outputs\":[{\"type\":\"future\",\"id\":\"4008617251745541310278402942725515740438336136760958012618696224551610246151field\",\"value\":\"{\\n  program_id: credits.aleo,\\n  function_name: transfer_public,\\n  arguments: [\\n    aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8,\\n    aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8,\\n    1u64\\n  ]\\n}\"}],\"tpk\":\"426663056102511765227671053009534797451999908535901481531983199661563048450group\",\"tcm\":\"1534997465320715735425891873359081363532823476989438880960500286633204303186field\"}],\"global_state_root\":\"ar1sdke9pp2kymvegysll924rkwq2sdp7u3v0dny8rvn0zyqupgmczs8t5x5g\",\"proof\":\"proof1qyqsqqqqqqqqqqqpqqqqqqqqqqqrsqlml9y8ntderna5vml8cm8zg0gd3xt557tg96qfkscekp5q38wr69qj46r5c287aqhcpkczf8qpq8kdxuec8lvv4yvdm4k4kgkzmr6mntmj58y799htwlf5avw88keq8fayp788ydtfmnje97w7rl8r6qd50yh8klpyhj7ywpe647rxhdrpy2qfvhxezkg05rzmx9t7j3zl6rgmd4hd4q38nwq25e3qp0nezgqq2g9tdkw2rp04vkt46gkp9x8ye3ecuxc9j0zqyw2egp8p7ztavrpflqlnze8tnyty95s5lq3fsmsqu5qy3masdcgtj95enct9wphfxk5ghqxdxzwz33txfpchx7uffyagxn6chlwhryc26ay3pegsjkxsrxmg84ugcacz20u9pjyrxrxrgjl44mqcshpug9dpkfvfq09s79eu9ufqf72w38zskq6e6l2ymvums8j333cugwse7kt8c75qduf0xjkt6zkgxhkpkf5kvncxr8dfxh3yuw3wv0kqggdzpah3rqmnqd7fqqdgejndua4e6mfk22hdj6fkmg005e2pchgjev3zpdu072r28gh9vvj6xkd36yf8a0nzxgrmt29zgwqtvknatj0zqls23yppgh0nt93p7zk69w66dhcu2khpzh5t8vwq6zgus3mhyjqytmqn2dj5caz7vrgp9krg7kdmrhran88w57x93l3y20xkxpdk64mpy7may527xuq6surcp6tva0e3eze7gx06t2gpd3e675srqq2rad0ycdj05l08rfgacpr3jcjw5layydprd9rc09xnrq58qtwssyry8nf7zwglewhycp30qxtm2mdd3087c7ncfkyg48qp53s9f2eghlfhcenfv9lwx0nvaw3qggmurtcd5gvx5qs6hpp8qphmrddjnmqvrgx86k4fajnj25y7gagvl09hvrmlec0gq4d3plc7qupgr62wsrl767vqtcgdz9eulgw4xsgn5xen9xpss7k57m63hcynmxz9m339thtd8t7v7d4p4la6l8sg2z6mgjgkayc4kmr5mww503xztklyc53vettl58040pm44mrxfgx8pz0kqx4zyqnsyjq4vyz4htlpe5fxqjcxyzq98d8u5dfary8q7j3sn2gw9g6rhpeh2jgmengr7a2qfmpfeszsy4z8r0r4u2xfnmx2hmq0qvqqqqqqqqqqq94jt9tgluuakrl50al544e27fd3h2am03dzknp358ctpkyq5xe4zzecjujs6l5eggjvr4f3p6h3qqqwsuc6vgxxdwze52ejnvprvxte69ejl3egtg49z3hs0k3350y0m3xnyxyelzdv4y9xdrvl8rr2qzvpqx5af4pdjgw7mkg0w4pph6l9708emuw2999wdav5jl9dql5x6wtqryl0a34w3ya2h88p0rnmzwdz3qz50dflxwxvh5nwpeac4xlwz9taup49gl4ly87cmju3vuvkkue6qqqqwmc8ml\"},\"fee\":{\"transition\":{\"id\":\"as1l6uhn2qtvu53ker3mc5htl8zez0kzlr8p56wrds2kj2pnhgsyvys0yl06h\",\"program\":\"credits.aleo\",\"function\":\"fee_public\",\"inputs\":[{\"type\":\"public\",\"id\":\"2996685536981548557454514078993156178337218831466422725062883491278181936893field\",\"value\":\"3023388u64\"},{\"type\":\"public\",\"id\":\"5905563935613274059571167025428691034361018068179412971300387031818027999760field\",\"value\":\"4378343675180703452881652483301549197321065542531755166623110530046157373401field\"}],\"outputs\":[{\"type\":\"future\",\"id\":\"2432320784444893954958591507626737090900611232878405555838091152358395595911field\",\"value\":\"{\\n  program_id: credits.aleo,\\n  function_name: fee_public,\\n  arguments: [\\n    aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8,\\n    3023388u64\\n  ]\\n}\"}],\"tpk\":\"7056720889980672352920575743315541922748301367116937819254368455578705794867group\",\"tcm\":\"1714869696957207071175089592056335794264687593708571261524376592074954518596field\"},\"global_state_root\":\"ar1sdke9pp2kymvegysll924rkwq2sdp7u3v0dny8rvn0zyqupgmczs8t5x5g\",\"proof\":\"proof1qyqsqqqqqqqqqqqpqqqqqqqqqqq8a7fc3jujtcjjeuuyc3z2gy5vle4f2h2zuys067arlpfpu9jxd8ag0rfs9hzjdny4mxqdrugx0pgpqxunzeu5sjmt643wtqjv7xq5j0jnma7qva6535x0faq9nl2t72cry74jx9vjmnxg27uxa5c6rjafgqgllxguqhvaxd3en3vwg5xmrdukrgrj4kfm6smc9nzrj3d5j3gfnvn4au0929fsa7sxnxr43klhgxq9j028mt9xncd2re4um2c5lzsgt95vkr8gw2xlg0k6utvqf8k0cn6znur0smw9zhzcelhrnpf43rqqn96x0g2nhpw5k5un2ugy4j85kwkccg0qvfn0wlwwgw0hu8m9n2qvxrt5alsh32lrhrd8uyp7yenczse8arlj7579lu24ru9u5fd9f46rvd5h8wwrs5ye03pwcfesc2nmfpl5m2hytqhztajznm0gamw9sz2yc9mqt44sp23tkzz7lhwns23wcra736vehapel3nxcu0jn2tfze5x40flnlqqcca3atxkpfr66q0dy5df23s866d4t3a4wh4hf4zmq68jzfa7v9u7g2gaa9vdslqmcl3h5va3lw5htskn7lrzf2nlqqq2p02fhjjrwlssv583qzjzuj4yyfpt2n2kvq4vqjmlx2ss9mz2vwmkf8kjsyc3wvpay5xa2ar9y6cpjekusmcveue56m2xd5pfqrqc7n03yds8lxk86d9vqrj52njehgpmr79pumzkegxv5cnqgdhl8l7s8scg5fm7396dnmnrue5j7rxyuz89uzlxgqh2p33jqnep205vkhah7nt9rt5rjqf4xjnfs2f9xe0gz967hydmky7vga84k34zec4uwvvjfxa4a0l7vg5uprlx9yje7wpqs60ttksl8sx3duakdhha7kajh9p4ps5pad6mmcua2y0j75c6jxs2jmzek2htm4e2ym7h8rq7eukpvvpw9f9yt70tazyzfxu3nmw2lcr9fj26lj4n6uaesa87ss0vdutpkkxnnnq8krk3v32kdmtqptdlxpcm8nzcmy7j08za6c93emdec3kvnchr2ntyj7fmdpazen9s4zcazqd8zp5k7xkrkqkkew9zqlf29fajj57lhhthcqhhv3f0dgvpcq5q0cu6xfxj8hpq7f3p2m3lcv7kf99pcy9w3nj0tttnwwkkcn0cw0ssqvqqqqqqqqqqqmv3vn28xnfyewqa6vzsdvuhg9fdy7lsrl79fvg0tp2z72n93hrpc22retjd9r5s3xz2wyevvqkzqqqpu2rktjv5jq97lmpu624e6ewlwe3j9dgz3pa20y49zxm5zx08mlsp7365398jxrjfc59zhmat5xcqq9y78vt8n4nvendus5aly2afpx96gctxac47j0zge206xvurag7se0x5z7c6yc98sgr6tprp249nz6pfv579zjqdhk0z4fcyxnct4r428h0j6nxzvyl4hyx87a8ud94dqqqqfs8ws3\"}}";
 
     const TRANSACTION_ID: &str = "at1nxeg7yttrgn2usx9kvprf4m6l90hf46xeaydtzcd9g75nus89spshz3nq3";
 
@@ -109,6 +260,11 @@ mod tests {
         let recovered_string = transaction.to_string();
         assert_eq!(transaction_id, TRANSACTION_ID);
         assert_eq!(transaction_type, "execute");
+        assert_eq!(transaction.id(), TRANSACTION_ID);
+        assert_eq!(transaction.fee_amount().unwrap(), 3023388);
+        assert!(transaction.execution_proof_size().unwrap().unwrap() > 0);
+        assert!(transaction.is_execute());
+        assert!(!transaction.is_deploy());
         assert_eq!(recovered_string, TRANSACTION_STRING);
 
         // Test to and from round trip
@@ -117,5 +273,58 @@ mod tests {
         assert_eq!(transaction_native, transaction_deconstruction);
         let transaction_from_native = Transaction::from(transaction_native);
         assert_eq!(transaction, transaction_from_native);
+
+        // Test byte round trip
+        let bytes = transaction.to_bytes().unwrap();
+        assert_eq!(Transaction::from_bytes(&bytes).unwrap(), transaction);
+        assert!(Transaction::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+        // Test byte buffer round trip
+        let mut buffer = ByteBuffer::new(0);
+        let len = transaction.to_bytes_into(&mut buffer).unwrap();
+        assert_eq!(len, buffer.len());
+        assert_eq!(Transaction::from_bytes_view(&buffer).unwrap(), transaction);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_compute_id() {
+        // Build the transaction around a wrong id, bypassing the id check of the string parser.
+        let TransactionNative::Execute(_, execution, fee) = TransactionNative::from_str(TRANSACTION_STRING).unwrap()
+        else {
+            panic!("The test transaction must be an execute transaction");
+        };
+        let wrong_id = crate::types::FieldNative::from_u64(1).into();
+        let transaction = Transaction::from(TransactionNative::Execute(wrong_id, execution, fee));
+        assert_ne!(transaction.id(), TRANSACTION_ID);
+
+        // Check the id computed from the contents matches the id the network assigned.
+        assert_eq!(transaction.compute_id().unwrap(), TRANSACTION_ID);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_transaction_transitions_and_owned_records() {
+        let transaction = Transaction::from_string(TRANSACTION_STRING).unwrap();
+        let transitions = transaction.transitions();
+        assert_eq!(transitions.length(), 2);
+
+        // The public transfer creates no records
+        let view_key = crate::PrivateKey::new().to_view_key();
+        assert_eq!(transaction.owned_records(&view_key).unwrap().length(), 0);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_transaction_decoded_inputs() {
+        let transaction = Transaction::from_string(TRANSACTION_STRING).unwrap();
+        let decoded = transaction.decoded_inputs(None).unwrap();
+        assert_eq!(decoded.length(), 2);
+
+        // The amount of the public transfer is decoded into a BigInt
+        let transfer = decoded.get(0);
+        let function_name = js_sys::Reflect::get(&transfer, &"functionName".into()).unwrap();
+        assert_eq!(function_name.as_string().unwrap(), "transfer_public");
+        let inputs = js_sys::Array::from(&js_sys::Reflect::get(&transfer, &"inputs".into()).unwrap());
+        let amount = js_sys::Reflect::get(&inputs.get(1), &"value".into()).unwrap();
+        let amount = js_sys::Reflect::get(&amount, &"value".into()).unwrap();
+        assert_eq!(amount, JsValue::from(js_sys::BigInt::from(1u64)));
     }
 }
@@ -0,0 +1,445 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    account::ViewKey,
+    record::record_plaintext::plaintext_to_js,
+    types::{
+        CiphertextNative,
+        CurrentNetwork,
+        FieldNative,
+        Input,
+        Network,
+        Output,
+        PlaintextNative,
+        ToBits,
+        TransitionNative,
+        U16,
+    },
+    RecordPlaintext,
+};
+
+use js_sys::{Array, Object, Reflect};
+use std::{fmt, ops::Deref, str::FromStr};
+use wasm_bindgen::prelude::*;
+
+/// Webassembly Representation of an Aleo transition
+///
+/// A transition is the execution of a single function within a transaction. The inputs and
+/// outputs of a transition are returned as objects of the form `{ type, id, value }` where the
+/// value of private inputs and outputs is only available after decrypting them with a view key.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transition(TransitionNative);
+
+#[wasm_bindgen]
+impl Transition {
+    /// Create a transition from a string
+    ///
+    /// @param {string} transition String representation of a transition
+    /// @returns {Transition | Error}
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(transition: &str) -> Result<Transition, String> {
+        Transition::from_str(transition)
+    }
+
+    /// Get the transition as a string
+    ///
+    /// @returns {string} String representation of the transition
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Get the id of the transition
+    ///
+    /// @returns {string} Transition id
+    pub fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    /// Compute the id of the transition from its contents without contacting a node, hashing them
+    /// the same way consensus does. Transitions are checked against their id when they are created
+    /// from a string, so this matches `id` for every transition object.
+    ///
+    /// @returns {string | Error} Transition id computed from the contents of the transition
+    #[wasm_bindgen(js_name = computeId)]
+    pub fn compute_id(&self) -> Result<String, String> {
+        let transition = TransitionNative::new(
+            *self.0.program_id(),
+            *self.0.function_name(),
+            self.0.inputs().to_vec(),
+            self.0.outputs().to_vec(),
+            *self.0.tpk(),
+            *self.0.tcm(),
+        )
+        .map_err(|e| e.to_string())?;
+        Ok(transition.id().to_string())
+    }
+
+    /// Get the id of the program the transition executed
+    ///
+    /// @returns {string} Program id
+    #[wasm_bindgen(js_name = programId)]
+    pub fn program_id(&self) -> String {
+        self.0.program_id().to_string()
+    }
+
+    /// Get the name of the function the transition executed
+    ///
+    /// @returns {string} Function name
+    #[wasm_bindgen(js_name = functionName)]
+    pub fn function_name(&self) -> String {
+        self.0.function_name().to_string()
+    }
+
+    /// Get the inputs of the transition without decrypting them. Private inputs are represented by
+    /// their ciphertext and record inputs by their serial number.
+    ///
+    /// @returns {Array | Error} Array of input objects
+    pub fn inputs(&self) -> Result<Array, String> {
+        self.0
+            .inputs()
+            .iter()
+            .map(|input| {
+                let (kind, value) = match input {
+                    Input::Constant(_, value) => ("constant", value.as_ref().map(ToString::to_string)),
+                    Input::Public(_, value) => ("public", value.as_ref().map(ToString::to_string)),
+                    Input::Private(_, ciphertext) => ("private", ciphertext.as_ref().map(ToString::to_string)),
+                    Input::Record(serial_number, _) => ("record", Some(serial_number.to_string())),
+                    Input::ExternalRecord(_) => ("external_record", None),
+                };
+                value_object(kind, &input.id().to_string(), value)
+            })
+            .collect()
+    }
+
+    /// Get the outputs of the transition without decrypting them. Private outputs are represented
+    /// by their ciphertext and record outputs by the record ciphertext.
+    ///
+    /// @returns {Array | Error} Array of output objects
+    pub fn outputs(&self) -> Result<Array, String> {
+        self.0
+            .outputs()
+            .iter()
+            .map(|output| {
+                let (kind, value) = match output {
+                    Output::Constant(_, value) => ("constant", value.as_ref().map(ToString::to_string)),
+                    Output::Public(_, value) => ("public", value.as_ref().map(ToString::to_string)),
+                    Output::Private(_, ciphertext) => ("private", ciphertext.as_ref().map(ToString::to_string)),
+                    Output::Record(_, _, record) => ("record", record.as_ref().map(ToString::to_string)),
+                    Output::ExternalRecord(_) => ("external_record", None),
+                    Output::Future(_, future) => ("future", future.as_ref().map(ToString::to_string)),
+                };
+                value_object(kind, &output.id().to_string(), value)
+            })
+            .collect()
+    }
+
+    /// Get the transition public key
+    ///
+    /// @returns {string} Transition public key
+    pub fn tpk(&self) -> String {
+        self.0.tpk().to_string()
+    }
+
+    /// Get the transition commitment
+    ///
+    /// @returns {string} Transition commitment
+    pub fn tcm(&self) -> String {
+        self.0.tcm().to_string()
+    }
+
+    /// Decrypt the inputs of the transition with the view key of the account which executed it.
+    /// Record inputs are represented by their serial number.
+    ///
+    /// @param {ViewKey} view_key View key of the caller of the transition
+    /// @returns {Array | Error} Array of input objects with the private inputs decrypted
+    #[wasm_bindgen(js_name = decryptInputs)]
+    pub fn decrypt_inputs(&self, view_key: &ViewKey) -> Result<Array, String> {
+        let (function_id, transition_view_key) = self.transition_view_key(view_key)?;
+        self.0
+            .inputs()
+            .iter()
+            .enumerate()
+            .map(|(index, input)| {
+                let (kind, value) = match input {
+                    Input::Constant(_, value) => ("constant", value.as_ref().map(ToString::to_string)),
+                    Input::Public(_, value) => ("public", value.as_ref().map(ToString::to_string)),
+                    Input::Private(_, ciphertext) => {
+                        let plaintext = decrypt_value(ciphertext.as_ref(), function_id, transition_view_key, index)?;
+                        ("private", plaintext.map(|plaintext| plaintext.to_string()))
+                    }
+                    Input::Record(serial_number, _) => ("record", Some(serial_number.to_string())),
+                    Input::ExternalRecord(_) => ("external_record", None),
+                };
+                value_object(kind, &input.id().to_string(), value)
+            })
+            .collect()
+    }
+
+    /// Decrypt the outputs of the transition with the view key of the account which executed it.
+    /// Record outputs are only decrypted if they are owned by the view key.
+    ///
+    /// @param {ViewKey} view_key View key of the caller of the transition
+    /// @returns {Array | Error} Array of output objects with the private outputs decrypted
+    #[wasm_bindgen(js_name = decryptOutputs)]
+    pub fn decrypt_outputs(&self, view_key: &ViewKey) -> Result<Array, String> {
+        let (function_id, transition_view_key) = self.transition_view_key(view_key)?;
+        let num_inputs = self.0.inputs().len();
+        self.0
+            .outputs()
+            .iter()
+            .enumerate()
+            .map(|(index, output)| {
+                let (kind, value) = match output {
+                    Output::Constant(_, value) => ("constant", value.as_ref().map(ToString::to_string)),
+                    Output::Public(_, value) => ("public", value.as_ref().map(ToString::to_string)),
+                    Output::Private(_, ciphertext) => (
+                        "private",
+                        decrypt_value(ciphertext.as_ref(), function_id, transition_view_key, num_inputs + index)?
+                            .map(|plaintext| plaintext.to_string()),
+                    ),
+                    Output::Record(_, _, record) => {
+                        let record = match record {
+                            Some(record) if record.is_owner(view_key) => {
+                                Some(record.decrypt(view_key).map_err(|e| e.to_string())?.to_string())
+                            }
+                            _ => None,
+                        };
+                        ("record", record)
+                    }
+                    Output::ExternalRecord(_) => ("external_record", None),
+                    Output::Future(_, future) => ("future", future.as_ref().map(ToString::to_string)),
+                };
+                value_object(kind, &output.id().to_string(), value)
+            })
+            .collect()
+    }
+}
+
+impl Transition {
+    // Decode the inputs and outputs of the transition into typed javascript values in the form
+    // `{ id, programId, functionName, inputs, outputs }`. Private values are decrypted when the view
+    // key belongs to the caller of the transition and records when the view key owns them
+    pub(crate) fn decode(&self, view_key: Option<&ViewKey>) -> Result<Object, String> {
+        let keys = view_key.and_then(|view_key| self.transition_view_key(view_key).ok());
+        let plaintext = |plaintext: Option<&PlaintextNative>| plaintext.map_or(Ok(JsValue::UNDEFINED), plaintext_to_js);
+        let private = |ciphertext: Option<&CiphertextNative>, index: usize| -> Result<JsValue, String> {
+            match keys {
+                Some((function_id, transition_view_key)) => {
+                    plaintext(decrypt_value(ciphertext, function_id, transition_view_key, index)?.as_ref())
+                }
+                None => Ok(JsValue::UNDEFINED),
+            }
+        };
+
+        let inputs = self
+            .0
+            .inputs()
+            .iter()
+            .enumerate()
+            .map(|(index, input)| {
+                let (kind, value) = match input {
+                    Input::Constant(_, value) => ("constant", plaintext(value.as_ref())?),
+                    Input::Public(_, value) => ("public", plaintext(value.as_ref())?),
+                    Input::Private(_, ciphertext) => ("private", private(ciphertext.as_ref(), index)?),
+                    Input::Record(serial_number, _) => ("record", JsValue::from_str(&serial_number.to_string())),
+                    Input::ExternalRecord(_) => ("external_record", JsValue::UNDEFINED),
+                };
+                typed_value_object(kind, &input.id().to_string(), value)
+            })
+            .collect::<Result<Array, String>>()?;
+
+        let num_inputs = self.0.inputs().len();
+        let outputs = self
+            .0
+            .outputs()
+            .iter()
+            .enumerate()
+            .map(|(index, output)| {
+                let (kind, value) = match output {
+                    Output::Constant(_, value) => ("constant", plaintext(value.as_ref())?),
+                    Output::Public(_, value) => ("public", plaintext(value.as_ref())?),
+                    Output::Private(_, ciphertext) => ("private", private(ciphertext.as_ref(), num_inputs + index)?),
+                    Output::Record(_, _, record) => {
+                        let record = match (record, view_key) {
+                            (Some(record), Some(view_key)) if record.is_owner(view_key) => {
+                                let record = record.decrypt(view_key).map_err(|e| e.to_string())?;
+                                RecordPlaintext::from(record).to_js_object()?.into()
+                            }
+                            _ => JsValue::UNDEFINED,
+                        };
+                        ("record", record)
+                    }
+                    Output::ExternalRecord(_) => ("external_record", JsValue::UNDEFINED),
+                    Output::Future(_, future) => {
+                        ("future", future.as_ref().map_or(JsValue::UNDEFINED, |future| future.to_string().into()))
+                    }
+                };
+                typed_value_object(kind, &output.id().to_string(), value)
+            })
+            .collect::<Result<Array, String>>()?;
+
+        let object = Object::new();
+        for (key, value) in [
+            ("id", JsValue::from_str(&self.id())),
+            ("programId", JsValue::from_str(&self.program_id())),
+            ("functionName", JsValue::from_str(&self.function_name())),
+            ("inputs", inputs.into()),
+            ("outputs", outputs.into()),
+        ] {
+            Reflect::set(&object, &JsValue::from_str(key), &value)
+                .map_err(|_| format!("Failed to set property '{key}'"))?;
+        }
+        Ok(object)
+    }
+
+    // Derive the function id and the transition view key, checking the view key belongs to the caller
+    fn transition_view_key(&self, view_key: &ViewKey) -> Result<(FieldNative, FieldNative), String> {
+        let transition_view_key = (*self.0.tpk() * ***view_key).to_x_coordinate();
+        let commitment = CurrentNetwork::hash_psd2(&[transition_view_key]).map_err(|e| e.to_string())?;
+        if &commitment != self.0.tcm() {
+            return Err("The view key provided is not the view key of the caller of this transition".to_string());
+        }
+        let program_id = self.0.program_id();
+        let function_id = CurrentNetwork::hash_bhp1024(
+            &(
+                U16::<CurrentNetwork>::new(CurrentNetwork::ID),
+                program_id.name(),
+                program_id.network(),
+                self.0.function_name(),
+            )
+                .to_bits_le(),
+        )
+        .map_err(|e| e.to_string())?;
+        Ok((function_id, transition_view_key))
+    }
+}
+
+// Decrypt a private input or output with the view key derived for its index in the transition
+fn decrypt_value(
+    ciphertext: Option<&CiphertextNative>,
+    function_id: FieldNative,
+    transition_view_key: FieldNative,
+    index: usize,
+) -> Result<Option<PlaintextNative>, String> {
+    let Some(ciphertext) = ciphertext else {
+        return Ok(None);
+    };
+    let index = FieldNative::from_u16(u16::try_from(index).map_err(|e| e.to_string())?);
+    let value_view_key =
+        CurrentNetwork::hash_psd4(&[function_id, transition_view_key, index]).map_err(|e| e.to_string())?;
+    Ok(Some(ciphertext.decrypt_symmetric(value_view_key).map_err(|e| e.to_string())?))
+}
+
+// Create a javascript object describing a transition input or output
+fn value_object(kind: &str, id: &str, value: Option<String>) -> Result<JsValue, String> {
+    typed_value_object(kind, id, value.map_or(JsValue::UNDEFINED, |value| JsValue::from_str(&value)))
+}
+
+// Create a javascript object describing a transition input or output with a decoded value
+fn typed_value_object(kind: &str, id: &str, value: JsValue) -> Result<JsValue, String> {
+    let object = Object::new();
+    for (key, value) in [("type", JsValue::from_str(kind)), ("id", JsValue::from_str(id)), ("value", value)] {
+        Reflect::set(&object, &JsValue::from_str(key), &value)
+            .map_err(|_| format!("Failed to set property '{key}'"))?;
+    }
+    Ok(object.into())
+}
+
+impl Deref for Transition {
+    type Target = TransitionNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for Transition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<Transition> for TransitionNative {
+    fn from(transition: Transition) -> Self {
+        transition.0
+    }
+}
+
+impl From<TransitionNative> for Transition {
+    fn from(transition: TransitionNative) -> Self {
+        Self(transition)
+    }
+}
+
+impl FromStr for Transition {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(TransitionNative::from_str(s).map_err(|e| e.to_string())?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+
+    use wasm_bindgen_test::*;
+
+    const TRANSITION_STRING: &str = "{\"id\":\"as1elwd3rrs6usm29au4m7930dw34qc9fgpcft2cttpr22v0wqtcugq3w9ju2\",\"program\":\"credits.aleo\",\"function\":\"transfer_public\",\"inputs\":[{\"type\":\"public\",\"id\":\"5907511150006977506230980075690536919685375158466705529320990737854928887990field\",\"value\":\"aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8\"},{\"type\":\"public\",\"id\":\"7982283145747718203249182128016464244397256863721538973871034171457064208262field\",\"value\":\"1u64\"}],\"outputs\":[{\"type\":\"future\",\"id\":\"4008617251745541310278402942725515740438336136760958012618696224551610246151field\",\"value\":\"{\\n  program_id: credits.aleo,\\n  function_name: transfer_public,\\n  arguments: [\\n    aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8,\\n    aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8,\\n    1u64\\n  ]\\n}\"}],\"tpk\":\"426663056102511765227671053009534797451999908535901481531983199661563048450group\",\"tcm\":\"1534997465320715735425891873359081363532823476989438880960500286633204303186field\"}";
+
+    #[wasm_bindgen_test]
+    fn test_transition_string_constructor_and_accessor_methods() {
+        let transition = Transition::from_string(TRANSITION_STRING).unwrap();
+        assert_eq!(transition.id(), "as1elwd3rrs6usm29au4m7930dw34qc9fgpcft2cttpr22v0wqtcugq3w9ju2");
+        assert_eq!(transition.compute_id().unwrap(), transition.id());
+        assert_eq!(transition.program_id(), "credits.aleo");
+        assert_eq!(transition.function_name(), "transfer_public");
+        assert_eq!(transition.to_string(), TRANSITION_STRING);
+        assert_eq!(
+            transition.tpk(),
+            "426663056102511765227671053009534797451999908535901481531983199661563048450group"
+        );
+        assert_eq!(
+            transition.tcm(),
+            "1534997465320715735425891873359081363532823476989438880960500286633204303186field"
+        );
+
+        let inputs = transition.inputs().unwrap();
+        assert_eq!(inputs.length(), 2);
+        let amount = inputs.get(1);
+        assert_eq!(Reflect::get(&amount, &"type".into()).unwrap(), "public");
+        assert_eq!(Reflect::get(&amount, &"value".into()).unwrap(), "1u64");
+        let outputs = transition.outputs().unwrap();
+        assert_eq!(outputs.length(), 1);
+        assert_eq!(Reflect::get(&outputs.get(0), &"type".into()).unwrap(), "future");
+
+        let transition_native = TransitionNative::from(transition.clone());
+        assert_eq!(Transition::from(transition_native), transition);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_decrypt_with_view_key_of_another_account_fails() {
+        let transition = Transition::from_string(TRANSITION_STRING).unwrap();
+        let view_key = PrivateKey::new().to_view_key();
+        assert!(transition.decrypt_inputs(&view_key).is_err());
+        assert!(transition.decrypt_outputs(&view_key).is_err());
+    }
+}
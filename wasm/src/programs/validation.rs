@@ -0,0 +1,359 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::types::{CommandNative, InstructionNative, OperandNative, Parser, ProcessNative, ProgramNative};
+
+use js_sys::{Array, Object, Reflect};
+use std::{collections::HashSet, str::FromStr};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Opcodes of the finalize commands which are not also instructions
+const COMMAND_OPCODES: &[&str] =
+    &["branch.eq", "branch.neq", "contains", "get", "get.or_use", "position", "rand.chacha", "remove", "set"];
+
+/// Lines beginning with these keywords declare the interface of a block rather than an operation
+const DECLARATION_KEYWORDS: &[&str] = &["finalize", "input", "key", "output", "value"];
+
+/// A single problem found while validating a program
+struct Diagnostic {
+    kind: &'static str,
+    message: String,
+    position: Option<(usize, usize)>,
+    instruction: Option<String>,
+    suggestion: Option<String>,
+}
+
+impl Diagnostic {
+    fn to_object(&self) -> Result<Object, String> {
+        let diagnostic = Object::new();
+        Reflect::set(&diagnostic, &"kind".into(), &self.kind.into()).map_err(|_| "Failed to set property")?;
+        Reflect::set(&diagnostic, &"message".into(), &self.message.clone().into())
+            .map_err(|_| "Failed to set property")?;
+        if let Some((line, column)) = self.position {
+            Reflect::set(&diagnostic, &"line".into(), &(line as u32).into()).map_err(|_| "Failed to set property")?;
+            Reflect::set(&diagnostic, &"column".into(), &(column as u32).into())
+                .map_err(|_| "Failed to set property")?;
+        }
+        if let Some(instruction) = &self.instruction {
+            Reflect::set(&diagnostic, &"instruction".into(), &instruction.into())
+                .map_err(|_| "Failed to set property")?;
+        }
+        if let Some(suggestion) = &self.suggestion {
+            Reflect::set(&diagnostic, &"suggestion".into(), &suggestion.into())
+                .map_err(|_| "Failed to set property")?;
+        }
+        Ok(diagnostic)
+    }
+}
+
+#[wasm_bindgen]
+impl Program {
+    /// Validate the source of an Aleo program and describe every problem found in it
+    ///
+    /// Unlike Program.fromString, which stops at the first opaque parser error, this reports
+    /// each offending line of the program. Each diagnostic has a `kind` (one of "unknown_opcode",
+    /// "invalid_instruction", "undefined_register", "type_mismatch", "syntax_error" or
+    /// "invalid_program"), a `message`, and where known the 1-based `line` and `column`, the
+    /// offending `instruction` and a `suggestion` for fixing it. Type checking is skipped for
+    /// programs importing programs other than credits.aleo, as the imports cannot be resolved.
+    ///
+    /// @param {string} source Source of the Aleo program
+    /// @returns {Array | Error} Array of diagnostics, empty if the program is valid
+    ///
+    /// @example
+    /// const diagnostics = aleo_wasm.Program.validate(source);
+    /// for (const { line, column, message, suggestion } of diagnostics) {
+    ///     console.log(`${line}:${column} ${message}`, suggestion ?? "");
+    /// }
+    #[wasm_bindgen]
+    pub fn validate(source: &str) -> Result<Array, String> {
+        let diagnostics = match ProgramNative::from_str(source) {
+            Ok(program) => check_program(source, &program),
+            Err(error) => {
+                // Resume scanning from the first component the parser could not consume
+                let offset = match ProgramNative::parse(source) {
+                    Ok((remainder, _)) => source.len() - remainder.len(),
+                    Err(_) => 0,
+                };
+                let mut diagnostics = scan_lines(source, offset);
+                if diagnostics.is_empty() {
+                    diagnostics.push(Diagnostic {
+                        kind: "syntax_error",
+                        message: error.to_string(),
+                        position: Some(position_of(source, offset)),
+                        instruction: None,
+                        suggestion: None,
+                    });
+                }
+                diagnostics
+            }
+        };
+        diagnostics.iter().map(|diagnostic| diagnostic.to_object()).collect::<Result<Array, String>>()
+    }
+}
+
+// Check each instruction line of the source from the given byte offset onward
+fn scan_lines(source: &str, offset: usize) -> Vec<Diagnostic> {
+    let line_start = source[..offset].rfind('\n').map_or(0, |index| index + 1);
+    let first_line = source[..line_start].matches('\n').count();
+    let mut block = "";
+    let mut diagnostics = Vec::new();
+
+    for (index, line) in source[line_start..].lines().enumerate() {
+        let code = line.split("//").next().unwrap_or_default();
+        let trimmed = code.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        // Block headers such as `function main:` set the kind of the lines that follow
+        if trimmed.ends_with(':') {
+            block = trimmed.split_whitespace().next().unwrap_or_default();
+            continue;
+        }
+        let opcode = trimmed.split_whitespace().next().unwrap_or_default().trim_end_matches(';');
+        if !matches!(block, "function" | "closure" | "finalize") || DECLARATION_KEYWORDS.contains(&opcode) {
+            continue;
+        }
+
+        let result = match block {
+            "finalize" => CommandNative::from_str(trimmed).map(|_| ()),
+            _ => InstructionNative::from_str(trimmed).map(|_| ()),
+        };
+        if let Err(error) = result {
+            let position = Some((first_line + index + 1, code.len() - code.trim_start().len() + 1));
+            let opcodes = known_opcodes(block == "finalize");
+            let diagnostic = if opcodes.iter().any(|known| known == opcode) {
+                Diagnostic {
+                    kind: "invalid_instruction",
+                    message: format!("invalid operands for '{opcode}': {error}"),
+                    position,
+                    instruction: Some(trimmed.to_string()),
+                    suggestion: Some(format!("check the operands, destination and types of '{opcode}'")),
+                }
+            } else {
+                Diagnostic {
+                    kind: "unknown_opcode",
+                    message: format!("unknown opcode '{opcode}'"),
+                    position,
+                    instruction: Some(trimmed.to_string()),
+                    suggestion: closest_opcode(opcode, &opcodes).map(|closest| format!("did you mean '{closest}'?")),
+                }
+            };
+            diagnostics.push(diagnostic);
+        }
+    }
+    diagnostics
+}
+
+// Check the registers and types of a program which parsed successfully
+fn check_program(source: &str, program: &ProgramNative) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    for function in program.functions().values() {
+        check_registers(
+            source,
+            &mut diagnostics,
+            function.inputs().iter().map(|input| input.register().locator()),
+            function.instructions(),
+            function.outputs().iter().map(|output| (output.to_string(), output.operand())),
+        );
+    }
+    for closure in program.closures().values() {
+        check_registers(
+            source,
+            &mut diagnostics,
+            closure.inputs().iter().map(|input| input.register().locator()),
+            closure.instructions(),
+            closure.outputs().iter().map(|output| (output.to_string(), output.operand())),
+        );
+    }
+    if !diagnostics.is_empty() {
+        return diagnostics;
+    }
+
+    // Type check the program by adding it to a process
+    let resolvable = program.imports().keys().all(|import| import.to_string() == "credits.aleo");
+    if !resolvable || program.id().to_string() == "credits.aleo" {
+        return diagnostics;
+    }
+    let result = ProcessNative::load_web().and_then(|mut process| process.add_program(program));
+    if let Err(error) = result {
+        let message = error.to_string();
+        let instruction = program
+            .functions()
+            .values()
+            .flat_map(|function| function.instructions())
+            .chain(program.closures().values().flat_map(|closure| closure.instructions()))
+            .map(|instruction| instruction.to_string())
+            .find(|instruction| message.contains(instruction.trim_end_matches(';')));
+        let kind = if message.to_lowercase().contains("type") { "type_mismatch" } else { "invalid_program" };
+        diagnostics.push(Diagnostic {
+            kind,
+            position: instruction.as_ref().and_then(|instruction| locate(source, instruction)),
+            instruction,
+            suggestion: None,
+            message,
+        });
+    }
+    diagnostics
+}
+
+// Report operands that read from a register before any input or instruction defines it
+fn check_registers<'a>(
+    source: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+    inputs: impl Iterator<Item = u64>,
+    instructions: &[InstructionNative],
+    outputs: impl Iterator<Item = (String, &'a OperandNative)>,
+) {
+    let mut defined = inputs.collect::<HashSet<u64>>();
+    let mut check = |statement: String, operand: &OperandNative, defined: &HashSet<u64>| {
+        if let OperandNative::Register(register) = operand {
+            if !defined.contains(&register.locator()) {
+                diagnostics.push(Diagnostic {
+                    kind: "undefined_register",
+                    message: format!("register '{register}' is used before it is defined"),
+                    position: locate(source, &statement),
+                    instruction: Some(statement),
+                    suggestion: Some(format!(
+                        "declare '{register}' as an input or assign it in an earlier instruction"
+                    )),
+                });
+            }
+        }
+    };
+    for instruction in instructions {
+        for operand in instruction.operands() {
+            check(instruction.to_string(), operand, &defined);
+        }
+        defined.extend(instruction.destinations().iter().map(|register| register.locator()));
+    }
+    for (statement, operand) in outputs {
+        check(statement, operand, &defined);
+    }
+}
+
+// Get the opcodes valid in a function or closure body, and in finalize blocks the commands
+fn known_opcodes(finalize: bool) -> Vec<String> {
+    let mut opcodes = InstructionNative::OPCODES.iter().map(|opcode| opcode.to_string()).collect::<Vec<_>>();
+    if finalize {
+        opcodes.extend(COMMAND_OPCODES.iter().map(|opcode| opcode.to_string()));
+    }
+    opcodes
+}
+
+// Find the known opcode closest to a misspelled one
+fn closest_opcode<'a>(opcode: &str, opcodes: &'a [String]) -> Option<&'a String> {
+    opcodes
+        .iter()
+        .map(|known| (edit_distance(opcode, known), known))
+        .filter(|(distance, _)| *distance <= 2.max(opcode.len() / 3))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, known)| known)
+}
+
+// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+// Find the line and column of a statement in the source, ignoring differences in whitespace
+fn locate(source: &str, statement: &str) -> Option<(usize, usize)> {
+    let normalize = |text: &str| text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let statement = normalize(statement);
+    source.lines().enumerate().find_map(|(index, line)| {
+        let code = line.split("//").next().unwrap_or_default();
+        (normalize(code) == statement).then(|| (index + 1, code.len() - code.trim_start().len() + 1))
+    })
+}
+
+// Convert a byte offset in the source to a line and column
+fn position_of(source: &str, offset: usize) -> (usize, usize) {
+    let prefix = &source[..offset];
+    let line_start = prefix.rfind('\n').map_or(0, |index| index + 1);
+    (prefix.matches('\n').count() + 1, prefix[line_start..].chars().count() + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::*;
+
+    const PROGRAM: &str = r#"program validate_test.aleo;
+
+function add_and_double:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    add r2 r2 into r3;
+    output r3 as u32.private;
+"#;
+
+    fn property(diagnostic: &JsValue, name: &str) -> JsValue {
+        Reflect::get(diagnostic, &name.into()).unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_validate_valid_program() {
+        let diagnostics = Program::validate(PROGRAM).unwrap();
+        assert_eq!(diagnostics.length(), 0);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_validate_unknown_opcode() {
+        let source = PROGRAM.replace("add r2 r2", "ad r2 r2");
+        let diagnostics = Program::validate(&source).unwrap();
+        assert_eq!(diagnostics.length(), 1);
+
+        let diagnostic = diagnostics.get(0);
+        assert_eq!(property(&diagnostic, "kind").as_string().unwrap(), "unknown_opcode");
+        assert_eq!(property(&diagnostic, "line").as_f64().unwrap(), 7.0);
+        assert_eq!(property(&diagnostic, "column").as_f64().unwrap(), 5.0);
+        assert_eq!(property(&diagnostic, "instruction").as_string().unwrap(), "ad r2 r2 into r3;");
+        assert_eq!(property(&diagnostic, "suggestion").as_string().unwrap(), "did you mean 'add'?");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_validate_undefined_register() {
+        let source = PROGRAM.replace("add r2 r2", "add r2 r4");
+        let diagnostics = Program::validate(&source).unwrap();
+        assert_eq!(diagnostics.length(), 1);
+
+        let diagnostic = diagnostics.get(0);
+        assert_eq!(property(&diagnostic, "kind").as_string().unwrap(), "undefined_register");
+        assert_eq!(property(&diagnostic, "line").as_f64().unwrap(), 7.0);
+        assert_eq!(property(&diagnostic, "instruction").as_string().unwrap(), "add r2 r4 into r3;");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("add", "add"), 0);
+        assert_eq!(edit_distance("ad", "add"), 1);
+        assert_eq!(edit_distance("hash.bhp265", "hash.bhp256"), 2);
+    }
+}
@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::types::{FromBytes, ToBytes, VerifyingKeyNative};
+use crate::{
+    types::{FromBytes, ToBytes, VerifyingKeyNative},
+    ByteBuffer,
+};
 
 use wasm_bindgen::prelude::wasm_bindgen;
 
@@ -44,6 +47,27 @@ impl VerifyingKey {
         self.0.to_bytes_le().map_err(|_| "Failed to serialize verifying key".to_string())
     }
 
+    /// Construct a verifying key from bytes held in wasm memory without copying them across the JS boundary
+    ///
+    /// @param {ByteBuffer} buffer Buffer holding the byte representation of a verifying key
+    /// @returns {VerifyingKey | Error}
+    #[wasm_bindgen(js_name = "fromBytesView")]
+    pub fn from_bytes_view(buffer: &ByteBuffer) -> Result<VerifyingKey, String> {
+        Self::from_bytes(buffer)
+    }
+
+    /// Write the byte representation of the verifying key into a buffer in wasm memory, replacing its
+    /// contents, so it can be read through a view of the buffer without another copy
+    ///
+    /// @param {ByteBuffer} buffer The buffer to write the verifying key into
+    /// @returns {number | Error} The number of bytes written
+    #[wasm_bindgen(js_name = "toBytesInto")]
+    pub fn to_bytes_into(&self, buffer: &mut ByteBuffer) -> Result<usize, String> {
+        buffer.truncate(0);
+        self.0.write_le(&mut **buffer).map_err(|_| "Failed to serialize verifying key".to_string())?;
+        Ok(buffer.len())
+    }
+
     /// Create a verifying key from string
     ///
     /// @param {String} string String representation of a verifying key
@@ -116,5 +140,10 @@ mod tests {
         let join_verifier = VerifyingKey::from_bytes(&join_verifier_bytes).unwrap();
         let join_key_string = join_verifier.to_string();
         assert_eq!(join_key_string, JOIN_VERIFYING_KEY_STRING);
+
+        let mut buffer = ByteBuffer::new(0);
+        assert_eq!(join_verifier.to_bytes_into(&mut buffer).unwrap(), join_verifier_bytes.len());
+        assert_eq!(*buffer, join_verifier_bytes);
+        assert_eq!(VerifyingKey::from_bytes_view(&buffer).unwrap(), join_verifier);
     }
 }
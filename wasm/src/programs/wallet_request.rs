@@ -0,0 +1,289 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    account::{PrivateKey, SignedMessage},
+    types::{AddressNative, IdentifierNative, ProgramIDNative},
+};
+
+use js_sys::Array;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// How the fee of a requested transaction is paid
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FeePolicy {
+    priority_fee: u64,
+    private_fee: bool,
+}
+
+/// Request from a dApp to a wallet to execute a function, in a JSON format shared between dApps
+/// and wallets
+///
+/// The wallet executes the request with `ProgramManager.buildExecutionTransaction`, passing the
+/// program source code fetched for `programId`, the `functionName` and the `inputs`, and paying
+/// the fee as described by `priorityFee` and `privateFee`.
+///
+/// @example
+/// const request = new TransactionRequest("hello.aleo", "main", ["1u32", "2u32"], 0n, false);
+/// const json = request.toJson();
+/// // {"program":"hello.aleo","function":"main","inputs":["1u32","2u32"],"fee":{"priorityFee":0,"privateFee":false}}
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionRequest {
+    program: String,
+    function: String,
+    inputs: Vec<String>,
+    #[serde(default)]
+    fee: FeePolicy,
+}
+
+#[wasm_bindgen]
+impl TransactionRequest {
+    /// Create a request to execute a function
+    ///
+    /// @param {string} program_id The id of the program, e.g. "hello.aleo"
+    /// @param {string} function_name The name of the function to execute
+    /// @param {Array} inputs The inputs to the function as strings
+    /// @param {bigint} priority_fee The priority fee in microcredits
+    /// @param {boolean} private_fee Whether the fee is paid from a record instead of the public balance
+    /// @returns {TransactionRequest | Error}
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        program_id: &str,
+        function_name: &str,
+        inputs: Array,
+        priority_fee: u64,
+        private_fee: bool,
+    ) -> Result<TransactionRequest, String> {
+        let inputs = inputs
+            .iter()
+            .map(|input| input.as_string().ok_or_else(|| "The inputs of a request must be strings".to_string()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let request = Self {
+            program: program_id.to_string(),
+            function: function_name.to_string(),
+            inputs,
+            fee: FeePolicy { priority_fee, private_fee },
+        };
+        request.validate()?;
+        Ok(request)
+    }
+
+    /// Get the id of the program the function belongs to
+    ///
+    /// @returns {string} Program id
+    #[wasm_bindgen(js_name = programId)]
+    pub fn program_id(&self) -> String {
+        self.program.clone()
+    }
+
+    /// Get the name of the function to execute
+    ///
+    /// @returns {string} Function name
+    #[wasm_bindgen(js_name = functionName)]
+    pub fn function_name(&self) -> String {
+        self.function.clone()
+    }
+
+    /// Get the inputs to the function
+    ///
+    /// @returns {Array} The inputs as strings
+    pub fn inputs(&self) -> Array {
+        self.inputs.iter().map(|input| JsValue::from_str(input)).collect()
+    }
+
+    /// Get the priority fee of the transaction
+    ///
+    /// @returns {bigint} The priority fee in microcredits
+    #[wasm_bindgen(js_name = priorityFee)]
+    pub fn priority_fee(&self) -> u64 {
+        self.fee.priority_fee
+    }
+
+    /// Determine whether the fee is paid from a record instead of the public balance
+    ///
+    /// @returns {boolean} True if the fee is paid privately
+    #[wasm_bindgen(js_name = privateFee)]
+    pub fn private_fee(&self) -> bool {
+        self.fee.private_fee
+    }
+
+    /// Get the JSON representation of the request
+    ///
+    /// @returns {string} JSON representation of the request
+    #[wasm_bindgen(js_name = toJson)]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// Create a request from its JSON representation
+    ///
+    /// @param {string} json JSON representation of a request
+    /// @returns {TransactionRequest | Error}
+    #[wasm_bindgen(js_name = fromJson)]
+    pub fn from_json(json: &str) -> Result<TransactionRequest, String> {
+        let request: Self = serde_json::from_str(json).map_err(|e| format!("Invalid transaction request: {e}"))?;
+        request.validate()?;
+        Ok(request)
+    }
+}
+
+impl TransactionRequest {
+    // Check the program id and function name are well formed
+    fn validate(&self) -> Result<(), String> {
+        ProgramIDNative::from_str(&self.program).map_err(|_| format!("Invalid program id '{}'", self.program))?;
+        IdentifierNative::from_str(&self.function).map_err(|_| format!("Invalid function name '{}'", self.function))?;
+        Ok(())
+    }
+}
+
+/// Request from a dApp to a wallet to sign a message, answered with a `SignedMessage` bound to
+/// the domain of the dApp
+///
+/// @example
+/// const request = new SignatureRequest("app.example.com", new TextEncoder().encode("Log in"));
+/// const json = request.toJson();
+/// // {"domain":"app.example.com","message":"4c6f6720696e"}
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignatureRequest {
+    domain: String,
+    message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+}
+
+#[wasm_bindgen]
+impl SignatureRequest {
+    /// Create a request to sign a message
+    ///
+    /// @param {string} domain Domain of the dApp requesting the signature, e.g. "app.example.com"
+    /// @param {Uint8Array} message Byte representation of the message to sign
+    /// @param {string | undefined} address (optional) The address expected to sign the message
+    /// @returns {SignatureRequest | Error}
+    #[wasm_bindgen(constructor)]
+    pub fn new(domain: &str, message: &[u8], address: Option<String>) -> Result<SignatureRequest, String> {
+        let request = Self { domain: domain.to_string(), message: hex::encode(message), address };
+        request.validate()?;
+        Ok(request)
+    }
+
+    /// Get the domain of the dApp requesting the signature
+    ///
+    /// @returns {string} Domain of the request
+    pub fn domain(&self) -> String {
+        self.domain.clone()
+    }
+
+    /// Get the message to sign
+    ///
+    /// @returns {Uint8Array | Error} Byte representation of the message
+    pub fn message(&self) -> Result<Vec<u8>, String> {
+        hex::decode(&self.message).map_err(|_| "Invalid message encoding".to_string())
+    }
+
+    /// Get the address expected to sign the message
+    ///
+    /// @returns {string | undefined} Address of the expected signer
+    pub fn address(&self) -> Option<String> {
+        self.address.clone()
+    }
+
+    /// Sign the message of the request, checking the private key belongs to the expected address
+    ///
+    /// @param {PrivateKey} private_key The private key to sign the message with
+    /// @returns {SignedMessage | Error} Signed message envelope answering the request
+    pub fn sign(&self, private_key: &PrivateKey) -> Result<SignedMessage, String> {
+        if let Some(address) = &self.address {
+            if &private_key.to_address().to_string() != address {
+                return Err(format!("The signature was requested from {address}"));
+            }
+        }
+        SignedMessage::sign(private_key, &self.domain, &self.message()?, None)
+    }
+
+    /// Get the JSON representation of the request
+    ///
+    /// @returns {string} JSON representation of the request
+    #[wasm_bindgen(js_name = toJson)]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// Create a request from its JSON representation
+    ///
+    /// @param {string} json JSON representation of a request
+    /// @returns {SignatureRequest | Error}
+    #[wasm_bindgen(js_name = fromJson)]
+    pub fn from_json(json: &str) -> Result<SignatureRequest, String> {
+        let request: Self = serde_json::from_str(json).map_err(|e| format!("Invalid signature request: {e}"))?;
+        request.validate()?;
+        Ok(request)
+    }
+}
+
+impl SignatureRequest {
+    // Check the domain is set and the message and address are well formed
+    fn validate(&self) -> Result<(), String> {
+        if self.domain.is_empty() {
+            return Err("The domain of a signature request cannot be empty".to_string());
+        }
+        self.message()?;
+        if let Some(address) = &self.address {
+            AddressNative::from_str(address).map_err(|_| format!("Invalid address '{address}'"))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_transaction_request_json_roundtrip() {
+        let inputs = Array::of2(&"1u32".into(), &"2u32".into());
+        let request = TransactionRequest::new("hello.aleo", "main", inputs, 1000, true).unwrap();
+        let json = request.to_json();
+        assert_eq!(
+            json,
+            r#"{"program":"hello.aleo","function":"main","inputs":["1u32","2u32"],"fee":{"priorityFee":1000,"privateFee":true}}"#
+        );
+        assert_eq!(TransactionRequest::from_json(&json).unwrap(), request);
+
+        let request =
+            TransactionRequest::from_json(r#"{"program":"hello.aleo","function":"main","inputs":[]}"#).unwrap();
+        assert_eq!(request.priority_fee(), 0);
+        assert!(!request.private_fee());
+        assert!(TransactionRequest::from_json(r#"{"program":"hello","function":"main","inputs":[]}"#).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_signature_request_sign() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address().to_string();
+        let request = SignatureRequest::new("app.example.com", b"Log in", Some(address)).unwrap();
+        let request = SignatureRequest::from_json(&request.to_json()).unwrap();
+        let signed_message = request.sign(&private_key).unwrap();
+        assert_eq!(signed_message.domain(), "app.example.com");
+        assert_eq!(signed_message.message().unwrap(), b"Log in");
+        assert!(request.sign(&PrivateKey::new()).is_err());
+    }
+}
@@ -17,5 +17,14 @@
 pub mod record_ciphertext;
 pub use record_ciphertext::*;
 
+pub mod record_finder;
+pub use record_finder::*;
+
 pub mod record_plaintext;
 pub use record_plaintext::*;
+
+pub mod record_scanner;
+pub use record_scanner::*;
+
+pub mod record_set;
+pub use record_set::*;
@@ -15,7 +15,10 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::RecordPlaintext;
-use crate::{account::ViewKey, types::RecordCiphertextNative};
+use crate::{
+    account::{GraphKey, ViewKey},
+    types::{FieldNative, FromBytes, RecordCiphertextNative, RecordPlaintextNative, ToBytes},
+};
 
 use std::{ops::Deref, str::FromStr};
 use wasm_bindgen::prelude::*;
@@ -45,6 +48,25 @@ impl RecordCiphertext {
         self.0.to_string()
     }
 
+    /// Get a record ciphertext from its little-endian byte representation
+    ///
+    /// @param {Uint8Array} bytes Byte representation of a record ciphertext
+    /// @returns {RecordCiphertext | Error} Record ciphertext
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<RecordCiphertext, String> {
+        Ok(Self(
+            RecordCiphertextNative::from_bytes_le(bytes).map_err(|_| "Invalid record ciphertext bytes".to_string())?,
+        ))
+    }
+
+    /// Get the little-endian byte representation of the record ciphertext
+    ///
+    /// @returns {Uint8Array | Error} Byte representation of the record ciphertext
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        self.0.to_bytes_le().map_err(|_| "Failed to serialize record ciphertext".to_string())
+    }
+
     /// Decrypt the record ciphertext into plaintext using the view key. The record will only
     /// decrypt if the record was encrypted by the account corresponding to the view key
     ///
@@ -64,6 +86,32 @@ impl RecordCiphertext {
     pub fn is_owner(&self, view_key: &ViewKey) -> bool {
         self.0.is_owner(view_key)
     }
+
+    /// Get the nonce of the record. This can be used to uniquely identify a record.
+    ///
+    /// @returns {string} Nonce of the record
+    pub fn nonce(&self) -> String {
+        self.0.nonce().to_string()
+    }
+
+    /// Get the tag of a record from its commitment, without decrypting the record. The tag is
+    /// published on chain when the record is spent
+    ///
+    /// @param {GraphKey} graph_key Graph key of the account that owns the record
+    /// @param {string} commitment String representation of the record commitment
+    /// @returns {string | Error} String representation of the record tag
+    pub fn tag(graph_key: &GraphKey, commitment: &str) -> Result<String, String> {
+        let commitment = FieldNative::from_str(commitment).map_err(|_| "Invalid commitment specified".to_string())?;
+        let tag = RecordPlaintextNative::tag((**graph_key).sk_tag(), commitment)
+            .map_err(|_| "Tag derivation failed".to_string())?;
+        Ok(tag.to_string())
+    }
+}
+
+impl From<RecordCiphertextNative> for RecordCiphertext {
+    fn from(record: RecordCiphertextNative) -> Self {
+        Self(record)
+    }
 }
 
 impl FromStr for RecordCiphertext {
@@ -107,6 +155,16 @@ mod tests {
         assert_eq!(record.to_string(), OWNER_CIPHERTEXT);
     }
 
+    #[wasm_bindgen_test]
+    fn test_to_and_from_bytes() {
+        let record = RecordCiphertext::from_string(OWNER_CIPHERTEXT).unwrap();
+        let bytes = record.to_bytes().unwrap();
+        assert_eq!(bytes, RecordCiphertextNative::from_str(OWNER_CIPHERTEXT).unwrap().to_bytes_le().unwrap());
+        assert_eq!(RecordCiphertext::from_bytes(&bytes).unwrap().to_string(), OWNER_CIPHERTEXT);
+        assert!(RecordCiphertext::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(RecordCiphertext::from_bytes(&[]).is_err());
+    }
+
     #[wasm_bindgen_test]
     fn test_invalid_strings() {
         let invalid_bech32 = "record2qqj3a67efazf0awe09grqqg44htnh9vaw7l729vl309c972x7ldquqq2k2cax8s7qsqqyqtpgvqqyqsq4seyrzvfa98fkggzccqr68af8e9m0q8rzeqh8a8aqql3a854v58sgrygdv4jn9s8ckwfd48vujrmv0rtfasqh8ygn88ch34ftck8szspvfpsqqszqzvxx9t8s9g66teeepgxmvnw5ymgapcwt2lpy9d5eus580k08wpq544jcl437wjv206u5pxst6few9ll4yhufwldgpx80rlwq8nhssqywmfsd85skg564vqhm3gxsp8q6r30udmqxrxmxx2v8xycdg8pn5ps3dhfvv";
@@ -135,4 +193,11 @@ mod tests {
         let incorrect_view_key = ViewKey::from_string(NON_OWNER_VIEW_KEY);
         assert!(!record.is_owner(&incorrect_view_key));
     }
+
+    #[wasm_bindgen_test]
+    fn test_nonce() {
+        let record = RecordCiphertext::from_string(OWNER_CIPHERTEXT).unwrap();
+        let plaintext = record.decrypt(&ViewKey::from_string(OWNER_VIEW_KEY)).unwrap();
+        assert_eq!(record.nonce(), plaintext.nonce());
+    }
 }
@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{RecordPlaintext, RecordScanner};
+use crate::{account::PrivateKey, log, AleoNetworkClient};
+
+use wasm_bindgen::prelude::*;
+
+/// Default number of recent blocks scanned for records
+const DEFAULT_BLOCKS_TO_SCAN: u32 = 5_000;
+
+/// Strategy used to select a record for an amount of microcredits
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecordSelection {
+    /// Select the largest record holding at least the amount
+    LargestFirst,
+    /// Select a record holding exactly the amount, or otherwise the smallest record holding more
+    ExactFit,
+}
+
+/// Finder of unspent credits records owned by a private key in the recent blocks of an Aleo network
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct RecordFinder {
+    private_key: PrivateKey,
+    url: String,
+    blocks_to_scan: u32,
+}
+
+#[wasm_bindgen]
+impl RecordFinder {
+    /// Create a record finder for a private key
+    ///
+    /// @param {PrivateKey} private_key Private key of the account to find records for
+    /// @param {string} url The url of the Aleo network node to fetch blocks from
+    /// @param {number | undefined} blocks_to_scan (optional) Number of recent blocks to scan, defaults to 5000
+    /// @returns {RecordFinder} Record finder
+    #[wasm_bindgen(constructor)]
+    pub fn new(private_key: &PrivateKey, url: &str, blocks_to_scan: Option<u32>) -> Self {
+        Self {
+            private_key: private_key.clone(),
+            url: url.trim_end_matches('/').to_string(),
+            blocks_to_scan: blocks_to_scan.unwrap_or(DEFAULT_BLOCKS_TO_SCAN),
+        }
+    }
+
+    /// Find an unspent credits record holding at least an amount of microcredits
+    ///
+    /// @param {number} microcredits Amount of microcredits the record must hold
+    /// @param {RecordSelection | undefined} selection (optional) Strategy used to select the record, defaults to largest first
+    /// @returns {RecordPlaintext | Error} Unspent credits record
+    #[wasm_bindgen(js_name = findCreditsRecord)]
+    pub async fn find_credits_record(
+        &self,
+        microcredits: u64,
+        selection: Option<RecordSelection>,
+    ) -> Result<RecordPlaintext, String> {
+        let selection = selection.unwrap_or(RecordSelection::LargestFirst);
+        let mut records = self.select_credits_records(&[(microcredits, selection)]).await?;
+        Ok(records.remove(0))
+    }
+}
+
+impl RecordFinder {
+    /// Find distinct unspent credits records for several amounts of microcredits
+    pub(crate) async fn select_credits_records(
+        &self,
+        amounts: &[(u64, RecordSelection)],
+    ) -> Result<Vec<RecordPlaintext>, String> {
+        select_records(self.unspent_credits_records().await?, amounts)
+    }
+
+    // Scan the recent blocks for credits records and keep the ones which have not been spent
+    pub(crate) async fn unspent_credits_records(&self) -> Result<Vec<RecordPlaintext>, String> {
+        let latest_height = AleoNetworkClient::new(&self.url).get_latest_height().await?;
+        let start_height = latest_height.saturating_sub(self.blocks_to_scan);
+        log(&format!("Searching blocks {start_height} to {latest_height} for unspent credits records"));
+        let view_key = self.private_key.to_view_key();
+        let scanner = RecordScanner::new(&view_key, &self.url, Some("credits.aleo".to_string()));
+        let records = scanner.scan(start_height, latest_height + 1, None).await?.into_records()?;
+
+        let serial_numbers = records
+            .iter()
+            .map(|record| record.serial_number_string(&self.private_key, "credits.aleo", "credits"))
+            .collect::<Result<Vec<_>, String>>()?;
+        let spent = RecordPlaintext::are_serial_numbers_spent(&self.url, &serial_numbers).await?;
+        Ok(records.into_iter().zip(spent).filter(|(_, spent)| !spent).map(|(record, _)| record).collect())
+    }
+}
+
+// Select a distinct record for each amount of microcredits from the candidates
+fn select_records(
+    mut candidates: Vec<RecordPlaintext>,
+    amounts: &[(u64, RecordSelection)],
+) -> Result<Vec<RecordPlaintext>, String> {
+    let mut records = Vec::with_capacity(amounts.len());
+    for (microcredits, selection) in amounts {
+        let sufficient = candidates.iter().enumerate().filter(|(_, record)| record.microcredits() >= *microcredits);
+        let index = match selection {
+            RecordSelection::LargestFirst => sufficient.max_by_key(|(_, record)| record.microcredits()),
+            RecordSelection::ExactFit => sufficient.min_by_key(|(_, record)| record.microcredits()),
+        }
+        .map(|(index, _)| index)
+        .ok_or_else(|| format!("No unspent record holding at least {microcredits} microcredits was found"))?;
+        records.push(candidates.swap_remove(index));
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    // Create a credits record holding an amount of microcredits
+    fn credits_record(microcredits: u64) -> RecordPlaintext {
+        RecordPlaintext::from_string(&format!(
+            "{{ owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3.private, microcredits: {microcredits}u64.private, _nonce: 0group.public }}"
+        ))
+        .unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_select_records() {
+        let candidates = vec![credits_record(100), credits_record(500), credits_record(250)];
+
+        // Check the selection strategies.
+        let records = select_records(candidates.clone(), &[(200, RecordSelection::LargestFirst)]).unwrap();
+        assert_eq!(records[0].microcredits(), 500);
+        let records = select_records(candidates.clone(), &[(200, RecordSelection::ExactFit)]).unwrap();
+        assert_eq!(records[0].microcredits(), 250);
+        let records = select_records(candidates.clone(), &[(100, RecordSelection::ExactFit)]).unwrap();
+        assert_eq!(records[0].microcredits(), 100);
+
+        // Check each amount is funded by a distinct record.
+        let amounts = [(200, RecordSelection::ExactFit), (200, RecordSelection::ExactFit)];
+        let records = select_records(candidates.clone(), &amounts).unwrap();
+        assert_eq!((records[0].microcredits(), records[1].microcredits()), (250, 500));
+        let amounts = [(300, RecordSelection::LargestFirst), (300, RecordSelection::LargestFirst)];
+        assert!(select_records(candidates, &amounts).is_err());
+    }
+}
@@ -14,14 +14,41 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
+use super::RecordCiphertext;
 use crate::{
-    account::PrivateKey,
-    types::{IdentifierNative, ProgramIDNative, RecordPlaintextNative},
+    account::{Address, PrivateKey},
+    types::{
+        CurrentNetwork,
+        EntryNative,
+        FieldNative,
+        FromBytes,
+        GroupNative,
+        IdentifierNative,
+        LiteralNative,
+        Network,
+        OwnerNative,
+        PlaintextNative,
+        ProgramIDNative,
+        RecordPlaintextNative,
+        ScalarNative,
+        ToBytes,
+        Uniform,
+    },
+    AleoNetworkClient,
     Credits,
 };
 
+use futures::{stream, StreamExt, TryStreamExt};
+use indexmap::IndexMap;
+use js_sys::{Array, BigInt, Object, Reflect};
+use once_cell::sync::OnceCell;
+use rand::{rngs::StdRng, SeedableRng};
+use serde_json::{json, Value};
 use std::{ops::Deref, str::FromStr};
-use wasm_bindgen::prelude::*;
+use wasm_bindgen::{prelude::*, JsCast};
+
+/// Maximum number of serial numbers looked up on the node at the same time
+const SPENT_CHECK_CONCURRENCY: usize = 8;
 
 /// Plaintext representation of an Aleo record
 #[wasm_bindgen]
@@ -48,6 +75,23 @@ impl RecordPlaintext {
         self.0.to_string()
     }
 
+    /// Get a record plaintext from its little-endian byte representation
+    ///
+    /// @param {Uint8Array} bytes Byte representation of a record plaintext
+    /// @returns {RecordPlaintext | Error} Record plaintext
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<RecordPlaintext, String> {
+        Ok(Self(RecordPlaintextNative::from_bytes_le(bytes).map_err(|_| "Invalid record plaintext bytes".to_string())?))
+    }
+
+    /// Get the little-endian byte representation of the record plaintext
+    ///
+    /// @returns {Uint8Array | Error} Byte representation of the record plaintext
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        self.0.to_bytes_le().map_err(|_| "Failed to serialize record plaintext".to_string())
+    }
+
     /// Returns the amount of microcredits in the record
     ///
     /// @returns {u64} Amount of microcredits in the record
@@ -63,6 +107,164 @@ impl RecordPlaintext {
         self.0.nonce().to_string()
     }
 
+    /// Get a javascript object representation of the record of the form
+    /// `{ owner, ownerVisibility, nonce, microcredits, data }`. The microcredits are only present for
+    /// credits records. Each data entry is an object `{ value, type, visibility }` where booleans are
+    /// decoded into booleans, integers up to 32 bits into numbers, larger integers into BigInts and
+    /// other literals are kept as their Aleo string representation. Struct values are objects of
+    /// `{ value, type }` members with the type "struct"
+    ///
+    /// @returns {Object | Error} Javascript object representation of the record
+    #[wasm_bindgen(js_name = toJsObject)]
+    pub fn to_js_object(&self) -> Result<Object, String> {
+        let owner = self.0.owner();
+        let visibility = if owner.is_public() { "public" } else { "private" };
+        let data = Object::new();
+        for (identifier, entry) in self.0.data() {
+            let (plaintext, visibility) = match entry {
+                EntryNative::Constant(plaintext) => (plaintext, "constant"),
+                EntryNative::Public(plaintext) => (plaintext, "public"),
+                EntryNative::Private(plaintext) => (plaintext, "private"),
+            };
+            let value = plaintext_to_js(plaintext)?;
+            set(&value, "visibility", &JsValue::from_str(visibility))?;
+            set(&data, &identifier.to_string(), &value)?;
+        }
+
+        let object = Object::new();
+        set(&object, "owner", &JsValue::from_str(&(**owner).to_string()))?;
+        set(&object, "ownerVisibility", &JsValue::from_str(visibility))?;
+        set(&object, "nonce", &JsValue::from_str(&self.0.nonce().to_string()))?;
+        if let Ok(microcredits) = self.0.microcredits() {
+            set(&object, "microcredits", &BigInt::from(microcredits).into())?;
+        }
+        set(&object, "data", &data)?;
+        Ok(object)
+    }
+
+    /// Create a record from its javascript object representation, see `toJsObject`
+    ///
+    /// @param {Object} object Javascript object representation of a record
+    /// @returns {RecordPlaintext | Error} Record plaintext
+    #[wasm_bindgen(js_name = fromJsObject)]
+    pub fn from_js_object(object: &Object) -> Result<RecordPlaintext, String> {
+        let owner = get_string(object, "owner")?;
+        let owner = LiteralNative::from_str(&owner).map_err(|_| "Invalid record owner".to_string())?;
+        let owner = match (owner, get_string(object, "ownerVisibility")?.as_str()) {
+            (LiteralNative::Address(address), "public") => OwnerNative::Public(address),
+            (LiteralNative::Address(address), "private") => {
+                OwnerNative::Private(PlaintextNative::from(LiteralNative::Address(address)))
+            }
+            _ => return Err("Invalid record owner".to_string()),
+        };
+        let nonce =
+            GroupNative::from_str(&get_string(object, "nonce")?).map_err(|_| "Invalid record nonce".to_string())?;
+
+        let mut data = IndexMap::new();
+        let entries = get(object, "data")?.dyn_into::<Object>().map_err(|_| "Invalid record data".to_string())?;
+        for entry in Object::entries(&entries).iter() {
+            let entry = Array::from(&entry);
+            let name = entry.get(0).as_string().ok_or_else(|| "Invalid record entry name".to_string())?;
+            let identifier =
+                IdentifierNative::from_str(&name).map_err(|_| format!("Invalid record entry name '{name}'"))?;
+            let value = entry.get(1);
+            let plaintext = plaintext_from_js(&value)?;
+            let entry = match get_string(&value, "visibility")?.as_str() {
+                "constant" => EntryNative::Constant(plaintext),
+                "public" => EntryNative::Public(plaintext),
+                "private" => EntryNative::Private(plaintext),
+                visibility => return Err(format!("Invalid visibility '{visibility}' for record entry '{name}'")),
+            };
+            data.insert(identifier, entry);
+        }
+        RecordPlaintextNative::from_plaintext(owner, data, nonce).map(Self).map_err(|e| e.to_string())
+    }
+
+    /// Get the data entries of the record as a JSON string. The entries are an array of objects
+    /// `{ name, type, value, visibility }` in the order they appear in the record. Literal values are
+    /// strings without the type suffix, except for booleans, and struct values are arrays of
+    /// `{ name, type, value }` members
+    ///
+    /// @returns {string | Error} JSON representation of the record data
+    #[wasm_bindgen(js_name = dataAsJson)]
+    pub fn data_as_json(&self) -> Result<String, String> {
+        Ok(data_to_json(self.0.data())?.to_string())
+    }
+
+    /// Create a record owned privately by an address from the JSON representation of its data, see
+    /// `dataAsJson`. The record is given a fresh nonce
+    ///
+    /// @param {Address} owner Address of the owner of the record
+    /// @param {string} data JSON representation of the record data
+    /// @returns {RecordPlaintext | Error} Record plaintext
+    #[wasm_bindgen(js_name = fromDataJson)]
+    pub fn from_data_json(owner: &Address, data: &str) -> Result<RecordPlaintext, String> {
+        let data = serde_json::from_str(data).map_err(|e| format!("Invalid record data JSON: {e}"))?;
+        let owner = OwnerNative::Private(PlaintextNative::from(LiteralNative::Address(**owner)));
+        let nonce = CurrentNetwork::g_scalar_multiply(&ScalarNative::rand(&mut StdRng::from_entropy()));
+        RecordPlaintextNative::from_plaintext(owner, data_from_json(&data)?, nonce).map(Self).map_err(|e| e.to_string())
+    }
+
+    /// Get the canonical JSON representation of the record of the form
+    /// `{ data, nonce, owner, ownerVisibility }` where the data entries are encoded as in
+    /// `dataAsJson`. The record can be restored exactly with `fromJsonString`
+    ///
+    /// @returns {string | Error} JSON representation of the record
+    #[wasm_bindgen(js_name = toJsonString)]
+    pub fn to_json_string(&self) -> Result<String, String> {
+        let owner = self.0.owner();
+        let visibility = if owner.is_public() { "public" } else { "private" };
+        let record = json!({
+            "owner": (**owner).to_string(),
+            "ownerVisibility": visibility,
+            "nonce": self.0.nonce().to_string(),
+            "data": data_to_json(self.0.data())?,
+        });
+        Ok(record.to_string())
+    }
+
+    /// Create a record from its canonical JSON representation, see `toJsonString`
+    ///
+    /// @param {string} json JSON representation of a record
+    /// @returns {RecordPlaintext | Error} Record plaintext
+    #[wasm_bindgen(js_name = fromJsonString)]
+    pub fn from_json_string(json: &str) -> Result<RecordPlaintext, String> {
+        let record: Value = serde_json::from_str(json).map_err(|e| format!("Invalid record JSON: {e}"))?;
+        let owner = record["owner"].as_str().and_then(|owner| LiteralNative::from_str(owner).ok());
+        let owner = match (owner, record["ownerVisibility"].as_str()) {
+            (Some(LiteralNative::Address(address)), Some("public")) => OwnerNative::Public(address),
+            (Some(LiteralNative::Address(address)), Some("private")) => {
+                OwnerNative::Private(PlaintextNative::from(LiteralNative::Address(address)))
+            }
+            _ => return Err("Invalid record owner".to_string()),
+        };
+        let nonce = record["nonce"]
+            .as_str()
+            .and_then(|nonce| GroupNative::from_str(nonce).ok())
+            .ok_or_else(|| "Invalid record nonce".to_string())?;
+        RecordPlaintextNative::from_plaintext(owner, data_from_json(&record["data"])?, nonce)
+            .map(Self)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Encrypt the record to an address. The owner of the record is set to the address, keeping the
+    /// visibility of the owner, and the record is given a fresh nonce
+    ///
+    /// @param {Address} address Address of the new owner of the record
+    /// @returns {RecordCiphertext | Error} Record ciphertext
+    pub fn encrypt(&self, address: &Address) -> Result<RecordCiphertext, String> {
+        let owner = match self.0.owner().is_public() {
+            true => OwnerNative::Public(**address),
+            false => OwnerNative::Private(PlaintextNative::from(LiteralNative::Address(**address))),
+        };
+        let randomizer = ScalarNative::rand(&mut StdRng::from_entropy());
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let record =
+            RecordPlaintextNative::from_plaintext(owner, self.0.data().clone(), nonce).map_err(|e| e.to_string())?;
+        let ciphertext = record.encrypt(randomizer).map_err(|_| "Record encryption failed".to_string())?;
+        Ok(RecordCiphertext::from(ciphertext))
+    }
+
     /// Attempt to get the serial number of a record to determine whether or not is has been spent
     ///
     /// @param {PrivateKey} private_key Private key of the account that owns the record
@@ -76,17 +278,126 @@ impl RecordPlaintext {
         program_id: &str,
         record_name: &str,
     ) -> Result<String, String> {
+        let commitment = self.commitment_field(program_id, record_name)?;
+        let serial_number = RecordPlaintextNative::serial_number(private_key.into(), commitment)
+            .map_err(|_| "Serial number derivation failed".to_string())?;
+        Ok(serial_number.to_string())
+    }
+
+    /// Get the commitment of the record. This can be used to join the record with the transition
+    /// outputs published on chain
+    ///
+    /// @param {string} program_id Program ID of the program that the record is associated with
+    /// @param {string} record_name Name of the record
+    /// @returns {string | Error} String representation of the record commitment
+    #[wasm_bindgen(js_name = commitmentString)]
+    pub fn commitment_string(&self, program_id: &str, record_name: &str) -> Result<String, String> {
+        Ok(self.commitment_field(program_id, record_name)?.to_string())
+    }
+
+    /// Get the tag of the record, which is published on chain when the record is spent. Scanners can
+    /// compare tags against the chain to find spent records without computing serial numbers
+    ///
+    /// @param {string} sk_tag Tag secret key of the account that owns the record, see `GraphKey.skTag`
+    /// @param {string} program_id Program ID of the program that the record is associated with
+    /// @param {string} record_name Name of the record
+    /// @returns {string | Error} String representation of the record tag
+    pub fn tag(&self, sk_tag: &str, program_id: &str, record_name: &str) -> Result<String, String> {
+        let sk_tag = FieldNative::from_str(sk_tag).map_err(|_| "Invalid sk_tag specified".to_string())?;
+        let commitment = self.commitment_field(program_id, record_name)?;
+        let tag = RecordPlaintextNative::tag(sk_tag, commitment).map_err(|_| "Tag derivation failed".to_string())?;
+        Ok(tag.to_string())
+    }
+
+    /// Determine whether or not the record has been spent by looking up its serial number on an
+    /// Aleo network node
+    ///
+    /// @param {PrivateKey} private_key Private key of the account that owns the record
+    /// @param {string} program_id Program ID of the program that the record is associated with
+    /// @param {string} record_name Name of the record
+    /// @param {string} url The url of the Aleo network node to query
+    /// @returns {boolean | Error} Boolean representing whether or not the record has been spent
+    #[wasm_bindgen(js_name = isSpent)]
+    pub async fn is_spent(
+        &self,
+        private_key: &PrivateKey,
+        program_id: &str,
+        record_name: &str,
+        url: &str,
+    ) -> Result<bool, String> {
+        let serial_number = self.serial_number_string(private_key, program_id, record_name)?;
+        Self::is_serial_number_spent(url, &serial_number).await
+    }
+
+    /// Determine whether or not each of many records of the same type has been spent. The node
+    /// is queried for up to 8 records at a time
+    ///
+    /// @param {Array<string>} records Array of string representations of record plaintexts
+    /// @param {PrivateKey} private_key Private key of the account that owns the records
+    /// @param {string} program_id Program ID of the program that the records are associated with
+    /// @param {string} record_name Name of the records
+    /// @param {string} url The url of the Aleo network node to query
+    /// @returns {Array<boolean> | Error} Array of booleans representing whether or not each record has been spent
+    #[wasm_bindgen(js_name = isSpentBatch)]
+    pub async fn is_spent_batch(
+        records: Array,
+        private_key: &PrivateKey,
+        program_id: &str,
+        record_name: &str,
+        url: &str,
+    ) -> Result<Array, String> {
+        let serial_numbers = (0..records.length())
+            .map(|index| {
+                let record = records
+                    .get(index)
+                    .as_string()
+                    .and_then(|record| RecordPlaintext::from_str(&record).ok())
+                    .ok_or_else(|| format!("Invalid record plaintext at index {index}"))?;
+                record.serial_number_string(private_key, program_id, record_name)
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let spent = Self::are_serial_numbers_spent(url, &serial_numbers).await?;
+        Ok(spent.into_iter().map(JsValue::from_bool).collect())
+    }
+}
+
+impl RecordPlaintext {
+    // Compute the commitment of the record for a program and record name
+    fn commitment_field(&self, program_id: &str, record_name: &str) -> Result<FieldNative, String> {
         let parsed_program_id =
             ProgramIDNative::from_str(program_id).map_err(|_| "Invalid ProgramID specified".to_string())?;
         let record_identifier = IdentifierNative::from_str(record_name)
             .map_err(|_| "Invalid Identifier specified for record".to_string())?;
-        let commitment = self
-            .to_commitment(&parsed_program_id, &record_identifier)
-            .map_err(|_| "A commitment for this record and program could not be computed".to_string())?;
+        self.to_commitment(&parsed_program_id, &record_identifier)
+            .map_err(|_| "A commitment for this record and program could not be computed".to_string())
+    }
 
-        let serial_number = RecordPlaintextNative::serial_number(private_key.into(), commitment)
-            .map_err(|_| "Serial number derivation failed".to_string())?;
-        Ok(serial_number.to_string())
+    // Check whether a serial number has been published on chain by looking up the transition
+    // which spent it
+    pub(crate) async fn is_serial_number_spent(url: &str, serial_number: &str) -> Result<bool, String> {
+        let (status, body) = AleoNetworkClient::new(url)
+            .get_response(&format!("find/transitionID/{serial_number}"))
+            .await
+            .map_err(|e| format!("Failed to query the serial number: {e}"))?;
+        if status.is_success() {
+            return Ok(true);
+        }
+        // Nodes respond with an error mentioning the missing transition ID for unspent records. Any
+        // other failure, including a bare 404 from a misconfigured url, leaves the status unknown.
+        if body.contains("Missing transition ID") {
+            return Ok(false);
+        }
+        Err(format!("Failed to query the serial number - the node responded with {status}: {body}"))
+    }
+
+    // Check whether each of many serial numbers has been spent, querying the node for a bounded
+    // number of them at a time and keeping the order of the serial numbers
+    pub(crate) async fn are_serial_numbers_spent(url: &str, serial_numbers: &[String]) -> Result<Vec<bool>, String> {
+        stream::iter(serial_numbers)
+            .map(|serial_number| Self::is_serial_number_spent(url, serial_number))
+            .buffered(SPENT_CHECK_CONCURRENCY)
+            .try_collect()
+            .await
     }
 }
 
@@ -112,9 +423,208 @@ impl Deref for RecordPlaintext {
     }
 }
 
+// Convert a plaintext value into a javascript object of the form `{ value, type }`
+pub(crate) fn plaintext_to_js(plaintext: &PlaintextNative) -> Result<JsValue, String> {
+    let object = Object::new();
+    match plaintext {
+        PlaintextNative::Literal(literal, _) => {
+            let value = match literal {
+                LiteralNative::Boolean(value) => JsValue::from_bool(**value),
+                LiteralNative::I8(value) => JsValue::from(**value),
+                LiteralNative::I16(value) => JsValue::from(**value),
+                LiteralNative::I32(value) => JsValue::from(**value),
+                LiteralNative::I64(value) => BigInt::from(**value).into(),
+                LiteralNative::I128(value) => BigInt::from(**value).into(),
+                LiteralNative::U8(value) => JsValue::from(**value),
+                LiteralNative::U16(value) => JsValue::from(**value),
+                LiteralNative::U32(value) => JsValue::from(**value),
+                LiteralNative::U64(value) => BigInt::from(**value).into(),
+                LiteralNative::U128(value) => BigInt::from(**value).into(),
+                literal => JsValue::from_str(&literal.to_string()),
+            };
+            set(&object, "value", &value)?;
+            set(&object, "type", &JsValue::from_str(&literal.to_type().to_string()))?;
+        }
+        PlaintextNative::Struct(members, _) => {
+            let value = Object::new();
+            for (identifier, member) in members {
+                set(&value, &identifier.to_string(), &plaintext_to_js(member)?)?;
+            }
+            set(&object, "value", &value)?;
+            set(&object, "type", &JsValue::from_str("struct"))?;
+        }
+        #[allow(unreachable_patterns)]
+        _ => return Err("Only literal and struct record entries are supported".to_string()),
+    }
+    Ok(object.into())
+}
+
+// Convert a javascript object of the form `{ value, type }` into a plaintext value
+fn plaintext_from_js(object: &JsValue) -> Result<PlaintextNative, String> {
+    let value = get(object, "value")?;
+    let literal_type = get_string(object, "type")?;
+    let literal = match literal_type.as_str() {
+        "struct" => {
+            let mut members = IndexMap::new();
+            for member in Object::entries(value.unchecked_ref::<Object>()).iter() {
+                let member = Array::from(&member);
+                let name = member.get(0).as_string().ok_or_else(|| "Invalid struct member name".to_string())?;
+                let identifier =
+                    IdentifierNative::from_str(&name).map_err(|_| format!("Invalid struct member name '{name}'"))?;
+                members.insert(identifier, plaintext_from_js(&member.get(1))?);
+            }
+            return Ok(PlaintextNative::Struct(members, OnceCell::new()));
+        }
+        "boolean" => value.as_bool().map(|value| value.to_string()),
+        "i8" | "i16" | "i32" | "i64" | "i128" | "u8" | "u16" | "u32" | "u64" | "u128" => {
+            let digits = match value.dyn_ref::<BigInt>() {
+                Some(value) => value.to_string(10).ok().map(String::from),
+                None => value.as_f64().filter(|value| value.fract() == 0.0).map(|value| format!("{value:.0}")),
+            };
+            digits.map(|digits| format!("{digits}{literal_type}"))
+        }
+        _ => value.as_string(),
+    };
+    let literal = literal
+        .and_then(|literal| LiteralNative::from_str(&literal).ok())
+        .filter(|literal| literal.to_type().to_string() == literal_type)
+        .ok_or_else(|| format!("Invalid value for type '{literal_type}'"))?;
+    Ok(PlaintextNative::from(literal))
+}
+
+// Convert record data entries into a JSON array of `{ name, type, value, visibility }` objects
+fn data_to_json(data: &IndexMap<IdentifierNative, EntryNative>) -> Result<Value, String> {
+    data.iter()
+        .map(|(identifier, entry)| {
+            let (plaintext, visibility) = match entry {
+                EntryNative::Constant(plaintext) => (plaintext, "constant"),
+                EntryNative::Public(plaintext) => (plaintext, "public"),
+                EntryNative::Private(plaintext) => (plaintext, "private"),
+            };
+            let mut value = plaintext_to_json(plaintext)?;
+            value["name"] = json!(identifier.to_string());
+            value["visibility"] = json!(visibility);
+            Ok(value)
+        })
+        .collect::<Result<Vec<_>, String>>()
+        .map(Value::Array)
+}
+
+// Convert a JSON array of `{ name, type, value, visibility }` objects into record data entries
+fn data_from_json(data: &Value) -> Result<IndexMap<IdentifierNative, EntryNative>, String> {
+    let entries = data.as_array().ok_or_else(|| "Record data must be an array of entries".to_string())?;
+    let mut data = IndexMap::new();
+    for value in entries {
+        let name = value["name"].as_str().ok_or_else(|| "Missing record entry name".to_string())?;
+        let identifier = IdentifierNative::from_str(name).map_err(|_| format!("Invalid record entry name '{name}'"))?;
+        let plaintext = plaintext_from_json(value)?;
+        let entry = match value["visibility"].as_str() {
+            Some("constant") => EntryNative::Constant(plaintext),
+            Some("public") => EntryNative::Public(plaintext),
+            Some("private") => EntryNative::Private(plaintext),
+            _ => return Err(format!("Invalid visibility for record entry '{name}'")),
+        };
+        if data.insert(identifier, entry).is_some() {
+            return Err(format!("Duplicate record entry '{name}'"));
+        }
+    }
+    Ok(data)
+}
+
+// Convert a plaintext value into a JSON object of the form `{ type, value }`
+fn plaintext_to_json(plaintext: &PlaintextNative) -> Result<Value, String> {
+    match plaintext {
+        PlaintextNative::Literal(literal, _) => {
+            let literal_type = literal.to_type().to_string();
+            let value = match literal {
+                LiteralNative::Boolean(value) => json!(**value),
+                literal => {
+                    let literal = literal.to_string();
+                    let value = match literal_type.as_str() {
+                        "string" => literal.trim_matches('"'),
+                        literal_type if has_type_suffix(literal_type) => literal.trim_end_matches(literal_type),
+                        _ => literal.as_str(),
+                    };
+                    json!(value)
+                }
+            };
+            Ok(json!({ "type": literal_type, "value": value }))
+        }
+        PlaintextNative::Struct(members, _) => {
+            let members = members
+                .iter()
+                .map(|(identifier, member)| {
+                    let mut value = plaintext_to_json(member)?;
+                    value["name"] = json!(identifier.to_string());
+                    Ok(value)
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+            Ok(json!({ "type": "struct", "value": members }))
+        }
+        #[allow(unreachable_patterns)]
+        _ => Err("Only literal and struct record entries are supported".to_string()),
+    }
+}
+
+// Convert a JSON object of the form `{ type, value }` into a plaintext value
+fn plaintext_from_json(value: &Value) -> Result<PlaintextNative, String> {
+    let literal_type = value["type"].as_str().ok_or_else(|| "Missing value type".to_string())?;
+    let literal = match (literal_type, &value["value"]) {
+        ("struct", Value::Array(entries)) => {
+            let mut members = IndexMap::new();
+            for member in entries {
+                let name = member["name"].as_str().ok_or_else(|| "Missing struct member name".to_string())?;
+                let identifier =
+                    IdentifierNative::from_str(name).map_err(|_| format!("Invalid struct member name '{name}'"))?;
+                members.insert(identifier, plaintext_from_json(member)?);
+            }
+            return Ok(PlaintextNative::Struct(members, OnceCell::new()));
+        }
+        ("boolean", Value::Bool(value)) => value.to_string(),
+        ("string", Value::String(value)) => format!("\"{value}\""),
+        (literal_type, Value::String(value)) if has_type_suffix(literal_type) => format!("{value}{literal_type}"),
+        (_, Value::String(value)) => value.clone(),
+        _ => return Err(format!("Invalid value for type '{literal_type}'")),
+    };
+    let literal = LiteralNative::from_str(&literal)
+        .ok()
+        .filter(|literal| literal.to_type().to_string() == literal_type)
+        .ok_or_else(|| format!("Invalid value for type '{literal_type}'"))?;
+    Ok(PlaintextNative::from(literal))
+}
+
+// Check whether the Aleo representation of literals of a type ends with the type name
+fn has_type_suffix(literal_type: &str) -> bool {
+    matches!(
+        literal_type,
+        "field" | "group" | "scalar" | "i8" | "i16" | "i32" | "i64" | "i128" | "u8" | "u16" | "u32" | "u64" | "u128"
+    )
+}
+
+// Get a property of a javascript object
+fn get(object: &JsValue, key: &str) -> Result<JsValue, String> {
+    Reflect::get(object, &JsValue::from_str(key))
+        .ok()
+        .filter(|value| !value.is_undefined())
+        .ok_or_else(|| format!("Missing property '{key}'"))
+}
+
+// Get a string property of a javascript object
+fn get_string(object: &JsValue, key: &str) -> Result<String, String> {
+    get(object, key)?.as_string().ok_or_else(|| format!("Property '{key}' must be a string"))
+}
+
+// Set a property of a javascript object
+fn set(object: &JsValue, key: &str, value: &JsValue) -> Result<(), String> {
+    Reflect::set(object, &JsValue::from_str(key), value)
+        .map(|_| ())
+        .map_err(|_| format!("Failed to set property '{key}'"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{account::GraphKey, record::RecordCiphertext};
 
     use wasm_bindgen_test::wasm_bindgen_test;
 
@@ -130,6 +640,63 @@ mod tests {
         assert_eq!(record.to_string(), RECORD);
     }
 
+    #[wasm_bindgen_test]
+    fn test_to_and_from_bytes() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        let bytes = record.to_bytes().unwrap();
+        assert_eq!(bytes, RecordPlaintextNative::from_str(RECORD).unwrap().to_bytes_le().unwrap());
+        assert_eq!(RecordPlaintext::from_bytes(&bytes).unwrap().to_string(), RECORD);
+        assert!(RecordPlaintext::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(RecordPlaintext::from_bytes(&[]).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_data_as_json() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        assert_eq!(
+            record.data_as_json().unwrap(),
+            r#"[{"name":"microcredits","type":"u64","value":"1500000000000000","visibility":"private"}]"#
+        );
+
+        let data = r#"[{"name":"amount","type":"u128","value":"7","visibility":"private"},{"name":"position","type":"struct","value":[{"name":"y","type":"i8","value":"-2"},{"name":"x","type":"field","value":"1"}],"visibility":"public"},{"name":"alive","type":"boolean","value":true,"visibility":"constant"}]"#;
+        let owner = PrivateKey::new().to_address();
+        let record = RecordPlaintext::from_data_json(&owner, data).unwrap();
+        assert_eq!(record.data_as_json().unwrap(), data);
+
+        assert!(
+            RecordPlaintext::from_data_json(
+                &owner,
+                r#"[{"name":"amount","type":"u8","value":"256","visibility":"private"}]"#
+            )
+            .is_err()
+        );
+        assert!(
+            RecordPlaintext::from_data_json(
+                &owner,
+                r#"[{"name":"amount","type":"u8","value":1,"visibility":"private"}]"#
+            )
+            .is_err()
+        );
+        assert!(RecordPlaintext::from_data_json(&owner, r#"{"amount":"1u8"}"#).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_to_and_from_json_string() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        let json = record.to_json_string().unwrap();
+        assert_eq!(
+            json,
+            r#"{"data":[{"name":"microcredits","type":"u64","value":"1500000000000000","visibility":"private"}],"nonce":"3077450429259593211617823051143573281856129402760267155982965992208217472983group","owner":"aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3","ownerVisibility":"private"}"#
+        );
+        let restored = RecordPlaintext::from_json_string(&json).unwrap();
+        assert_eq!(restored.to_string(), RECORD);
+        assert_eq!(restored.to_bytes().unwrap(), record.to_bytes().unwrap());
+
+        assert!(RecordPlaintext::from_json_string(&json.replace("private\"}", "hidden\"}")).is_err());
+        assert!(RecordPlaintext::from_json_string(&json.replace("group", "field")).is_err());
+        assert!(RecordPlaintext::from_json_string("{}").is_err());
+    }
+
     #[wasm_bindgen_test]
     fn test_microcredits_from_string() {
         let record = RecordPlaintext::from_string(RECORD).unwrap();
@@ -187,4 +754,84 @@ mod tests {
         );
         assert!(RecordPlaintext::from_string(invalid_bech32).is_err());
     }
+
+    #[wasm_bindgen_test]
+    async fn test_is_spent_batch_rejects_invalid_records() {
+        let pk = PrivateKey::from_string("APrivateKey1zkpDeRpuKmEtLNPdv57aFruPepeH1aGvTkEjBo8bqTzNUhE").unwrap();
+        let records = Array::new();
+        records.push(&JsValue::from_str(RECORD));
+        records.push(&JsValue::from_str("not a record"));
+        let result =
+            RecordPlaintext::is_spent_batch(records, &pk, "credits.aleo", "credits", "http://localhost:3030").await;
+        assert_eq!(result.err(), Some("Invalid record plaintext at index 1".to_string()));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_tag() {
+        let pk = PrivateKey::from_string("APrivateKey1zkpDeRpuKmEtLNPdv57aFruPepeH1aGvTkEjBo8bqTzNUhE").unwrap();
+        let graph_key = GraphKey::from_private_key(&pk);
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        let commitment = record.commitment_string("credits.aleo", "credits").unwrap();
+
+        // Check the tag of the plaintext matches the tag derived from the commitment and graph key.
+        let tag = record.tag(&graph_key.sk_tag(), "credits.aleo", "credits").unwrap();
+        assert_eq!(tag, RecordCiphertext::tag(&graph_key, &commitment).unwrap());
+
+        // Ensure tags depend on the owner of the graph key.
+        let other_graph_key = GraphKey::from_private_key(&PrivateKey::new());
+        assert_ne!(tag, record.tag(&other_graph_key.sk_tag(), "credits.aleo", "credits").unwrap());
+        assert!(record.tag("not a field", "credits.aleo", "credits").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_commitment_and_nonce() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        assert_eq!(record.nonce(), "3077450429259593211617823051143573281856129402760267155982965992208217472983group");
+
+        // Check the commitment depends on the program and record name.
+        let commitment = record.commitment_string("credits.aleo", "credits").unwrap();
+        assert!(commitment.ends_with("field"));
+        assert_eq!(commitment, record.commitment_string("credits.aleo", "credits").unwrap());
+        assert_ne!(commitment, record.commitment_string("token.aleo", "credits").unwrap());
+        assert!(record.commitment_string("not a real program id", "credits").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_to_and_from_js_object() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        let object = record.to_js_object().unwrap();
+        assert_eq!(
+            get_string(&object, "owner").unwrap(),
+            "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3"
+        );
+        assert_eq!(get_string(&object, "ownerVisibility").unwrap(), "private");
+        let microcredits = get(&object, "microcredits").unwrap();
+        assert_eq!(microcredits, JsValue::from(BigInt::from(1500000000000000u64)));
+        let entry = get(&get(&object, "data").unwrap(), "microcredits").unwrap();
+        assert_eq!(get_string(&entry, "type").unwrap(), "u64");
+        assert_eq!(get_string(&entry, "visibility").unwrap(), "private");
+
+        // Check the record survives the round trip.
+        assert_eq!(RecordPlaintext::from_js_object(&object).unwrap().to_string(), RECORD);
+
+        // Ensure values which do not match their type are rejected.
+        set(&entry, "value", &JsValue::from_f64(1.5)).unwrap();
+        assert!(RecordPlaintext::from_js_object(&object).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encrypt() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        let recipient = PrivateKey::new();
+        let ciphertext = record.encrypt(&recipient.to_address()).unwrap();
+
+        // Check the recipient owns the ciphertext and the record has a fresh nonce.
+        let view_key = recipient.to_view_key();
+        assert!(ciphertext.is_owner(&view_key));
+        let decrypted = ciphertext.decrypt(&view_key).unwrap();
+        assert_eq!(**decrypted.owner(), *recipient.to_address());
+        assert_eq!(decrypted.microcredits(), record.microcredits());
+        assert_ne!(decrypted.nonce(), record.nonce());
+        assert_eq!(decrypted.nonce(), ciphertext.nonce());
+    }
 }
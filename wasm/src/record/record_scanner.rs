@@ -0,0 +1,297 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::RecordPlaintext;
+use crate::{
+    account::ViewKey,
+    log,
+    network::MAX_BLOCKS_PER_REQUEST,
+    types::{BlockNative, RecordPlaintextNative},
+    AleoNetworkClient,
+};
+
+use js_sys::Array;
+use wasm_bindgen::prelude::*;
+
+/// Scanner which fetches blocks from an Aleo node and finds the records owned by a view key
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct RecordScanner {
+    view_key: ViewKey,
+    client: AleoNetworkClient,
+    program_id: Option<String>,
+    chunk_size: u32,
+}
+
+#[wasm_bindgen]
+impl RecordScanner {
+    /// Create a record scanner for a view key
+    ///
+    /// @param {ViewKey} view_key View key of the account to find records for
+    /// @param {string} url The url of the Aleo network node to fetch blocks from
+    /// @param {string | undefined} program_id (optional) Only find records created by this program
+    /// @returns {RecordScanner} Record scanner
+    #[wasm_bindgen(constructor)]
+    pub fn new(view_key: &ViewKey, url: &str, program_id: Option<String>) -> Self {
+        Self {
+            view_key: view_key.clone(),
+            client: AleoNetworkClient::new(url),
+            program_id,
+            chunk_size: MAX_BLOCKS_PER_REQUEST,
+        }
+    }
+
+    /// Set the number of blocks fetched from the node per request
+    ///
+    /// @param {number} chunk_size Number of blocks per request, between 1 and 50, 50 by default
+    #[wasm_bindgen(js_name = setChunkSize)]
+    pub fn set_chunk_size(&mut self, chunk_size: u32) -> Result<(), String> {
+        if chunk_size == 0 || chunk_size > MAX_BLOCKS_PER_REQUEST {
+            return Err(format!("The chunk size must be between 1 and {MAX_BLOCKS_PER_REQUEST}"));
+        }
+        self.chunk_size = chunk_size;
+        Ok(())
+    }
+
+    /// Scan the blocks in the height range `[start_height, end_height)` for records owned by the
+    /// view key. If `max_blocks` is given at most that many blocks are scanned, and the cursor of
+    /// the result can be used as the start height of the next scan to resume where it stopped.
+    /// If fetching blocks fails part way, the result holds the records found before the failure,
+    /// the height of the first block which was not scanned and the error
+    ///
+    /// @param {number} start_height Height of the first block to scan
+    /// @param {number} end_height Height after the last block to scan
+    /// @param {number | undefined} max_blocks (optional) Maximum number of blocks to scan
+    /// @returns {ScanResult | Error} Records found, the height to resume scanning from and the
+    /// error which stopped the scan if any
+    pub async fn scan(
+        &self,
+        start_height: u32,
+        end_height: u32,
+        max_blocks: Option<u32>,
+    ) -> Result<ScanResult, String> {
+        if start_height > end_height {
+            return Err(format!("Invalid block range - start height {start_height} is after end height {end_height}"));
+        }
+        let end_height = match max_blocks {
+            Some(max_blocks) => end_height.min(start_height.saturating_add(max_blocks)),
+            None => end_height,
+        };
+        let mut records = Vec::new();
+        let mut cursor = start_height;
+        while cursor < end_height {
+            let chunk_end = end_height.min(cursor.saturating_add(self.chunk_size));
+            log(&format!("Scanning blocks {cursor} to {chunk_end} for records"));
+            let blocks = match self.client.blocks(cursor, chunk_end).await {
+                Ok(blocks) => blocks,
+                Err(error) => return Ok(ScanResult { records, cursor, error: Some(error) }),
+            };
+            for block in blocks {
+                records.extend(self.find_records(&block));
+            }
+            cursor = chunk_end;
+        }
+        Ok(ScanResult { records, cursor, error: None })
+    }
+}
+
+impl RecordScanner {
+    // Find and decrypt the records owned by the view key in a block
+    pub(crate) fn find_records(&self, block: &BlockNative) -> Vec<ScannedRecord> {
+        let mut records = Vec::new();
+        for confirmed in block.transactions().iter() {
+            let transaction = confirmed.transaction();
+            for transition in transaction.transitions() {
+                if let Some(program_id) = &self.program_id {
+                    if &transition.program_id().to_string() != program_id {
+                        continue;
+                    }
+                }
+                for (commitment, record) in transition.records() {
+                    if !record.is_owner(&self.view_key) {
+                        continue;
+                    }
+                    if let Ok(record) = record.decrypt(&self.view_key) {
+                        records.push(ScannedRecord {
+                            record,
+                            commitment: commitment.to_string(),
+                            program_id: transition.program_id().to_string(),
+                            function_name: transition.function_name().to_string(),
+                            transition_id: transition.id().to_string(),
+                            transaction_id: transaction.id().to_string(),
+                            height: block.height(),
+                        });
+                    }
+                }
+            }
+        }
+        records
+    }
+}
+
+/// Result of a record scan
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct ScanResult {
+    records: Vec<ScannedRecord>,
+    cursor: u32,
+    error: Option<String>,
+}
+
+impl ScanResult {
+    /// Get the decrypted records found by a scan which completed
+    pub(crate) fn into_records(self) -> Result<Vec<RecordPlaintext>, String> {
+        if let Some(error) = self.error {
+            return Err(format!("The scan stopped at block {}: {error}", self.cursor));
+        }
+        Ok(self.records.into_iter().map(|record| RecordPlaintext::from(record.record)).collect())
+    }
+}
+
+#[wasm_bindgen]
+impl ScanResult {
+    /// Get the records found by the scan
+    ///
+    /// @returns {Array<ScannedRecord>} Records owned by the view key
+    pub fn records(&self) -> Array {
+        self.records.iter().cloned().map(JsValue::from).collect()
+    }
+
+    /// Get the height of the next block to scan to resume the scan
+    ///
+    /// @returns {number} Height to resume scanning from
+    pub fn cursor(&self) -> u32 {
+        self.cursor
+    }
+
+    /// Get the error which stopped the scan before the end height, if any
+    ///
+    /// @returns {string | undefined} Error message
+    pub fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+}
+
+/// Record owned by a view key found by a record scanner
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct ScannedRecord {
+    record: RecordPlaintextNative,
+    commitment: String,
+    program_id: String,
+    function_name: String,
+    transition_id: String,
+    transaction_id: String,
+    height: u32,
+}
+
+#[wasm_bindgen]
+impl ScannedRecord {
+    /// Get the decrypted record
+    ///
+    /// @returns {RecordPlaintext} Record plaintext
+    pub fn record(&self) -> RecordPlaintext {
+        RecordPlaintext::from(self.record.clone())
+    }
+
+    /// Get the commitment of the record
+    ///
+    /// @returns {string} Record commitment
+    pub fn commitment(&self) -> String {
+        self.commitment.clone()
+    }
+
+    /// Get the id of the program which created the record
+    ///
+    /// @returns {string} Program id
+    #[wasm_bindgen(js_name = programId)]
+    pub fn program_id(&self) -> String {
+        self.program_id.clone()
+    }
+
+    /// Get the name of the function which created the record
+    ///
+    /// @returns {string} Function name
+    #[wasm_bindgen(js_name = functionName)]
+    pub fn function_name(&self) -> String {
+        self.function_name.clone()
+    }
+
+    /// Get the id of the transition which created the record
+    ///
+    /// @returns {string} Transition id
+    #[wasm_bindgen(js_name = transitionId)]
+    pub fn transition_id(&self) -> String {
+        self.transition_id.clone()
+    }
+
+    /// Get the id of the transaction which created the record
+    ///
+    /// @returns {string} Transaction id
+    #[wasm_bindgen(js_name = transactionId)]
+    pub fn transaction_id(&self) -> String {
+        self.transaction_id.clone()
+    }
+
+    /// Get the height of the block containing the record
+    ///
+    /// @returns {number} Block height
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::account::PrivateKey;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    async fn test_empty_and_invalid_ranges() {
+        let view_key = PrivateKey::new().to_view_key();
+        let scanner = RecordScanner::new(&view_key, "https://api.explorer.aleo.org/v1/", None);
+        assert_eq!(scanner.client.host(), "https://api.explorer.aleo.org/v1");
+
+        // Check empty ranges are scanned without contacting the node.
+        let result = scanner.scan(10, 10, None).await.unwrap();
+        assert_eq!(result.cursor(), 10);
+        assert_eq!(result.records().length(), 0);
+        let result = scanner.scan(10, 20, Some(0)).await.unwrap();
+        assert_eq!(result.cursor(), 10);
+        assert!(result.error().is_none());
+
+        // Ensure inverted ranges are rejected.
+        assert!(scanner.scan(20, 10, None).await.is_err());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_partial_scan() {
+        let view_key = PrivateKey::new().to_view_key();
+        let mut scanner = RecordScanner::new(&view_key, "http://localhost:1", None);
+        assert!(scanner.set_chunk_size(0).is_err());
+        assert!(scanner.set_chunk_size(MAX_BLOCKS_PER_REQUEST + 1).is_err());
+        scanner.set_chunk_size(10).unwrap();
+
+        // Check a scan which cannot reach the node reports where it stopped instead of failing.
+        let result = scanner.scan(100, 120, None).await.unwrap();
+        assert_eq!(result.cursor(), 100);
+        assert_eq!(result.records().length(), 0);
+        assert!(result.error().is_some());
+        assert!(result.into_records().is_err());
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::RecordPlaintext;
+use crate::types::RecordPlaintextNative;
+
+use js_sys::Array;
+use wasm_bindgen::prelude::*;
+
+/// Record along with the program and record name it belongs to
+#[derive(Clone)]
+struct RecordSetEntry {
+    record: RecordPlaintextNative,
+    program_id: String,
+    record_name: String,
+}
+
+/// Collection of records which can be filtered and aggregated without converting each record to
+/// javascript
+#[wasm_bindgen]
+#[derive(Clone, Default)]
+pub struct RecordSet(Vec<RecordSetEntry>);
+
+#[wasm_bindgen]
+impl RecordSet {
+    /// Create an empty record set
+    ///
+    /// @returns {RecordSet} Empty record set
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a record to the set
+    ///
+    /// @param {RecordPlaintext} record The record to add
+    /// @param {string} program_id Program ID of the program that the record is associated with
+    /// @param {string} record_name Name of the record
+    pub fn add(&mut self, record: &RecordPlaintext, program_id: &str, record_name: &str) {
+        self.0.push(RecordSetEntry {
+            record: (**record).clone(),
+            program_id: program_id.to_string(),
+            record_name: record_name.to_string(),
+        });
+    }
+
+    /// Get the number of records in the set
+    ///
+    /// @returns {number} Number of records
+    pub fn length(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Get the records in the set
+    ///
+    /// @returns {Array<RecordPlaintext>} Records in the set
+    pub fn records(&self) -> Array {
+        self.0.iter().map(|entry| JsValue::from(RecordPlaintext::from(entry.record.clone()))).collect()
+    }
+
+    /// Get the records associated with a program
+    ///
+    /// @param {string} program_id Program ID to filter by
+    /// @returns {RecordSet} Records associated with the program
+    #[wasm_bindgen(js_name = filterByProgram)]
+    pub fn filter_by_program(&self, program_id: &str) -> RecordSet {
+        self.filter(|entry| entry.program_id == program_id)
+    }
+
+    /// Get the records with a record name
+    ///
+    /// @param {string} record_name Record name to filter by
+    /// @returns {RecordSet} Records with the record name
+    #[wasm_bindgen(js_name = filterByRecordName)]
+    pub fn filter_by_record_name(&self, record_name: &str) -> RecordSet {
+        self.filter(|entry| entry.record_name == record_name)
+    }
+
+    /// Get the records holding at least a given amount of microcredits
+    ///
+    /// @param {number} min_microcredits Minimum amount of microcredits
+    /// @returns {RecordSet} Records holding at least the amount of microcredits
+    #[wasm_bindgen(js_name = filterByMinMicrocredits)]
+    pub fn filter_by_min_microcredits(&self, min_microcredits: u64) -> RecordSet {
+        self.filter(|entry| entry.record.microcredits().map_or(false, |microcredits| microcredits >= min_microcredits))
+    }
+
+    /// Get the total amount of microcredits held by the records in the set. Records without
+    /// microcredits are ignored
+    ///
+    /// @returns {number | Error} Total amount of microcredits
+    #[wasm_bindgen(js_name = totalMicrocredits)]
+    pub fn total_microcredits(&self) -> Result<u64, String> {
+        self.0.iter().filter_map(|entry| entry.record.microcredits().ok()).try_fold(0u64, |total, microcredits| {
+            total.checked_add(microcredits).ok_or_else(|| "The total amount of microcredits overflowed".to_string())
+        })
+    }
+}
+
+impl RecordSet {
+    // Get the records matching a predicate
+    fn filter(&self, predicate: impl Fn(&RecordSetEntry) -> bool) -> RecordSet {
+        Self(self.0.iter().filter(|entry| predicate(entry)).cloned().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::str::FromStr;
+    use wasm_bindgen_test::*;
+
+    const RECORD: &str = r"{
+  owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3.private,
+  microcredits: 1500000000000000u64.private,
+  _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public
+}";
+    const TOKEN_RECORD: &str = r"{
+  owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3.private,
+  amount: 100u64.private,
+  _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public
+}";
+
+    #[wasm_bindgen_test]
+    fn test_filter_and_total() {
+        let mut records = RecordSet::new();
+        records.add(&RecordPlaintext::from_str(RECORD).unwrap(), "credits.aleo", "credits");
+        records.add(&RecordPlaintext::from_str(RECORD).unwrap(), "credits.aleo", "credits");
+        records.add(&RecordPlaintext::from_str(TOKEN_RECORD).unwrap(), "token.aleo", "token");
+        assert_eq!(records.length(), 3);
+        assert_eq!(records.total_microcredits().unwrap(), 3000000000000000);
+
+        let credits = records.filter_by_program("credits.aleo");
+        assert_eq!(credits.length(), 2);
+        assert_eq!(records.filter_by_record_name("token").length(), 1);
+        assert_eq!(records.filter_by_min_microcredits(1500000000000000).length(), 2);
+        assert_eq!(records.filter_by_min_microcredits(1500000000000001).length(), 0);
+        assert_eq!(records.filter_by_program("token.aleo").total_microcredits().unwrap(), 0);
+        assert_eq!(credits.records().length(), 2);
+    }
+}
@@ -15,9 +15,14 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 use futures::future::try_join_all;
+use once_cell::sync::Lazy;
 use rayon::ThreadBuilder;
 use spmc::{channel, Receiver, Sender};
-use std::future::Future;
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 
@@ -59,36 +64,60 @@ extern "C" {
     ) -> js_sys::Promise;
 }
 
-async fn spawn_workers(url: web_sys::Url, num_threads: usize) -> Result<Sender<ThreadBuilder>, JsValue> {
-    let module = wasm_bindgen::module();
-    let memory = wasm_bindgen::memory();
+// Web workers hosting rayon threads along with the channel used to hand threads to them
+struct Workers {
+    url: String,
+    sender: Sender<ThreadBuilder>,
+    receiver: &'static Receiver<ThreadBuilder>,
+    spawned: usize,
+    global_threads: usize,
+}
 
-    let (sender, receiver) = channel();
+static WORKERS: Mutex<Option<Workers>> = Mutex::new(None);
 
-    let receiver = Box::leak(Box::new(receiver));
+// Thread pool replacing the global pool after it has been resized
+static POOL: Mutex<Option<Arc<rayon::ThreadPool>>> = Mutex::new(None);
+
+// Thread pools limiting the parallelism of sections such as proving or record scanning
+static BUDGETS: Lazy<Mutex<HashMap<String, Arc<rayon::ThreadPool>>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+async fn spawn_workers(
+    url: &web_sys::Url,
+    receiver: &'static Receiver<ThreadBuilder>,
+    num_threads: usize,
+) -> Result<(), JsValue> {
+    let module = wasm_bindgen::module();
+    let memory = wasm_bindgen::memory();
 
     let workers =
-        try_join_all((0..num_threads).map(|_| JsFuture::from(spawn_worker(&url, &module, &memory, receiver)))).await?;
+        try_join_all((0..num_threads).map(|_| JsFuture::from(spawn_worker(url, &module, &memory, receiver)))).await?;
 
     // Needed to work around a Firefox bug where Workers get garbage collected too early
     // https://bugzilla.mozilla.org/show_bug.cgi?id=1592227
     std::mem::forget(workers);
 
-    Ok(sender)
+    Ok(())
 }
 
 async fn spawn_global_thread_pool(url: web_sys::Url, num_threads: usize) -> Result<(), JsValue> {
-    if num_threads == 1 {
+    let (sender, receiver) = channel();
+    let receiver = Box::leak(Box::new(receiver));
+
+    let global_threads = if num_threads == 1 {
         rayon::ThreadPoolBuilder::new().num_threads(1).use_current_thread().build_global().unwrap_throw();
+        0
     } else {
-        let mut sender = spawn_workers(url, num_threads).await?;
+        spawn_workers(&url, receiver, num_threads).await?;
+        num_threads
+    };
+
+    *WORKERS.lock().unwrap() =
+        Some(Workers { url: url.href(), sender, receiver, spawned: global_threads, global_threads });
 
+    if num_threads > 1 {
         rayon::ThreadPoolBuilder::new()
             .num_threads(num_threads)
-            .spawn_handler(move |thread| {
-                sender.send(thread).unwrap_throw();
-                Ok(())
-            })
+            .spawn_handler(send_thread)
             .build_global()
             .unwrap_throw();
     }
@@ -96,6 +125,101 @@ async fn spawn_global_thread_pool(url: web_sys::Url, num_threads: usize) -> Resu
     Ok(())
 }
 
+// Hand a rayon thread to the next idle worker
+fn send_thread(thread: ThreadBuilder) -> std::io::Result<()> {
+    let mut workers = WORKERS.lock().unwrap();
+    let workers = workers
+        .as_mut()
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::Other, "The thread pool has not been initialized"))?;
+    workers
+        .sender
+        .send(thread)
+        .map_err(|_| std::io::Error::new(std::io::ErrorKind::Other, "The thread pool workers have stopped"))
+}
+
+// Build a thread pool whose threads run on the workers of the module
+fn build_pool(num_threads: usize) -> Result<Arc<rayon::ThreadPool>, JsValue> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .spawn_handler(send_thread)
+        .build()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Arc::new(pool))
+}
+
+// Spawn workers until there is one for every thread of the global pool and the pools built since
+async fn spawn_missing_workers() -> Result<(), JsValue> {
+    let pool_threads = POOL.lock().unwrap().as_ref().map_or(0, |pool| pool.current_num_threads())
+        + BUDGETS.lock().unwrap().values().map(|pool| pool.current_num_threads()).sum::<usize>();
+    let (url, receiver, missing) = {
+        let workers = WORKERS.lock().unwrap();
+        let workers = workers.as_ref().ok_or("The thread pool has not been initialized")?;
+        (workers.url.clone(), workers.receiver, (workers.global_threads + pool_threads).saturating_sub(workers.spawned))
+    };
+    if missing > 0 {
+        spawn_workers(&web_sys::Url::new(&url)?, receiver, missing).await?;
+        if let Some(workers) = WORKERS.lock().unwrap().as_mut() {
+            workers.spawned += missing;
+        }
+    }
+    Ok(())
+}
+
+/// Get the number of threads the device can run concurrently, falling back to 1 when the
+/// environment does not report it
+pub fn available_concurrency() -> usize {
+    js_sys::Reflect::get(&js_sys::global(), &"navigator".into())
+        .and_then(|navigator| js_sys::Reflect::get(&navigator, &"hardwareConcurrency".into()))
+        .ok()
+        .and_then(|concurrency| concurrency.as_f64())
+        .map_or(1, |concurrency| concurrency as usize)
+        .max(1)
+}
+
+/// Get the number of threads used by parallel sections without a budget of their own
+pub fn thread_pool_size() -> usize {
+    POOL.lock().unwrap().as_ref().map_or_else(rayon::current_num_threads, |pool| pool.current_num_threads())
+}
+
+/// Resize the thread pool used by parallel sections without a budget of their own
+///
+/// The global rayon pool cannot be rebuilt once it is started, so a pool of the requested size
+/// replaces it instead. Passing the size of the global pool goes back to using it.
+pub async fn set_thread_pool_size(num_threads: usize) -> Result<(), JsValue> {
+    let pool = if num_threads == 0 || num_threads == rayon::current_num_threads() {
+        None
+    } else {
+        Some(build_pool(num_threads)?)
+    };
+    *POOL.lock().unwrap() = pool;
+    spawn_missing_workers().await
+}
+
+/// Limit the number of threads a parallel section such as "proving" or "scanning" may use, or
+/// remove its budget when no number of threads is given
+pub async fn set_thread_budget(section: &str, num_threads: Option<usize>) -> Result<(), JsValue> {
+    match num_threads.filter(|num_threads| *num_threads > 0) {
+        Some(num_threads) => {
+            let pool = build_pool(num_threads)?;
+            BUDGETS.lock().unwrap().insert(section.to_string(), pool);
+        }
+        None => {
+            BUDGETS.lock().unwrap().remove(section);
+        }
+    }
+    spawn_missing_workers().await
+}
+
+/// Run a parallel section on the thread pool of its budget, or on the resized or global pool
+/// when the section has no budget
+pub(crate) fn with_thread_budget<R: Send>(section: &str, op: impl FnOnce() -> R + Send) -> R {
+    let pool = BUDGETS.lock().unwrap().get(section).cloned().or_else(|| POOL.lock().unwrap().clone());
+    match pool {
+        Some(pool) => pool.install(op),
+        None => op(),
+    }
+}
+
 pub struct ThreadPool {
     url: Option<web_sys::Url>,
     num_threads: Option<usize>,
@@ -117,13 +241,7 @@ impl ThreadPool {
     }
 
     fn defaults(self) -> (web_sys::Url, usize) {
-        (
-            self.url.expect("Missing url for ThreadPool"),
-            self.num_threads.unwrap_or_else(|| {
-                let window: web_sys::Window = js_sys::global().unchecked_into();
-                window.navigator().hardware_concurrency() as usize
-            }),
-        )
+        (self.url.expect("Missing url for ThreadPool"), self.num_threads.unwrap_or_else(available_concurrency))
     }
 
     pub fn build_global(self) -> impl Future<Output = Result<(), JsValue>> {
@@ -141,5 +259,8 @@ where
 {
     // This is safe because it uses `Box::leak` so the Receiver lives forever
     let receiver = unsafe { &*receiver };
-    receiver.recv().unwrap_throw().run();
+    // Keep serving threads so the worker can be reused by pools built after the global pool
+    while let Ok(thread) = receiver.recv() {
+        thread.run();
+    }
 }
@@ -16,31 +16,40 @@
 
 pub use snarkvm_circuit_network::{Aleo, AleoV0};
 pub use snarkvm_console::{
-    account::{Address, PrivateKey, Signature, ViewKey},
+    account::{Address, ComputeKey, GraphKey, PrivateKey, Signature, ViewKey},
     network::{Network, Testnet3},
+    prelude::{FromBits, Inverse, Parser, SizeInDataBits, ToBits, ToFields},
     program::{
         Ciphertext,
         Entry,
         EntryType,
         Identifier,
+        InputID,
         Literal,
+        LiteralType,
+        Owner,
         Plaintext,
         PlaintextType,
         ProgramID,
         ProgramOwner,
         Record,
+        Request,
         Response,
+        StatePath,
+        Value,
         ValueType,
     },
-    types::Field,
+    types::{Field, Group, Scalar, U16},
 };
-pub use snarkvm_ledger_block::{Execution, Transaction};
-pub use snarkvm_ledger_query::Query;
+pub use snarkvm_ledger_block::{Block, Deployment, Execution, Fee, Input, Output, Transaction, Transition};
+pub use snarkvm_ledger_query::{Query, QueryTrait};
 pub use snarkvm_ledger_store::helpers::memory::BlockMemory;
 pub use snarkvm_synthesizer::{
     cost_in_microcredits,
     deployment_cost,
+    program::{Command, Instruction, Operand},
     snark::{ProvingKey, VerifyingKey},
+    Authorization,
     Process,
     Program,
     VM,
@@ -53,12 +62,20 @@ pub use snarkvm_wasm::{
 
 // Account types
 pub type AddressNative = Address<CurrentNetwork>;
+pub type ComputeKeyNative = ComputeKey<CurrentNetwork>;
+pub type GraphKeyNative = GraphKey<CurrentNetwork>;
 pub type PrivateKeyNative = PrivateKey<CurrentNetwork>;
 pub type SignatureNative = Signature<CurrentNetwork>;
 pub type ViewKeyNative = ViewKey<CurrentNetwork>;
 
 // Algebraic types
 pub type FieldNative = Field<CurrentNetwork>;
+pub type GroupNative = Group<CurrentNetwork>;
+pub type ScalarNative = Scalar<CurrentNetwork>;
+
+// Ledger types
+pub type BlockNative = Block<CurrentNetwork>;
+pub type BlockHashNative = <CurrentNetwork as Network>::BlockHash;
 
 // Network types
 pub type CurrentNetwork = Testnet3;
@@ -66,21 +83,38 @@ pub type CurrentAleo = AleoV0;
 
 // Record types
 pub type CiphertextNative = Ciphertext<CurrentNetwork>;
+pub type EntryNative = Entry<CurrentNetwork, PlaintextNative>;
+pub type OwnerNative = Owner<CurrentNetwork, PlaintextNative>;
 pub type PlaintextNative = Plaintext<CurrentNetwork>;
 pub type RecordCiphertextNative = Record<CurrentNetwork, CiphertextNative>;
 pub type RecordPlaintextNative = Record<CurrentNetwork, PlaintextNative>;
 
+// State types
+pub type StatePathNative = StatePath<CurrentNetwork>;
+pub type StateRootNative = <CurrentNetwork as Network>::StateRoot;
+
 // Program types
 type CurrentBlockMemory = BlockMemory<CurrentNetwork>;
+pub type AuthorizationNative = Authorization<CurrentNetwork>;
+pub type CommandNative = Command<CurrentNetwork>;
+pub type DeploymentNative = Deployment<CurrentNetwork>;
 pub type ExecutionNative = Execution<CurrentNetwork>;
+pub type FeeNative = Fee<CurrentNetwork>;
 pub type IdentifierNative = Identifier<CurrentNetwork>;
+pub type InputIDNative = InputID<CurrentNetwork>;
+pub type InstructionNative = Instruction<CurrentNetwork>;
 pub type LiteralNative = Literal<CurrentNetwork>;
+pub type OperandNative = Operand<CurrentNetwork>;
 pub type ProcessNative = Process<CurrentNetwork>;
 pub type ProgramIDNative = ProgramID<CurrentNetwork>;
 pub type ProgramNative = Program<CurrentNetwork>;
 pub type ProgramOwnerNative = ProgramOwner<CurrentNetwork>;
 pub type ProvingKeyNative = ProvingKey<CurrentNetwork>;
 pub type QueryNative = Query<CurrentNetwork, CurrentBlockMemory>;
+pub type RequestNative = Request<CurrentNetwork>;
 pub type ResponseNative = Response<CurrentNetwork>;
 pub type TransactionNative = Transaction<CurrentNetwork>;
+pub type TransitionNative = Transition<CurrentNetwork>;
+pub type ValueNative = Value<CurrentNetwork>;
+pub type ValueTypeNative = ValueType<CurrentNetwork>;
 pub type VerifyingKeyNative = VerifyingKey<CurrentNetwork>;
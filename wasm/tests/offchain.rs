@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use aleo_wasm::{PrivateKey, Program, ProgramManager, RecordPlaintext};
+use aleo_wasm::{PrivateKey, Program, ProgramManager, RecordPlaintext, TransferOptions};
 use js_sys::{Array, Object, Reflect};
 use wasm_bindgen::JsValue;
 use wasm_bindgen_test::*;
@@ -215,6 +215,21 @@ async fn test_fee_validation() {
     .await;
     assert!(transfer.is_err());
 
+    // Ensure the fee is still validated when records would be searched for but both are provided
+    let mut options = TransferOptions::new(
+        &private_key,
+        500_000,
+        "aleo184vuwr5u7u0ha5f5k44067dd2uaqewxx6pe5ltha5pv99wvhfqxqv339h4",
+        "private",
+        "https://vm.aleo.org/api",
+    );
+    options.set_amount_record(&fee_record);
+    options.set_priority_fee(100_000_000);
+    options.set_fee_record(&fee_record);
+    options.set_find_records(true);
+    let transfer = ProgramManager::transfer_with_options(&options).await;
+    assert!(transfer.is_err());
+
     // Ensure join fails when fee amount is greater than the balance available in the record
     let join = ProgramManager::join(
         &private_key,